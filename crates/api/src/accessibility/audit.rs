@@ -0,0 +1,310 @@
+use std::fmt;
+
+use dces::prelude::*;
+
+use crate::{
+    tree::Tree,
+    utils::{Brush, Color, String16, Visibility},
+};
+
+/// Minimal contrast ratio between the foreground and the background of normal text (WCAG AA).
+pub const MIN_CONTRAST_RATIO: f64 = 4.5;
+
+/// Describes how serious an accessibility issue is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The widget is hard to use with assistive technologies.
+    Warning,
+
+    /// The widget could not be used with assistive technologies.
+    Critical,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+/// Describes an accessibility issue of a widget that is found by the `AccessibilityAudit`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditIssue {
+    /// The widget with the issue.
+    pub entity: Entity,
+
+    /// Describes how serious the issue is.
+    pub severity: Severity,
+
+    /// Describes the issue.
+    pub message: String,
+}
+
+impl fmt::Display for AuditIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Accessibility {} (entity: {}): {}",
+            self.severity, self.entity.0, self.message
+        )
+    }
+}
+
+/// The `AccessibilityAudit` checks the widgets of a window for common accessibility issues:
+///
+/// * widgets with an `accessible_name` property (e.g. `Button`, `TextBox` and `Slider`) that have
+///   no name and no text, water mark or icon that could be used as name
+/// * widgets with an `alt_text` property (e.g. `ImageWidget`) without alternative text
+/// * text with a contrast ratio to its background below 4.5:1
+///
+/// In debug builds the audit runs when a window is initialized and logs the issues. See
+/// `Application::enable_accessibility_audit`.
+pub struct AccessibilityAudit;
+
+impl AccessibilityAudit {
+    /// Runs the audit for the given widget and all of its visible descendants.
+    pub fn run(
+        ecm: &EntityComponentManager<Tree, StringComponentStore>,
+        entity: Entity,
+    ) -> Vec<AuditIssue> {
+        let mut issues = vec![];
+        let mut stack = vec![entity];
+
+        while let Some(current) = stack.pop() {
+            if let Ok(visibility) = ecm
+                .component_store()
+                .get::<Visibility>("visibility", current)
+            {
+                if *visibility != Visibility::Visible {
+                    continue;
+                }
+            }
+
+            check_accessible_name(ecm, current, &mut issues);
+            check_alt_text(ecm, current, &mut issues);
+            check_contrast(ecm, current, &mut issues);
+
+            if let Some(children) = ecm.entity_store().children.get(&current) {
+                stack.extend(children.iter().rev());
+            }
+        }
+
+        issues
+    }
+}
+
+// Checks if a widget that should be named has a name or a text that could be used as name.
+fn check_accessible_name(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    issues: &mut Vec<AuditIssue>,
+) {
+    let store = ecm.component_store();
+
+    let accessible_name = match store.get::<String>("accessible_name", entity) {
+        Ok(accessible_name) => accessible_name,
+        Err(_) => return,
+    };
+
+    if !accessible_name.trim().is_empty() {
+        return;
+    }
+
+    let has_label = ["text", "water_mark"].iter().any(|key| {
+        store
+            .get::<String16>(key, entity)
+            .map_or(false, |text| !text.as_string().trim().is_empty())
+    }) || store
+        .get::<String>("icon", entity)
+        .map_or(false, |icon| !icon.is_empty());
+
+    if !has_label {
+        issues.push(AuditIssue {
+            entity,
+            severity: Severity::Critical,
+            message: format!(
+                "{} has no accessible name. Set `accessible_name` or a text.",
+                widget_name(ecm, entity)
+            ),
+        });
+    }
+}
+
+// Checks if an image has an alternative text.
+fn check_alt_text(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    issues: &mut Vec<AuditIssue>,
+) {
+    if let Ok(alt_text) = ecm.component_store().get::<String>("alt_text", entity) {
+        if alt_text.trim().is_empty() {
+            issues.push(AuditIssue {
+                entity,
+                severity: Severity::Warning,
+                message: format!(
+                    "{} has no alternative text. Set `alt_text`.",
+                    widget_name(ecm, entity)
+                ),
+            });
+        }
+    }
+}
+
+// Checks the contrast of a text to its background. Text is drawn by the leaves of the tree, the
+// background is the first solid background of the widget or of one of its parents.
+fn check_contrast(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    issues: &mut Vec<AuditIssue>,
+) {
+    let store = ecm.component_store();
+
+    if ecm
+        .entity_store()
+        .children
+        .get(&entity)
+        .map_or(false, |children| !children.is_empty())
+    {
+        return;
+    }
+
+    if store
+        .get::<String16>("text", entity)
+        .map_or(true, |text| text.is_empty())
+    {
+        return;
+    }
+
+    let foreground = match store.get::<Brush>("foreground", entity) {
+        Ok(Brush::SolidColor(color)) if color.a() > 0 => *color,
+        _ => return,
+    };
+
+    let mut current = Some(entity);
+
+    while let Some(parent) = current {
+        if let Ok(Brush::SolidColor(background)) = store.get::<Brush>("background", parent) {
+            if background.a() == 255 {
+                let ratio = contrast_ratio(foreground, *background);
+
+                if ratio < MIN_CONTRAST_RATIO {
+                    issues.push(AuditIssue {
+                        entity,
+                        severity: Severity::Warning,
+                        message: format!(
+                            "{} has a contrast ratio of {:.2}:1 to its background, expected at least {}:1.",
+                            widget_name(ecm, entity),
+                            ratio,
+                            MIN_CONTRAST_RATIO
+                        ),
+                    });
+                }
+
+                return;
+            }
+        }
+
+        current = ecm.entity_store().parent.get(&parent).copied().flatten();
+    }
+}
+
+fn widget_name(ecm: &EntityComponentManager<Tree, StringComponentStore>, entity: Entity) -> String {
+    ecm.component_store()
+        .get::<String>("name", entity)
+        .map_or_else(|_| "Widget".to_string(), |name| name.clone())
+}
+
+// Returns the relative luminance of the given color as defined by WCAG 2.0.
+fn relative_luminance(color: Color) -> f64 {
+    let channel = |value: u8| {
+        let value = value as f64 / 255.0;
+
+        if value <= 0.03928 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * channel(color.r()) + 0.7152 * channel(color.g()) + 0.0722 * channel(color.b())
+}
+
+/// Calculates the contrast ratio between two colors as defined by WCAG 2.0. The ratio is between
+/// `1.0` (same luminance) and `21.0` (black and white).
+pub fn contrast_ratio(a: Color, b: Color) -> f64 {
+    let a = relative_luminance(a);
+    let b = relative_luminance(b);
+
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render::RenderContext2D;
+
+    use super::*;
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = Color::rgb(0, 0, 0);
+        let white = Color::rgb(255, 255, 255);
+
+        assert!((contrast_ratio(black, white) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(white, black) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(white, white) - 1.0).abs() < 0.01);
+        assert!(contrast_ratio(Color::rgb(118, 118, 118), white) >= MIN_CONTRAST_RATIO);
+        assert!(contrast_ratio(Color::rgb(150, 150, 150), white) < MIN_CONTRAST_RATIO);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+
+        let root = ecm.create_entity().build();
+        ecm.component_store_mut()
+            .register("background", root, Brush::from("#ffffff"));
+
+        let button = ecm.create_entity().build();
+        ecm.component_store_mut()
+            .register("accessible_name", button, String::default());
+        ecm.entity_store_mut().append_child(root, button).unwrap();
+
+        let named = ecm.create_entity().build();
+        ecm.component_store_mut()
+            .register("accessible_name", named, String::from("Save"));
+        ecm.entity_store_mut().append_child(root, named).unwrap();
+
+        let image = ecm.create_entity().build();
+        ecm.component_store_mut()
+            .register("alt_text", image, String::default());
+        ecm.entity_store_mut().append_child(root, image).unwrap();
+
+        let text = ecm.create_entity().build();
+        ecm.component_store_mut()
+            .register("text", text, String16::from("Hello"));
+        ecm.component_store_mut()
+            .register("foreground", text, Brush::from("#eeeeee"));
+        ecm.entity_store_mut().append_child(root, text).unwrap();
+
+        let hidden = ecm.create_entity().build();
+        ecm.component_store_mut()
+            .register("accessible_name", hidden, String::default());
+        ecm.component_store_mut()
+            .register("visibility", hidden, Visibility::Collapsed);
+        ecm.entity_store_mut().append_child(root, hidden).unwrap();
+
+        let issues = AccessibilityAudit::run(ecm, root);
+
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0].entity, button);
+        assert_eq!(issues[0].severity, Severity::Critical);
+        assert_eq!(issues[1].entity, image);
+        assert_eq!(issues[1].severity, Severity::Warning);
+        assert_eq!(issues[2].entity, text);
+        assert_eq!(issues[2].severity, Severity::Warning);
+    }
+}
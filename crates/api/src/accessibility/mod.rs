@@ -0,0 +1,5 @@
+//! This module contains tools to check the accessibility of the widgets of a window.
+
+pub use self::audit::*;
+
+mod audit;
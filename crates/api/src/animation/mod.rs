@@ -0,0 +1,9 @@
+//! This module contains the animation models that could be used to animate widget properties.
+//!
+pub use self::spring::*;
+pub use self::transition::*;
+pub use self::tween::*;
+
+mod spring;
+mod transition;
+mod tween;
@@ -0,0 +1,223 @@
+/// Distance and velocity threshold that is used to detect if a spring has come to rest.
+pub const SPRING_REST_THRESHOLD: f64 = 0.01;
+
+/// Describes the physical parameters of a spring animation.
+///
+/// The spring is simulated by integrating the damped harmonic oscillator equation
+/// `a = (target - pos) * stiffness / mass - velocity * damping / mass` each frame.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SpringAnimation {
+    /// The stiffness of the spring. Higher values results in a faster motion.
+    pub stiffness: f64,
+
+    /// The damping of the spring. Higher values reduces the oscillation.
+    pub damping: f64,
+
+    /// The mass of the object attached to the spring.
+    pub mass: f64,
+
+    /// The target value the spring moves to.
+    pub target: f64,
+
+    /// The velocity of the spring on start.
+    pub initial_velocity: f64,
+}
+
+impl Default for SpringAnimation {
+    fn default() -> Self {
+        SpringAnimation {
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 1.0,
+            target: 0.0,
+            initial_velocity: 0.0,
+        }
+    }
+}
+
+impl SpringAnimation {
+    /// Creates a new spring animation with default parameters that moves to the given target.
+    pub fn new(target: f64) -> Self {
+        SpringAnimation {
+            target,
+            ..Default::default()
+        }
+    }
+
+    /// Builder method that is used to set the stiffness of the spring.
+    pub fn stiffness(mut self, stiffness: f64) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// Builder method that is used to set the damping of the spring.
+    pub fn damping(mut self, damping: f64) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Builder method that is used to set the mass of the spring.
+    pub fn mass(mut self, mass: f64) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// Builder method that is used to set the initial velocity of the spring.
+    pub fn initial_velocity(mut self, initial_velocity: f64) -> Self {
+        self.initial_velocity = initial_velocity;
+        self
+    }
+
+    /// Starts the spring animation from the given position.
+    pub fn start(self, pos: f64) -> RunningSpring {
+        RunningSpring {
+            pos,
+            velocity: self.initial_velocity,
+            spring: self,
+        }
+    }
+}
+
+/// Represents a running spring animation with its current position and velocity.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RunningSpring {
+    spring: SpringAnimation,
+    pos: f64,
+    velocity: f64,
+}
+
+impl RunningSpring {
+    /// Gets the current position of the spring.
+    pub fn pos(&self) -> f64 {
+        self.pos
+    }
+
+    /// Gets the current velocity of the spring.
+    pub fn velocity(&self) -> f64 {
+        self.velocity
+    }
+
+    /// Gets the target of the spring.
+    pub fn target(&self) -> f64 {
+        self.spring.target
+    }
+
+    /// Changes the target of the running spring. The current velocity is kept.
+    pub fn set_target(&mut self, target: f64) {
+        self.spring.target = target;
+    }
+
+    /// Returns `true` if the spring has come to rest at its target.
+    pub fn is_finished(&self) -> bool {
+        (self.pos - self.spring.target).abs() < SPRING_REST_THRESHOLD
+            && self.velocity.abs() < SPRING_REST_THRESHOLD
+    }
+
+    /// Advances the spring by the given time step in seconds using a semi-implicit Euler step
+    /// and returns the new position. If the spring comes to rest it snaps to the target.
+    pub fn step(&mut self, delta: f64) -> f64 {
+        if self.is_finished() {
+            self.pos = self.spring.target;
+            self.velocity = 0.0;
+            return self.pos;
+        }
+
+        let spring = &self.spring;
+        let acceleration = (spring.target - self.pos) * spring.stiffness / spring.mass
+            - self.velocity * spring.damping / spring.mass;
+
+        self.velocity += acceleration * delta;
+        self.pos += self.velocity * delta;
+
+        if self.is_finished() {
+            self.pos = self.spring.target;
+            self.velocity = 0.0;
+        }
+
+        self.pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start() {
+        let spring = SpringAnimation::new(1.0).initial_velocity(2.0).start(0.5);
+        assert_eq!(0.5, spring.pos());
+        assert_eq!(2.0, spring.velocity());
+        assert_eq!(1.0, spring.target());
+    }
+
+    #[test]
+    fn test_step() {
+        let mut spring = SpringAnimation::new(1.0).start(0.0);
+
+        let pos = spring.step(1.0 / 60.0);
+        assert!(pos > 0.0 && pos < 1.0);
+        assert!(spring.velocity() > 0.0);
+    }
+
+    #[test]
+    fn test_is_finished() {
+        let mut spring = SpringAnimation::new(1.0).start(0.0);
+        assert!(!spring.is_finished());
+
+        for _ in 0..600 {
+            spring.step(1.0 / 60.0);
+        }
+
+        assert!(spring.is_finished());
+        assert_eq!(1.0, spring.pos());
+        assert_eq!(0.0, spring.velocity());
+    }
+
+    #[test]
+    fn test_settle() {
+        let mut spring = SpringAnimation::new(1.0).start(0.0);
+        let mut steps = 0;
+
+        while !spring.is_finished() {
+            spring.step(1.0 / 60.0);
+            steps += 1;
+            assert!(steps < 600, "spring does not settle");
+        }
+
+        // once settled the spring stays at its target
+        assert_eq!(1.0, spring.step(1.0 / 60.0));
+        assert_eq!(0.0, spring.velocity());
+    }
+
+    #[test]
+    fn test_overshoot() {
+        let max_pos = |spring: SpringAnimation| {
+            let mut spring = spring.start(0.0);
+            let mut max_pos: f64 = 0.0;
+
+            for _ in 0..600 {
+                max_pos = max_pos.max(spring.step(1.0 / 60.0));
+            }
+
+            max_pos
+        };
+
+        // an under damped spring moves beyond its target before it settles
+        assert!(max_pos(SpringAnimation::new(1.0).damping(5.0)) > 1.0);
+
+        // a critically damped spring does not overshoot
+        assert!(max_pos(SpringAnimation::new(1.0).stiffness(100.0).damping(20.0)) <= 1.0);
+    }
+
+    #[test]
+    fn test_set_target() {
+        let mut spring = SpringAnimation::new(1.0).start(0.0);
+        spring.step(1.0 / 60.0);
+
+        let velocity = spring.velocity();
+        spring.set_target(-1.0);
+
+        assert_eq!(-1.0, spring.target());
+        assert_eq!(velocity, spring.velocity());
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use super::EasingFn;
+
+/// Describes how the change of a property value is animated.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TransitionConfig {
+    /// Duration of the transition in milliseconds.
+    pub duration_ms: u64,
+
+    /// Delay in milliseconds before the transition starts.
+    pub delay_ms: u64,
+
+    /// Easing function of the transition.
+    pub easing: EasingFn,
+}
+
+impl TransitionConfig {
+    /// Reads a transition config from a theme value like `{ "duration_ms": 200, "easing": "ease_in_out" }`.
+    pub fn from_value(value: ron::Value) -> Option<Self> {
+        let map = value.into_rust::<HashMap<String, ron::Value>>().ok()?;
+        let mut config = TransitionConfig::default();
+
+        for (key, value) in map {
+            match key.as_str() {
+                "duration_ms" => config.duration_ms = value.into_rust::<u64>().ok()?,
+                "delay_ms" => config.delay_ms = value.into_rust::<u64>().ok()?,
+                "easing" => config.easing = EasingFn::from(value.into_rust::<String>().ok()?),
+                _ => {}
+            }
+        }
+
+        Some(config)
+    }
+}
+
+/// Reads a map of property keys and their transition configs from a theme value.
+///
+/// Invalid entries are skipped.
+pub fn transitions_from_value(value: ron::Value) -> HashMap<String, TransitionConfig> {
+    let mut transitions = HashMap::new();
+
+    if let Ok(map) = value.into_rust::<HashMap<String, ron::Value>>() {
+        for (key, value) in map {
+            if let Some(config) = TransitionConfig::from_value(value) {
+                transitions.insert(key, config);
+            }
+        }
+    }
+
+    transitions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theming::{config::ThemeConfig, Selector, Theme};
+
+    fn transition_of(theme: &str) -> ron::Value {
+        let theme = Theme::from_config(ThemeConfig::from(theme));
+        let properties = theme
+            .properties(&Selector::new("button"), (800.0, 600.0))
+            .unwrap();
+
+        properties.get("transition").cloned().unwrap()
+    }
+
+    #[test]
+    fn test_transitions_from_theme() {
+        let transitions = transitions_from_value(transition_of(
+            r#"
+            Theme (
+                styles: {
+                    "button": (
+                        properties: {
+                            "transition": {
+                                "background": {
+                                    "duration_ms": 200,
+                                    "delay_ms": 50,
+                                    "easing": "ease_in_out",
+                                },
+                                "font_size": {
+                                    "duration_ms": 100,
+                                },
+                            },
+                        },
+                    ),
+                },
+            )
+            "#,
+        ));
+
+        assert_eq!(2, transitions.len());
+        assert_eq!(
+            Some(&TransitionConfig {
+                duration_ms: 200,
+                delay_ms: 50,
+                easing: EasingFn::EaseInOut,
+            }),
+            transitions.get("background")
+        );
+        assert_eq!(
+            Some(&TransitionConfig {
+                duration_ms: 100,
+                ..Default::default()
+            }),
+            transitions.get("font_size")
+        );
+    }
+
+    #[test]
+    fn test_invalid_transitions_from_theme() {
+        let transitions = transitions_from_value(transition_of(
+            r#"
+            Theme (
+                styles: {
+                    "button": (
+                        properties: {
+                            "transition": {
+                                "background": {
+                                    "duration_ms": "fast",
+                                },
+                                "foreground": {
+                                    "duration_ms": 150,
+                                },
+                            },
+                        },
+                    ),
+                },
+            )
+            "#,
+        ));
+
+        assert_eq!(1, transitions.len());
+        assert_eq!(150, transitions["foreground"].duration_ms);
+    }
+}
@@ -0,0 +1,151 @@
+/// Describes the easing function that is used to map the progress of a tween.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EasingFn {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+}
+
+impl Default for EasingFn {
+    fn default() -> Self {
+        EasingFn::Linear
+    }
+}
+
+impl EasingFn {
+    /// Maps the given linear progress between `0.0` and `1.0` to the eased progress.
+    pub fn ease(self, t: f64) -> f64 {
+        let t = t.max(0.0).min(1.0);
+
+        match self {
+            EasingFn::Linear => t,
+            EasingFn::EaseIn => t * t * t,
+            EasingFn::EaseOut => 1.0 - (1.0 - t).powi(3),
+            EasingFn::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+impl From<&str> for EasingFn {
+    fn from(s: &str) -> EasingFn {
+        match s {
+            "ease_in" | "EaseIn" => EasingFn::EaseIn,
+            "ease_out" | "EaseOut" => EasingFn::EaseOut,
+            "ease_in_out" | "EaseInOut" => EasingFn::EaseInOut,
+            _ => EasingFn::Linear,
+        }
+    }
+}
+
+impl From<String> for EasingFn {
+    fn from(s: String) -> EasingFn {
+        EasingFn::from(s.as_str())
+    }
+}
+
+/// A `Tween` interpolates between two values over a fixed duration.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tween {
+    from: f64,
+    to: f64,
+    duration: f64,
+    delay: f64,
+    easing: EasingFn,
+    elapsed: f64,
+}
+
+impl Tween {
+    /// Creates a new linear tween from `from` to `to` with the given duration in seconds.
+    pub fn new(from: f64, to: f64, duration: f64) -> Self {
+        Tween {
+            from,
+            to,
+            duration,
+            delay: 0.0,
+            easing: EasingFn::default(),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Builder method that is used to set the delay in seconds before the tween starts.
+    pub fn delay(mut self, delay: f64) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Builder method that is used to set the easing function of the tween.
+    pub fn easing(mut self, easing: impl Into<EasingFn>) -> Self {
+        self.easing = easing.into();
+        self
+    }
+
+    /// Gets the eased progress of the tween between `0.0` and `1.0`.
+    pub fn progress(&self) -> f64 {
+        if self.duration <= 0.0 {
+            return if self.elapsed >= self.delay { 1.0 } else { 0.0 };
+        }
+
+        self.easing
+            .ease((self.elapsed - self.delay) / self.duration)
+    }
+
+    /// Gets the current value of the tween.
+    pub fn value(&self) -> f64 {
+        self.from + (self.to - self.from) * self.progress()
+    }
+
+    /// Returns `true` if the tween has reached its end value.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.delay + self.duration
+    }
+
+    /// Advances the tween by the given time step in seconds and returns the new value.
+    pub fn step(&mut self, delta: f64) -> f64 {
+        self.elapsed = (self.elapsed + delta).min(self.delay + self.duration);
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ease() {
+        for easing in &[
+            EasingFn::Linear,
+            EasingFn::EaseIn,
+            EasingFn::EaseOut,
+            EasingFn::EaseInOut,
+        ] {
+            assert_eq!(0.0, easing.ease(0.0));
+            assert_eq!(1.0, easing.ease(1.0));
+        }
+
+        assert_eq!(0.5, EasingFn::EaseInOut.ease(0.5));
+    }
+
+    #[test]
+    fn test_easing_from_str() {
+        assert_eq!(EasingFn::EaseInOut, EasingFn::from("ease_in_out"));
+        assert_eq!(EasingFn::Linear, EasingFn::from("unknown"));
+    }
+
+    #[test]
+    fn test_step() {
+        let mut tween = Tween::new(0.0, 10.0, 1.0).delay(0.5);
+
+        assert_eq!(0.0, tween.step(0.5));
+        assert_eq!(5.0, tween.step(0.5));
+        assert!(!tween.is_finished());
+        assert_eq!(10.0, tween.step(1.0));
+        assert!(tween.is_finished());
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
+    any::TypeId,
     cell::{Cell, RefCell},
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet, HashMap},
     rc::Rc,
     sync::mpsc,
 };
@@ -13,8 +14,10 @@ use crate::{
     event::*,
     layout::*,
     render_object::*,
+    services::{ClipboardService, FontService, ScreenService, UndoService},
     shell::{ShellRequest, WindowRequest},
-    utils::Point,
+    theme::IconTheme,
+    utils::{Color, Point},
     widget_base::*,
 };
 
@@ -27,10 +30,24 @@ pub struct ContextProvider {
     pub states: Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
     pub event_queue: Rc<RefCell<EventQueue>>,
     pub mouse_position: Rc<Cell<Point>>,
+    pub hovered_widgets: Rc<RefCell<BTreeSet<Entity>>>,
+    pub files_hovered: Rc<Cell<bool>>,
+    pub drop_targets: Rc<RefCell<BTreeSet<Entity>>>,
     pub window_sender: mpsc::Sender<WindowRequest>,
+    pub task_sender: mpsc::Sender<Box<TaskFn>>,
+    pub task_receiver: Rc<mpsc::Receiver<Box<TaskFn>>>,
     pub shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     pub application_name: String,
     pub first_run: Rc<Cell<bool>>,
+    pub layout_debug: Rc<Cell<bool>>,
+    pub accessibility_audit: Rc<Cell<bool>>,
+    pub layout_debug_colors: Rc<RefCell<HashMap<TypeId, Color>>>,
+    pub undo_service: UndoService,
+    pub clipboard_service: ClipboardService,
+    pub font_service: FontService,
+    pub screen_service: ScreenService,
+    pub icon_theme: Rc<RefCell<IconTheme>>,
+    pub quit_handler: Rc<RefCell<Option<Rc<QuitHandlerFn>>>>,
 }
 
 impl ContextProvider {
@@ -39,7 +56,10 @@ impl ContextProvider {
         window_sender: mpsc::Sender<WindowRequest>,
         shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
         application_name: impl Into<String>,
+        icon_theme: IconTheme,
     ) -> Self {
+        let (task_sender, task_receiver) = mpsc::channel();
+
         ContextProvider {
             render_objects: Rc::new(RefCell::new(BTreeMap::new())),
             layouts: Rc::new(RefCell::new(BTreeMap::new())),
@@ -47,10 +67,68 @@ impl ContextProvider {
             states: Rc::new(RefCell::new(BTreeMap::new())),
             event_queue: Rc::new(RefCell::new(EventQueue::new())),
             mouse_position: Rc::new(Cell::new(Point::new(0.0, 0.0))),
+            hovered_widgets: Rc::new(RefCell::new(BTreeSet::new())),
+            files_hovered: Rc::new(Cell::new(false)),
+            drop_targets: Rc::new(RefCell::new(BTreeSet::new())),
             window_sender,
+            task_sender,
+            task_receiver: Rc::new(task_receiver),
             shell_sender,
             application_name: application_name.into(),
             first_run: Rc::new(Cell::new(true)),
+            layout_debug: Rc::new(Cell::new(false)),
+            accessibility_audit: Rc::new(Cell::new(false)),
+            layout_debug_colors: Rc::new(RefCell::new(default_layout_debug_colors())),
+            undo_service: UndoService::new(),
+            clipboard_service: ClipboardService::new(),
+            font_service: FontService::new(),
+            screen_service: ScreenService::new(),
+            icon_theme: Rc::new(RefCell::new(icon_theme)),
+            quit_handler: Rc::new(RefCell::new(None)),
         }
     }
+
+    /// Enables or disables the drawing of the layout boundaries of all widgets.
+    pub fn set_layout_debug(&self, layout_debug: bool) {
+        self.layout_debug.set(layout_debug);
+    }
+
+    /// Returns `true` if the layout boundaries of all widgets are drawn.
+    pub fn layout_debug(&self) -> bool {
+        self.layout_debug.get()
+    }
+
+    /// Enables or disables the panic on critical accessibility issues when the window is
+    /// initialized.
+    pub fn set_accessibility_audit(&self, accessibility_audit: bool) {
+        self.accessibility_audit.set(accessibility_audit);
+    }
+
+    /// Returns `true` if critical accessibility issues panic when the window is initialized.
+    pub fn accessibility_audit(&self) -> bool {
+        self.accessibility_audit.get()
+    }
+
+    /// Gets the color that is used to draw the boundary of the layout with the given type id.
+    pub fn layout_debug_color(&self, type_id: TypeId) -> Color {
+        self.layout_debug_colors
+            .borrow()
+            .get(&type_id)
+            .copied()
+            .unwrap_or_else(|| Color::rgb(128, 128, 128))
+    }
+}
+
+// Default boundary colors of the build in layouts.
+fn default_layout_debug_colors() -> HashMap<TypeId, Color> {
+    let mut colors = HashMap::new();
+    colors.insert(TypeId::of::<GridLayout>(), Color::rgb(0, 200, 0));
+    colors.insert(TypeId::of::<StackLayout>(), Color::rgb(0, 80, 255));
+    colors.insert(TypeId::of::<AbsoluteLayout>(), Color::rgb(255, 0, 0));
+    colors.insert(TypeId::of::<PaddingLayout>(), Color::rgb(255, 140, 0));
+    colors.insert(TypeId::of::<FixedSizeLayout>(), Color::rgb(160, 0, 200));
+    colors.insert(TypeId::of::<PopupLayout>(), Color::rgb(255, 220, 0));
+    colors.insert(TypeId::of::<TextSelectionLayout>(), Color::rgb(0, 200, 200));
+    colors.insert(TypeId::of::<MasonryLayout>(), Color::rgb(200, 0, 120));
+    colors
 }
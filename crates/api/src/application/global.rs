@@ -2,7 +2,11 @@ use std::collections::HashMap;
 
 use dces::prelude::Entity;
 
-use crate::{shell::Key, theming::Theme};
+use crate::{
+    shell::Key,
+    theming::Theme,
+    utils::{CursorShape, Point, Rectangle},
+};
 
 #[derive(Default, Clone, Debug, PartialEq)]
 /// The `Global` struct is used to define global `properties` that could be access application width.
@@ -18,6 +22,29 @@ pub struct Global {
 
     /// The current window theme
     pub theme: Theme,
+
+    /// The shape of the mouse cursor that is displayed over the window.
+    pub cursor_shape: CursorShape,
+
+    /// If `true` the cursor is hidden and locked and the mouse movement is reported by `MouseRawDeltaEvent`.
+    pub raw_input: bool,
+
+    /// The title of the window.
+    pub window_title: String,
+
+    /// The icon of the window as encoded image file.
+    pub window_icon: Option<Vec<u8>>,
+
+    /// The bounds of the window. Is updated before `WindowResizedEvent` is pushed.
+    pub bounds: Rectangle,
+
+    /// The scale factor of the screen the window is on. Is updated before
+    /// `WindowDpiChangedEvent` is pushed.
+    pub device_pixel_ratio: f64,
+
+    /// Requested move of the window to a position on the screen with the given id. Is sent to the
+    /// shell and reset by the `ShellUpdateSystem`.
+    pub move_window: Option<(u64, Point)>,
 }
 
 /// Contains the state information for the keyboard.
@@ -61,6 +88,16 @@ impl KeyboardState {
         self.is_key_down(Key::Control)
     }
 
+    /// Returns whether or not any meta (super, windows or command) key is down.
+    pub fn is_meta_down(&self) -> bool {
+        self.is_key_down(Key::Meta)
+    }
+
+    /// Returns whether or not any modifier key (shift, alt, control or meta) is down.
+    pub fn is_any_modifier_down(&self) -> bool {
+        self.is_shift_down() || self.is_alt_down() || self.is_ctrl_down() || self.is_meta_down()
+    }
+
     /// Returns whether or not any home key is down.
     pub fn is_home_down(&self) -> bool {
         self.is_key_down(Key::Home)
@@ -131,4 +168,22 @@ mod tests {
         state.set_key_state(Key::Alt, false);
         assert_eq!(state.is_alt_down(), false);
     }
+
+    #[test]
+    fn test_meta() {
+        let mut state = KeyboardState::default();
+        assert_eq!(state.is_meta_down(), false);
+        assert_eq!(state.is_any_modifier_down(), false);
+
+        state.set_key_state(Key::Meta, true);
+        assert_eq!(state.is_meta_down(), true);
+        assert_eq!(state.is_any_modifier_down(), true);
+
+        state.set_key_state(Key::Meta, false);
+        state.set_key_state(Key::Space, true);
+        assert_eq!(state.is_any_modifier_down(), false);
+
+        state.set_key_state(Key::ShiftR, true);
+        assert_eq!(state.is_any_modifier_down(), true);
+    }
 }
@@ -1,13 +1,15 @@
 //! This module contains the base elements of an OrbTk application (Application, WindowBuilder and Window).
 
-use std::sync::mpsc;
+use std::{rc::Rc, sync::mpsc};
 
 use dces::prelude::Entity;
 
 use crate::{
+    event::QuitHandlerFn,
     shell::{Shell, ShellRequest},
+    theme::IconTheme,
     theming::Theme,
-    widget_base::BuildContext,
+    widget_base::{BuildContext, StatesContext},
 };
 
 pub use self::context_provider::*;
@@ -27,6 +29,11 @@ pub struct Application {
     shell: Shell<WindowAdapter>,
     name: Box<str>,
     theme: Theme,
+    icon_theme: IconTheme,
+    window_title: Option<String>,
+    window_icon: Option<Vec<u8>>,
+    quit_handler: Option<Rc<QuitHandlerFn>>,
+    accessibility_audit: bool,
 }
 
 impl Application {
@@ -37,10 +44,47 @@ impl Application {
 
     /// Sets the default theme for the application. Could be changed per window.
     pub fn theme(mut self, theme: Theme) -> Self {
+        log_theme_errors(&theme);
         self.theme = theme;
         self
     }
 
+    /// Sets the icon theme for the application. It maps the icon names used by the widgets to
+    /// the graphics of an icon set.
+    pub fn icon_theme(mut self, icon_theme: IconTheme) -> Self {
+        self.icon_theme = icon_theme;
+        self
+    }
+
+    /// Sets the title of all windows that are added after this call. Overwrites the title of the window widget.
+    pub fn window_title(mut self, title: impl Into<String>) -> Self {
+        self.window_title = Some(title.into());
+        self
+    }
+
+    /// Sets the icon of all windows that are added after this call. `icon` is an encoded image
+    /// file e.g. `include_bytes!("icon.png")`.
+    pub fn window_icon(mut self, icon: &[u8]) -> Self {
+        self.window_icon = Some(icon.to_vec());
+        self
+    }
+
+    /// Sets a handler that is called if a window that is added after this call should be closed.
+    /// If the handler returns `false` the window stays open, e.g. to ask the user to save the
+    /// unsaved changes before.
+    pub fn on_quit<H: Fn(&mut StatesContext) -> bool + 'static>(mut self, handler: H) -> Self {
+        self.quit_handler = Some(Rc::new(handler));
+        self
+    }
+
+    /// Enables the accessibility audit for all windows that are added after this call. The
+    /// audit runs when a window is initialized and panics if a widget has a critical issue e.g.
+    /// a button without text and accessible name. In debug builds the issues are always logged.
+    pub fn enable_accessibility_audit(mut self) -> Self {
+        self.accessibility_audit = true;
+        self
+    }
+
     /// Create a new application with the given name.
     pub fn from_name(name: impl Into<Box<str>>) -> Self {
         let (sender, receiver) = mpsc::channel();
@@ -50,18 +94,45 @@ impl Application {
             name: name.into(),
             shell: Shell::new(receiver),
             theme: crate::theme::dark_theme(),
+            icon_theme: crate::theme::material_icon_theme(),
+            window_title: None,
+            window_icon: None,
+            quit_handler: None,
+            accessibility_audit: false,
         }
     }
 
     /// Creates a new window and add it to the application.
     pub fn window<F: Fn(&mut BuildContext) -> Entity + 'static>(mut self, create_fn: F) -> Self {
-        let (adapter, settings, receiver) = create_window(
+        let (mut adapter, mut settings, receiver) = create_window(
             self.name.clone(),
             self.theme.clone(),
+            self.icon_theme.clone(),
             self.request_sender.clone(),
             create_fn,
         );
 
+        {
+            let ecm = adapter.entity_component_manager();
+            let root = ecm.entity_store().root();
+            let global = ecm
+                .component_store_mut()
+                .get_mut::<Global>("global", root)
+                .unwrap();
+
+            if let Some(title) = &self.window_title {
+                settings.title = title.clone();
+                global.window_title = title.clone();
+            }
+
+            global.window_icon = self.window_icon.clone();
+        }
+
+        *adapter.context_provider().quit_handler.borrow_mut() = self.quit_handler.clone();
+        adapter
+            .context_provider()
+            .set_accessibility_audit(self.accessibility_audit);
+
         self.shell
             .create_window_from_settings(settings, adapter)
             .request_receiver(receiver)
@@ -75,3 +146,14 @@ impl Application {
         self.shell.run();
     }
 }
+
+// Logs the invalid media queries and the properties of the theme with invalid values.
+pub(crate) fn log_theme_errors(theme: &Theme) {
+    for error in theme.media_errors() {
+        crate::shell::CONSOLE.warn(error.as_str());
+    }
+
+    for error in theme.validate() {
+        crate::shell::CONSOLE.warn(error.to_string());
+    }
+}
@@ -21,7 +21,15 @@ use crate::{
 
 widget!(
     /// The `Overlay` is used to draw its children on the top of all other widgets in the tree.
-    Overlay
+    Overlay {
+        attached_properties: {
+            /// Attach the mode that describes how the left and top margin of a child are used.
+            position_mode: PositionMode,
+
+            /// Attach a size in percent of the overlay size to a child.
+            relative_constraint: RelativeConstraint
+        }
+    }
 );
 
 impl Template for Overlay {
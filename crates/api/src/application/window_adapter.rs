@@ -1,4 +1,4 @@
-use std::{cell::RefCell, collections::HashMap, sync::mpsc};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, sync::mpsc};
 
 use dces::prelude::*;
 
@@ -7,12 +7,13 @@ use crate::{
     event::*,
     properties::*,
     render,
-    services::Settings,
+    services::{AnimationService, FileDialogService, Settings},
     shell,
-    shell::{ShellRequest, WindowRequest, WindowSettings},
+    shell::{ScreenInfo, ShellRequest, WindowRequest, WindowSettings},
     systems::*,
+    theme::IconTheme,
     tree::Tree,
-    utils::{Point, Rectangle},
+    utils::{CursorShape, Point, Rectangle},
     widget_base::*,
 };
 
@@ -30,6 +31,18 @@ impl WindowAdapter {
     ) -> Self {
         WindowAdapter { world, ctx }
     }
+
+    /// Gets the entity component manager of the window.
+    pub fn entity_component_manager(
+        &mut self,
+    ) -> &mut EntityComponentManager<Tree, StringComponentStore> {
+        self.world.entity_component_manager()
+    }
+
+    /// Gets the context provider of the window.
+    pub fn context_provider(&self) -> &ContextProvider {
+        &self.ctx
+    }
 }
 
 impl WindowAdapter {
@@ -45,6 +58,16 @@ impl WindowAdapter {
 impl shell::WindowAdapter for WindowAdapter {
     fn resize(&mut self, width: f64, height: f64) {
         let root = self.root();
+
+        if let Ok(global) = self
+            .world
+            .entity_component_manager()
+            .component_store_mut()
+            .get_mut::<Global>("global", root)
+        {
+            global.bounds = Rectangle::from((0.0, 0.0, width, height));
+        }
+
         self.ctx
             .event_queue
             .borrow_mut()
@@ -53,6 +76,34 @@ impl shell::WindowAdapter for WindowAdapter {
                 EventStrategy::Direct,
                 root,
             );
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event(WindowResizedEvent { width, height }, root);
+    }
+
+    fn screens(&mut self, screens: Vec<ScreenInfo>) {
+        self.ctx.screen_service.set_screens(screens);
+    }
+
+    fn scale_factor_changed(&mut self, scale_factor: f64) {
+        let root = self.root();
+
+        if let Ok(global) = self
+            .world
+            .entity_component_manager()
+            .component_store_mut()
+            .get_mut::<Global>("global", root)
+        {
+            global.device_pixel_ratio = scale_factor;
+        }
+
+        self.ctx.event_queue.borrow_mut().register_event(
+            WindowDpiChangedEvent {
+                new_scale: scale_factor,
+            },
+            root,
+        );
     }
 
     fn mouse(&mut self, x: f64, y: f64) {
@@ -66,11 +117,13 @@ impl shell::WindowAdapter for WindowAdapter {
         )
     }
 
-    fn scroll(&mut self, delta_x: f64, delta_y: f64) {
+    fn scroll(&mut self, event: shell::MouseScrollEvent) {
         let root = self.root();
         self.ctx.event_queue.borrow_mut().register_event(
             ScrollEvent {
-                delta: Point::new(delta_x, delta_y),
+                delta: event.delta,
+                velocity: event.velocity,
+                is_trackpad: event.is_trackpad,
             },
             root,
         )
@@ -109,6 +162,52 @@ impl shell::WindowAdapter for WindowAdapter {
         self.ctx.mouse_position.get()
     }
 
+    fn raw_mouse_delta(&mut self, dx: f64, dy: f64) {
+        let root = self.root();
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event(MouseRawDeltaEvent { dx, dy }, root)
+    }
+
+    fn raw_input(&mut self) -> bool {
+        let root = self.root();
+
+        self.world
+            .entity_component_manager()
+            .component_store()
+            .get::<Global>("global", root)
+            .map(|global| global.raw_input)
+            .unwrap_or_default()
+    }
+
+    fn cursor_shape(&mut self) -> CursorShape {
+        let root = self.root();
+
+        self.world
+            .entity_component_manager()
+            .component_store()
+            .get::<Global>("global", root)
+            .map(|global| global.cursor_shape)
+            .unwrap_or_default()
+    }
+
+    fn files_hovered(&mut self, hovered: bool) {
+        self.ctx.files_hovered.set(hovered);
+    }
+
+    fn files_dropped(&mut self, paths: Vec<PathBuf>, x: f64, y: f64) {
+        let root = self.root();
+        self.ctx.files_hovered.set(false);
+        self.ctx.event_queue.borrow_mut().register_event(
+            FilesDroppedEvent {
+                paths,
+                position: Point::new(x, y),
+            },
+            root,
+        )
+    }
+
     fn key_event(&mut self, event: shell::KeyEvent) {
         let root = self.root();
         match event.state {
@@ -125,13 +224,16 @@ impl shell::WindowAdapter for WindowAdapter {
         }
     }
 
-    fn quit_event(&mut self) {
+    fn quit_event(&mut self) -> bool {
         let root = self.root();
 
         self.ctx
             .event_queue
             .borrow_mut()
             .register_event(SystemEvent::Quit, root);
+
+        // the window is closed by the event state system after the quit handler is asked
+        false
     }
 
     fn active(&mut self, active: bool) {
@@ -156,6 +258,7 @@ impl shell::WindowAdapter for WindowAdapter {
 pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
     app_name: impl Into<String>,
     theme: Theme,
+    icon_theme: IconTheme,
     request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     create_fn: F,
 ) -> (WindowAdapter, WindowSettings, mpsc::Receiver<WindowRequest>) {
@@ -168,16 +271,37 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
     let registry = Rc::new(RefCell::new(Registry::new()));
 
     if app_name.is_empty() {
-        registry
-            .borrow_mut()
-            .register("settings", Settings::default());
+        registry.borrow_mut().insert(Settings::default());
     } else {
         registry
             .borrow_mut()
-            .register("settings", Settings::new(app_name.clone()));
+            .insert(Settings::new(app_name.clone()));
     };
 
-    let context_provider = ContextProvider::new(sender, request_sender.clone(), app_name);
+    let context_provider =
+        ContextProvider::new(sender, request_sender.clone(), app_name, icon_theme);
+
+    registry
+        .borrow_mut()
+        .insert(context_provider.undo_service.clone());
+
+    registry
+        .borrow_mut()
+        .insert(context_provider.clipboard_service.clone());
+
+    registry.borrow_mut().insert(FileDialogService::new());
+
+    registry
+        .borrow_mut()
+        .insert(context_provider.font_service.clone());
+
+    registry
+        .borrow_mut()
+        .insert(context_provider.screen_service.clone());
+
+    registry
+        .borrow_mut()
+        .register("icon_theme", context_provider.icon_theme.clone());
 
     let window = {
         let overlay = Overlay::new().build(&mut BuildContext::new(
@@ -187,6 +311,7 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             &context_provider.handler_map,
             &mut *context_provider.states.borrow_mut(),
             &theme,
+            &context_provider.icon_theme,
             &context_provider.event_queue,
         ));
 
@@ -202,6 +327,7 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             &context_provider.handler_map,
             &mut *context_provider.states.borrow_mut(),
             &theme,
+            &context_provider.icon_theme,
             &context_provider.event_queue,
         ));
 
@@ -239,6 +365,12 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         crate::theme::fonts::MATERIAL_ICONS_FONT,
     );
 
+    for (family, font_file) in &fonts {
+        context_provider
+            .font_service
+            .register_font(family.as_str(), *font_file);
+    }
+
     let settings = WindowSettings {
         title: world
             .entity_component_manager()
@@ -267,12 +399,19 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
     };
 
     let mut global = Global::default();
-    global.theme = theme;
+    global.theme = theme.clone();
+    global.window_title = settings.title.clone();
+    global.bounds = Rectangle::from((0.0, 0.0, constraint.width(), constraint.height()));
+    global.device_pixel_ratio = 1.0;
 
     world
         .entity_component_manager()
         .component_store_mut()
         .register("global", window, global);
+    world
+        .entity_component_manager()
+        .component_store_mut()
+        .register("animation_service", window, AnimationService::new());
     world
         .entity_component_manager()
         .component_store_mut()
@@ -282,6 +421,11 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             Rectangle::from((0.0, 0.0, constraint.width(), constraint.height())),
         );
 
+    // the widgets are built before the window size is known
+    if theme.media_changed((0.0, 0.0), (constraint.width(), constraint.height())) {
+        WidgetContainer::new(window, world.entity_component_manager(), &theme, None).update(true);
+    }
+
     world.register_init_system(InitSystem::new(context_provider.clone(), registry.clone()));
 
     world.register_cleanup_system(CleanupSystem::new(
@@ -297,6 +441,11 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .with_priority(0)
         .build();
 
+    world
+        .create_system(AnimationSystem::new(context_provider.clone()))
+        .with_priority(0)
+        .build();
+
     world
         .create_system(LayoutSystem::new(context_provider.clone()))
         .with_priority(1)
@@ -315,6 +464,11 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .with_priority(3)
         .build();
 
+    world
+        .create_system(ShellUpdateSystem::new(context_provider.clone()))
+        .with_priority(4)
+        .build();
+
     (
         WindowAdapter::new(world, context_provider),
         settings,
@@ -0,0 +1,68 @@
+use std::rc::Rc;
+
+use crate::{application::KeyboardState, prelude::*, proc_macros::*, shell::Key, utils::Point};
+
+use super::{EventBox, EventHandler};
+
+/// `ContextMenuEvent` occurs if the context menu of a widget is requested by the keyboard. It is
+/// sent to the focused widget on Shift+F10 or on the menu key, the position is the center of the
+/// widget on the window.
+#[derive(Event)]
+pub struct ContextMenuEvent {
+    /// Indicates the position on the window where the context menu should appear.
+    pub position: Point,
+}
+
+/// Checks if the given key requests the context menu, that is the menu key or Shift+F10.
+pub fn is_context_menu_key(key: Key, keyboard_state: &KeyboardState) -> bool {
+    key == Key::Application || (key == Key::F10 && keyboard_state.is_shift_down())
+}
+
+/// Used to handle context menu events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct ContextMenuEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for ContextMenuEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<ContextMenuEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ContextMenuEvent>()
+    }
+}
+
+pub trait ContextMenuHandler: Sized + Widget {
+    /// Inserts a handler that is called with the position of the requested context menu.
+    fn on_context_menu<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ContextMenuEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_context_menu_key() {
+        let mut keyboard_state = KeyboardState::default();
+
+        assert!(is_context_menu_key(Key::Application, &keyboard_state));
+        assert!(!is_context_menu_key(Key::F10, &keyboard_state));
+        assert!(!is_context_menu_key(Key::Enter, &keyboard_state));
+
+        keyboard_state.set_key_state(Key::ShiftL, true);
+        assert!(is_context_menu_key(Key::F10, &keyboard_state));
+        assert!(!is_context_menu_key(Key::F9, &keyboard_state));
+    }
+}
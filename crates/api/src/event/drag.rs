@@ -0,0 +1,212 @@
+use std::{any::Any, fmt, rc::Rc};
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::*,
+};
+
+/// Distance in pixels the mouse has to move with a pressed button before a drag starts.
+pub const DRAG_START_DISTANCE: f64 = 8.0;
+
+/// `DragPayload` is the data that is carried from the source of a drag to the widget it is
+/// dropped on. All clones share the same data.
+#[derive(Clone, Default)]
+pub struct DragPayload(Option<Rc<dyn Any>>);
+
+impl DragPayload {
+    /// Creates a new payload with the given data.
+    pub fn new(data: impl Any) -> Self {
+        DragPayload(Some(Rc::new(data)))
+    }
+
+    /// Gets a reference of the data if it is of the given type.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.0.as_ref().and_then(|data| data.downcast_ref::<T>())
+    }
+
+    /// Returns `true` if the payload has no data.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl fmt::Debug for DragPayload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DragPayload")
+            .field(&if self.is_empty() { "empty" } else { "data" })
+            .finish()
+    }
+}
+
+into_property_source!(DragPayload);
+
+/// `DragStartEvent` occurs when the mouse is moved more than `DRAG_START_DISTANCE` with a pressed
+/// button over a widget that has a drag payload.
+#[derive(Event)]
+pub struct DragStartEvent {
+    /// Indicates the widget that is dragged.
+    pub source: Entity,
+
+    /// Indicates the position of the mouse on the window.
+    pub position: Point,
+
+    /// Indicates the data that is dragged.
+    pub payload: DragPayload,
+}
+
+/// `DragMoveEvent` occurs when the mouse is moved during a drag. It is sent to the widgets below
+/// the mouse.
+#[derive(Event)]
+pub struct DragMoveEvent {
+    /// Indicates the widget that is dragged.
+    pub source: Entity,
+
+    /// Indicates the position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `DropEvent` occurs when the mouse button is released during a drag. It is sent to the widgets
+/// below the mouse, the widget that handles the event is the drop target.
+#[derive(Event)]
+pub struct DropEvent {
+    /// Indicates the widget that is dropped.
+    pub source: Entity,
+
+    /// Indicates the drop position on the window.
+    pub position: Point,
+
+    /// Indicates the data that is dropped.
+    pub payload: DragPayload,
+}
+
+/// Defines the drag move handler function.
+pub type DragMoveHandlerFunction = dyn Fn(&mut StatesContext, Entity, Point) -> bool + 'static;
+
+/// Defines the drop handler function.
+pub type DropHandlerFunction =
+    dyn Fn(&mut StatesContext, Entity, Point, DragPayload) -> bool + 'static;
+
+/// Used to handle drag start events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragStartEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for DragStartEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragStartEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragStartEvent>()
+    }
+}
+
+/// Used to handle drag move events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragMoveEventHandler {
+    handler: Rc<DragMoveHandlerFunction>,
+}
+
+impl EventHandler for DragMoveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragMoveEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.source, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragMoveEvent>()
+    }
+}
+
+/// Used to handle drop events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DropEventHandler {
+    handler: Rc<DropHandlerFunction>,
+}
+
+impl EventHandler for DropEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DropEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(
+                    state_context,
+                    event.source,
+                    event.position,
+                    event.payload.clone(),
+                )
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DropEvent>()
+    }
+}
+
+pub trait DragHandler: Sized + Widget {
+    /// Makes the widget draggable. The payload is passed to the drop handler of the widget it is
+    /// dropped on.
+    fn drag_payload(self, data: impl Any) -> Self {
+        self.attach(AttachedProperty::new(
+            "drag_payload",
+            DragPayload::new(data),
+        ))
+    }
+
+    /// Inserts a handler that is called if a drag of the widget starts.
+    fn on_drag_start<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragStartEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called with the dragged widget if the mouse is moved over the
+    /// widget during a drag.
+    fn on_drag_move<H: Fn(&mut StatesContext, Entity, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragMoveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called with the dragged widget and its payload if it is dropped
+    /// on the widget.
+    fn on_drop<H: Fn(&mut StatesContext, Entity, Point, DragPayload) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DropEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drag_payload() {
+        let payload = DragPayload::new(String::from("item"));
+
+        assert_eq!(payload.get::<String>(), Some(&String::from("item")));
+        assert_eq!(payload.clone().get::<String>(), Some(&String::from("item")));
+        assert_eq!(payload.get::<usize>(), None);
+        assert!(!payload.is_empty());
+        assert!(DragPayload::default().is_empty());
+    }
+}
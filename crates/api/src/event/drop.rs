@@ -0,0 +1,56 @@
+use std::{path::PathBuf, rc::Rc};
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::*,
+};
+
+/// `FilesDroppedEvent` occurs when files from the file manager of the operating system are
+/// dropped on the window.
+#[derive(Event)]
+pub struct FilesDroppedEvent {
+    /// Indicates the paths of the dropped files.
+    pub paths: Vec<PathBuf>,
+
+    /// Indicates the drop position on the window.
+    pub position: Point,
+}
+
+/// Defines the files dropped handler function.
+pub type FilesDroppedHandlerFunction =
+    dyn Fn(&mut StatesContext, Vec<PathBuf>, Point) -> bool + 'static;
+
+/// Used to handle files dropped events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct FilesDroppedEventHandler {
+    handler: Rc<FilesDroppedHandlerFunction>,
+}
+
+impl EventHandler for FilesDroppedEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<FilesDroppedEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.paths.clone(), event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<FilesDroppedEvent>()
+    }
+}
+
+pub trait DropHandler: Sized + Widget {
+    /// Inserts a handler that is called if files from the file manager are dropped on the widget.
+    /// While files are dragged over the widget its selector gets the `drop_target` state.
+    fn on_files_dropped<H: Fn(&mut StatesContext, Vec<PathBuf>, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(FilesDroppedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
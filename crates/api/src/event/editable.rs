@@ -1,10 +1,10 @@
-use std::rc::Rc;
+use std::{collections::HashMap, rc::Rc};
 
 use dces::prelude::*;
 
 use super::*;
 
-use crate::{proc_macros::*, widget_base::*};
+use crate::{proc_macros::*, utils::Value, widget_base::*};
 
 crate::trigger_event!(
     ActivateEvent,
@@ -50,6 +50,86 @@ pub trait SelectionChangedHandler: Sized + Widget {
     }
 }
 
+/// This event occurs when a child of a widget is moved from the first to the second index by
+/// drag and drop.
+#[derive(Clone, Event)]
+pub struct ReorderEvent(pub Entity, pub usize, pub usize);
+
+/// Used to define a reorder callback.
+pub type ReorderHandlerFn = dyn Fn(&mut StatesContext, Entity, usize, usize) + 'static;
+
+#[derive(IntoHandler)]
+pub struct ReorderEventHandler {
+    pub handler: Rc<ReorderHandlerFn>,
+}
+
+impl EventHandler for ReorderEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<ReorderEvent>() {
+            (self.handler)(states, event.0, event.1, event.2);
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ReorderEvent>()
+    }
+}
+
+pub trait ReorderHandler: Sized + Widget {
+    /// Inserts a handler that is called with the old and the new index of a reordered child.
+    fn on_reorder<H: Fn(&mut StatesContext, Entity, usize, usize) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ReorderEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+/// This event occurs when a form is submitted and all of its fields are valid. It contains the
+/// values of the fields by their id.
+#[derive(Clone, Event)]
+pub struct SubmitEvent(pub Entity, pub HashMap<String, Value>);
+
+/// Used to define a submit callback.
+pub type SubmitHandlerFn = dyn Fn(&mut StatesContext, Entity, HashMap<String, Value>) + 'static;
+
+#[derive(IntoHandler)]
+pub struct SubmitEventHandler {
+    pub handler: Rc<SubmitHandlerFn>,
+}
+
+impl EventHandler for SubmitEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<SubmitEvent>() {
+            (self.handler)(states, event.0, event.1.clone());
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<SubmitEvent>()
+    }
+}
+
+pub trait SubmitHandler: Sized + Widget {
+    /// Inserts a handler that is called with the values of the fields of a submitted form.
+    fn on_submit<H: Fn(&mut StatesContext, Entity, HashMap<String, Value>) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(SubmitEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
 #[derive(Clone, Event)]
 /// This event occurs when a property of a widget is updated.
 pub struct ChangedEvent(pub Entity, pub String);
@@ -85,3 +165,84 @@ pub trait ChangedHandler: Sized + Widget {
         })
     }
 }
+
+/// This event occurs when the low or the high value of a range is changed.
+#[derive(Clone, Event)]
+pub struct RangeChangedEvent {
+    pub entity: Entity,
+    pub lo: f64,
+    pub hi: f64,
+}
+
+/// Used to define a range changed callback.
+pub type RangeChangedHandlerFn = dyn Fn(&mut StatesContext, Entity, f64, f64) + 'static;
+
+#[derive(IntoHandler)]
+pub struct RangeChangedEventHandler {
+    pub handler: Rc<RangeChangedHandlerFn>,
+}
+
+impl EventHandler for RangeChangedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<RangeChangedEvent>() {
+            (self.handler)(states, event.entity, event.lo, event.hi);
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<RangeChangedEvent>()
+    }
+}
+
+pub trait RangeChangedHandler: Sized + Widget {
+    /// Inserts a handler that is called with the new low and high value of a range.
+    fn on_range_changed<H: Fn(&mut StatesContext, Entity, f64, f64) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(RangeChangedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+/// This event occurs when a node of a tree is selected. It contains the id of the node.
+#[derive(Clone, Event)]
+pub struct TreeSelectionEvent {
+    pub id: String,
+}
+
+/// Used to define a tree selection callback.
+pub type TreeSelectionHandlerFn = dyn Fn(&mut StatesContext, &str) + 'static;
+
+#[derive(IntoHandler)]
+pub struct TreeSelectionEventHandler {
+    pub handler: Rc<TreeSelectionHandlerFn>,
+}
+
+impl EventHandler for TreeSelectionEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<TreeSelectionEvent>() {
+            (self.handler)(states, event.id.as_str());
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TreeSelectionEvent>()
+    }
+}
+
+pub trait TreeSelectionHandler: Sized + Widget {
+    /// Inserts a handler that is called with the id of the selected node.
+    fn on_tree_selection<H: Fn(&mut StatesContext, &str) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(TreeSelectionEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -54,14 +54,12 @@ pub trait KeyDownHandler: Sized + Widget {
 
     /// Handles events triggered by a specific key.
     fn on_key_down_key<H: Fn() -> bool + 'static>(self, key: Key, handler: H) -> Self {
-        self.on_key_down(
-            move |_, event| {
-                if event.key == key {
-                    handler()
-                } else {
-                    false
-                }
-            },
-        )
+        self.on_key_down(move |_, event| {
+            if event.logical == key {
+                handler()
+            } else {
+                false
+            }
+        })
     }
 }
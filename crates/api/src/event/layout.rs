@@ -0,0 +1,14 @@
+use std::rc::Rc;
+
+use dces::prelude::*;
+
+use super::*;
+
+use crate::widget_base::*;
+
+crate::trigger_event!(
+    LayoutCompleteEvent,
+    LayoutCompleteEventHandler,
+    LayoutCompleteHandler,
+    on_layout_complete
+);
@@ -6,20 +6,28 @@ use dces::entity::Entity;
 
 use crate::widget_base::StatesContext;
 
+pub use self::context_menu::*;
+pub use self::drag::*;
+pub use self::drop::*;
 pub use self::editable::*;
 pub use self::event_handler::*;
 pub use self::event_queue::*;
 pub use self::focus::*;
 pub use self::key::*;
+pub use self::layout::*;
 pub use self::mouse::*;
 pub use self::system::*;
 pub use self::window::*;
 
+mod context_menu;
+mod drag;
+mod drop;
 mod editable;
 mod event_handler;
 mod event_queue;
 mod focus;
 mod key;
+mod layout;
 mod mouse;
 mod system;
 mod window;
@@ -27,8 +35,9 @@ mod window;
 /// Defines the strategy of an event how it moves through the tree.
 #[derive(Debug, Clone, PartialEq)]
 pub enum EventStrategy {
-    // /// From root to leaf.
-    // TopDown,
+    /// From root to leaf.
+    TopDown,
+
     /// From leaf to root.
     BottomUp,
 
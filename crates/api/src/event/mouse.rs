@@ -33,11 +33,65 @@ pub struct MouseMoveEvent {
     pub position: Point,
 }
 
+/// `MouseEnterEvent` occurs when the mouse enters the bounds of a widget.
+#[derive(Event)]
+pub struct MouseEnterEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `MouseLeaveEvent` occurs when the mouse leaves the bounds of a widget.
+#[derive(Event)]
+pub struct MouseLeaveEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `MouseRawDeltaEvent` reports the unfiltered movement of the mouse while raw input is enabled
+/// (see `Context::set_raw_input`). The movement is not constrained by the screen boundaries.
+#[derive(Event)]
+pub struct MouseRawDeltaEvent {
+    /// Indicates the horizontal movement.
+    pub dx: f64,
+
+    /// Indicates the vertical movement.
+    pub dy: f64,
+}
+
 /// `ScrollEvent` occurs when the mouse wheel is moved.
 #[derive(Event)]
 pub struct ScrollEvent {
     /// Indicates the scroll offset x and y.
     pub delta: Point,
+
+    /// Indicates the scroll velocity in pixels per second, estimated from successive scroll events.
+    pub velocity: Point,
+
+    /// Indicates if the event is sent by a trackpad (continuous) or by a mouse wheel (discrete).
+    pub is_trackpad: bool,
+}
+
+/// Represents the current scroll state of a scroll event.
+#[derive(Debug, Copy, Clone)]
+pub struct Scroll {
+    /// Indicates the scroll offset x and y.
+    pub delta: Point,
+
+    /// Indicates the scroll velocity in pixels per second.
+    pub velocity: Point,
+
+    /// Indicates if the event is sent by a trackpad (continuous) or by a mouse wheel (discrete).
+    pub is_trackpad: bool,
+}
+
+impl From<&ScrollEvent> for Scroll {
+    fn from(event: &ScrollEvent) -> Self {
+        Scroll {
+            delta: event.delta,
+            velocity: event.velocity,
+            is_trackpad: event.is_trackpad,
+        }
+    }
 }
 
 /// Represents the current mouse state of an mouse event.
@@ -67,6 +121,14 @@ pub struct ClickEvent {
     pub position: Point,
 }
 
+/// `DoubleClickEvent` occurs when a user clicked twice in a short time on the same position of an
+/// element. It follows the `ClickEvent` of the second click.
+#[derive(Event)]
+pub struct DoubleClickEvent {
+    /// Indicates the x and y position of the second click.
+    pub position: Point,
+}
+
 /// `MouseDownEvent` occurs when a mouse button is pressed.
 #[derive(Event)]
 pub struct MouseDownEvent {
@@ -95,6 +157,9 @@ pub type MouseHandlerFunction = dyn Fn(&mut StatesContext, Mouse) -> bool + 'sta
 //// Defines a position based event handler.
 pub type PositionHandlerFunction = dyn Fn(&mut StatesContext, Point) -> bool + 'static;
 
+/// Defines the scroll handler function.
+pub type ScrollHandlerFunction = dyn Fn(&mut StatesContext, Scroll) -> bool + 'static;
+
 /// Defines the global bouse handler function.
 pub type GlobalMouseHandlerFunction = dyn Fn(&mut StatesContext, Mouse) + 'static;
 
@@ -122,6 +187,25 @@ impl EventHandler for ClickEventHandler {
     }
 }
 
+/// Used to handle double click events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DoubleClickEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for DoubleClickEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DoubleClickEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DoubleClickEvent>()
+    }
+}
+
 /// Used to handle mouse down events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct MouseDownEventHandler {
@@ -242,6 +326,84 @@ impl EventHandler for ScrollEventHandler {
     }
 }
 
+/// Used to handle mouse enter events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseEnterEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for MouseEnterEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MouseEnterEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseEnterEvent>()
+    }
+}
+
+/// Used to handle mouse leave events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseLeaveEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for MouseLeaveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MouseLeaveEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseLeaveEvent>()
+    }
+}
+
+/// Used to handle scroll events with velocity information. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct ScrollWithVelocityEventHandler {
+    handler: Rc<ScrollHandlerFunction>,
+}
+
+impl EventHandler for ScrollWithVelocityEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<ScrollEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.into()))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ScrollEvent>()
+    }
+}
+
+/// Used to handle raw mouse delta events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseRawDeltaEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for MouseRawDeltaEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MouseRawDeltaEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, Point::new(event.dx, event.dy))
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseRawDeltaEvent>()
+    }
+}
+
 pub trait MouseHandler: Sized + Widget {
     /// Inserts a click handler.
     fn on_click<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
@@ -250,6 +412,16 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Inserts a double click handler.
+    fn on_double_click<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DoubleClickEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse down handler.
     fn on_mouse_down<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(MouseDownEventHandler {
@@ -278,10 +450,50 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Insert a handler that is called if the mouse enters the widget.
+    fn on_mouse_enter<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MouseEnterEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a handler that is called if the mouse leaves the widget.
+    fn on_mouse_leave<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MouseLeaveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a handler for the unfiltered mouse movement while raw input is enabled.
+    fn on_mouse_raw_delta<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MouseRawDeltaEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse up handler.
     fn on_scroll<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(ScrollEventHandler {
             handler: Rc::new(handler),
         })
     }
+
+    /// Insert a scroll handler that receives the scroll delta, velocity and source.
+    fn on_scroll_with_velocity<H: Fn(&mut StatesContext, Scroll) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ScrollWithVelocityEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
 }
@@ -1,6 +1,17 @@
-use crate::{event::Event, proc_macros::Event};
+use dces::prelude::Entity;
+
+use crate::{event::Event, proc_macros::Event, widget_base::StatesContext};
+
+/// Defines the handler that is called if a window should be closed. If it returns `false` the
+/// window stays open e.g. to ask the user to save the changes.
+pub type QuitHandlerFn = dyn Fn(&mut StatesContext) -> bool + 'static;
 
 #[derive(Event)]
 pub enum SystemEvent {
+    /// Requests to close the window. The window is closed if no quit handler is set or the quit
+    /// handler returns `true`.
     Quit,
+
+    /// Marks the given widget as dirty, so its state is updated again.
+    Update(Entity),
 }
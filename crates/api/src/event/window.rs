@@ -31,3 +31,89 @@ impl EventHandler for WindowEventHandler {
         event.is_type::<WindowEvent>()
     }
 }
+
+/// `WindowResizedEvent` occurs after the window is resized.
+///
+/// Global events could not be handled and could be read on each state.
+#[derive(Clone, Event)]
+pub struct WindowResizedEvent {
+    /// Indicates the new width of the window.
+    pub width: f64,
+
+    /// Indicates the new height of the window.
+    pub height: f64,
+}
+
+/// Defines the window resized handler function.
+pub type WindowResizedHandlerFn = dyn Fn(&mut StatesContext, f64, f64) + 'static;
+
+/// Used to handle window resized events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct WindowResizedEventHandler {
+    pub handler: Rc<WindowResizedHandlerFn>,
+}
+
+impl EventHandler for WindowResizedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<WindowResizedEvent>() {
+            (self.handler)(states, event.width, event.height);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<WindowResizedEvent>()
+    }
+}
+
+/// `WindowDpiChangedEvent` occurs after the scale factor of the window is changed e.g. if the
+/// window is moved to a screen with another scale factor.
+///
+/// Global events could not be handled and could be read on each state.
+#[derive(Clone, Event)]
+pub struct WindowDpiChangedEvent {
+    /// Indicates the new scale factor of the window.
+    pub new_scale: f64,
+}
+
+/// Defines the dpi changed handler function.
+pub type WindowDpiChangedHandlerFn = dyn Fn(&mut StatesContext, f64) + 'static;
+
+/// Used to handle dpi changed events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct WindowDpiChangedEventHandler {
+    pub handler: Rc<WindowDpiChangedHandlerFn>,
+}
+
+impl EventHandler for WindowDpiChangedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<WindowDpiChangedEvent>() {
+            (self.handler)(states, event.new_scale);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<WindowDpiChangedEvent>()
+    }
+}
+
+pub trait WindowHandler: Sized + Widget {
+    /// Inserts a handler that is called with the new width and height after the window is
+    /// resized.
+    fn on_window_resized<H: Fn(&mut StatesContext, f64, f64) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(WindowResizedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called with the new scale factor after the scale factor of the
+    /// window is changed.
+    fn on_dpi_changed<H: Fn(&mut StatesContext, f64) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(WindowDpiChangedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -3,12 +3,22 @@ use std::{cell::RefCell, collections::BTreeMap};
 use dces::prelude::*;
 
 use crate::{
-    render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*, widget_base::mark_as_dirty,
+    properties::{percent_of, Constraint, PositionMode, RelativeConstraint},
+    render::RenderContext2D,
+    theming::*,
+    tree::Tree,
+    utils::prelude::*,
+    widget_base::mark_as_dirty,
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_try_mut, try_component, Layout};
 
 /// Place widgets absolute on the screen.
+///
+/// The size of a child could be given in percent of the layout size by the attached
+/// `relative_constraint` property. With the attached `position_mode` property set to
+/// `PositionMode::Percent`, the left and top margin of a child are used as position in percent of
+/// the layout size.
 #[derive(Default)]
 pub struct AbsoluteLayout {
     desired_size: RefCell<DirtySize>,
@@ -40,12 +50,12 @@ impl Layout for AbsoluteLayout {
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
             if let Some(child_layout) = layouts.get(&child) {
-                let dirty = child_layout
+                if child_layout
                     .measure(render_context_2_d, child, ecm, layouts, theme)
                     .dirty()
-                    || self.desired_size.borrow().dirty();
-
-                self.desired_size.borrow_mut().set_dirty(dirty);
+                {
+                    self.desired_size.borrow_mut().set_child_dirty(true);
+                }
             }
         }
 
@@ -73,20 +83,43 @@ impl Layout for AbsoluteLayout {
 
         mark_as_dirty("bounds", entity, ecm);
 
+        let size = self.desired_size.borrow().size();
+
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(relative_constraint) =
+                try_component::<RelativeConstraint>(ecm, child, "relative_constraint")
+            {
+                let (width, height) = relative_constraint.size(size);
+
+                if let Some(constraint) = component_try_mut::<Constraint>(ecm, child, "constraint")
+                {
+                    if let Some(width) = width {
+                        constraint.set_width(width);
+                    }
+
+                    if let Some(height) = height {
+                        constraint.set_height(height);
+                    }
+                }
+            }
+
             if let Some(child_layout) = layouts.get(&child) {
-                child_layout.arrange(
-                    render_context_2_d,
-                    (
-                        self.desired_size.borrow().width(),
-                        self.desired_size.borrow().height(),
-                    ),
-                    child,
-                    ecm,
-                    layouts,
-                    theme,
-                );
+                child_layout.arrange(render_context_2_d, size, child, ecm, layouts, theme);
+            }
+
+            if try_component::<PositionMode>(ecm, child, "position_mode")
+                == Some(PositionMode::Percent)
+            {
+                let margin: Thickness = component(ecm, child, "margin");
+
+                if let Some(bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                    bounds.set_x(percent_of(margin.left(), size.0).unwrap_or_default());
+                    bounds.set_y(percent_of(margin.top(), size.1).unwrap_or_default());
+                }
+
+                mark_as_dirty("bounds", child, ecm);
             }
         }
 
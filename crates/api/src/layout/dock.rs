@@ -0,0 +1,310 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+use dces::prelude::*;
+
+use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*};
+
+use super::{component, component_or_default, component_try_mut, Layout};
+
+/// Docks its children to the edges of the available space in the order they are added. A child
+/// docked to `Top` or `Bottom` takes the remaining width, a child docked to `Left` or `Right` the
+/// remaining height. A `Fill` child gets the space that is left.
+#[derive(Default)]
+pub struct DockLayout {
+    desired_size: RefCell<DirtySize>,
+    children_sizes: RefCell<BTreeMap<Entity, (f64, f64)>>,
+}
+
+impl DockLayout {
+    pub fn new() -> Self {
+        DockLayout::default()
+    }
+}
+
+impl Layout for DockLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return *self.desired_size.borrow();
+        }
+
+        let constraint: Constraint = component(ecm, entity, "constraint");
+
+        self.children_sizes.borrow_mut().clear();
+        let mut docked_sizes = vec![];
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                if child_desired_size.dirty() {
+                    self.desired_size.borrow_mut().set_child_dirty(true);
+                }
+
+                if component::<Visibility>(ecm, child, "visibility") == Visibility::Collapsed {
+                    continue;
+                }
+
+                let margin: Thickness = component(ecm, child, "margin");
+                let dock: DockRegion = component_or_default(ecm, child, "dock");
+                let size = (
+                    child_desired_size.width() + margin.left() + margin.right(),
+                    child_desired_size.height() + margin.top() + margin.bottom(),
+                );
+
+                docked_sizes.push((dock, size));
+                self.children_sizes.borrow_mut().insert(child, size);
+            }
+        }
+
+        let size = constraint.perform(desired_dock_size(&docked_sizes));
+        self.desired_size.borrow_mut().set_size(size.0, size.1);
+
+        *self.desired_size.borrow()
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() {
+            return self.desired_size.borrow().size();
+        }
+
+        let horizontal_alignment: Alignment = component(ecm, entity, "h_align");
+        let vertical_alignment: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+
+        let size = constraint.perform((
+            horizontal_alignment.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            vertical_alignment.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        let mut children = vec![];
+        let mut docked_sizes = vec![];
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_size) = self.children_sizes.borrow().get(&child) {
+                let dock: DockRegion = component_or_default(ecm, child, "dock");
+                children.push(child);
+                docked_sizes.push((dock, *child_size));
+            }
+        }
+
+        for (child, rect) in children.iter().zip(dock_rects(&docked_sizes, size)) {
+            if let Some(child_layout) = layouts.get(child) {
+                child_layout.arrange(
+                    render_context_2_d,
+                    (rect.2, rect.3),
+                    *child,
+                    ecm,
+                    layouts,
+                    theme,
+                );
+            }
+
+            let child_horizontal_alignment: Alignment = component(ecm, *child, "h_align");
+            let child_vertical_alignment: Alignment = component(ecm, *child, "v_align");
+            let child_margin: Thickness = component(ecm, *child, "margin");
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, *child, "bounds") {
+                child_bounds.set_x(
+                    rect.0
+                        + child_horizontal_alignment.align_position(
+                            rect.2,
+                            child_bounds.width(),
+                            child_margin.left(),
+                            child_margin.right(),
+                        ),
+                );
+                child_bounds.set_y(
+                    rect.1
+                        + child_vertical_alignment.align_position(
+                            rect.3,
+                            child_bounds.height(),
+                            child_margin.top(),
+                            child_margin.bottom(),
+                        ),
+                );
+            }
+
+            mark_as_dirty("bounds", *child, ecm);
+        }
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.desired_size.borrow_mut().set_dirty(false);
+        size
+    }
+}
+
+impl Into<Box<dyn Layout>> for DockLayout {
+    fn into(self) -> Box<dyn Layout> {
+        Box::new(self)
+    }
+}
+
+// Returns the size that is needed to dock all children. Docked children add up along their edge,
+// a fill child only needs to fit into the space that is left.
+fn desired_dock_size(docked_sizes: &[(DockRegion, (f64, f64))]) -> (f64, f64) {
+    let mut size = (0.0_f64, 0.0_f64);
+    let mut docked = (0.0, 0.0);
+
+    for (dock, child_size) in docked_sizes {
+        match dock {
+            DockRegion::Top | DockRegion::Bottom => {
+                size.0 = size.0.max(docked.0 + child_size.0);
+                docked.1 += child_size.1;
+            }
+            DockRegion::Left | DockRegion::Right => {
+                size.1 = size.1.max(docked.1 + child_size.1);
+                docked.0 += child_size.0;
+            }
+            DockRegion::Fill => {
+                size.0 = size.0.max(docked.0 + child_size.0);
+                size.1 = size.1.max(docked.1 + child_size.1);
+            }
+        }
+    }
+
+    (size.0.max(docked.0), size.1.max(docked.1))
+}
+
+// Returns the x, y, width and height of each child. Each docked child strips its space from the
+// remaining space, several children docked to the same edge are stacked.
+fn dock_rects(
+    docked_sizes: &[(DockRegion, (f64, f64))],
+    size: (f64, f64),
+) -> Vec<(f64, f64, f64, f64)> {
+    let mut remaining = (0.0, 0.0, size.0, size.1);
+
+    docked_sizes
+        .iter()
+        .map(|(dock, child_size)| {
+            let width = child_size.0.min(remaining.2);
+            let height = child_size.1.min(remaining.3);
+
+            match dock {
+                DockRegion::Top => {
+                    let rect = (remaining.0, remaining.1, remaining.2, height);
+                    remaining.1 += height;
+                    remaining.3 -= height;
+                    rect
+                }
+                DockRegion::Bottom => {
+                    remaining.3 -= height;
+                    (remaining.0, remaining.1 + remaining.3, remaining.2, height)
+                }
+                DockRegion::Left => {
+                    let rect = (remaining.0, remaining.1, width, remaining.3);
+                    remaining.0 += width;
+                    remaining.2 -= width;
+                    rect
+                }
+                DockRegion::Right => {
+                    remaining.2 -= width;
+                    (remaining.0 + remaining.2, remaining.1, width, remaining.3)
+                }
+                DockRegion::Fill => remaining,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_desired_dock_size() {
+        let docked_sizes = [
+            (DockRegion::Top, (100.0, 20.0)),
+            (DockRegion::Left, (30.0, 50.0)),
+            (DockRegion::Fill, (120.0, 60.0)),
+            (DockRegion::Bottom, (40.0, 10.0)),
+        ];
+
+        assert_eq!(desired_dock_size(&docked_sizes), (150.0, 80.0));
+        assert_eq!(desired_dock_size(&[]), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_dock_rects() {
+        let docked_sizes = [
+            (DockRegion::Top, (50.0, 20.0)),
+            (DockRegion::Top, (50.0, 10.0)),
+            (DockRegion::Bottom, (50.0, 10.0)),
+            (DockRegion::Left, (30.0, 50.0)),
+            (DockRegion::Right, (20.0, 50.0)),
+            (DockRegion::Fill, (10.0, 10.0)),
+        ];
+
+        assert_eq!(
+            dock_rects(&docked_sizes, (200.0, 100.0)),
+            vec![
+                (0.0, 0.0, 200.0, 20.0),
+                (0.0, 20.0, 200.0, 10.0),
+                (0.0, 90.0, 200.0, 10.0),
+                (0.0, 30.0, 30.0, 60.0),
+                (180.0, 30.0, 20.0, 60.0),
+                (30.0, 30.0, 150.0, 60.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dock_rects_exceeds_size() {
+        let docked_sizes = [
+            (DockRegion::Left, (80.0, 10.0)),
+            (DockRegion::Right, (80.0, 10.0)),
+            (DockRegion::Fill, (10.0, 10.0)),
+        ];
+
+        assert_eq!(
+            dock_rects(&docked_sizes, (100.0, 50.0)),
+            vec![
+                (0.0, 0.0, 80.0, 50.0),
+                (80.0, 0.0, 20.0, 50.0),
+                (80.0, 0.0, 0.0, 50.0),
+            ]
+        );
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
     properties::Constraint,
     render::Image,
     render::RenderContext2D,
+    render_object::displayed_text,
     theming::*,
     tree::Tree,
     utils::prelude::*,
@@ -60,7 +61,10 @@ impl Layout for FixedSizeLayout {
             .map(|image| (image.width(), image.height()))
             .or_else(|| {
                 widget.try_get::<String16>("text").and_then(|text| {
-                    let font = widget.get::<String>("font");
+                    let font = FontFallbackChain::from((
+                        widget.clone::<String>("font"),
+                        widget.clone_or_default::<Vec<String>>("font_fallbacks"),
+                    ));
                     let font_size = widget.get::<f64>("font_size");
 
                     if text.is_empty() {
@@ -71,16 +75,14 @@ impl Layout for FixedSizeLayout {
                                 let text_metrics = render_context_2_d.measure(
                                     water_mark.to_string().as_str(),
                                     *font_size,
-                                    font.as_str(),
+                                    font.clone(),
                                 );
                                 (text_metrics.width, text_metrics.height)
                             })
                     } else {
-                        let text_metrics = render_context_2_d.measure(
-                            text.to_string().as_str(),
-                            *font_size,
-                            font.as_str(),
-                        );
+                        let text = displayed_text(&widget, text.clone());
+                        let text_metrics =
+                            render_context_2_d.measure(text.to_string().as_str(), *font_size, font);
 
                         Some((text_metrics.width, text_metrics.height))
                     }
@@ -123,12 +125,12 @@ impl Layout for FixedSizeLayout {
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
             if let Some(child_layout) = layouts.get(&child) {
-                let dirty = child_layout
+                if child_layout
                     .measure(render_context_2_d, child, ecm, layouts, theme)
                     .dirty()
-                    || self.desired_size.borrow().dirty();
-
-                self.desired_size.borrow_mut().set_dirty(dirty);
+                {
+                    self.desired_size.borrow_mut().set_child_dirty(true);
+                }
             }
         }
 
@@ -1,6 +1,6 @@
 use std::{
     cell::{Cell, RefCell},
-    collections::BTreeMap,
+    collections::{BTreeMap, HashSet},
 };
 
 use dces::prelude::*;
@@ -11,6 +11,8 @@ use super::{component, component_try_mut, Layout};
 
 /// Orders its children in a grid layout with columns and rows. If no columns and rows are defined
 /// the grid layout could also be used as an alignment layout.
+///
+/// Children without an attached column and row are placed in the next free cell row by row.
 #[derive(Default)]
 pub struct GridLayout {
     desired_size: RefCell<DirtySize>,
@@ -71,6 +73,38 @@ impl GridLayout {
         (y, height)
     }
 
+    // gets the cell (column, row) of each child, children without attached column and row are
+    // placed by `auto_place`
+    fn place_children(
+        &self,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> BTreeMap<Entity, (usize, usize)> {
+        let column_count = ecm
+            .component_store()
+            .get::<Columns>("columns", entity)
+            .map_or(0, |columns| columns.len())
+            .max(1);
+
+        let children: Vec<Entity> = ecm.entity_store().children[&entity].clone();
+        let store = ecm.component_store();
+
+        let attachments: Vec<GridAttachment> = children
+            .iter()
+            .map(|child| GridAttachment {
+                column: store.get::<usize>("column", *child).ok().copied(),
+                row: store.get::<usize>("row", *child).ok().copied(),
+                column_span: store.get::<usize>("column_span", *child).map_or(1, |s| *s),
+                row_span: store.get::<usize>("row_span", *child).map_or(1, |s| *s),
+            })
+            .collect();
+
+        children
+            .into_iter()
+            .zip(auto_place(&attachments, column_count))
+            .collect()
+    }
+
     fn calculate_column_width(
         &self,
         child: Entity,
@@ -138,24 +172,23 @@ impl GridLayout {
                 }
             }
 
-            // calculates the width of the stretch columns
+            // distributes the rest width proportionally to the fraction (and stretch) columns
             let used_width: f64 = columns
                 .iter()
-                .filter(|column| column.width != ColumnWidth::Stretch)
+                .filter(|column| column.width.fraction().is_none())
                 .map(|column| column.current_width())
                 .sum();
 
-            let stretch_width = ((size.0 - used_width)
-                / columns
-                    .iter()
-                    .filter(|column| column.width == ColumnWidth::Stretch)
-                    .count() as f64)
-                .trunc();
+            let fractions: Vec<f64> = columns
+                .iter()
+                .filter_map(|column| column.width.fraction())
+                .collect();
 
             columns
                 .iter_mut()
-                .filter(|column| column.width == ColumnWidth::Stretch)
-                .for_each(|column| column.set_current_width(stretch_width));
+                .filter(|column| column.width.fraction().is_some())
+                .zip(fraction_sizes(size.0 - used_width, &fractions))
+                .for_each(|(column, width)| column.set_current_width(width));
 
             let mut column_sum = 0.0;
 
@@ -170,7 +203,7 @@ impl GridLayout {
                 if let Some(last_column) = columns
                     .iter_mut()
                     .rev()
-                    .find(|column| column.width == ColumnWidth::Stretch)
+                    .find(|column| column.width.fraction().is_some())
                 {
                     last_column
                         .set_current_width(last_column.current_width() + size.0 - column_sum);
@@ -201,23 +234,22 @@ impl GridLayout {
                 }
             }
 
-            // calculates the height of the stretch rows
+            // distributes the rest height proportionally to the fraction (and stretch) rows
             let used_height: f64 = rows
                 .iter()
-                .filter(|row| row.height != RowHeight::Stretch)
+                .filter(|row| row.height.fraction().is_none())
                 .map(|row| row.current_height())
                 .sum();
 
-            let stretch_height = ((size.1 - used_height)
-                / rows
-                    .iter()
-                    .filter(|row| row.height == RowHeight::Stretch)
-                    .count() as f64)
-                .trunc();
+            let fractions: Vec<f64> = rows
+                .iter()
+                .filter_map(|row| row.height.fraction())
+                .collect();
 
             rows.iter_mut()
-                .filter(|row| row.height == RowHeight::Stretch)
-                .for_each(|row| row.set_current_height(stretch_height));
+                .filter(|row| row.height.fraction().is_some())
+                .zip(fraction_sizes(size.1 - used_height, &fractions))
+                .for_each(|(row, height)| row.set_current_height(height));
 
             let mut row_sum = 0.0;
 
@@ -232,7 +264,7 @@ impl GridLayout {
                 if let Some(last_row) = rows
                     .iter_mut()
                     .rev()
-                    .find(|row| row.height == RowHeight::Stretch)
+                    .find(|row| row.height.fraction().is_some())
                 {
                     last_row.set_current_height(last_row.current_height() + size.1 - row_sum);
                 }
@@ -273,9 +305,9 @@ impl Layout for GridLayout {
                 let child_desired_size =
                     child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
 
-                let dirty = child_desired_size.dirty() || self.desired_size.borrow().dirty();
-
-                self.desired_size.borrow_mut().set_dirty(dirty);
+                if child_desired_size.dirty() {
+                    self.desired_size.borrow_mut().set_child_dirty(true);
+                }
                 desired_size.0 = desired_size.0.max(child_desired_size.width());
                 desired_size.1 = desired_size.1.max(child_desired_size.height());
 
@@ -343,6 +375,25 @@ impl Layout for GridLayout {
         let mut rows_cache = Vec::new();
         let mut row_heights = BTreeMap::new();
 
+        let has_columns =
+            if let Ok(columns) = ecm.component_store().get::<Columns>("columns", entity) {
+                !columns.is_empty()
+            } else {
+                false
+            };
+
+        let has_rows = if let Ok(rows) = ecm.component_store().get::<Rows>("rows", entity) {
+            !rows.is_empty()
+        } else {
+            false
+        };
+
+        let cells = if has_columns || has_rows {
+            self.place_children(entity, ecm)
+        } else {
+            BTreeMap::new()
+        };
+
         // calculates the auto column widths
 
         for index in 0..ecm.entity_store().children[&entity].len() {
@@ -350,22 +401,18 @@ impl Layout for GridLayout {
 
             let margin: Thickness = component(ecm, entity, "margin");
 
-            if let Ok(grid_column) = ecm.component_store().get::<usize>("column", child) {
+            if let Some((grid_column, grid_row)) = cells.get(&child).copied() {
                 if let Ok(columns) = ecm.component_store().get::<Columns>("columns", entity) {
-                    if let Some(column) = columns.get(*grid_column) {
+                    if let Some(column) = columns.get(grid_column) {
                         self.calculate_column_width(
                             child,
                             *column,
-                            *grid_column,
+                            grid_column,
                             &mut column_widths,
                             margin,
                         );
                     }
                 }
-            }
-
-            if let Ok(grid_row) = ecm.component_store().get::<usize>("row", child) {
-                let grid_row = *grid_row;
 
                 if let Ok(rows) = ecm.component_store().get::<Rows>("rows", entity) {
                     if let Some(row) = rows.get(grid_row) {
@@ -400,23 +447,10 @@ impl Layout for GridLayout {
                 *ecm.component_store().get("v_align", child).unwrap();
             let mut cell_position = (0.0, 0.0);
             let mut available_size = size;
-
-            let has_columns =
-                if let Ok(columns) = ecm.component_store().get::<Columns>("columns", entity) {
-                    !columns.is_empty()
-                } else {
-                    false
-                };
+            let (grid_column, grid_row) = cells.get(&child).copied().unwrap_or_default();
 
             // column
             if has_columns {
-                let grid_column =
-                    if let Ok(grid_column) = ecm.component_store().get::<usize>("column", child) {
-                        *grid_column
-                    } else {
-                        0
-                    };
-
                 let (offset_x, available_width) = self.get_column_x_and_width(
                     &columns_cache,
                     child,
@@ -430,21 +464,8 @@ impl Layout for GridLayout {
                 available_size.0 = size.0;
             }
 
-            let has_rows = if let Ok(rows) = ecm.component_store().get::<Rows>("rows", entity) {
-                !rows.is_empty()
-            } else {
-                false
-            };
-
             // rows
             if has_rows {
-                let grid_row =
-                    if let Ok(grid_row) = ecm.component_store().get::<usize>("row", child) {
-                        *grid_row
-                    } else {
-                        0
-                    };
-
                 let (offset_y, available_height) = self.get_row_y_and_height(
                     &rows_cache,
                     child,
@@ -520,8 +541,175 @@ impl Layout for GridLayout {
     }
 }
 
+// Describes the attached cell properties of a grid child.
+#[derive(Copy, Clone, Debug, Default)]
+struct GridAttachment {
+    column: Option<usize>,
+    row: Option<usize>,
+    column_span: usize,
+    row_span: usize,
+}
+
+// Returns the cell (column, row) of each child. Children with an attached column or row keep their
+// cell. The other children are placed row by row in the next cells that are not occupied, like
+// the auto placement of css grids.
+fn auto_place(children: &[GridAttachment], column_count: usize) -> Vec<(usize, usize)> {
+    let mut occupied = HashSet::new();
+
+    for child in children {
+        if child.column.is_some() || child.row.is_some() {
+            occupy(
+                &mut occupied,
+                child.column.unwrap_or_default(),
+                child.row.unwrap_or_default(),
+                child,
+            );
+        }
+    }
+
+    let mut cursor = (0, 0);
+
+    children
+        .iter()
+        .map(|child| {
+            if child.column.is_some() || child.row.is_some() {
+                return (
+                    child.column.unwrap_or_default(),
+                    child.row.unwrap_or_default(),
+                );
+            }
+
+            let column_span = child.column_span.max(1).min(column_count);
+
+            loop {
+                if cursor.0 + column_span > column_count {
+                    cursor = (0, cursor.1 + 1);
+                }
+
+                if is_free(&occupied, cursor.0, cursor.1, child) {
+                    break;
+                }
+
+                cursor.0 += 1;
+            }
+
+            let cell = cursor;
+            occupy(&mut occupied, cell.0, cell.1, child);
+            cursor.0 += column_span;
+
+            cell
+        })
+        .collect()
+}
+
+fn cells(
+    column: usize,
+    row: usize,
+    child: &GridAttachment,
+) -> impl Iterator<Item = (usize, usize)> {
+    let rows = row..row + child.row_span.max(1);
+
+    (column..column + child.column_span.max(1)).flat_map(move |c| rows.clone().map(move |r| (c, r)))
+}
+
+fn occupy(
+    occupied: &mut HashSet<(usize, usize)>,
+    column: usize,
+    row: usize,
+    child: &GridAttachment,
+) {
+    occupied.extend(cells(column, row, child));
+}
+
+fn is_free(
+    occupied: &HashSet<(usize, usize)>,
+    column: usize,
+    row: usize,
+    child: &GridAttachment,
+) -> bool {
+    cells(column, row, child).all(|cell| !occupied.contains(&cell))
+}
+
+// Distributes the available size proportionally to the given fractions. The sizes are truncated,
+// the rounding gap is added to the last column or row by the caller.
+fn fraction_sizes(available: f64, fractions: &[f64]) -> Vec<f64> {
+    let total: f64 = fractions.iter().sum();
+
+    if total <= 0.0 {
+        return vec![0.0; fractions.len()];
+    }
+
+    fractions
+        .iter()
+        .map(|fraction| (available.max(0.0) * fraction / total).trunc())
+        .collect()
+}
+
 impl Into<Box<dyn Layout>> for GridLayout {
     fn into(self) -> Box<dyn Layout> {
         Box::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auto() -> GridAttachment {
+        GridAttachment {
+            column_span: 1,
+            row_span: 1,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_auto_place() {
+        let children = vec![auto(), auto(), auto(), auto()];
+        assert_eq!(
+            auto_place(&children, 2),
+            vec![(0, 0), (1, 0), (0, 1), (1, 1)]
+        );
+
+        // explicit placed children keep their cell and their cells are skipped
+        let children = vec![
+            auto(),
+            GridAttachment {
+                column: Some(1),
+                row: Some(0),
+                ..auto()
+            },
+            auto(),
+        ];
+        assert_eq!(auto_place(&children, 2), vec![(0, 0), (1, 0), (0, 1)]);
+
+        // spans occupy multiple cells
+        let children = vec![
+            GridAttachment {
+                column_span: 2,
+                ..auto()
+            },
+            GridAttachment {
+                row_span: 2,
+                ..auto()
+            },
+            auto(),
+            auto(),
+        ];
+        assert_eq!(
+            auto_place(&children, 2),
+            vec![(0, 0), (0, 1), (1, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_fraction_sizes() {
+        assert_eq!(fraction_sizes(300.0, &[1.0, 2.0]), vec![100.0, 200.0]);
+        assert_eq!(
+            fraction_sizes(100.0, &[1.0, 1.0, 1.0]),
+            vec![33.0, 33.0, 33.0]
+        );
+        assert_eq!(fraction_sizes(-10.0, &[1.0]), vec![0.0]);
+        assert_eq!(fraction_sizes(100.0, &[0.0]), vec![0.0]);
+    }
+}
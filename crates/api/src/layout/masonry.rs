@@ -0,0 +1,231 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+use dces::prelude::*;
+
+use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*};
+
+use super::{component, component_or_default, component_try_mut, Layout};
+
+/// Orders its children in columns of the same width. Each child is placed in the column with
+/// the lowest height, so children with different heights are packed without gaps.
+#[derive(Default)]
+pub struct MasonryLayout {
+    desired_size: RefCell<DirtySize>,
+    children_sizes: RefCell<BTreeMap<Entity, (f64, f64)>>,
+}
+
+impl MasonryLayout {
+    pub fn new() -> Self {
+        MasonryLayout::default()
+    }
+}
+
+impl Layout for MasonryLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return *self.desired_size.borrow();
+        }
+
+        let columns = component_or_default::<usize>(ecm, entity, "columns").max(1);
+        let gap: f64 = component_or_default(ecm, entity, "gap");
+
+        self.children_sizes.borrow_mut().clear();
+        let mut column_heights = vec![0.0; columns];
+        let mut max_width: f64 = 0.0;
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                if child_desired_size.dirty() {
+                    self.desired_size.borrow_mut().set_child_dirty(true);
+                }
+
+                if component::<Visibility>(ecm, child, "visibility") == Visibility::Collapsed {
+                    continue;
+                }
+
+                let margin: Thickness = component(ecm, child, "margin");
+                let width = child_desired_size.width() + margin.left() + margin.right();
+                let height = child_desired_size.height() + margin.top() + margin.bottom();
+
+                max_width = max_width.max(width);
+                column_heights[shortest_column(&column_heights)] += height + gap;
+
+                self.children_sizes
+                    .borrow_mut()
+                    .insert(child, (width, height));
+            }
+        }
+
+        let size = component::<Constraint>(ecm, entity, "constraint").perform((
+            max_width * columns as f64 + gap * (columns - 1) as f64,
+            content_height(&column_heights, gap),
+        ));
+
+        self.desired_size.borrow_mut().set_size(size.0, size.1);
+
+        *self.desired_size.borrow()
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() {
+            return self.desired_size.borrow().size();
+        }
+
+        let horizontal_alignment: Alignment = component(ecm, entity, "h_align");
+        let vertical_alignment: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let columns = component_or_default::<usize>(ecm, entity, "columns").max(1);
+        let gap: f64 = component_or_default(ecm, entity, "gap");
+
+        let mut size = constraint.perform((
+            horizontal_alignment.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            vertical_alignment.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        let column_width = ((size.0 - gap * (columns - 1) as f64) / columns as f64).max(0.0);
+        let mut column_heights = vec![0.0; columns];
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            let child_height = match self.children_sizes.borrow().get(&child) {
+                Some(child_size) => child_size.1,
+                None => continue,
+            };
+
+            if let Some(child_layout) = layouts.get(&child) {
+                child_layout.arrange(
+                    render_context_2_d,
+                    (column_width, child_height),
+                    child,
+                    ecm,
+                    layouts,
+                    theme,
+                );
+            }
+
+            let child_margin: Thickness = component(ecm, child, "margin");
+            let child_horizontal_alignment: Alignment = component(ecm, child, "h_align");
+            let column = shortest_column(&column_heights);
+            let y = column_heights[column];
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                child_bounds.set_x(
+                    column as f64 * (column_width + gap)
+                        + child_horizontal_alignment.align_position(
+                            column_width,
+                            child_bounds.width(),
+                            child_margin.left(),
+                            child_margin.right(),
+                        ),
+                );
+                child_bounds.set_y(y + child_margin.top());
+
+                column_heights[column] +=
+                    child_bounds.height() + child_margin.top() + child_margin.bottom() + gap;
+            }
+
+            mark_as_dirty("bounds", child, ecm);
+        }
+
+        size.1 = constraint
+            .perform((size.0, content_height(&column_heights, gap)))
+            .1;
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.desired_size.borrow_mut().set_dirty(false);
+        size
+    }
+}
+
+impl Into<Box<dyn Layout>> for MasonryLayout {
+    fn into(self) -> Box<dyn Layout> {
+        Box::new(self)
+    }
+}
+
+// Returns the index of the column with the lowest height, the first one if several columns have
+// the same height.
+fn shortest_column(column_heights: &[f64]) -> usize {
+    column_heights
+        .iter()
+        .enumerate()
+        .fold((0, f64::MAX), |shortest, (index, height)| {
+            if *height < shortest.1 {
+                (index, *height)
+            } else {
+                shortest
+            }
+        })
+        .0
+}
+
+// Returns the height of the highest column without the gap after its last child.
+fn content_height(column_heights: &[f64], gap: f64) -> f64 {
+    let height = column_heights
+        .iter()
+        .fold(0.0_f64, |height, column_height| height.max(*column_height));
+
+    (height - gap).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortest_column() {
+        assert_eq!(shortest_column(&[0.0, 0.0, 0.0]), 0);
+        assert_eq!(shortest_column(&[20.0, 10.0, 10.0]), 1);
+        assert_eq!(shortest_column(&[20.0, 30.0, 5.0]), 2);
+    }
+
+    #[test]
+    fn test_content_height() {
+        assert_eq!(content_height(&[0.0, 0.0], 8.0), 0.0);
+        assert_eq!(content_height(&[58.0, 108.0], 8.0), 100.0);
+    }
+}
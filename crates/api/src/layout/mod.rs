@@ -5,20 +5,26 @@ use dces::prelude::*;
 use crate::{render::RenderContext2D, theming::*, tree::Tree, utils::*};
 
 pub use self::absolute::*;
+pub use self::dock::*;
 pub use self::fixed_size::*;
 pub use self::grid::*;
+pub use self::masonry::*;
 pub use self::padding::*;
 pub use self::popup::*;
 pub use self::stack::*;
 pub use self::text_selection::*;
+pub use self::wrap::*;
 
 mod absolute;
+mod dock;
 mod fixed_size;
 mod grid;
+mod masonry;
 mod padding;
 mod popup;
 mod stack;
 mod text_selection;
+mod wrap;
 
 /// A layout is used to dynamic order the children of a widget.
 pub trait Layout: Any {
@@ -42,6 +48,12 @@ pub trait Layout: Any {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> (f64, f64);
+
+    /// Gets the short type name of the layout e.g. `GridLayout`. Used to label layout boundaries in debug mode.
+    fn name(&self) -> &'static str {
+        let name = std::any::type_name::<Self>();
+        name.rsplit("::").next().unwrap_or(name)
+    }
 }
 
 fn component<C: Component + Clone>(
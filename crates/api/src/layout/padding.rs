@@ -67,8 +67,9 @@ impl Layout for PaddingLayout {
                     child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
                 let mut desired_size = self.desired_size.borrow().size();
 
-                let dirty = child_desired_size.dirty() || self.desired_size.borrow().dirty();
-                self.desired_size.borrow_mut().set_dirty(dirty);
+                if child_desired_size.dirty() {
+                    self.desired_size.borrow_mut().set_child_dirty(true);
+                }
 
                 let child_margin = *ecm
                     .component_store()
@@ -7,7 +7,7 @@ use dces::prelude::*;
 
 use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*};
 
-use super::{component, component_try_mut, try_component, Layout};
+use super::{component, component_or_default, component_try_mut, try_component, Layout};
 
 /// Add padding to the widget.
 #[derive(Default)]
@@ -73,8 +73,9 @@ impl Layout for PopupLayout {
                     child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
                 let mut desired_size = self.desired_size.borrow().size();
 
-                let dirty = child_desired_size.dirty() || self.desired_size.borrow().dirty();
-                self.desired_size.borrow_mut().set_dirty(dirty);
+                if child_desired_size.dirty() {
+                    self.desired_size.borrow_mut().set_child_dirty(true);
+                }
 
                 let child_margin = *ecm
                     .component_store()
@@ -120,6 +121,8 @@ impl Layout for PopupLayout {
         }
 
         if !self.desired_size.borrow().dirty() {
+            // the target could be moved without changing the size of the popup
+            place_at_target(entity, ecm);
             return self.desired_size.borrow().size();
         }
 
@@ -206,6 +209,8 @@ impl Layout for PopupLayout {
             mark_as_dirty("bounds", child, ecm);
         }
 
+        place_at_target(entity, ecm);
+
         self.desired_size.borrow_mut().set_dirty(false);
         size
     }
@@ -216,3 +221,178 @@ impl Into<Box<dyn Layout>> for PopupLayout {
         Box::new(self)
     }
 }
+
+// --- Helpers --
+
+// Places the popup at its target. The target could be anywhere in the tree, e.g. the popup lives
+// in the overlay while the target is deep inside of the main tree.
+fn place_at_target(entity: Entity, ecm: &mut EntityComponentManager<Tree, StringComponentStore>) {
+    let target: Entity = match try_component::<u32>(ecm, entity, "target") {
+        Some(target) => target.into(),
+        None => return,
+    };
+
+    let target_bounds = match try_component::<Rectangle>(ecm, target, "bounds") {
+        Some(bounds) => bounds,
+        None => return,
+    };
+
+    let target_position = window_position(target, ecm);
+    let parent_position = ecm
+        .entity_store()
+        .parent
+        .get(&entity)
+        .copied()
+        .flatten()
+        .map_or(Point::default(), |parent| window_position(parent, ecm));
+    let root = ecm.entity_store().root();
+    let window_bounds = component::<Rectangle>(ecm, root, "bounds");
+    let preferred_alignment = component_or_default(ecm, entity, "preferred_alignment");
+    let preferred_side = component_or_default(ecm, entity, "preferred_side");
+
+    if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+        let x = calculate_x(
+            preferred_alignment,
+            target_position.x(),
+            target_bounds.width(),
+            bounds.width(),
+            window_bounds.width(),
+        );
+        let y = calculate_y(
+            preferred_side,
+            target_position.y(),
+            target_bounds.height(),
+            bounds.height(),
+            window_bounds.height(),
+        );
+
+        bounds.set_x(x - parent_position.x());
+        bounds.set_y(y - parent_position.y());
+    }
+
+    mark_as_dirty("bounds", entity, ecm);
+}
+
+// Sums up the bounds of the widget and its ancestors to get its position inside of the window.
+fn window_position(
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+) -> Point {
+    let tree = ecm.entity_store();
+
+    std::iter::once(entity)
+        .chain(tree.walk_ancestors(entity))
+        .filter_map(|e| ecm.component_store().get::<Rectangle>("bounds", e).ok())
+        .fold(Point::default(), |position, bounds| {
+            Point::new(position.x() + bounds.x(), position.y() + bounds.y())
+        })
+}
+
+fn calculate_x(
+    alignment: PopupAlignment,
+    target_x: f64,
+    target_width: f64,
+    width: f64,
+    window_width: f64,
+) -> f64 {
+    let start = target_x;
+    let end = (target_x + target_width - width).max(0.0);
+
+    match alignment {
+        PopupAlignment::Start => start,
+        PopupAlignment::End => end,
+        PopupAlignment::Auto => {
+            if start + width > window_width {
+                end
+            } else {
+                start
+            }
+        }
+    }
+}
+
+fn calculate_y(
+    side: PopupSide,
+    target_y: f64,
+    target_height: f64,
+    height: f64,
+    window_height: f64,
+) -> f64 {
+    let bottom = 1.0 + target_y + target_height;
+    let top = target_y - height - 1.0;
+
+    match side {
+        PopupSide::Bottom => bottom,
+        PopupSide::Top => top,
+        PopupSide::Auto => {
+            // flips only if there is enough space above the target
+            if bottom + height > window_height && top >= 0.0 {
+                top
+            } else {
+                bottom
+            }
+        }
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_x() {
+        assert_eq!(
+            10.0,
+            calculate_x(PopupAlignment::Auto, 10.0, 100.0, 150.0, 400.0)
+        );
+        assert_eq!(
+            250.0,
+            calculate_x(PopupAlignment::Auto, 300.0, 100.0, 150.0, 400.0)
+        );
+        assert_eq!(
+            300.0,
+            calculate_x(PopupAlignment::Start, 300.0, 100.0, 150.0, 400.0)
+        );
+        assert_eq!(
+            0.0,
+            calculate_x(PopupAlignment::End, 10.0, 100.0, 150.0, 400.0)
+        );
+    }
+
+    #[test]
+    fn test_calculate_y() {
+        assert_eq!(41.0, calculate_y(PopupSide::Auto, 10.0, 30.0, 100.0, 400.0));
+        assert_eq!(
+            199.0,
+            calculate_y(PopupSide::Auto, 300.0, 30.0, 100.0, 400.0)
+        );
+        assert_eq!(41.0, calculate_y(PopupSide::Auto, 10.0, 30.0, 100.0, 100.0));
+        assert_eq!(
+            331.0,
+            calculate_y(PopupSide::Bottom, 300.0, 30.0, 100.0, 400.0)
+        );
+        assert_eq!(-91.0, calculate_y(PopupSide::Top, 10.0, 30.0, 100.0, 400.0));
+    }
+
+    #[test]
+    fn test_window_position() {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+
+        let root = ecm.create_entity().build();
+        let child = ecm.create_entity().build();
+        ecm.entity_store_mut().append_child(root, child).unwrap();
+        ecm.component_store_mut().register(
+            "bounds",
+            root,
+            Rectangle::new((10.0, 20.0), 100.0, 100.0),
+        );
+        ecm.component_store_mut()
+            .register("bounds", child, Rectangle::new((5.0, 6.0), 10.0, 10.0));
+
+        assert_eq!(window_position(child, ecm), Point::new(15.0, 26.0));
+    }
+}
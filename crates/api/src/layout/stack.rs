@@ -9,7 +9,9 @@ use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::
 
 use super::{component, component_or_default, component_try_mut, Layout};
 
-/// Stacks visual the children widgets vertical or horizontal.
+/// Stacks visual the children widgets vertical or horizontal. If the `reverse` property of the
+/// widget is set, the children are arranged from the bottom to the top or from the right to the
+/// left.
 #[derive(Default)]
 pub struct StackLayout {
     desired_size: RefCell<DirtySize>,
@@ -50,7 +52,7 @@ impl Layout for StackLayout {
         }
 
         let orientation: Orientation = component(ecm, entity, "orientation");
-        let mut dirty = false;
+        let mut child_dirty = false;
         let mut desired_size: (f64, f64) = (0.0, 0.0);
 
         let nchildren = ecm.entity_store().children[&entity].len();
@@ -82,13 +84,13 @@ impl Layout for StackLayout {
                     orientation,
                 );
 
-                if child_desired_size.dirty() || self.desired_size.borrow().dirty() {
-                    dirty = true;
+                if child_desired_size.dirty() {
+                    child_dirty = true;
                 }
             }
         }
 
-        self.set_dirty(dirty);
+        self.desired_size.borrow_mut().set_child_dirty(child_dirty);
 
         let mut desired = self.desired_size.borrow_mut();
         desired.set_size(desired_size.0, desired_size.1);
@@ -139,9 +141,16 @@ impl Layout for StackLayout {
         let available_size = size;
         let nchildren = ecm.entity_store().children[&entity].len();
         let spacing: f64 = component_or_default(ecm, entity, "spacing");
+        let reverse: bool = component_or_default(ecm, entity, "reverse");
 
+        // index is the position of the child in the stack, not in the list of children
         for index in 0..nchildren {
-            let child = ecm.entity_store().children[&entity][index];
+            let child_index = if reverse {
+                nchildren - 1 - index
+            } else {
+                index
+            };
+            let child = ecm.entity_store().children[&entity][child_index];
 
             match orientation {
                 Orientation::Horizontal => {
@@ -326,7 +335,105 @@ mod tests {
     use orbtk_utils::{Orientation, Thickness};
     use std::iter;
 
-    use super::apply_spacing;
+    use super::*;
+
+    // Measures and arranges its widget with a fixed size.
+    struct FixedLayout(f64, f64);
+
+    impl Layout for FixedLayout {
+        fn measure(
+            &self,
+            _: &mut RenderContext2D,
+            _: Entity,
+            _: &mut EntityComponentManager<Tree, StringComponentStore>,
+            _: &BTreeMap<Entity, Box<dyn Layout>>,
+            _: &Theme,
+        ) -> DirtySize {
+            let mut desired_size = DirtySize::default();
+            desired_size.set_size(self.0, self.1);
+            desired_size
+        }
+
+        fn arrange(
+            &self,
+            _: &mut RenderContext2D,
+            _: (f64, f64),
+            entity: Entity,
+            ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+            _: &BTreeMap<Entity, Box<dyn Layout>>,
+            _: &Theme,
+        ) -> (f64, f64) {
+            if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+                bounds.set_width(self.0);
+                bounds.set_height(self.1);
+            }
+
+            (self.0, self.1)
+        }
+    }
+
+    // Arranges a vertical stack with three children and returns their y positions.
+    fn arrange_children(reverse: bool) -> Vec<f64> {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let mut render_context = RenderContext2D::new(100.0, 100.0);
+        let theme = crate::theme::dark_theme();
+        let ecm = world.entity_component_manager();
+        let mut layouts: BTreeMap<Entity, Box<dyn Layout>> = BTreeMap::new();
+
+        let stack = ecm.create_entity().build();
+        let store = ecm.component_store_mut();
+        store.register("visibility", stack, Visibility::Visible);
+        store.register("h_align", stack, Alignment::Stretch);
+        store.register("v_align", stack, Alignment::Stretch);
+        store.register("margin", stack, Thickness::default());
+        store.register("constraint", stack, Constraint::default());
+        store.register("orientation", stack, Orientation::Vertical);
+        store.register("reverse", stack, reverse);
+        store.register("bounds", stack, Rectangle::default());
+        store.register("dirty", stack, false);
+
+        let mut children = vec![];
+
+        for height in &[10.0, 20.0, 30.0] {
+            let child = ecm.create_entity().build();
+            let store = ecm.component_store_mut();
+            store.register("h_align", child, Alignment::Stretch);
+            store.register("v_align", child, Alignment::Start);
+            store.register("margin", child, Thickness::default());
+            store.register("bounds", child, Rectangle::default());
+            store.register("dirty", child, false);
+            ecm.entity_store_mut().append_child(stack, child).unwrap();
+            layouts.insert(child, Box::new(FixedLayout(100.0, *height)));
+            children.push(child);
+        }
+
+        let layout = StackLayout::new();
+        layout.measure(&mut render_context, stack, ecm, &layouts, &theme);
+        layout.arrange(
+            &mut render_context,
+            (100.0, 300.0),
+            stack,
+            ecm,
+            &layouts,
+            &theme,
+        );
+
+        children
+            .iter()
+            .map(|child| component::<Rectangle>(ecm, *child, "bounds").y())
+            .collect()
+    }
+
+    #[test]
+    fn test_arrange() {
+        assert_eq!(arrange_children(false), vec![0.0, 10.0, 30.0]);
+    }
+
+    #[test]
+    fn test_arrange_reverse() {
+        assert_eq!(arrange_children(true), vec![50.0, 30.0, 0.0]);
+    }
 
     const NUM_WIDGETS: usize = 5;
 
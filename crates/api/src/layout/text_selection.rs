@@ -59,11 +59,12 @@ impl Layout for TextSelectionLayout {
             let child = ecm.entity_store().children[&entity][index];
 
             if let Some(child_layout) = layouts.get(&child) {
-                let dirty = child_layout
+                if child_layout
                     .measure(render_context_2_d, child, ecm, layouts, theme)
                     .dirty()
-                    || self.desired_size.borrow().dirty();
-                self.desired_size.borrow_mut().set_dirty(dirty);
+                {
+                    self.desired_size.borrow_mut().set_child_dirty(true);
+                }
             }
         }
 
@@ -77,18 +78,6 @@ impl Layout for TextSelectionLayout {
                 .set_height(constraint.height());
         }
 
-        for index in 0..ecm.entity_store().children[&entity].len() {
-            let child = ecm.entity_store().children[&entity][index];
-
-            if let Some(child_layout) = layouts.get(&child) {
-                let dirty = child_layout
-                    .measure(render_context_2_d, child, ecm, layouts, theme)
-                    .dirty()
-                    || self.desired_size.borrow().dirty();
-                self.desired_size.borrow_mut().set_dirty(dirty);
-            }
-        }
-
         *self.desired_size.borrow()
     }
 
@@ -124,7 +113,14 @@ impl Layout for TextSelectionLayout {
                 margin.bottom(),
             );
 
-            if let Some(text) = try_component::<String16>(ecm, text_block, "text") {
+            if let Some(mut text) = try_component::<String16>(ecm, text_block, "text") {
+                // the caret is placed on the displayed mask of a password
+                if try_component::<bool>(ecm, text_block, "password_mode").unwrap_or_default() {
+                    let mask_char = try_component::<String>(ecm, text_block, "mask_char")
+                        .unwrap_or_else(|| DEFAULT_MASK_CHAR.to_string());
+                    text = mask_text(&text, &mask_char);
+                }
+
                 let font: String = component(ecm, text_block, "font");
                 let font_size: f64 = component(ecm, text_block, "font_size");
                 text_len = text.len();
@@ -173,13 +169,9 @@ impl Layout for TextSelectionLayout {
             }
 
             if !expanded && selection_start > 0 {
-                if pos < 0.0 || pos + size.0 > view_port_width {
-                    let delta = if pos < 0.0 {
-                        pos
-                    } else {
-                        pos - view_port_width + size.0
-                    };
+                let delta = calculate_scroll_delta(pos, size.0, view_port_width);
 
+                if delta != 0.0 {
                     pos = pos - delta;
 
                     // adjust the position of the text block
@@ -216,3 +208,119 @@ impl Layout for TextSelectionLayout {
         size
     }
 }
+
+// Returns the distance the cursor at the given position has to be moved to be inside of the view
+// port. Is `0.0` if the cursor is already visible.
+fn calculate_scroll_delta(pos: f64, width: f64, view_port_width: f64) -> f64 {
+    if pos < 0.0 {
+        return pos;
+    }
+
+    if pos + width > view_port_width {
+        return pos - view_port_width + width;
+    }
+
+    0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+
+    // Counts the calls of measure.
+    struct CountingLayout(Rc<Cell<usize>>);
+
+    impl Layout for CountingLayout {
+        fn measure(
+            &self,
+            _: &mut RenderContext2D,
+            _: Entity,
+            _: &mut EntityComponentManager<Tree, StringComponentStore>,
+            _: &BTreeMap<Entity, Box<dyn Layout>>,
+            _: &Theme,
+        ) -> DirtySize {
+            self.0.set(self.0.get() + 1);
+            DirtySize::default()
+        }
+
+        fn arrange(
+            &self,
+            _: &mut RenderContext2D,
+            _: (f64, f64),
+            _: Entity,
+            _: &mut EntityComponentManager<Tree, StringComponentStore>,
+            _: &BTreeMap<Entity, Box<dyn Layout>>,
+            _: &Theme,
+        ) -> (f64, f64) {
+            (0.0, 0.0)
+        }
+    }
+
+    #[test]
+    fn test_measure_children_once() {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+        let count = Rc::new(Cell::new(0));
+        let mut layouts: BTreeMap<Entity, Box<dyn Layout>> = BTreeMap::new();
+
+        let entity = ecm.create_entity().build();
+        ecm.component_store_mut()
+            .register("visibility", entity, Visibility::Visible);
+        ecm.component_store_mut()
+            .register("constraint", entity, Constraint::default());
+
+        for _ in 0..2 {
+            let child = ecm.create_entity().build();
+            ecm.entity_store_mut().append_child(entity, child).unwrap();
+            layouts.insert(child, Box::new(CountingLayout(count.clone())));
+        }
+
+        let desired_size = TextSelectionLayout::new().measure(
+            &mut RenderContext2D::new(100.0, 100.0),
+            entity,
+            ecm,
+            &layouts,
+            &crate::theme::dark_theme(),
+        );
+
+        assert_eq!(count.get(), 2);
+        assert!(desired_size.reason().child_dirty);
+    }
+}
+
+#[cfg(test)]
+mod prop_tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_scroll_delta_moves_cursor_into_view_port(
+            pos in -2000.0..2000.0f64,
+            view_port_width in 1.0..1000.0f64,
+            width_factor in 0.0..1.0f64
+        ) {
+            let width = view_port_width * width_factor;
+            let pos = pos - calculate_scroll_delta(pos, width, view_port_width);
+
+            prop_assert!(pos >= -1e-9);
+            prop_assert!(pos + width <= view_port_width + 1e-9);
+        }
+
+        #[test]
+        fn test_scroll_delta_zero_if_visible(
+            view_port_width in 1.0..1000.0f64,
+            width_factor in 0.0..1.0f64,
+            pos_factor in 0.0..1.0f64
+        ) {
+            let width = view_port_width * width_factor;
+            let pos = (view_port_width - width) * pos_factor;
+
+            prop_assert_eq!(0.0, calculate_scroll_delta(pos, width, view_port_width));
+        }
+    }
+}
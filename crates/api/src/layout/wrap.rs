@@ -0,0 +1,295 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{prelude::*, render::RenderContext2D, theming::*, tree::Tree, utils::prelude::*};
+
+use super::{component, component_or_default, component_try_mut, Layout};
+
+/// Orders its children from left to right and continues with a new row if a child does not fit
+/// into the width of the current row. The children of a row are aligned by `align_items`.
+#[derive(Default)]
+pub struct WrapLayout {
+    desired_size: RefCell<DirtySize>,
+    children_sizes: RefCell<BTreeMap<Entity, (f64, f64)>>,
+    arranged_width: Cell<f64>,
+}
+
+impl WrapLayout {
+    pub fn new() -> Self {
+        WrapLayout::default()
+    }
+}
+
+impl Layout for WrapLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return *self.desired_size.borrow();
+        }
+
+        let h_gap: f64 = component_or_default(ecm, entity, "h_gap");
+        let v_gap: f64 = component_or_default(ecm, entity, "v_gap");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+
+        self.children_sizes.borrow_mut().clear();
+        let mut sizes = vec![];
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                if child_desired_size.dirty() {
+                    self.desired_size.borrow_mut().set_child_dirty(true);
+                }
+
+                if component::<Visibility>(ecm, child, "visibility") == Visibility::Collapsed {
+                    continue;
+                }
+
+                let margin: Thickness = component(ecm, child, "margin");
+                let size = (
+                    child_desired_size.width() + margin.left() + margin.right(),
+                    child_desired_size.height() + margin.top() + margin.bottom(),
+                );
+
+                sizes.push(size);
+                self.children_sizes.borrow_mut().insert(child, size);
+            }
+        }
+
+        // the width of the parent is not known before the arrangement, so the width of the last
+        // arrangement is used to wrap the rows
+        let available_width = if constraint.width() > 0.0 {
+            constraint.width()
+        } else if self.arranged_width.get() > 0.0 {
+            self.arranged_width.get()
+        } else {
+            f64::MAX
+        };
+
+        let widths: Vec<f64> = sizes.iter().map(|size| size.0).collect();
+        let rows = row_sizes(&sizes, &wrap_rows(&widths, available_width, h_gap), h_gap);
+        let size = constraint.perform(content_size(&rows, v_gap));
+
+        self.desired_size.borrow_mut().set_size(size.0, size.1);
+
+        *self.desired_size.borrow()
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() {
+            return self.desired_size.borrow().size();
+        }
+
+        let horizontal_alignment: Alignment = component(ecm, entity, "h_align");
+        let vertical_alignment: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let h_gap: f64 = component_or_default(ecm, entity, "h_gap");
+        let v_gap: f64 = component_or_default(ecm, entity, "v_gap");
+        let align_items: Alignment = component_or_default(ecm, entity, "align_items");
+
+        let mut size = constraint.perform((
+            horizontal_alignment.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            vertical_alignment.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        // the rows are wrapped again, because the available width could differ from the measure
+        let children: Vec<(Entity, (f64, f64))> = ecm.entity_store().children[&entity]
+            .iter()
+            .filter_map(|child| {
+                self.children_sizes
+                    .borrow()
+                    .get(child)
+                    .map(|child_size| (*child, *child_size))
+            })
+            .collect();
+        let sizes: Vec<(f64, f64)> = children.iter().map(|(_, size)| *size).collect();
+        let widths: Vec<f64> = sizes.iter().map(|size| size.0).collect();
+        let child_rows = wrap_rows(&widths, size.0, h_gap);
+        let rows = row_sizes(&sizes, &child_rows, h_gap);
+
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let mut current_row = 0;
+
+        for ((child, child_size), row) in children.iter().zip(child_rows.iter()) {
+            if *row != current_row {
+                x = 0.0;
+                y += rows[current_row].1 + v_gap;
+                current_row = *row;
+            }
+
+            let row_height = rows[*row].1;
+
+            if let Some(child_layout) = layouts.get(child) {
+                child_layout.arrange(
+                    render_context_2_d,
+                    (child_size.0, row_height),
+                    *child,
+                    ecm,
+                    layouts,
+                    theme,
+                );
+            }
+
+            let child_margin: Thickness = component(ecm, *child, "margin");
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, *child, "bounds") {
+                child_bounds.set_x(x + child_margin.left());
+                child_bounds.set_y(
+                    y + align_items.align_position(
+                        row_height,
+                        child_bounds.height(),
+                        child_margin.top(),
+                        child_margin.bottom(),
+                    ),
+                );
+            }
+
+            mark_as_dirty("bounds", *child, ecm);
+
+            x += child_size.0 + h_gap;
+        }
+
+        size.1 = constraint.perform((size.0, content_size(&rows, v_gap).1)).1;
+        self.arranged_width.set(size.0);
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.desired_size.borrow_mut().set_dirty(false);
+        size
+    }
+}
+
+impl Into<Box<dyn Layout>> for WrapLayout {
+    fn into(self) -> Box<dyn Layout> {
+        Box::new(self)
+    }
+}
+
+// Returns the index of the row of each child. A child is moved to the next row if it does not fit
+// next to the previous children of the row, a child that is wider than the row gets its own row.
+fn wrap_rows(widths: &[f64], available_width: f64, h_gap: f64) -> Vec<usize> {
+    let mut rows = Vec::with_capacity(widths.len());
+    let mut row = 0;
+    let mut row_width = 0.0;
+
+    for (index, width) in widths.iter().enumerate() {
+        if index == 0 {
+            row_width = *width;
+        } else if row_width + h_gap + width > available_width {
+            row += 1;
+            row_width = *width;
+        } else {
+            row_width += h_gap + width;
+        }
+
+        rows.push(row);
+    }
+
+    rows
+}
+
+// Returns the width and the height of each row.
+fn row_sizes(sizes: &[(f64, f64)], rows: &[usize], h_gap: f64) -> Vec<(f64, f64)> {
+    let mut row_sizes: Vec<(f64, f64)> = vec![];
+
+    for (size, row) in sizes.iter().zip(rows.iter()) {
+        if let Some(row_size) = row_sizes.get_mut(*row) {
+            row_size.0 += h_gap + size.0;
+            row_size.1 = row_size.1.max(size.1);
+        } else {
+            row_sizes.push(*size);
+        }
+    }
+
+    row_sizes
+}
+
+// Returns the width of the widest row and the height of all rows with the gaps between them.
+fn content_size(row_sizes: &[(f64, f64)], v_gap: f64) -> (f64, f64) {
+    let width = row_sizes
+        .iter()
+        .fold(0.0_f64, |width, row_size| width.max(row_size.0));
+    let height = row_sizes.iter().map(|row_size| row_size.1).sum::<f64>()
+        + v_gap * row_sizes.len().saturating_sub(1) as f64;
+
+    (width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_rows() {
+        assert_eq!(wrap_rows(&[40.0, 40.0, 40.0], 100.0, 0.0), vec![0, 0, 1]);
+        assert_eq!(wrap_rows(&[40.0, 40.0, 40.0], 90.0, 20.0), vec![0, 1, 2]);
+        assert_eq!(wrap_rows(&[40.0, 40.0, 40.0], f64::MAX, 8.0), vec![0, 0, 0]);
+        assert_eq!(wrap_rows(&[150.0, 20.0, 20.0], 100.0, 0.0), vec![0, 1, 1]);
+        assert!(wrap_rows(&[], 100.0, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_row_sizes() {
+        let sizes = [(40.0, 10.0), (40.0, 30.0), (40.0, 20.0)];
+
+        assert_eq!(
+            row_sizes(&sizes, &[0, 0, 1], 8.0),
+            vec![(88.0, 30.0), (40.0, 20.0)]
+        );
+        assert!(row_sizes(&[], &[], 8.0).is_empty());
+    }
+
+    #[test]
+    fn test_content_size() {
+        assert_eq!(
+            content_size(&[(88.0, 30.0), (40.0, 20.0)], 4.0),
+            (88.0, 54.0)
+        );
+        assert_eq!(content_size(&[], 4.0), (0.0, 0.0));
+    }
+}
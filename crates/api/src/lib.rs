@@ -13,6 +13,8 @@ pub(crate) use orbtk_theming as theming;
 pub(crate) use orbtk_tree::prelude as tree;
 pub(crate) use orbtk_utils::prelude as utils;
 
+pub mod accessibility;
+pub mod animation;
 pub mod application;
 #[macro_use]
 pub mod event;
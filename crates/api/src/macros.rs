@@ -110,6 +110,8 @@ macro_rules! widget {
             clip: bool,
             #[property(f32)]
             opacity: f32,
+            #[property(CompositeMode)]
+            composite_mode: CompositeMode,
             #[property(Visibility)]
             visibility: Visibility,
             #[property(Selector)]
@@ -231,6 +233,11 @@ macro_rules! widget {
                 self.set_property("opacity", opacity)
             }
 
+            /// Sets or shares the composite mode property.
+            pub fn composite_mode(self, composite_mode: impl IntoPropertySource<CompositeMode>) -> Self {
+                self.set_property("composite_mode", composite_mode)
+            }
+
             /// Inserts a new width.
             pub fn width(mut self, width: impl Into<f64>) -> Self {
                 if !self.width.is_none() {
@@ -439,6 +446,7 @@ macro_rules! widget {
                 ctx.register_property("enabled", entity, this.enabled);
                 ctx.register_property("clip", entity, this.clip);
                 ctx.register_property("opacity", entity, this.opacity);
+                ctx.register_property("composite_mode", entity, this.composite_mode);
                 ctx.register_property("type_id", entity, TypeId::of::<$widget>());
                 ctx.register_property("type_name", entity, std::any::type_name::<$widget>().to_string());
                 ctx.register_property("dirty", entity, false);
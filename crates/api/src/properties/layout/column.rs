@@ -94,7 +94,14 @@ impl From<&str> for Column {
     fn from(t: &str) -> Self {
         match t {
             "Auto" | "auto" => Column::new().width(ColumnWidth::Auto).build(),
-            _ => Column::new().width(ColumnWidth::Stretch).build(),
+            _ => {
+                // e.g. "2fr"
+                if let Some(fraction) = t.strip_suffix("fr").and_then(|f| f.trim().parse().ok()) {
+                    return Column::new().width(ColumnWidth::Fraction(fraction)).build();
+                }
+
+                Column::new().width(ColumnWidth::Stretch).build()
+            }
         }
     }
 }
@@ -117,11 +124,27 @@ pub enum ColumnWidth {
     /// Column is measured by the largest child.
     Auto,
 
-    /// Column expands to the rest available width.
+    /// Column expands to the rest available width. Is the same as `Fraction(1.0)`.
     Stretch,
 
     /// Defines a fixed size for the column.
     Width(f64),
+
+    /// Column takes the given fractional units of the width that is left after the other columns
+    /// are sized.
+    Fraction(f64),
+}
+
+impl ColumnWidth {
+    /// Returns the fractional units of the rest width that are taken by the column. Returns `None`
+    /// for columns with fixed or auto width.
+    pub fn fraction(&self) -> Option<f64> {
+        match self {
+            ColumnWidth::Stretch => Some(1.0),
+            ColumnWidth::Fraction(fraction) => Some(*fraction),
+            _ => None,
+        }
+    }
 }
 
 impl Default for ColumnWidth {
@@ -326,5 +349,19 @@ mod tests {
 
         let column: Column = 64.0.into();
         assert_eq!(column.width(), ColumnWidth::Width(64.0));
+
+        let column: Column = "2fr".into();
+        assert_eq!(column.width(), ColumnWidth::Fraction(2.0));
+
+        let column: Column = "0.5fr".into();
+        assert_eq!(column.width(), ColumnWidth::Fraction(0.5));
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(ColumnWidth::Stretch.fraction(), Some(1.0));
+        assert_eq!(ColumnWidth::Fraction(2.0).fraction(), Some(2.0));
+        assert_eq!(ColumnWidth::Auto.fraction(), None);
+        assert_eq!(ColumnWidth::Width(64.0).fraction(), None);
     }
 }
@@ -2,10 +2,14 @@
 
 pub use self::column::*;
 pub use self::constraint::*;
+pub use self::position_mode::*;
+pub use self::relative_constraint::*;
 pub use self::row::*;
 pub use self::scroll_viewer_mode::*;
 
 mod column;
 mod constraint;
+mod position_mode;
+mod relative_constraint;
 mod row;
 mod scroll_viewer_mode;
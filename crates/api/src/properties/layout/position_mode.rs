@@ -0,0 +1,39 @@
+/// The `PositionMode` defines how the left and top margin of a child of an `AbsoluteLayout` are
+/// interpreted.
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub enum PositionMode {
+    /// The child is placed by its own layout, the margin is used in pixels.
+    Pixels,
+
+    /// The left and top margin are percentages of the width and height of the parent.
+    Percent,
+}
+
+impl Default for PositionMode {
+    fn default() -> Self {
+        PositionMode::Pixels
+    }
+}
+
+impl From<&str> for PositionMode {
+    fn from(s: &str) -> PositionMode {
+        match s {
+            "Percent" | "percent" => PositionMode::Percent,
+            _ => PositionMode::Pixels,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let position_mode: PositionMode = "Percent".into();
+        assert_eq!(position_mode, PositionMode::Percent);
+
+        let position_mode: PositionMode = "other".into();
+        assert_eq!(position_mode, PositionMode::Pixels);
+    }
+}
@@ -0,0 +1,65 @@
+/// `RelativeConstraint` describes the size of a child of an `AbsoluteLayout` in percent of the
+/// size of its parent. A value of `0.0` means the size is not constrained.
+#[derive(Default, Copy, Debug, Clone, PartialEq)]
+pub struct RelativeConstraint {
+    /// Width in percent of the parent width.
+    pub width: f64,
+
+    /// Height in percent of the parent height.
+    pub height: f64,
+}
+
+impl RelativeConstraint {
+    /// Creates a new relative constraint with the given width and height in percent.
+    pub fn new(width: f64, height: f64) -> Self {
+        RelativeConstraint { width, height }
+    }
+
+    /// Calculates the size in pixels for the given parent size. Unconstrained values are `None`.
+    pub fn size(&self, parent_size: (f64, f64)) -> (Option<f64>, Option<f64>) {
+        (
+            percent_of(self.width, parent_size.0),
+            percent_of(self.height, parent_size.1),
+        )
+    }
+}
+
+/// Calculates `percent` percent of `value`. Returns `None` if `percent` is not greater than `0.0`.
+pub fn percent_of(percent: f64, value: f64) -> Option<f64> {
+    if percent > 0.0 {
+        Some(value * percent / 100.0)
+    } else {
+        None
+    }
+}
+
+// --- Conversions ---
+
+impl From<(f64, f64)> for RelativeConstraint {
+    fn from(s: (f64, f64)) -> RelativeConstraint {
+        RelativeConstraint::new(s.0, s.1)
+    }
+}
+
+impl From<(i32, i32)> for RelativeConstraint {
+    fn from(s: (i32, i32)) -> RelativeConstraint {
+        RelativeConstraint::new(s.0 as f64, s.1 as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size() {
+        assert_eq!(
+            RelativeConstraint::new(50.0, 25.0).size((800.0, 600.0)),
+            (Some(400.0), Some(150.0))
+        );
+        assert_eq!(
+            RelativeConstraint::new(0.0, 100.0).size((800.0, 600.0)),
+            (None, Some(600.0))
+        );
+    }
+}
@@ -94,7 +94,14 @@ impl From<&str> for Row {
     fn from(t: &str) -> Self {
         match t {
             "Auto" | "auto" => Row::new().height(RowHeight::Auto).build(),
-            _ => Row::new().height(RowHeight::Stretch).build(),
+            _ => {
+                // e.g. "2fr"
+                if let Some(fraction) = t.strip_suffix("fr").and_then(|f| f.trim().parse().ok()) {
+                    return Row::new().height(RowHeight::Fraction(fraction)).build();
+                }
+
+                Row::new().height(RowHeight::Stretch).build()
+            }
         }
     }
 }
@@ -117,11 +124,27 @@ pub enum RowHeight {
     /// Row is measured by the highest child.
     Auto,
 
-    /// Column expands to the rest available height.
+    /// Column expands to the rest available height. Is the same as `Fraction(1.0)`.
     Stretch,
 
     /// Defines a fixed size for the row.
     Height(f64),
+
+    /// Row takes the given fractional units of the height that is left after the other rows are
+    /// sized.
+    Fraction(f64),
+}
+
+impl RowHeight {
+    /// Returns the fractional units of the rest height that are taken by the row. Returns `None`
+    /// for rows with fixed or auto height.
+    pub fn fraction(&self) -> Option<f64> {
+        match self {
+            RowHeight::Stretch => Some(1.0),
+            RowHeight::Fraction(fraction) => Some(*fraction),
+            _ => None,
+        }
+    }
 }
 
 impl Default for RowHeight {
@@ -328,5 +351,8 @@ mod tests {
 
         let row: Row = 64.0.into();
         assert_eq!(row.height(), RowHeight::Height(64.0));
+
+        let row: Row = "3fr".into();
+        assert_eq!(row.height(), RowHeight::Fraction(3.0));
     }
 }
@@ -1,6 +1,6 @@
 //! This sub module contains extra structs used as widget properties.
 
-use std::{collections::HashSet, fmt::Debug};
+use std::{collections::HashSet, fmt::Debug, rc::Rc};
 
 use dces::prelude::{Component, Entity, StringComponentStore};
 
@@ -80,7 +80,9 @@ where
 into_property_source!(bool);
 into_property_source!(String: &str, utils::Value);
 into_property_source!(usize);
+into_property_source!(Option<usize>: usize);
 into_property_source!(u32);
+into_property_source!(u64);
 into_property_source!(f32: utils::Value);
 into_property_source!(f64: i32, f32, utils::Value);
 into_property_source!(i32);
@@ -89,8 +91,12 @@ into_property_source!(i64);
 // Implementation of PropertySource for utils types
 into_property_source!(utils::Alignment: &str);
 into_property_source!(utils::Brush: &str, utils::Color, utils::Value);
+into_property_source!(utils::CompositeMode: &str);
+into_property_source!(utils::DockRegion: &str);
 into_property_source!(utils::Orientation: &str);
 into_property_source!(utils::Point: f64, i32, (i32, i32), (f64, f64));
+into_property_source!(utils::PopupAlignment: &str);
+into_property_source!(utils::PopupSide: &str);
 into_property_source!(utils::Rectangle: (i32, i32, i32, i32), (f64, f64, f64, f64));
 into_property_source!(
     utils::Thickness: i32,
@@ -118,8 +124,12 @@ into_property_source!(render::Image: &str, String, (u32, u32, Vec<u32>));
 into_property_source!(Columns: ColumnsBuilder);
 into_property_source!(Constraint: ConstraintBuilder);
 into_property_source!(DefaultRenderPipeline);
+into_property_source!(PositionMode: &str);
+into_property_source!(RelativeConstraint: (f64, f64), (i32, i32));
 into_property_source!(Rows: RowsBuilder);
 into_property_source!(ScrollViewerMode: (&str, &str));
 into_property_source!(SelectedEntities: HashSet<Entity>);
 into_property_source!(SelectedIndices: HashSet<usize>);
 into_property_source!(TextSelection: (usize, usize));
+into_property_source!(Validators: Vec<Rc<dyn Validator<String>>>);
+into_property_source!(ErrorMessage);
@@ -3,8 +3,10 @@ pub use self::render_pipeline::*;
 pub use self::selected_entities::*;
 pub use self::selected_indices::*;
 pub use self::text_selection::*;
+pub use self::validation::*;
 
 mod render_pipeline;
 mod selected_entities;
 mod selected_indices;
 mod text_selection;
+mod validation;
@@ -0,0 +1,218 @@
+use std::{fmt, rc::Rc};
+
+#[cfg(feature = "regex-filter")]
+use regex::Regex;
+
+/// Describes the current validation error of a widget. `None` means the value is valid.
+pub type ErrorMessage = Option<String>;
+
+/// A `Validator` checks a value and returns an error message if the value is not valid.
+pub trait Validator<T> {
+    /// Validates the given value.
+    fn validate(&self, value: &T) -> Result<(), String>;
+}
+
+impl<T, F> Validator<T> for F
+where
+    F: Fn(&T) -> Result<(), String>,
+{
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self(value)
+    }
+}
+
+/// `Validators` is a list of text validators that are run one after another.
+#[derive(Clone, Default)]
+pub struct Validators(pub Vec<Rc<dyn Validator<String>>>);
+
+impl Validators {
+    /// Runs all validators and returns the message of the first failed validator.
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        let value = value.to_string();
+
+        for validator in &self.0 {
+            validator.validate(&value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the list contains no validators.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for Validators {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Validators({})", self.0.len())
+    }
+}
+
+impl PartialEq for Validators {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(other.0.iter())
+                .all(|(a, b)| Rc::ptr_eq(a, b))
+    }
+}
+
+impl From<Vec<Rc<dyn Validator<String>>>> for Validators {
+    fn from(validators: Vec<Rc<dyn Validator<String>>>) -> Self {
+        Validators(validators)
+    }
+}
+
+/// Fails if the value is empty or contains only whitespace.
+pub fn required() -> Rc<dyn Validator<String>> {
+    Rc::new(|value: &String| {
+        if value.trim().is_empty() {
+            return Err("This field is required.".to_string());
+        }
+
+        Ok(())
+    })
+}
+
+/// Fails if the value has less than `min` characters.
+pub fn min_length(min: usize) -> Rc<dyn Validator<String>> {
+    Rc::new(move |value: &String| {
+        if value.chars().count() < min {
+            return Err(format!("Enter at least {} characters.", min));
+        }
+
+        Ok(())
+    })
+}
+
+/// Fails if the value has more than `max` characters.
+pub fn max_length(max: usize) -> Rc<dyn Validator<String>> {
+    Rc::new(move |value: &String| {
+        if value.chars().count() > max {
+            return Err(format!("Enter at most {} characters.", max));
+        }
+
+        Ok(())
+    })
+}
+
+/// Fails if the value is not empty and does not look like an email address.
+pub fn email() -> Rc<dyn Validator<String>> {
+    Rc::new(|value: &String| {
+        if value.is_empty() {
+            return Ok(());
+        }
+
+        let mut parts = value.split('@');
+        let valid = match (parts.next(), parts.next(), parts.next()) {
+            (Some(local), Some(domain), None) => {
+                !local.is_empty()
+                    && !value.contains(char::is_whitespace)
+                    && domain.contains('.')
+                    && !domain.starts_with('.')
+                    && !domain.ends_with('.')
+            }
+            _ => false,
+        };
+
+        if !valid {
+            return Err("Enter a valid email address.".to_string());
+        }
+
+        Ok(())
+    })
+}
+
+/// Fails if the value does not match the given regular expression. Requires the `regex-filter`
+/// feature.
+///
+/// # Panics
+///
+/// Panics if `regex` is not a valid regular expression.
+#[cfg(feature = "regex-filter")]
+pub fn pattern(regex: &str) -> Rc<dyn Validator<String>> {
+    let regex = Regex::new(regex).expect("pattern: invalid regular expression.");
+
+    Rc::new(move |value: &String| {
+        if !regex.is_match(value) {
+            return Err("The value has an invalid format.".to_string());
+        }
+
+        Ok(())
+    })
+}
+
+/// Fails if the value is not a number between `min` and `max` (inclusive).
+pub fn range(min: f64, max: f64) -> Rc<dyn Validator<String>> {
+    Rc::new(move |value: &String| match value.trim().parse::<f64>() {
+        Ok(number) if number >= min && number <= max => Ok(()),
+        _ => Err(format!("Enter a number between {} and {}.", min, max)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(validator: &Rc<dyn Validator<String>>, value: &str) -> bool {
+        validator.validate(&value.to_string()).is_ok()
+    }
+
+    #[test]
+    fn test_required() {
+        assert!(!check(&required(), ""));
+        assert!(!check(&required(), "  "));
+        assert!(check(&required(), "a"));
+    }
+
+    #[test]
+    fn test_length() {
+        assert!(!check(&min_length(3), "ab"));
+        assert!(check(&min_length(3), "abc"));
+        assert!(check(&max_length(3), "abc"));
+        assert!(!check(&max_length(3), "abcd"));
+    }
+
+    #[test]
+    fn test_email() {
+        assert!(check(&email(), ""));
+        assert!(check(&email(), "name@example.org"));
+        assert!(!check(&email(), "name@example"));
+        assert!(!check(&email(), "@example.org"));
+        assert!(!check(&email(), "na me@example.org"));
+        assert!(!check(&email(), "a@b@example.org"));
+    }
+
+    #[test]
+    #[cfg(feature = "regex-filter")]
+    fn test_pattern() {
+        let validator = pattern(r"^\d{4}$");
+        assert!(check(&validator, "1234"));
+        assert!(!check(&validator, "12a4"));
+    }
+
+    #[test]
+    fn test_range() {
+        assert!(check(&range(1.0, 10.0), "5"));
+        assert!(check(&range(1.0, 10.0), "10"));
+        assert!(!check(&range(1.0, 10.0), "11"));
+        assert!(!check(&range(1.0, 10.0), "abc"));
+    }
+
+    #[test]
+    fn test_validators_first_error() {
+        let validators = Validators(vec![required(), min_length(3)]);
+        assert_eq!(
+            validators.validate(""),
+            Err("This field is required.".to_string())
+        );
+        assert_eq!(
+            validators.validate("ab"),
+            Err("Enter at least 3 characters.".to_string())
+        );
+        assert_eq!(validators.validate("abc"), Ok(()));
+    }
+}
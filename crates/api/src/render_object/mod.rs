@@ -53,12 +53,12 @@ pub trait RenderObject: Any {
             return;
         }
 
+        let opacity = *ecm
+            .component_store()
+            .get::<f32>("opacity", entity)
+            .unwrap_or(&1.0);
+
         render_context.begin_path();
-        render_context.set_alpha(
-            *ecm.component_store()
-                .get::<f32>("opacity", entity)
-                .unwrap_or(&1.0),
-        );
 
         // Could be unwrap because every widget has the clip property
         let clip = *ecm.component_store().get::<bool>("clip", entity).unwrap();
@@ -75,6 +75,23 @@ pub trait RenderObject: Any {
             }
         }
 
+        // draws the widget and its children into an offscreen buffer that is composited with the
+        // opacity of the widget
+        let offscreen = if opacity < 1.0 && composite_as_group(ecm, entity) {
+            let root = ecm.entity_store().root();
+            let window_bounds = ecm
+                .component_store()
+                .get::<Rectangle>("bounds", root)
+                .map(|bounds| *bounds)
+                .unwrap_or_default();
+
+            Some(render_context.begin_offscreen(window_bounds.width(), window_bounds.height()))
+        } else {
+            None
+        };
+
+        render_context.set_alpha(if offscreen.is_some() { 1.0 } else { opacity });
+
         self.render_self(
             &mut Context::new((entity, ecm), &theme, context_provider, render_context),
             &global_position,
@@ -110,6 +127,13 @@ pub trait RenderObject: Any {
 
         render_context.close_path();
 
+        if let Some(offscreen) = offscreen {
+            let texture = render_context.end_offscreen(offscreen);
+            render_context.set_alpha(opacity);
+            render_context.draw_texture(texture, 0.0, 0.0);
+            render_context.release_texture(texture);
+        }
+
         if clip {
             render_context.restore();
         }
@@ -128,6 +152,26 @@ pub trait RenderObject: Any {
                 render_context.close_path();
             }
         }
+
+        // render the boundary and the name of the layout of each widget
+        if context_provider.layout_debug() {
+            if let Some(layout) = context_provider.layouts.borrow().get(&entity) {
+                if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", entity) {
+                    let x = global_position.x() + bounds.x();
+                    let y = global_position.y() + bounds.y();
+                    let color = context_provider.layout_debug_color((**layout).type_id());
+
+                    render_context.begin_path();
+                    render_context.set_stroke_style(Brush::from(color));
+                    render_context.stroke_rect(x, y, bounds.width(), bounds.height());
+                    render_context.set_font_family("Roboto-Regular");
+                    render_context.set_font_size(8.0);
+                    render_context.set_fill_style(Brush::from(color));
+                    render_context.fill_text(layout.name(), x + 1.0, y + 1.0);
+                    render_context.close_path();
+                }
+            }
+        }
     }
 
     fn render_self(&self, _: &mut Context, _: &Point) {}
@@ -159,3 +203,42 @@ pub trait RenderObject: Any {
         }
     }
 }
+
+// Checks if the widget and its children should be drawn as group. For `CompositeMode::Auto`
+// this is the case if at least two visible children overlap each other.
+fn composite_as_group(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> bool {
+    let store = ecm.component_store();
+
+    match store
+        .get::<CompositeMode>("composite_mode", entity)
+        .map(|mode| *mode)
+        .unwrap_or_default()
+    {
+        CompositeMode::Individual => false,
+        CompositeMode::Group => true,
+        CompositeMode::Auto => {
+            let bounds: Vec<Rectangle> = ecm.entity_store().children[&entity]
+                .iter()
+                .filter(|child| {
+                    store
+                        .get::<Visibility>("visibility", **child)
+                        .map_or(false, |visibility| *visibility == Visibility::Visible)
+                })
+                .filter_map(|child| store.get::<Rectangle>("bounds", *child).ok())
+                .copied()
+                .collect();
+
+            bounds.iter().enumerate().any(|(i, a)| {
+                bounds[i + 1..].iter().any(|b| {
+                    a.x() < b.x() + b.width()
+                        && b.x() < a.x() + a.width()
+                        && a.y() < b.y() + b.height()
+                        && b.y() < a.y() + a.height()
+                })
+            })
+        }
+    }
+}
@@ -1,8 +1,81 @@
 use crate::{
     render_object::*,
     utils::{Brush, Point, Rectangle, String16},
+    widget_base::WidgetContainer,
 };
 
+/// Is appended to a text that is truncated by `max_lines`.
+pub static TRUNCATION_ELLIPSIS: &str = "…";
+
+/// Is appended to a text that is truncated by `max_lines` if the widget is `expandable`.
+pub static TRUNCATION_MORE: &str = "… more";
+
+/// Replaces each character of a text in `password_mode` if no `mask_char` is set.
+pub static DEFAULT_MASK_CHAR: &str = "•";
+
+/// Returns a text that contains the mask character once for each character of the given text. The
+/// length of the masked text is the same as the length of the text, so the indices of a text
+/// selection could be used for both.
+pub fn mask_text(text: &String16, mask_char: &str) -> String16 {
+    let mask_char = mask_char.chars().next().unwrap_or('•');
+    let mut masked = String16::new();
+
+    for _ in 0..text.len() {
+        masked.push(mask_char);
+    }
+
+    masked
+}
+
+/// Returns the first `max_lines` lines of the given text followed by the suffix, or `None` if the
+/// text does not have more lines.
+pub fn truncate_lines(text: &String16, max_lines: usize, suffix: &str) -> Option<String16> {
+    let lines = text.lines();
+
+    if lines.len() <= max_lines {
+        return None;
+    }
+
+    let mut truncated = lines[..max_lines]
+        .iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<String>>()
+        .join("\n");
+    truncated.push_str(suffix);
+
+    Some(String16::from(truncated))
+}
+
+/// Returns the text that is displayed by the widget. If the optional `max_lines` property of the
+/// widget is set, the text is truncated after the given number of lines. If the optional
+/// `password_mode` property is `true`, the text is masked by the `mask_char` property.
+pub fn displayed_text(widget: &WidgetContainer, text: String16) -> String16 {
+    if widget
+        .try_clone::<bool>("password_mode")
+        .unwrap_or_default()
+    {
+        let mask_char = widget
+            .try_clone::<String>("mask_char")
+            .unwrap_or_else(|| DEFAULT_MASK_CHAR.to_string());
+
+        return mask_text(&text, &mask_char);
+    }
+
+    if let Some(max_lines) = widget.try_clone::<Option<usize>>("max_lines").flatten() {
+        let suffix = if widget.try_clone::<bool>("expandable").unwrap_or_default() {
+            TRUNCATION_MORE
+        } else {
+            TRUNCATION_ELLIPSIS
+        };
+
+        if let Some(truncated) = truncate_lines(&text, max_lines, suffix) {
+            return truncated;
+        }
+    }
+
+    text
+}
+
 /// Used to render a text.
 pub struct TextRenderObject;
 
@@ -14,13 +87,13 @@ impl Into<Box<dyn RenderObject>> for TextRenderObject {
 
 impl RenderObject for TextRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
-        let (bounds, text, foreground, font, font_size) = {
+        let (bounds, text, foreground, font, font_fallbacks, font_size) = {
             let widget = ctx.widget();
             let text = widget.clone::<String16>("text");
 
             let txt = {
                 if !text.is_empty() {
-                    text
+                    displayed_text(&widget, text)
                 } else {
                     widget.clone_or_default::<String16>("water_mark")
                 }
@@ -30,6 +103,7 @@ impl RenderObject for TextRenderObject {
                 txt.to_string(),
                 widget.get::<Brush>("foreground").clone(),
                 widget.get::<String>("font").clone(),
+                widget.clone_or_default::<Vec<String>>("font_fallbacks"),
                 *widget.get::<f64>("font_size"),
             )
         };
@@ -46,6 +120,7 @@ impl RenderObject for TextRenderObject {
         if !text.is_empty() {
             ctx.render_context_2_d().begin_path();
             ctx.render_context_2_d().set_font_family(font);
+            ctx.render_context_2_d().set_font_fallbacks(font_fallbacks);
             ctx.render_context_2_d().set_font_size(font_size);
             ctx.render_context_2_d().set_fill_style(foreground);
 
@@ -58,3 +133,37 @@ impl RenderObject for TextRenderObject {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_lines() {
+        let text = String16::from("one\ntwo\nthree");
+
+        assert_eq!(
+            truncate_lines(&text, 2, TRUNCATION_ELLIPSIS),
+            Some(String16::from("one\ntwo…"))
+        );
+        assert_eq!(
+            truncate_lines(&text, 1, TRUNCATION_MORE),
+            Some(String16::from("one… more"))
+        );
+        assert_eq!(truncate_lines(&text, 3, TRUNCATION_ELLIPSIS), None);
+        assert_eq!(
+            truncate_lines(&String16::new(), 0, TRUNCATION_ELLIPSIS),
+            None
+        );
+    }
+
+    #[test]
+    fn test_mask_text() {
+        let text = String16::from("Übung");
+
+        assert_eq!(mask_text(&text, "*"), String16::from("*****"));
+        assert_eq!(mask_text(&text, ""), String16::from("•••••"));
+        assert_eq!(mask_text(&text, DEFAULT_MASK_CHAR).len(), text.len());
+        assert!(mask_text(&String16::new(), "*").is_empty());
+    }
+}
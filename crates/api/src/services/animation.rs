@@ -0,0 +1,324 @@
+use std::time::Instant;
+
+use dces::prelude::*;
+
+use crate::{prelude::*, tree::Tree, utils::*};
+
+/// Time step of one animation frame in seconds. Is used for the first frame after the application
+/// was idle.
+pub const ANIMATION_FRAME_TIME: f64 = 1.0 / 60.0;
+
+// a longer time between two frames means that the application was idle
+const MAX_FRAME_TIME: f64 = 0.1;
+
+/// Describes the value that is animated by a property animation.
+#[derive(Clone)]
+enum AnimatedValue {
+    F64,
+    F32,
+    Color { from: Color, to: Color },
+    Position { from: Point, to: Point },
+}
+
+/// Animates the property with the given key of a widget entity.
+#[derive(Clone)]
+struct PropertyAnimation {
+    entity: Entity,
+    key: String,
+    tween: Tween,
+    value: AnimatedValue,
+}
+
+/// The `AnimationService` holds and advances all running property animations e.g. theme transitions.
+#[derive(Clone, Default)]
+pub struct AnimationService {
+    animations: Vec<PropertyAnimation>,
+    last_frame: Option<Instant>,
+    frame_time: f64,
+}
+
+impl AnimationService {
+    /// Creates a new animation service.
+    pub fn new() -> Self {
+        AnimationService::default()
+    }
+
+    /// Starts a new frame and measures the time that elapsed since the previous frame. Is called
+    /// once per frame by the `AnimationSystem`.
+    pub fn start_frame(&mut self, now: Instant) {
+        self.frame_time = frame_time(self.last_frame, now);
+        self.last_frame = Some(now);
+    }
+
+    /// Gets the elapsed time of the current frame in seconds. Widgets that animate on their own
+    /// should advance their animations by this time.
+    pub fn frame_time(&self) -> f64 {
+        self.frame_time
+    }
+
+    /// Returns `true` if there are no running animations.
+    pub fn is_empty(&self) -> bool {
+        self.animations.is_empty()
+    }
+
+    /// Gets the number of running animations.
+    pub fn len(&self) -> usize {
+        self.animations.len()
+    }
+
+    fn push(&mut self, entity: Entity, key: &str, tween: Tween, value: AnimatedValue) {
+        // a new animation of the same property replaces the running one
+        self.animations
+            .retain(|a| !(a.entity == entity && a.key == key));
+        self.animations.push(PropertyAnimation {
+            entity,
+            key: key.to_string(),
+            tween,
+            value,
+        });
+    }
+
+    /// Animates the `f64` property with the given key from `from` to `to`.
+    pub fn animate_f64(
+        &mut self,
+        entity: Entity,
+        key: &str,
+        from: f64,
+        to: f64,
+        config: &TransitionConfig,
+    ) {
+        self.push(entity, key, tween(from, to, config), AnimatedValue::F64);
+    }
+
+    /// Animates the `f32` property with the given key from `from` to `to`.
+    pub fn animate_f32(
+        &mut self,
+        entity: Entity,
+        key: &str,
+        from: f32,
+        to: f32,
+        config: &TransitionConfig,
+    ) {
+        self.push(
+            entity,
+            key,
+            tween(from as f64, to as f64, config),
+            AnimatedValue::F32,
+        );
+    }
+
+    /// Animates the `Brush` property with the given key from the color `from` to the color `to`.
+    pub fn animate_color(
+        &mut self,
+        entity: Entity,
+        key: &str,
+        from: Color,
+        to: Color,
+        config: &TransitionConfig,
+    ) {
+        self.push(
+            entity,
+            key,
+            tween(0.0, 1.0, config),
+            AnimatedValue::Color { from, to },
+        );
+    }
+
+    /// Moves the widget by animating the position of its `bounds` from `from` to `to`.
+    pub fn animate_position(
+        &mut self,
+        entity: Entity,
+        from: Point,
+        to: Point,
+        config: &TransitionConfig,
+    ) {
+        self.push(
+            entity,
+            "bounds",
+            tween(0.0, 1.0, config),
+            AnimatedValue::Position { from, to },
+        );
+    }
+
+    /// Advances all animations by the given time step in seconds, writes the new values to the
+    /// animated properties and removes the finished animations.
+    pub fn advance(
+        &mut self,
+        delta: f64,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        for animation in &mut self.animations {
+            let value = animation.tween.step(delta);
+            let entity = animation.entity;
+            let key = animation.key.as_str();
+
+            let updated = match &animation.value {
+                AnimatedValue::F64 => ecm
+                    .component_store_mut()
+                    .get_mut::<f64>(key, entity)
+                    .map(|v| *v = value)
+                    .is_ok(),
+                AnimatedValue::F32 => ecm
+                    .component_store_mut()
+                    .get_mut::<f32>(key, entity)
+                    .map(|v| *v = value as f32)
+                    .is_ok(),
+                AnimatedValue::Color { from, to } => ecm
+                    .component_store_mut()
+                    .get_mut::<Brush>(key, entity)
+                    .map(|v| *v = Brush::from(Color::interpolate(*from, *to, value)))
+                    .is_ok(),
+                AnimatedValue::Position { from, to } => ecm
+                    .component_store_mut()
+                    .get_mut::<Rectangle>(key, entity)
+                    .map(|v| {
+                        v.set_x(from.x() + (to.x() - from.x()) * value);
+                        v.set_y(from.y() + (to.y() - from.y()) * value);
+                    })
+                    .is_ok(),
+            };
+
+            if updated {
+                mark_as_dirty(key, entity, ecm);
+            }
+        }
+
+        self.animations.retain(|a| !a.tween.is_finished());
+    }
+}
+
+// Returns the time between the previous and the current frame.
+fn frame_time(last_frame: Option<Instant>, now: Instant) -> f64 {
+    match last_frame {
+        Some(last_frame) => {
+            let elapsed = now.duration_since(last_frame).as_secs_f64();
+
+            if elapsed > MAX_FRAME_TIME {
+                ANIMATION_FRAME_TIME
+            } else {
+                elapsed
+            }
+        }
+        None => ANIMATION_FRAME_TIME,
+    }
+}
+
+fn tween(from: f64, to: f64, config: &TransitionConfig) -> Tween {
+    Tween::new(from, to, config.duration_ms as f64 / 1000.0)
+        .delay(config.delay_ms as f64 / 1000.0)
+        .easing(config.easing)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::render::RenderContext2D;
+
+    fn transition(duration_ms: u64) -> TransitionConfig {
+        TransitionConfig {
+            duration_ms,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_frame_time() {
+        let now = Instant::now();
+
+        assert_eq!(ANIMATION_FRAME_TIME, frame_time(None, now));
+        assert_eq!(0.02, frame_time(Some(now), now + Duration::from_millis(20)));
+
+        // the first frame after the application was idle
+        assert_eq!(
+            ANIMATION_FRAME_TIME,
+            frame_time(Some(now), now + Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn test_start_frame() {
+        let now = Instant::now();
+        let mut service = AnimationService::new();
+
+        service.start_frame(now);
+        assert_eq!(ANIMATION_FRAME_TIME, service.frame_time());
+
+        service.start_frame(now + Duration::from_millis(20));
+        assert_eq!(0.02, service.frame_time());
+    }
+
+    #[test]
+    fn test_advance() {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+
+        let entity = ecm.create_entity().build();
+        ecm.component_store_mut()
+            .register("opacity", entity, 0.0_f64);
+        ecm.component_store_mut().register(
+            "bounds",
+            entity,
+            Rectangle::new((0.0, 0.0), 10.0, 10.0),
+        );
+
+        let mut service = AnimationService::new();
+        assert!(service.is_empty());
+
+        service.animate_f64(entity, "opacity", 0.0, 1.0, &transition(1000));
+        service.animate_position(
+            entity,
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 20.0),
+            &transition(500),
+        );
+        assert_eq!(2, service.len());
+
+        service.advance(0.5, ecm);
+        assert_eq!(
+            0.5,
+            *ecm.component_store().get::<f64>("opacity", entity).unwrap()
+        );
+        assert_eq!(
+            Point::new(10.0, 20.0),
+            ecm.component_store()
+                .get::<Rectangle>("bounds", entity)
+                .unwrap()
+                .position()
+        );
+
+        // the finished position animation is removed
+        assert_eq!(1, service.len());
+
+        service.advance(0.5, ecm);
+        assert_eq!(
+            1.0,
+            *ecm.component_store().get::<f64>("opacity", entity).unwrap()
+        );
+        assert!(service.is_empty());
+    }
+
+    #[test]
+    fn test_replace_animation() {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+
+        let entity = ecm.create_entity().build();
+        ecm.component_store_mut()
+            .register("opacity", entity, 0.0_f64);
+
+        let mut service = AnimationService::new();
+        service.animate_f64(entity, "opacity", 0.0, 1.0, &transition(1000));
+        service.animate_f64(entity, "opacity", 1.0, 0.5, &transition(1000));
+        assert_eq!(1, service.len());
+
+        service.advance(1.0, ecm);
+        assert_eq!(
+            0.5,
+            *ecm.component_store().get::<f64>("opacity", entity).unwrap()
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use std::{cell::RefCell, rc::Rc};
+
+/// A `ClipboardProvider` reads and writes the text of a clipboard. Implement it to connect a
+/// custom clipboard with `ClipboardService::set_provider`.
+pub trait ClipboardProvider {
+    /// Gets the text of the clipboard. Returns `None` if the clipboard is empty or does not
+    /// contain text.
+    fn get(&mut self) -> Option<String>;
+
+    /// Replaces the content of the clipboard by the given text.
+    fn set(&mut self, text: &str);
+}
+
+/// Clipboard that keeps its text in memory. It is used if the system clipboard is not available
+/// and could only be used to copy and paste text inside of the application.
+#[derive(Clone, Default, Debug)]
+pub struct MemoryClipboard {
+    text: Option<String>,
+}
+
+impl ClipboardProvider for MemoryClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.text.clone()
+    }
+
+    fn set(&mut self, text: &str) {
+        self.text = Some(text.to_string());
+    }
+}
+
+/// `ClipboardService` gives access to the clipboard of the system. It is accessible from widget
+/// states via `ctx.clipboard_service()` and is registered by its type in the `Registry`.
+///
+/// On the web and on Redox the text is only shared inside of the application.
+///
+/// The service is a shared handle, all clones work on the same clipboard.
+#[derive(Clone)]
+pub struct ClipboardService {
+    provider: Rc<RefCell<Box<dyn ClipboardProvider>>>,
+}
+
+impl Default for ClipboardService {
+    fn default() -> Self {
+        ClipboardService {
+            provider: Rc::new(RefCell::new(platform::provider())),
+        }
+    }
+}
+
+impl ClipboardService {
+    /// Creates a new clipboard service that uses the clipboard of the system.
+    pub fn new() -> Self {
+        ClipboardService::default()
+    }
+
+    /// Replaces the clipboard provider of the service and of all its clones.
+    pub fn set_provider(&self, provider: impl ClipboardProvider + 'static) {
+        *self.provider.borrow_mut() = Box::new(provider);
+    }
+
+    /// Gets the text of the clipboard.
+    pub fn get(&self) -> Option<String> {
+        self.provider.borrow_mut().get()
+    }
+
+    /// Replaces the content of the clipboard by the given text.
+    pub fn set(&self, text: &str) {
+        self.provider.borrow_mut().set(text);
+    }
+}
+
+#[cfg(all(not(target_os = "redox"), not(target_arch = "wasm32")))]
+mod platform {
+    use copypasta::{ClipboardContext, ClipboardProvider as _};
+
+    use super::{ClipboardProvider, MemoryClipboard};
+
+    struct SystemClipboard {
+        context: ClipboardContext,
+    }
+
+    impl ClipboardProvider for SystemClipboard {
+        fn get(&mut self) -> Option<String> {
+            self.context.get_contents().ok()
+        }
+
+        fn set(&mut self, text: &str) {
+            if let Err(error) = self.context.set_contents(text.to_string()) {
+                crate::shell::CONSOLE.warn(format!("could not write clipboard: {}", error));
+            }
+        }
+    }
+
+    // falls back to a memory clipboard if the system clipboard could not be opened
+    pub fn provider() -> Box<dyn ClipboardProvider> {
+        match ClipboardContext::new() {
+            Ok(context) => Box::new(SystemClipboard { context }),
+            Err(_) => Box::new(MemoryClipboard::default()),
+        }
+    }
+}
+
+#[cfg(any(target_os = "redox", target_arch = "wasm32"))]
+mod platform {
+    use super::{ClipboardProvider, MemoryClipboard};
+
+    pub fn provider() -> Box<dyn ClipboardProvider> {
+        Box::new(MemoryClipboard::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_provider() {
+        let service = ClipboardService::new();
+        service.set_provider(MemoryClipboard::default());
+
+        assert_eq!(service.get(), None);
+
+        service.clone().set("orbtk");
+        assert_eq!(service.get(), Some("orbtk".to_string()));
+    }
+}
@@ -0,0 +1,177 @@
+use std::{future::Future, path::PathBuf};
+
+/// Describes a file type filter of a file dialog e.g. `FileFilter::new("Images", &["png", "jpg"])`.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct FileFilter {
+    /// Display name of the filter.
+    pub name: String,
+
+    /// File extensions without leading dot.
+    pub extensions: Vec<String>,
+}
+
+impl FileFilter {
+    /// Creates a new file filter.
+    pub fn new(name: impl Into<String>, extensions: &[&str]) -> Self {
+        FileFilter {
+            name: name.into(),
+            extensions: extensions.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+}
+
+/// Configuration of an open file dialog.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct OpenFileConfig {
+    /// Title of the dialog window.
+    pub title: String,
+
+    /// File type filters of the dialog.
+    pub filters: Vec<FileFilter>,
+
+    /// Directory the dialog starts in.
+    pub initial_dir: Option<PathBuf>,
+
+    /// If `true` the user can pick more than one file. Use `FileDialogService::open_files` to get all picked files.
+    pub multi_select: bool,
+}
+
+/// Configuration of a save file dialog.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct SaveFileConfig {
+    /// Title of the dialog window.
+    pub title: String,
+
+    /// File type filters of the dialog.
+    pub filters: Vec<FileFilter>,
+
+    /// Directory the dialog starts in.
+    pub initial_dir: Option<PathBuf>,
+
+    /// Preset file name.
+    pub file_name: Option<String>,
+}
+
+/// `FileDialogService` opens native open and save file dialogs. It is registered in the `Registry`
+/// by its type, use `registry.get_required::<FileDialogService>()` to get it.
+///
+/// All dialogs are asynchronous, the returned futures could be awaited without blocking the ui thread
+/// e.g. by running them on a separate thread. On the web the browser file input is used, because
+/// the browser does not provide file system paths, the returned paths contain only the file names.
+#[derive(Clone, Default, Debug)]
+pub struct FileDialogService;
+
+impl FileDialogService {
+    /// Creates a new file dialog service.
+    pub fn new() -> Self {
+        FileDialogService
+    }
+
+    /// Opens a dialog to pick one file. Returns `None` if the dialog was canceled.
+    pub fn open_file(&self, config: OpenFileConfig) -> impl Future<Output = Option<PathBuf>> {
+        async move { platform::open_files(config, false).await.into_iter().next() }
+    }
+
+    /// Opens a dialog to pick one or more files (depends on `multi_select`). Returns an empty
+    /// vector if the dialog was canceled.
+    pub fn open_files(&self, config: OpenFileConfig) -> impl Future<Output = Vec<PathBuf>> {
+        let multi_select = config.multi_select;
+        platform::open_files(config, multi_select)
+    }
+
+    /// Opens a dialog to pick the path to save a file. Returns `None` if the dialog was canceled.
+    pub fn save_file(&self, config: SaveFileConfig) -> impl Future<Output = Option<PathBuf>> {
+        platform::save_file(config)
+    }
+}
+
+#[cfg(not(target_os = "redox"))]
+mod platform {
+    use std::path::PathBuf;
+
+    use rfd::{AsyncFileDialog, FileHandle};
+
+    use super::{FileFilter, OpenFileConfig, SaveFileConfig};
+
+    fn dialog(
+        title: &str,
+        filters: &[FileFilter],
+        initial_dir: &Option<PathBuf>,
+    ) -> AsyncFileDialog {
+        let mut dialog = AsyncFileDialog::new();
+
+        if !title.is_empty() {
+            dialog = dialog.set_title(title);
+        }
+
+        for filter in filters {
+            let extensions: Vec<&str> = filter.extensions.iter().map(|e| e.as_str()).collect();
+            dialog = dialog.add_filter(&filter.name, &extensions);
+        }
+
+        if let Some(initial_dir) = initial_dir {
+            dialog = dialog.set_directory(initial_dir);
+        }
+
+        dialog
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn path(handle: FileHandle) -> PathBuf {
+        handle.path().to_path_buf()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn path(handle: FileHandle) -> PathBuf {
+        PathBuf::from(handle.file_name())
+    }
+
+    pub async fn open_files(config: OpenFileConfig, multi_select: bool) -> Vec<PathBuf> {
+        let dialog = dialog(&config.title, &config.filters, &config.initial_dir);
+
+        if multi_select {
+            return dialog
+                .pick_files()
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .map(path)
+                .collect();
+        }
+
+        dialog.pick_file().await.map(path).into_iter().collect()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn save_file(config: SaveFileConfig) -> Option<PathBuf> {
+        let mut dialog = dialog(&config.title, &config.filters, &config.initial_dir);
+
+        if let Some(file_name) = &config.file_name {
+            dialog = dialog.set_file_name(file_name);
+        }
+
+        dialog.save_file().await.map(path)
+    }
+
+    // The browser file input could not be used to pick a save location.
+    #[cfg(target_arch = "wasm32")]
+    pub async fn save_file(_: SaveFileConfig) -> Option<PathBuf> {
+        None
+    }
+}
+
+// There is no file dialog available on Redox yet.
+#[cfg(target_os = "redox")]
+mod platform {
+    use std::path::PathBuf;
+
+    use super::{OpenFileConfig, SaveFileConfig};
+
+    pub async fn open_files(_: OpenFileConfig, _: bool) -> Vec<PathBuf> {
+        vec![]
+    }
+
+    pub async fn save_file(_: SaveFileConfig) -> Option<PathBuf> {
+        None
+    }
+}
@@ -0,0 +1,262 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::render::TextMetrics;
+
+/// Describes one style of a font family.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FontStyle {
+    /// Weight of the font e.g. `400.0` for regular and `700.0` for bold.
+    pub weight: f32,
+
+    /// `true` if the font is italic or oblique.
+    pub italic: bool,
+}
+
+impl Default for FontStyle {
+    fn default() -> Self {
+        FontStyle {
+            weight: 400.0,
+            italic: false,
+        }
+    }
+}
+
+/// Describes an available font family with all of its styles.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct FontInfo {
+    /// Name of the font family.
+    pub family: String,
+
+    /// Available styles of the family.
+    pub styles: Vec<FontStyle>,
+}
+
+#[derive(Default)]
+struct Fonts {
+    registered: HashMap<String, &'static [u8]>,
+    system_fonts: Option<Vec<FontInfo>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    loaded: HashMap<String, Option<font_kit::font::Font>>,
+}
+
+/// `FontService` lists the available fonts and measures text without a render context. The fonts
+/// of OrbTk are registered by default. System fonts are queried by `fontconfig` on Linux, by
+/// DirectWrite on Windows and by Core Text on macOS.
+///
+/// The service is a shared handle, cloning it does not clone the fonts.
+#[derive(Clone, Default)]
+pub struct FontService {
+    fonts: Rc<RefCell<Fonts>>,
+}
+
+impl FontService {
+    /// Creates a new font service without registered fonts.
+    pub fn new() -> Self {
+        FontService::default()
+    }
+
+    /// Registers a font that is not installed on the system e.g. a font bundled with the application.
+    pub fn register_font(&self, family: impl Into<String>, font_file: &'static [u8]) {
+        let family = family.into();
+        let mut fonts = self.fonts.borrow_mut();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fonts.loaded.remove(&family);
+
+        fonts.registered.insert(family, font_file);
+    }
+
+    /// Returns the registered fonts followed by all fonts installed on the system. The system
+    /// fonts are queried only on the first call.
+    pub fn list_fonts(&self) -> Vec<FontInfo> {
+        let mut fonts = self.fonts.borrow_mut();
+
+        if fonts.system_fonts.is_none() {
+            fonts.system_fonts = Some(platform::system_fonts());
+        }
+
+        let mut registered: Vec<FontInfo> = fonts
+            .registered
+            .iter()
+            .map(|(family, font_file)| FontInfo {
+                family: family.clone(),
+                styles: vec![platform::font_style(font_file)],
+            })
+            .collect();
+        registered.sort_by(|a, b| a.family.cmp(&b.family));
+
+        registered
+            .into_iter()
+            .chain(fonts.system_fonts.iter().flatten().cloned())
+            .collect()
+    }
+
+    /// Measures the size of the given text with the given font family and font size. If the
+    /// font is not available the default sans serif font of the system is used.
+    pub fn measure_text(&self, text: &str, font: &str, size: f64) -> TextMetrics {
+        platform::measure_text(&mut self.fonts.borrow_mut(), text, font, size)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod platform {
+    use std::sync::Arc;
+
+    use font_kit::{
+        family_name::FamilyName, font::Font, properties::Properties, properties::Style,
+        source::SystemSource,
+    };
+
+    use super::{FontInfo, FontStyle, Fonts};
+    use crate::render::TextMetrics;
+
+    fn style(font: &Font) -> FontStyle {
+        let properties = font.properties();
+
+        FontStyle {
+            weight: properties.weight.0,
+            italic: properties.style != Style::Normal,
+        }
+    }
+
+    fn load(fonts: &Fonts, family: &str) -> Option<Font> {
+        if let Some(font_file) = fonts.registered.get(family) {
+            return Font::from_bytes(Arc::new(font_file.to_vec()), 0).ok();
+        }
+
+        SystemSource::new()
+            .select_best_match(
+                &[FamilyName::Title(family.to_string()), FamilyName::SansSerif],
+                &Properties::new(),
+            )
+            .ok()?
+            .load()
+            .ok()
+    }
+
+    pub(super) fn font_style(font_file: &'static [u8]) -> FontStyle {
+        Font::from_bytes(Arc::new(font_file.to_vec()), 0)
+            .map(|font| style(&font))
+            .unwrap_or_default()
+    }
+
+    pub(super) fn system_fonts() -> Vec<FontInfo> {
+        let source = SystemSource::new();
+        let mut families = source.all_families().unwrap_or_default();
+        families.sort();
+        families.dedup();
+
+        families
+            .into_iter()
+            .map(|family| {
+                let styles = source
+                    .select_family_by_name(&family)
+                    .map(|handle| {
+                        handle
+                            .fonts()
+                            .iter()
+                            .filter_map(|font| font.load().ok())
+                            .map(|font| style(&font))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                FontInfo { family, styles }
+            })
+            .collect()
+    }
+
+    pub(super) fn measure_text(
+        fonts: &mut Fonts,
+        text: &str,
+        family: &str,
+        size: f64,
+    ) -> TextMetrics {
+        if !fonts.loaded.contains_key(family) {
+            let font = load(fonts, family);
+            fonts.loaded.insert(family.to_string(), font);
+        }
+
+        let width = match &fonts.loaded[family] {
+            Some(font) => {
+                let scale = size / font.metrics().units_per_em as f64;
+
+                text.chars()
+                    .filter_map(|c| font.glyph_for_char(c))
+                    .filter_map(|glyph| font.advance(glyph).ok())
+                    .map(|advance| advance.x() as f64 * scale)
+                    .sum::<f64>()
+            }
+            None => 0.0,
+        };
+
+        TextMetrics {
+            width: width.ceil(),
+            height: size.ceil(),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    use stdweb::{
+        unstable::TryInto,
+        web::{document, html_element::CanvasElement, CanvasRenderingContext2d},
+    };
+
+    use super::{FontInfo, FontStyle, Fonts};
+    use crate::render::TextMetrics;
+
+    pub(super) fn font_style(_: &'static [u8]) -> FontStyle {
+        FontStyle::default()
+    }
+
+    // The browser does not provide a list of the installed fonts.
+    pub(super) fn system_fonts() -> Vec<FontInfo> {
+        vec![]
+    }
+
+    pub(super) fn measure_text(_: &mut Fonts, text: &str, family: &str, size: f64) -> TextMetrics {
+        let canvas: CanvasElement = document()
+            .create_element("canvas")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let context: CanvasRenderingContext2d = canvas.get_context().unwrap();
+        context.set_font(&format!("{}px {}", size, family));
+
+        TextMetrics {
+            width: context.measure_text(text).unwrap().get_width(),
+            height: size.ceil(),
+        }
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure_registered_font() {
+        let service = FontService::new();
+        service.register_font("Roboto-Regular", crate::theme::fonts::ROBOTO_REGULAR_FONT);
+
+        let short = service.measure_text("ab", "Roboto-Regular", 12.0);
+        let long = service.measure_text("abab", "Roboto-Regular", 12.0);
+
+        assert!(short.width > 0.0);
+        assert!(long.width > short.width);
+        assert_eq!(short.height, 12.0);
+        assert_eq!(service.measure_text("", "Roboto-Regular", 12.0).width, 0.0);
+    }
+
+    #[test]
+    fn test_list_registered_fonts_first() {
+        let service = FontService::new();
+        service.register_font("Roboto-Regular", crate::theme::fonts::ROBOTO_REGULAR_FONT);
+
+        let fonts = service.list_fonts();
+        assert_eq!(fonts[0].family, "Roboto-Regular");
+        assert_eq!(fonts[0].styles.len(), 1);
+    }
+}
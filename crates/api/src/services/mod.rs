@@ -1,5 +1,19 @@
 //! This module contains global services.
 //!
+pub use self::animation::*;
+pub use self::clipboard::*;
+pub use self::file_dialog::*;
+pub use self::font::*;
+pub use self::screen::*;
 pub use self::settings::*;
+pub use self::undo::*;
+pub use self::window::*;
 
+mod animation;
+mod clipboard;
+mod file_dialog;
+mod font;
+mod screen;
 mod settings;
+mod undo;
+mod window;
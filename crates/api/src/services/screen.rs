@@ -0,0 +1,72 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::shell::ScreenInfo;
+
+/// `ScreenService` lists the screens (monitors) that are connected to the system. The screens are
+/// reported by the shell after the window is created and if they are changed. It is accessible
+/// from widget states via `ctx.screen_service()`.
+///
+/// The service is a shared handle, cloning it does not clone the screens.
+#[derive(Clone, Default)]
+pub struct ScreenService {
+    screens: Rc<RefCell<Vec<ScreenInfo>>>,
+}
+
+impl ScreenService {
+    /// Creates a new screen service without screens.
+    pub fn new() -> Self {
+        ScreenService::default()
+    }
+
+    /// Gets all available screens. Is empty if the shell provides no screen information.
+    pub fn screens(&self) -> Vec<ScreenInfo> {
+        self.screens.borrow().clone()
+    }
+
+    /// Gets the screen with the given id.
+    pub fn screen(&self, id: u64) -> Option<ScreenInfo> {
+        self.screens.borrow().iter().find(|s| s.id == id).cloned()
+    }
+
+    /// Gets the primary screen of the system.
+    pub fn primary(&self) -> Option<ScreenInfo> {
+        self.screens.borrow().iter().find(|s| s.primary).cloned()
+    }
+
+    /// Replaces the available screens. Is called by the window adapter.
+    pub fn set_screens(&self, screens: Vec<ScreenInfo>) {
+        *self.screens.borrow_mut() = screens;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_screens() {
+        let service = ScreenService::new();
+        assert!(service.primary().is_none());
+
+        service.clone().set_screens(vec![
+            ScreenInfo {
+                id: 0,
+                scale_factor: 1.0,
+                name: "DP-1".to_string(),
+                ..Default::default()
+            },
+            ScreenInfo {
+                id: 1,
+                scale_factor: 2.0,
+                primary: true,
+                name: "eDP-1".to_string(),
+                ..Default::default()
+            },
+        ]);
+
+        assert_eq!(service.screens().len(), 2);
+        assert_eq!(service.primary().unwrap().id, 1);
+        assert_eq!(service.screen(0).unwrap().name, "DP-1");
+        assert!(service.screen(2).is_none());
+    }
+}
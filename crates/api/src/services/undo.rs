@@ -0,0 +1,306 @@
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use dces::prelude::*;
+
+use crate::{tree::Tree, widget_base::mark_as_dirty};
+
+/// An `UndoAction` describes a reversible change. The `undo` and `redo` closures are called
+/// with the target of the change e.g. the entity component manager of the window.
+#[derive(Clone)]
+pub struct UndoAction {
+    /// Human readable description of the change e.g. `Change value`.
+    pub description: String,
+
+    /// Reverts the change.
+    pub undo: Rc<dyn Fn(&mut dyn Any)>,
+
+    /// Applies the change again.
+    pub redo: Rc<dyn Fn(&mut dyn Any)>,
+}
+
+impl UndoAction {
+    /// Creates a new undo action.
+    pub fn new(
+        description: impl Into<String>,
+        undo: impl Fn(&mut dyn Any) + 'static,
+        redo: impl Fn(&mut dyn Any) + 'static,
+    ) -> Self {
+        UndoAction {
+            description: description.into(),
+            undo: Rc::new(undo),
+            redo: Rc::new(redo),
+        }
+    }
+
+    /// Creates an undo action that changes the property with the given key of the given widget
+    /// entity between `old_value` and `new_value`. The target of the action has to be the entity
+    /// component manager of the window.
+    pub fn property<P>(
+        description: impl Into<String>,
+        entity: Entity,
+        key: impl Into<String>,
+        old_value: P,
+        new_value: P,
+    ) -> Self
+    where
+        P: Component + Clone,
+    {
+        let undo_key = key.into();
+        let redo_key = undo_key.clone();
+
+        UndoAction::new(
+            description,
+            move |target| set_property(target, entity, &undo_key, old_value.clone()),
+            move |target| set_property(target, entity, &redo_key, new_value.clone()),
+        )
+    }
+
+    /// Creates an undo action that reverts and applies the given actions as one step. The
+    /// actions are reverted in reverse order.
+    pub fn group(description: impl Into<String>, actions: Vec<UndoAction>) -> Self {
+        let undo_actions = Rc::new(actions);
+        let redo_actions = undo_actions.clone();
+
+        UndoAction::new(
+            description,
+            move |target| {
+                for action in undo_actions.iter().rev() {
+                    (action.undo)(target);
+                }
+            },
+            move |target| {
+                for action in redo_actions.iter() {
+                    (action.redo)(target);
+                }
+            },
+        )
+    }
+}
+
+/// The default count of actions that are kept on the undo stack.
+pub const DEFAULT_MAX_HISTORY: usize = 200;
+
+struct UndoStacks {
+    undo_stack: Vec<UndoAction>,
+    redo_stack: Vec<UndoAction>,
+    history: Vec<String>,
+    max_history: usize,
+}
+
+impl Default for UndoStacks {
+    fn default() -> Self {
+        UndoStacks {
+            undo_stack: vec![],
+            redo_stack: vec![],
+            history: vec![],
+            max_history: DEFAULT_MAX_HISTORY,
+        }
+    }
+}
+
+impl UndoStacks {
+    // drops the oldest entries that exceed the maximum history
+    fn truncate(&mut self) {
+        truncate_front(&mut self.undo_stack, self.max_history);
+        truncate_front(&mut self.redo_stack, self.max_history);
+        truncate_front(&mut self.history, self.max_history);
+    }
+}
+
+fn truncate_front<T>(stack: &mut Vec<T>, len: usize) {
+    if stack.len() > len {
+        let overflow = stack.len() - len;
+        stack.drain(..overflow);
+    }
+}
+
+/// The `UndoService` stores the undo and redo stacks of an application window.
+///
+/// The service is a shared handle, all clones work on the same stacks. It is registered by its
+/// type in the `Registry` and could also be accessed by `Context::undo_service`.
+#[derive(Clone, Default)]
+pub struct UndoService {
+    stacks: Rc<RefCell<UndoStacks>>,
+}
+
+impl UndoService {
+    /// Creates a new undo service with empty stacks.
+    pub fn new() -> Self {
+        UndoService::default()
+    }
+
+    /// Pushes a new action on the undo stack and clears the redo stack. If the stack holds more
+    /// than `max_history` actions, the oldest action is dropped.
+    pub fn push(&self, action: UndoAction) {
+        let mut stacks = self.stacks.borrow_mut();
+        stacks.history.push(action.description.clone());
+        stacks.undo_stack.push(action);
+        stacks.redo_stack.clear();
+        stacks.truncate();
+    }
+
+    /// Sets the maximum count of actions that are kept on the undo and redo stacks.
+    pub fn set_max_history(&self, max_history: usize) {
+        let mut stacks = self.stacks.borrow_mut();
+        stacks.max_history = max_history;
+        stacks.truncate();
+    }
+
+    /// Gets the maximum count of actions that are kept on the undo and redo stacks.
+    pub fn max_history(&self) -> usize {
+        self.stacks.borrow().max_history
+    }
+
+    /// Reverts the last action with the given target and moves it to the redo stack.
+    /// Returns `false` if there is nothing to undo.
+    pub fn undo(&self, target: &mut dyn Any) -> bool {
+        let action = self.stacks.borrow_mut().undo_stack.pop();
+
+        if let Some(action) = action {
+            (action.undo)(target);
+            self.stacks.borrow_mut().redo_stack.push(action);
+            return true;
+        }
+
+        false
+    }
+
+    /// Applies the last reverted action again with the given target and moves it back to the
+    /// undo stack. Returns `false` if there is nothing to redo.
+    pub fn redo(&self, target: &mut dyn Any) -> bool {
+        let action = self.stacks.borrow_mut().redo_stack.pop();
+
+        if let Some(action) = action {
+            (action.redo)(target);
+            self.stacks.borrow_mut().undo_stack.push(action);
+            return true;
+        }
+
+        false
+    }
+
+    /// Returns `true` if there is an action that could be reverted.
+    pub fn can_undo(&self) -> bool {
+        !self.stacks.borrow().undo_stack.is_empty()
+    }
+
+    /// Returns `true` if there is an action that could be applied again.
+    pub fn can_redo(&self) -> bool {
+        !self.stacks.borrow().redo_stack.is_empty()
+    }
+
+    /// Gets the descriptions of all pushed actions.
+    pub fn history(&self) -> Vec<String> {
+        self.stacks.borrow().history.clone()
+    }
+
+    /// Clears the undo and redo stacks and the history.
+    pub fn clear(&self) {
+        let mut stacks = self.stacks.borrow_mut();
+        stacks.undo_stack.clear();
+        stacks.redo_stack.clear();
+        stacks.history.clear();
+    }
+}
+
+// Sets the property of the given widget if the target is an entity component manager.
+fn set_property<P: Component + Clone>(target: &mut dyn Any, entity: Entity, key: &str, value: P) {
+    if let Some(ecm) = target.downcast_mut::<EntityComponentManager<Tree, StringComponentStore>>() {
+        if let Ok(property) = ecm.component_store_mut().get_mut::<P>(key, entity) {
+            *property = value;
+        } else {
+            return;
+        }
+
+        mark_as_dirty(key, entity, ecm);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counter_action(description: &str) -> UndoAction {
+        UndoAction::new(
+            description,
+            |target| *target.downcast_mut::<i32>().unwrap() -= 1,
+            |target| *target.downcast_mut::<i32>().unwrap() += 1,
+        )
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let service = UndoService::new();
+        let mut counter = 1;
+
+        assert!(!service.can_undo());
+        assert!(!service.undo(&mut counter));
+
+        service.push(counter_action("increment"));
+        assert!(service.can_undo());
+        assert!(!service.can_redo());
+
+        assert!(service.undo(&mut counter));
+        assert_eq!(0, counter);
+        assert!(service.can_redo());
+
+        assert!(service.redo(&mut counter));
+        assert_eq!(1, counter);
+        assert!(!service.can_redo());
+    }
+
+    #[test]
+    fn test_push_clears_redo() {
+        let service = UndoService::new();
+        let mut counter = 2;
+
+        service.push(counter_action("first"));
+        service.undo(&mut counter);
+        service.push(counter_action("second"));
+
+        assert!(!service.can_redo());
+        assert_eq!(
+            vec!["first".to_string(), "second".to_string()],
+            service.history()
+        );
+    }
+
+    #[test]
+    fn test_max_history() {
+        let service = UndoService::new();
+        let mut counter = 0;
+
+        assert_eq!(DEFAULT_MAX_HISTORY, service.max_history());
+
+        service.set_max_history(2);
+
+        for _ in 0..3 {
+            counter += 1;
+            service.push(counter_action("increment"));
+        }
+
+        assert_eq!(2, service.history().len());
+        assert!(service.undo(&mut counter));
+        assert!(service.undo(&mut counter));
+        assert!(!service.undo(&mut counter));
+        assert_eq!(1, counter);
+    }
+
+    #[test]
+    fn test_group() {
+        let service = UndoService::new();
+        let mut counter = 2;
+
+        service.push(UndoAction::group(
+            "increment twice",
+            vec![counter_action("first"), counter_action("second")],
+        ));
+
+        assert!(service.undo(&mut counter));
+        assert_eq!(0, counter);
+
+        assert!(service.redo(&mut counter));
+        assert_eq!(2, counter);
+        assert_eq!(vec!["increment twice".to_string()], service.history());
+    }
+}
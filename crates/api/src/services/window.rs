@@ -0,0 +1,38 @@
+use crate::{application::Global, utils::Point, widget_base::WidgetContainer};
+
+/// `WindowService` changes the title, the icon and the position of the window of a widget at
+/// runtime. It is accessible from widget states via `ctx.window_service()`.
+///
+/// The values are stored in the `Global` of the window, the `ShellUpdateSystem` propagates changes
+/// to the shell after the current update.
+pub struct WindowService<'a> {
+    window: WidgetContainer<'a>,
+}
+
+impl<'a> WindowService<'a> {
+    /// Creates a new window service for the given window widget.
+    pub fn new(window: WidgetContainer<'a>) -> Self {
+        WindowService { window }
+    }
+
+    /// Gets the current title of the window.
+    pub fn title(&self) -> &str {
+        self.window.get::<Global>("global").window_title.as_str()
+    }
+
+    /// Sets the title of the window.
+    pub fn set_title(&mut self, title: &str) {
+        self.window.get_mut::<Global>("global").window_title = title.to_string();
+    }
+
+    /// Sets the icon of the window. `image` is an encoded image file e.g. a png file.
+    pub fn set_icon(&mut self, image: &[u8]) {
+        self.window.get_mut::<Global>("global").window_icon = Some(image.to_vec());
+    }
+
+    /// Moves the window to the given position on the screen with the given id. The ids of the
+    /// screens are provided by `ScreenService`.
+    pub fn move_window(&mut self, screen_id: u64, position: impl Into<Point>) {
+        self.window.get_mut::<Global>("global").move_window = Some((screen_id, position.into()));
+    }
+}
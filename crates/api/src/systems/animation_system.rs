@@ -0,0 +1,58 @@
+use std::time::Instant;
+
+use dces::prelude::*;
+
+use crate::{prelude::*, render::RenderContext2D, shell::WindowRequest, tree::Tree};
+
+/// The `AnimationSystem` advances the running animations of the `AnimationService` each frame.
+#[derive(Constructor)]
+pub struct AnimationSystem {
+    context_provider: ContextProvider,
+}
+
+impl System<Tree, StringComponentStore, RenderContext2D> for AnimationSystem {
+    fn run_with_context(
+        &self,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        _: &mut RenderContext2D,
+    ) {
+        let root = ecm.entity_store().root();
+
+        let mut animation_service = match ecm
+            .component_store_mut()
+            .get_mut::<AnimationService>("animation_service", root)
+        {
+            Ok(service) => {
+                // the frame time is also read by widgets with their own animations
+                service.start_frame(Instant::now());
+
+                if service.is_empty() {
+                    return;
+                }
+
+                service.clone()
+            }
+            _ => return,
+        };
+
+        let frame_time = animation_service.frame_time();
+        animation_service.advance(frame_time, ecm);
+
+        let running = !animation_service.is_empty();
+
+        if let Ok(service) = ecm
+            .component_store_mut()
+            .get_mut::<AnimationService>("animation_service", root)
+        {
+            *service = animation_service;
+        }
+
+        // request the next frame as long as there are running animations
+        if running {
+            self.context_provider
+                .window_sender
+                .send(WindowRequest::Redraw)
+                .expect("AnimationSystem: could not send redraw request to window.");
+        }
+    }
+}
@@ -1,53 +1,363 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, theming::Theme, tree::Tree, utils::*};
+use super::widget_removal::remove_pending_widgets;
+
+use crate::{
+    prelude::*,
+    render::RenderContext2D,
+    shell::{Key, WindowRequest},
+    theming::{Selector, Theme},
+    tree::Tree,
+    utils::*,
+};
 
 /// The `EventStateSystem` pops events from the event queue and delegates the events to the corresponding event handlers of the widgets and updates the states.
-#[derive(Constructor)]
 pub struct EventStateSystem {
     context_provider: ContextProvider,
     registry: Rc<RefCell<Registry>>,
+    focused_widget: Cell<Option<Entity>>,
+    drag: RefCell<Option<(Entity, DragPayload)>>,
 }
 
 impl EventStateSystem {
-    // Remove all objects of a widget.
-    fn remove_widget(
+    /// Creates a new event state system.
+    pub fn new(context_provider: ContextProvider, registry: Rc<RefCell<Registry>>) -> Self {
+        EventStateSystem {
+            context_provider,
+            registry,
+            focused_widget: Cell::new(None),
+            drag: RefCell::new(None),
+        }
+    }
+
+    // Passes the message of a panic in the update of a descendant to the error boundary. The
+    // boundary is marked as dirty and shows its fallback with its next update.
+    fn report_panic(
+        &self,
+        boundary: Entity,
+        message: String,
+        theme: &Theme,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        crate::shell::CONSOLE.error(format!(
+            "ErrorBoundary (entity: {}): {}",
+            boundary.0, message
+        ));
+
+        WidgetContainer::new(
+            boundary,
+            ecm,
+            theme,
+            Some(&self.context_provider.event_queue),
+        )
+        .set("error_message", message);
+    }
+
+    // Calls the focus lost and focus gained hooks of the states if the focused widget of the
+    // window is changed since the last call.
+    fn process_focus_change(
         &self,
-        entity: Entity,
         theme: &Theme,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
         render_context: &mut RenderContext2D,
     ) {
-        {
-            let registry = &mut self.registry.borrow_mut();
+        let root = ecm.entity_store().root();
+        let focused_widget = ecm
+            .component_store()
+            .get::<Global>("global", root)
+            .unwrap()
+            .focused_widget;
+        let old_focused_widget = self.focused_widget.replace(focused_widget);
 
-            let mut ctx = Context::new(
-                (entity, ecm),
-                &theme,
-                &self.context_provider,
-                render_context,
-            );
+        if focused_widget == old_focused_widget {
+            return;
+        }
+
+        let registry = &mut *self.registry.borrow_mut();
+
+        if let Some(entity) = old_focused_widget {
+            let mut ctx =
+                Context::new((entity, ecm), theme, &self.context_provider, render_context);
 
             if let Some(state) = self.context_provider.states.borrow_mut().get_mut(&entity) {
-                state.cleanup(registry, &mut ctx);
+                state.on_focus_lost(registry, &mut ctx);
             }
 
             drop(ctx);
         }
-        self.context_provider.states.borrow_mut().remove(&entity);
-
-        ecm.remove_entity(entity);
-        self.context_provider.layouts.borrow_mut().remove(&entity);
-        self.context_provider
-            .render_objects
-            .borrow_mut()
-            .remove(&entity);
-        self.context_provider
+
+        if let Some(entity) = focused_widget {
+            let mut ctx =
+                Context::new((entity, ecm), theme, &self.context_provider, render_context);
+
+            if let Some(state) = self.context_provider.states.borrow_mut().get_mut(&entity) {
+                state.on_focus_gained(registry, &mut ctx);
+            }
+
+            drop(ctx);
+        }
+    }
+
+    // Sends a context menu event to the focused widget if the given key requests the context
+    // menu. The event is positioned at the center of the focused widget.
+    fn process_context_menu_key(
+        &self,
+        key: Key,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let root = ecm.entity_store().root();
+        let global = ecm.component_store().get::<Global>("global", root).unwrap();
+
+        if !is_context_menu_key(key, &global.keyboard_state) {
+            return false;
+        }
+
+        let focused_widget = match global.focused_widget {
+            Some(focused_widget) => focused_widget,
+            None => return false,
+        };
+
+        let position = match (
+            ecm.component_store()
+                .get::<Point>("position", focused_widget),
+            ecm.component_store()
+                .get::<Rectangle>("bounds", focused_widget),
+        ) {
+            (Ok(position), Ok(bounds)) => Point::new(
+                position.x() + bounds.width() / 2.0,
+                position.y() + bounds.height() / 2.0,
+            ),
+            _ => return false,
+        };
+
+        let handlers = match self
+            .context_provider
             .handler_map
-            .borrow_mut()
-            .remove(&entity);
+            .borrow()
+            .get(&focused_widget)
+        {
+            Some(handlers) => handlers.clone(),
+            None => return false,
+        };
+
+        let event = EventBox::new(
+            ContextMenuEvent { position },
+            EventStrategy::Direct,
+            focused_widget,
+        );
+
+        handlers.iter().any(|handler| {
+            handler.handle_event(
+                &mut StatesContext::new(&mut *self.context_provider.states.borrow_mut(), ecm),
+                &event,
+            )
+        })
+    }
+
+    // Sends enter and leave events to the widgets with enter or leave handlers if the mouse
+    // enters or leaves their bounds.
+    fn process_hover(
+        &self,
+        mouse_position: Point,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let root = ecm.entity_store().root();
+
+        let theme = ecm
+            .component_store()
+            .get::<Global>("global", root)
+            .unwrap()
+            .theme
+            .clone();
+
+        let enter = EventBox::new(
+            MouseEnterEvent {
+                position: mouse_position,
+            },
+            EventStrategy::Direct,
+            root,
+        );
+        let leave = EventBox::new(
+            MouseLeaveEvent {
+                position: mouse_position,
+            },
+            EventStrategy::Direct,
+            root,
+        );
+
+        let widgets: Vec<Entity> = self
+            .context_provider
+            .handler_map
+            .borrow()
+            .iter()
+            .filter(|(_, handlers)| {
+                handlers
+                    .iter()
+                    .any(|handler| handler.handles_event(&enter) || handler.handles_event(&leave))
+            })
+            .map(|(entity, _)| *entity)
+            .collect();
+
+        let mut hovered_widgets = self.context_provider.hovered_widgets.borrow_mut();
+
+        for widget in widgets {
+            let visible = ecm
+                .component_store()
+                .get::<Visibility>("visibility", widget)
+                .map_or(true, |visibility| *visibility == Visibility::Visible);
+
+            let hovered = visible
+                && check_mouse_condition(
+                    mouse_position,
+                    &WidgetContainer::new(widget, ecm, &theme, None),
+                );
+
+            if hovered && hovered_widgets.insert(widget) {
+                self.context_provider
+                    .event_queue
+                    .borrow_mut()
+                    .register_event_with_strategy(
+                        MouseEnterEvent {
+                            position: mouse_position,
+                        },
+                        EventStrategy::Direct,
+                        widget,
+                    );
+            } else if !hovered && hovered_widgets.remove(&widget) {
+                self.context_provider
+                    .event_queue
+                    .borrow_mut()
+                    .register_event_with_strategy(
+                        MouseLeaveEvent {
+                            position: mouse_position,
+                        },
+                        EventStrategy::Direct,
+                        widget,
+                    );
+            }
+        }
+    }
+
+    // Sets the `drop_target` selector state on the widgets with a files dropped handler while files
+    // from the file manager are dragged over their bounds and resets it after the drag.
+    fn process_drop_targets(
+        &self,
+        drag_position: Option<Point>,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let mut drop_targets = self.context_provider.drop_targets.borrow_mut();
+
+        if drag_position.is_none() && drop_targets.is_empty() {
+            return;
+        }
+
+        let root = ecm.entity_store().root();
+
+        let theme = ecm
+            .component_store()
+            .get::<Global>("global", root)
+            .unwrap()
+            .theme
+            .clone();
+
+        let dropped = EventBox::new(
+            FilesDroppedEvent {
+                paths: vec![],
+                position: Point::default(),
+            },
+            EventStrategy::Direct,
+            root,
+        );
+
+        let widgets: Vec<Entity> = self
+            .context_provider
+            .handler_map
+            .borrow()
+            .iter()
+            .filter(|(_, handlers)| {
+                handlers
+                    .iter()
+                    .any(|handler| handler.handles_event(&dropped))
+            })
+            .map(|(entity, _)| *entity)
+            .collect();
+
+        for widget in widgets {
+            let visible = ecm
+                .component_store()
+                .get::<Visibility>("visibility", widget)
+                .map_or(true, |visibility| *visibility == Visibility::Visible);
+
+            let mut widget = WidgetContainer::new(widget, ecm, &theme, None);
+
+            let target = visible
+                && drag_position.map_or(false, |position| check_mouse_condition(position, &widget));
+
+            if target && drop_targets.insert(widget.entity()) {
+                if let Some(selector) = widget.try_get_mut::<Selector>("selector") {
+                    selector.set_state("drop_target");
+                }
+                widget.update(false);
+            } else if !target && drop_targets.remove(&widget.entity()) {
+                if let Some(selector) = widget.try_get_mut::<Selector>("selector") {
+                    if selector.has_state("drop_target") {
+                        selector.remove_state("drop_target");
+                    }
+                }
+                widget.update(false);
+            }
+        }
+    }
+
+    // Keeps track of the dragged widget and returns a drag move event for the mouse moves and a
+    // drop event for the mouse up during a drag.
+    fn process_drag(
+        &self,
+        event: &EventBox,
+        ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    ) -> Option<EventBox> {
+        let root = ecm.entity_store().root();
+
+        if let Ok(event) = event.downcast_ref::<DragStartEvent>() {
+            *self.drag.borrow_mut() = Some((event.source, event.payload.clone()));
+            return None;
+        }
+
+        if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+            return self.drag.borrow().as_ref().map(|(source, _)| {
+                EventBox::new(
+                    DragMoveEvent {
+                        source: *source,
+                        position: event.position,
+                    },
+                    EventStrategy::BottomUp,
+                    root,
+                )
+            });
+        }
+
+        if let Ok(event) = event.downcast_ref::<MouseUpEvent>() {
+            return self.drag.borrow_mut().take().map(|(source, payload)| {
+                EventBox::new(
+                    DropEvent {
+                        source,
+                        position: event.position,
+                        payload,
+                    },
+                    EventStrategy::BottomUp,
+                    root,
+                )
+            });
+        }
+
+        None
     }
 
     fn process_direct(
@@ -107,14 +417,23 @@ impl EventStateSystem {
         if let Ok(event) = event.downcast_ref::<KeyDownEvent>() {
             if let Ok(global) = ecm.component_store_mut().get_mut::<Global>("global", root) {
                 // Set this value on the keyboard state
-                global.keyboard_state.set_key_state(event.event.key, true);
+                global
+                    .keyboard_state
+                    .set_key_state(event.event.logical.0, true);
+            }
+
+            // the key is consumed if the focused widget shows its context menu
+            if self.process_context_menu_key(event.event.logical.0, ecm) {
+                return true;
             }
         }
 
         if let Ok(event) = event.downcast_ref::<KeyUpEvent>() {
             if let Ok(global) = ecm.component_store_mut().get_mut::<Global>("global", root) {
                 // Set this value on the keyboard state
-                global.keyboard_state.set_key_state(event.event.key, false);
+                global
+                    .keyboard_state
+                    .set_key_state(event.event.logical.0, false);
             }
         }
 
@@ -182,8 +501,39 @@ impl EventStateSystem {
                     }
                     unknown_event = false;
                 }
-                // click handling
-                if let Ok(event) = event.downcast_ref::<ClickEvent>() {
+                // click, double click, drag move and drop handling
+                if let Some(position) = pointer_position_of(event) {
+                    if check_mouse_condition(
+                        position,
+                        &WidgetContainer::new(
+                            current_node,
+                            ecm,
+                            &theme,
+                            Some(&self.context_provider.event_queue),
+                        ),
+                    ) {
+                        let mut add = true;
+                        if let Some(op) = clipped_parent.get(0) {
+                            if !check_mouse_condition(
+                                position,
+                                &WidgetContainer::new(
+                                    *op,
+                                    ecm,
+                                    &theme,
+                                    Some(&self.context_provider.event_queue),
+                                ),
+                            ) {
+                                add = false;
+                            }
+                        }
+                        if add && has_handler {
+                            matching_nodes.push(current_node);
+                        }
+                    }
+                    unknown_event = false;
+                }
+                // files dropped handling
+                if let Ok(event) = event.downcast_ref::<FilesDroppedEvent>() {
                     if check_mouse_condition(
                         event.position,
                         &WidgetContainer::new(
@@ -336,6 +686,58 @@ impl EventStateSystem {
     }
 }
 
+impl EventStateSystem {
+    // Calls the handlers from the root to the source of the event, so an ancestor could handle
+    // the event before its descendants. The event is not passed to disabled or hidden widgets
+    // and their descendants and it is stopped by the first handler that returns `true`.
+    fn process_top_down_event(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let mut update = false;
+
+        for node in path_from_root(event.source, ecm) {
+            let enabled = *ecm
+                .component_store()
+                .get::<bool>("enabled", node)
+                .unwrap_or(&true);
+            let visible = ecm
+                .component_store()
+                .get::<Visibility>("visibility", node)
+                .map_or(true, |visibility| *visibility == Visibility::Visible);
+
+            if !enabled || !visible {
+                break;
+            }
+
+            if let Some(handlers) = self.context_provider.handler_map.borrow().get(&node) {
+                if !handlers.iter().any(|handler| handler.handles_event(event)) {
+                    continue;
+                }
+
+                update = true;
+
+                let handled = handlers.iter().any(|handler| {
+                    handler.handle_event(
+                        &mut StatesContext::new(
+                            &mut *self.context_provider.states.borrow_mut(),
+                            ecm,
+                        ),
+                        event,
+                    )
+                });
+
+                if handled {
+                    break;
+                }
+            }
+        }
+
+        update
+    }
+}
+
 impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
     fn run_with_context(
         &self,
@@ -344,16 +746,57 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
     ) {
         let mut update = false;
 
+        // run the tasks that are sent from other threads
+        for task in self.context_provider.task_receiver.try_iter() {
+            task(&mut StatesContext::new(
+                &mut *self.context_provider.states.borrow_mut(),
+                ecm,
+            ));
+        }
+
         loop {
+            let mut hover_position = None;
+            let mut drag_events = vec![];
+
             {
                 let mouse_position = self.context_provider.mouse_position.get();
                 for event in self.context_provider.event_queue.borrow_mut().into_iter() {
+                    if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+                        hover_position = Some(event.position);
+                    }
+
+                    if let Some(drag_event) = self.process_drag(&event, ecm) {
+                        drag_events.push(drag_event);
+                    }
+
                     if let Ok(event) = event.downcast_ref::<SystemEvent>() {
                         match event {
                             SystemEvent::Quit => {
-                                // todo send close shell request
+                                let quit_handler =
+                                    self.context_provider.quit_handler.borrow().clone();
+
+                                let quit = quit_handler.map_or(true, |handler| {
+                                    handler(&mut StatesContext::new(
+                                        &mut *self.context_provider.states.borrow_mut(),
+                                        ecm,
+                                    ))
+                                });
+
+                                // the quit is cancelled by the quit handler
+                                if !quit {
+                                    continue;
+                                }
+
+                                self.context_provider
+                                    .window_sender
+                                    .send(WindowRequest::Close)
+                                    .expect("EventStateSystem: could not send request to window.");
                                 return;
                             }
+                            SystemEvent::Update(entity) => {
+                                mark_as_dirty("dirty", *entity, ecm);
+                                continue;
+                            }
                         }
                     }
 
@@ -363,9 +806,9 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                                 update = self.process_direct(&event, ecm) || update;
                             }
                         }
-                        // EventStrategy::TopDown => {
-                        //     self.process_top_down_event(&event, ecm);
-                        // }
+                        EventStrategy::TopDown => {
+                            update = self.process_top_down_event(&event, ecm) || update;
+                        }
                         EventStrategy::BottomUp => {
                             let should_update =
                                 self.process_bottom_up_event(mouse_position, &event, ecm);
@@ -375,6 +818,24 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                 }
             }
 
+            // the drag move and drop events are handled with the next run of the loop
+            self.context_provider
+                .event_queue
+                .borrow_mut()
+                .append(&mut drag_events);
+
+            if let Some(position) = hover_position {
+                self.process_hover(position, ecm);
+            }
+
+            let drag_position = if self.context_provider.files_hovered.get() {
+                Some(self.context_provider.mouse_position.get())
+            } else {
+                None
+            };
+
+            self.process_drop_targets(drag_position, ecm);
+
             // handle states
 
             // crate::shell::CONSOLE.time("update-time:");
@@ -388,7 +849,8 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                 .theme
                 .clone();
 
-            let mut remove_widget_list: Vec<Entity> = vec![];
+            // widgets are removed after the loop, because removed widgets could be in the list of dirty widgets
+            let mut pending_removal: Vec<Entity> = vec![];
 
             let mut dirty_index = 0;
 
@@ -416,9 +878,15 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                     skip = true;
                 }
 
+                // the focus could be changed by the update of the previous widget
+                self.process_focus_change(&theme, ecm, render_context);
+
                 let mut keys = vec![];
 
                 if !skip {
+                    let boundary = error_boundary_of(widget, ecm);
+                    let mut panic_message = None;
+
                     {
                         let registry = &mut self.registry.borrow_mut();
 
@@ -432,12 +900,23 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                         if let Some(state) =
                             self.context_provider.states.borrow_mut().get_mut(&widget)
                         {
-                            state.update(registry, &mut ctx);
+                            if boundary.is_some() {
+                                // the panic is caught to keep the rest of the application alive
+                                if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| {
+                                    state.before_update(registry, &mut ctx);
+                                    state.update(registry, &mut ctx);
+                                })) {
+                                    panic_message = Some(panic_message_of(payload));
+                                }
+                            } else {
+                                state.before_update(registry, &mut ctx);
+                                state.update(registry, &mut ctx);
+                            }
                         }
 
                         keys.append(&mut ctx.new_states_keys());
 
-                        remove_widget_list.append(ctx.remove_widget_list());
+                        pending_removal.append(ctx.remove_widget_list());
                         drop(ctx);
 
                         for key in keys {
@@ -457,23 +936,23 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                         }
                     }
 
-                    for remove_widget in remove_widget_list.pop() {
-                        let mut children = vec![];
-                        get_all_children(&mut children, remove_widget, ecm.entity_store());
-
-                        // remove children of target widget.
-                        for entity in children.iter().rev() {
-                            self.remove_widget(*entity, &theme, ecm, render_context);
-                        }
-
-                        // remove target widget
-                        self.remove_widget(remove_widget, &theme, ecm, render_context);
+                    if let (Some(boundary), Some(message)) = (boundary, panic_message) {
+                        self.report_panic(boundary, message, &theme, ecm);
                     }
                 }
 
                 dirty_index += 1;
             }
 
+            remove_pending_widgets(
+                pending_removal,
+                &theme,
+                &self.context_provider,
+                &self.registry,
+                ecm,
+                render_context,
+            );
+
             // crate::shell::CONSOLE.time_end("update-time:");
 
             if self.context_provider.event_queue.borrow().is_empty() {
@@ -482,3 +961,113 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
         }
     }
 }
+
+// Returns the next ancestor of the widget that catches the panics of its descendants.
+fn error_boundary_of(
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+) -> Option<Entity> {
+    let mut current = ecm.entity_store().parent.get(&entity).copied().flatten();
+
+    while let Some(parent) = current {
+        if let Ok(true) = ecm
+            .component_store()
+            .get::<bool>("catch_panics", parent)
+            .map(|catch_panics| *catch_panics)
+        {
+            return Some(parent);
+        }
+
+        current = ecm.entity_store().parent.get(&parent).copied().flatten();
+    }
+
+    None
+}
+
+// Returns the mouse position of the events that are only sent to the widgets below the mouse.
+fn pointer_position_of(event: &EventBox) -> Option<Point> {
+    if let Ok(event) = event.downcast_ref::<ClickEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<DoubleClickEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<DragMoveEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<DropEvent>() {
+        return Some(event.position);
+    }
+
+    None
+}
+
+// Returns the ancestors of the widget starting with the root followed by the widget itself.
+fn path_from_root(
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+) -> Vec<Entity> {
+    let mut path = vec![entity];
+    let mut current = ecm.entity_store().parent.get(&entity).copied().flatten();
+
+    while let Some(parent) = current {
+        path.push(parent);
+        current = ecm.entity_store().parent.get(&parent).copied().flatten();
+    }
+
+    path.reverse();
+    path
+}
+
+// Reads the message of a caught panic.
+fn panic_message_of(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    String::from("unknown panic")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panic_message_of() {
+        let payload = panic::catch_unwind(|| panic!("static message")).unwrap_err();
+        assert_eq!(panic_message_of(payload), "static message");
+
+        let payload = panic::catch_unwind(|| panic!("formatted {}", 42)).unwrap_err();
+        assert_eq!(panic_message_of(payload), "formatted 42");
+
+        let payload = panic::catch_unwind(|| panic::resume_unwind(Box::new(42))).unwrap_err();
+        assert_eq!(panic_message_of(payload), "unknown panic");
+    }
+
+    #[test]
+    fn test_path_from_root() {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+
+        let root = ecm.create_entity().build();
+        let parent = ecm.create_entity().build();
+        let child = ecm.create_entity().build();
+        let sibling = ecm.create_entity().build();
+
+        ecm.entity_store_mut().append_child(root, parent).unwrap();
+        ecm.entity_store_mut().append_child(parent, child).unwrap();
+        ecm.entity_store_mut().append_child(root, sibling).unwrap();
+
+        assert_eq!(path_from_root(child, ecm), vec![root, parent, child]);
+        assert_eq!(path_from_root(sibling, ecm), vec![root, sibling]);
+        assert_eq!(path_from_root(root, ecm), vec![root]);
+    }
+}
@@ -27,6 +27,34 @@ impl InitSystem {
             }
         }
     }
+
+    // Logs the accessibility issues of the window in debug builds. Panics on critical issues if
+    // the audit is enabled.
+    fn audit(&self, ecm: &EntityComponentManager<Tree, StringComponentStore>, root: Entity) {
+        let enabled = self.context_provider.accessibility_audit();
+
+        if !enabled && !cfg!(debug_assertions) {
+            return;
+        }
+
+        let issues = AccessibilityAudit::run(ecm, root);
+
+        for issue in &issues {
+            match issue.severity {
+                Severity::Critical => crate::shell::CONSOLE.error(issue.to_string()),
+                Severity::Warning => crate::shell::CONSOLE.warn(issue.to_string()),
+            }
+        }
+
+        if enabled {
+            if let Some(issue) = issues
+                .iter()
+                .find(|issue| issue.severity == Severity::Critical)
+            {
+                panic!("InitSystem: {}", issue);
+            }
+        }
+    }
 }
 
 impl System<Tree, StringComponentStore, RenderContext2D> for InitSystem {
@@ -43,11 +71,11 @@ impl System<Tree, StringComponentStore, RenderContext2D> for InitSystem {
         let debug = false;
 
         if debug {
-            crate::shell::CONSOLE.log("\n------ Widget tree ------\n".to_string());
+            crate::shell::CONSOLE.debug("\n------ Widget tree ------\n".to_string());
 
             print_tree(root, 0, ecm);
 
-            crate::shell::CONSOLE.log("\n------ Widget tree ------\n".to_string());
+            crate::shell::CONSOLE.debug("\n------ Widget tree ------\n".to_string());
         }
 
         // init css ids
@@ -92,6 +120,8 @@ impl System<Tree, StringComponentStore, RenderContext2D> for InitSystem {
                 break;
             }
         }
+
+        self.audit(ecm, root);
     }
 }
 
@@ -108,7 +138,7 @@ pub fn print_tree(
         Selector::default()
     };
 
-    crate::shell::CONSOLE.log(format!(
+    crate::shell::CONSOLE.debug(format!(
         "{}{} (entity: {}{})",
         "| ".repeat(depth),
         name,
@@ -2,7 +2,9 @@ use std::{cell::RefCell, rc::Rc};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, theming::Theme, tree::Tree};
+use super::widget_removal::remove_pending_widgets;
+
+use crate::{prelude::*, render::RenderContext2D, tree::Tree};
 
 /// The `PostLayoutStateSystem` calls the update_post_layout methods of widget states.
 #[derive(Constructor)]
@@ -11,43 +13,6 @@ pub struct PostLayoutStateSystem {
     registry: Rc<RefCell<Registry>>,
 }
 
-impl PostLayoutStateSystem {
-    fn remove_widget(
-        &self,
-        entity: Entity,
-        theme: &Theme,
-        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
-        render_context: &mut RenderContext2D,
-    ) {
-        {
-            let mut ctx = Context::new(
-                (entity, ecm),
-                &theme,
-                &self.context_provider,
-                render_context,
-            );
-
-            if let Some(state) = self.context_provider.states.borrow_mut().get_mut(&entity) {
-                state.cleanup(&mut self.registry.borrow_mut(), &mut ctx);
-            }
-
-            drop(ctx);
-        }
-        self.context_provider.states.borrow_mut().remove(&entity);
-
-        ecm.remove_entity(entity);
-        self.context_provider.layouts.borrow_mut().remove(&entity);
-        self.context_provider
-            .render_objects
-            .borrow_mut()
-            .remove(&entity);
-        self.context_provider
-            .handler_map
-            .borrow_mut()
-            .remove(&entity);
-    }
-}
-
 impl System<Tree, StringComponentStore, RenderContext2D> for PostLayoutStateSystem {
     fn run_with_context(
         &self,
@@ -68,7 +33,8 @@ impl System<Tree, StringComponentStore, RenderContext2D> for PostLayoutStateSyst
             .theme
             .clone();
 
-        let mut remove_widget_list: Vec<Entity> = vec![];
+        // widgets are removed after all states are updated, because the removal modifies the tree
+        let mut pending_removal: Vec<Entity> = vec![];
 
         {
             let mut keys = vec![];
@@ -86,27 +52,25 @@ impl System<Tree, StringComponentStore, RenderContext2D> for PostLayoutStateSyst
                     let mut ctx =
                         Context::new((key, ecm), &theme, &self.context_provider, render_context);
 
-                    self.context_provider
-                        .states
-                        .borrow_mut()
-                        .get_mut(&key)
-                        .unwrap()
-                        .update_post_layout(&mut *self.registry.borrow_mut(), &mut ctx);
-                }
-
-                for remove_widget in remove_widget_list.pop() {
-                    let mut children = vec![];
-                    get_all_children(&mut children, remove_widget, ecm.entity_store());
+                    let registry = &mut *self.registry.borrow_mut();
+                    let mut states = self.context_provider.states.borrow_mut();
+                    let state = states.get_mut(&key).unwrap();
 
-                    // remove children of target widget.
-                    for entity in children.iter().rev() {
-                        self.remove_widget(*entity, &theme, ecm, render_context);
-                    }
+                    state.update_post_layout(registry, &mut ctx);
+                    state.after_update(registry, &mut ctx);
 
-                    // remove target widget
-                    self.remove_widget(remove_widget, &theme, ecm, render_context);
+                    pending_removal.append(ctx.remove_widget_list());
                 }
             }
         }
+
+        remove_pending_widgets(
+            pending_removal,
+            &theme,
+            &self.context_provider,
+            &self.registry,
+            ecm,
+            render_context,
+        );
     }
 }
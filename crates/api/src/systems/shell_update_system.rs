@@ -0,0 +1,68 @@
+use std::cell::RefCell;
+
+use dces::prelude::*;
+
+use crate::{prelude::*, render::RenderContext2D, shell::WindowRequest, tree::Tree};
+
+/// The `ShellUpdateSystem` propagates changes of the window title, the window icon and the
+/// requested window moves stored in the `Global` of the window to the shell.
+pub struct ShellUpdateSystem {
+    context_provider: ContextProvider,
+    title: RefCell<String>,
+    icon: RefCell<Option<Vec<u8>>>,
+}
+
+impl ShellUpdateSystem {
+    /// Creates a new shell update system.
+    pub fn new(context_provider: ContextProvider) -> Self {
+        ShellUpdateSystem {
+            context_provider,
+            title: RefCell::new(String::new()),
+            icon: RefCell::new(None),
+        }
+    }
+
+    fn send_request(&self, request: WindowRequest) {
+        self.context_provider
+            .window_sender
+            .send(request)
+            .expect("ShellUpdateSystem: could not send request to window.");
+    }
+}
+
+impl System<Tree, StringComponentStore, RenderContext2D> for ShellUpdateSystem {
+    fn run_with_context(
+        &self,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        _: &mut RenderContext2D,
+    ) {
+        let root = ecm.entity_store().root();
+
+        let global = match ecm.component_store().get::<Global>("global", root) {
+            Ok(global) => global,
+            _ => return,
+        };
+
+        if *self.title.borrow() != global.window_title {
+            *self.title.borrow_mut() = global.window_title.clone();
+            self.send_request(WindowRequest::ChangeTitle(global.window_title.clone()));
+        }
+
+        if *self.icon.borrow() != global.window_icon {
+            *self.icon.borrow_mut() = global.window_icon.clone();
+
+            if let Some(icon) = &global.window_icon {
+                self.send_request(WindowRequest::ChangeIcon(icon.clone()));
+            }
+        }
+
+        if let Ok(global) = ecm.component_store_mut().get_mut::<Global>("global", root) {
+            if let Some((screen_id, position)) = global.move_window.take() {
+                self.send_request(WindowRequest::MoveToScreen {
+                    screen_id,
+                    position,
+                });
+            }
+        }
+    }
+}
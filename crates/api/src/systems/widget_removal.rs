@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+
+use dces::prelude::*;
+
+use crate::{prelude::*, render::RenderContext2D, theming::Theme, tree::Tree};
+
+/// Removes the given widgets with all of their children and drops them from the list of dirty
+/// widgets. Is used by the systems that update the widget states.
+pub(crate) fn remove_pending_widgets(
+    pending_removal: Vec<Entity>,
+    theme: &Theme,
+    context_provider: &ContextProvider,
+    registry: &RefCell<Registry>,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    render_context: &mut RenderContext2D,
+) {
+    if pending_removal.is_empty() {
+        return;
+    }
+
+    let mut removed = vec![];
+
+    for remove_widget in pending_removal {
+        // the widget could already be removed as child of an other removed widget
+        if !ecm.entity_store().children.contains_key(&remove_widget) {
+            continue;
+        }
+
+        // collect the complete subtree before the store is modified
+        let mut children: Vec<Entity> = ecm.entity_store().walk_children(remove_widget).collect();
+
+        // remove children of target widget.
+        for entity in children.iter().rev() {
+            remove_widget(
+                *entity,
+                theme,
+                context_provider,
+                registry,
+                ecm,
+                render_context,
+            );
+        }
+
+        // remove target widget
+        remove_widget(
+            remove_widget,
+            theme,
+            context_provider,
+            registry,
+            ecm,
+            render_context,
+        );
+
+        removed.append(&mut children);
+        removed.push(remove_widget);
+    }
+
+    let root = ecm.entity_store().root();
+
+    if let Ok(dirty_widgets) = ecm
+        .component_store_mut()
+        .get_mut::<Vec<Entity>>("dirty_widgets", root)
+    {
+        dirty_widgets.retain(|widget| !removed.contains(widget));
+    }
+}
+
+// Remove all objects of a widget.
+fn remove_widget(
+    entity: Entity,
+    theme: &Theme,
+    context_provider: &ContextProvider,
+    registry: &RefCell<Registry>,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    render_context: &mut RenderContext2D,
+) {
+    {
+        let registry = &mut registry.borrow_mut();
+
+        let mut ctx = Context::new((entity, ecm), &theme, context_provider, render_context);
+
+        if let Some(state) = context_provider.states.borrow_mut().get_mut(&entity) {
+            state.cleanup(registry, &mut ctx);
+        }
+
+        drop(ctx);
+    }
+    context_provider.states.borrow_mut().remove(&entity);
+
+    ecm.remove_entity(entity);
+    context_provider.layouts.borrow_mut().remove(&entity);
+    context_provider.render_objects.borrow_mut().remove(&entity);
+    context_provider.handler_map.borrow_mut().remove(&entity);
+    context_provider
+        .hovered_widgets
+        .borrow_mut()
+        .remove(&entity);
+    context_provider.drop_targets.borrow_mut().remove(&entity);
+}
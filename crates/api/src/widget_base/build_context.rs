@@ -2,13 +2,23 @@ use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render_object::RenderObject, theming::Theme, tree::Tree};
+use crate::{
+    prelude::*,
+    render_object::RenderObject,
+    theme::{IconSource, IconTheme},
+    theming::Theme,
+    tree::Tree,
+};
 
 use super::State;
 
 pub type WidgetBuildContext = Option<Box<dyn Fn(&mut BuildContext, usize) -> Entity + 'static>>;
 
 /// Used to create an entity for a widget with its properties as components.
+///
+/// Widgets are built from the inside out: each child is built before it is appended to its
+/// parent, and theme updates walk the tree with an explicit stack, so the nesting depth of a
+/// widget tree is not limited by the call stack.
 #[derive(Constructor)]
 pub struct BuildContext<'a> {
     ecm: &'a mut EntityComponentManager<Tree, StringComponentStore>,
@@ -17,6 +27,7 @@ pub struct BuildContext<'a> {
     handlers: &'a RefCell<EventHandlerMap>,
     states: &'a mut BTreeMap<Entity, Box<dyn State>>,
     theme: &'a Theme,
+    icon_theme: &'a RefCell<IconTheme>,
     event_queue: &'a Rc<RefCell<EventQueue>>,
 }
 
@@ -31,6 +42,19 @@ impl<'a> BuildContext<'a> {
         self.ecm.create_entity().build()
     }
 
+    /// Gets the icon with the given name from the icon theme of the window.
+    pub fn icon(&self, name: &str) -> Option<IconSource> {
+        self.icon_theme.borrow().get(name).cloned()
+    }
+
+    /// Gets the font and the text of the icon with the given name. Returns empty strings if the
+    /// icon theme contains no font glyph for the name.
+    pub fn icon_glyph(&self, name: &str) -> (String, String) {
+        self.icon(name)
+            .and_then(|icon| icon.glyph())
+            .unwrap_or_default()
+    }
+
     /// Update theme by state.
     pub fn update_theme_by_state(&mut self, entity: Entity) {
         self.get_widget(entity).update(true);
@@ -154,3 +178,51 @@ pub fn register_property<P: Component>(
 ) {
     ctx.register_property(key, entity, property);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPTH: usize = 500;
+
+    #[test]
+    fn test_build_deep_tree() {
+        let mut world: World<Tree, StringComponentStore, crate::render::RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let render_objects = RefCell::new(BTreeMap::new());
+        let layouts = RefCell::new(BTreeMap::new());
+        let handlers = RefCell::new(EventHandlerMap::new());
+        let mut states = BTreeMap::new();
+        let theme = crate::theme::dark_theme();
+        let icon_theme = RefCell::new(crate::theme::material_icon_theme());
+        let event_queue = Rc::new(RefCell::new(EventQueue::new()));
+
+        let mut ctx = BuildContext::new(
+            world.entity_component_manager(),
+            &render_objects,
+            &layouts,
+            &handlers,
+            &mut states,
+            &theme,
+            &icon_theme,
+            &event_queue,
+        );
+
+        let leaf = Overlay::new().build(&mut ctx);
+        let mut root = leaf;
+
+        for _ in 0..DEPTH {
+            root = Overlay::new().child(root).build(&mut ctx);
+        }
+
+        ctx.update_theme_by_state(root);
+
+        let tree = world.entity_component_manager().entity_store();
+
+        assert_eq!(tree.len(), DEPTH + 1);
+        assert_eq!(tree.walk_ancestors(leaf).count(), DEPTH);
+        assert_eq!(tree.walk_ancestors(leaf).last(), Some(root));
+        assert_eq!(tree.walk_children(root).count(), DEPTH);
+        assert_eq!(layouts.borrow().len(), DEPTH + 1);
+    }
+}
@@ -5,8 +5,9 @@ use dces::prelude::*;
 use crate::{
     application::{create_window, ContextProvider},
     prelude::*,
-    render::RenderContext2D,
+    render::{RenderContext2D, TextMetrics},
     shell::{ShellRequest, WindowRequest},
+    theme::{IconSource, IconTheme},
     theming::prelude::*,
     tree::Tree,
 };
@@ -115,17 +116,10 @@ impl<'a> Context<'a> {
     /// Returns a parent of the widget of the current state referenced by css `id`.
     /// Panics if a parent with the given id could not be found
     pub fn parent_from_id<'b>(&mut self, id: impl Into<&'b str>) -> WidgetContainer<'_> {
-        let mut current = self.entity;
         let id = id.into();
 
-        while let Some(parent) = self.ecm.entity_store().parent[&current] {
-            if let Ok(parent_id) = self.ecm.component_store().get::<String>("id", parent) {
-                if parent_id == id {
-                    return self.get_widget(parent);
-                }
-            }
-
-            current = parent;
+        if let Some(parent) = self.parent_entity_by_id(id) {
+            return self.get_widget(parent);
         }
 
         panic!(
@@ -140,20 +134,21 @@ impl<'a> Context<'a> {
         &mut self,
         id: impl Into<&'b str>,
     ) -> Option<WidgetContainer<'_>> {
-        let mut current = self.entity;
-        let id = id.into();
-
-        while let Some(parent) = self.ecm.entity_store().parent[&current] {
-            if let Ok(parent_id) = self.ecm.component_store().get::<String>("id", parent) {
-                if parent_id == id {
-                    return Some(self.get_widget(parent));
-                }
-            }
+        let parent = self.parent_entity_by_id(id.into())?;
+        Some(self.get_widget(parent))
+    }
 
-            current = parent;
-        }
+    // Returns the first ancestor of the current widget with the given id.
+    fn parent_entity_by_id(&self, id: &str) -> Option<Entity> {
+        let ecm = &self.ecm;
 
-        None
+        ecm.entity_store()
+            .walk_ancestors(self.entity)
+            .find(|parent| {
+                ecm.component_store()
+                    .get::<String>("id", *parent)
+                    .map_or(false, |parent_id| parent_id == id)
+            })
     }
 
     /// Returns the child of the current widget.
@@ -188,6 +183,7 @@ impl<'a> Context<'a> {
             &self.provider.handler_map,
             &mut self.new_states,
             &self.theme,
+            &self.provider.icon_theme,
             &self.provider.event_queue,
         )
     }
@@ -272,6 +268,15 @@ impl<'a> Context<'a> {
         }
     }
 
+    /// Moves the given child of the current widget to the given index of its children. If the
+    /// given entity is not a child of the current widget nothing will happen.
+    pub fn move_child(&mut self, child: Entity, index: usize) {
+        let _ = self
+            .ecm
+            .entity_store_mut()
+            .move_child(self.entity, child, index);
+    }
+
     /// Returns a mutable reference of the children that should be removed.
     pub fn remove_widget_list(&mut self) -> &mut Vec<Entity> {
         &mut self.remove_widget_list
@@ -312,11 +317,18 @@ impl<'a> Context<'a> {
 
     // -- Manipulation --
 
-    /// Returns the entity id of an child by the given name.
+    /// Returns the entity id of an child by the given name. The id could also be a dot-separated
+    /// path e.g. `outer.inner.button`, then each segment is searched starting at the child found
+    /// for the previous segment.
     pub fn entity_of_child<'b>(&mut self, id: impl Into<&'b str>) -> Option<Entity> {
-        let id = id.into();
+        id.into().split('.').try_fold(self.entity, |entity, id| {
+            self.entity_of_child_impl(entity, id)
+        })
+    }
 
-        let mut current_node = self.entity;
+    // Returns the first entity with the given id, starting the search at `start_node`.
+    fn entity_of_child_impl(&self, start_node: Entity, id: &str) -> Option<Entity> {
+        let mut current_node = start_node;
 
         loop {
             if let Ok(child_id) = self.ecm.component_store().get::<String>("id", current_node) {
@@ -338,34 +350,60 @@ impl<'a> Context<'a> {
         None
     }
 
+    /// Returns the entity of the first descendant of the current widget with the given id.
+    /// The whole subtree is searched in breadth-first order, so the descendant with the lowest depth wins.
+    pub fn entity_of_descendant<'b>(&self, id: impl Into<&'b str>) -> Option<Entity> {
+        let id = id.into();
+        let ecm = &self.ecm;
+
+        ecm.entity_store()
+            .walk_children_breadth_first(self.entity)
+            .find(|child| {
+                ecm.component_store()
+                    .get::<String>("id", *child)
+                    .map_or(false, |child_id| child_id == id)
+            })
+    }
+
+    /// Returns the entities of all descendants of the current widget in depth-first order.
+    pub fn descendants(&self) -> Vec<Entity> {
+        self.ecm.entity_store().walk_children(self.entity).collect()
+    }
+
+    /// Returns the entities of the children of the given widget.
+    pub fn children_of(&self, entity: Entity) -> Vec<Entity> {
+        self.ecm
+            .entity_store()
+            .children
+            .get(&entity)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the ancestors of the current widget, starting with its parent.
+    pub fn ancestors(&self) -> Vec<Entity> {
+        self.ecm
+            .entity_store()
+            .walk_ancestors(self.entity)
+            .collect()
+    }
+
     /// Returns the entity of the parent referenced by css `element`.
     /// If there is no id defined None will be returned.
     pub fn parent_entity_by_style<'b>(&mut self, element: impl Into<&'b str>) -> Option<Entity> {
-        let mut current = self.entity;
         let element = element.into();
+        let ecm = &self.ecm;
 
-        while let Some(parent) = self.ecm.entity_store().parent[&current] {
-            if let Ok(selector) = self
-                .ecm
-                .component_store()
-                .get::<Selector>("selector", parent)
-            {
-                if let Some(parent_element) = &selector.style {
-                    if parent_element == element
-                        && self
-                            .ecm
-                            .component_store()
-                            .is_origin::<Selector>("selector", parent)
-                    {
-                        return Some(parent);
-                    }
-                }
-            }
-
-            current = parent;
-        }
-
-        None
+        ecm.entity_store()
+            .walk_ancestors(self.entity)
+            .find(|parent| {
+                ecm.component_store()
+                    .get::<Selector>("selector", *parent)
+                    .map_or(false, |selector| selector.style.as_deref() == Some(element))
+                    && ecm
+                        .component_store()
+                        .is_origin::<Selector>("selector", *parent)
+            })
     }
 
     /// Returns the entity of the parent.
@@ -429,11 +467,20 @@ impl<'a> Context<'a> {
             .register_event_with_strategy(event, strategy, entity);
     }
 
+    /// Requests another update of the widget with the next run e.g. to poll a timer. Should be
+    /// called from `update_post_layout`, because the events pushed by `update` are handled in
+    /// the same run.
+    pub fn request_update(&mut self) {
+        self.push_event_by_entity(SystemEvent::Update(self.entity), self.entity);
+        self.send_window_request(WindowRequest::Redraw);
+    }
+
     /// Creates and show a new window.
     pub fn show_window<F: Fn(&mut BuildContext) -> Entity + 'static>(&mut self, create_fn: F) {
         let (adapter, settings, receiver) = create_window(
             self.provider.application_name.clone(),
             self.theme.clone(),
+            self.provider.icon_theme.borrow().clone(),
             self.provider.shell_sender.clone(),
             create_fn,
         );
@@ -448,6 +495,12 @@ impl<'a> Context<'a> {
         self.render_context
     }
 
+    /// Measures the size of the given text with the given font size and font family. Could be
+    /// used to check if a text fits before the layout is calculated e.g. in `State::update`.
+    pub fn measure_text(&mut self, text: &str, font_size: f64, font: &str) -> TextMetrics {
+        self.render_context.measure(text, font_size, font)
+    }
+
     /// Gets a new sender that allows to communicate with the window shell.
     pub fn send_window_request(&self, request: WindowRequest) {
         self.provider
@@ -456,11 +509,83 @@ impl<'a> Context<'a> {
             .expect("Context::send_window_request: could not send request to window.");
     }
 
+    /// Sets the shape of the mouse cursor. The shape is displayed by the shell after the current update.
+    pub fn set_cursor(&mut self, cursor_shape: CursorShape) {
+        self.window().get_mut::<Global>("global").cursor_shape = cursor_shape;
+    }
+
+    /// Enables or disables raw mouse input. While enabled the cursor is hidden and locked and the
+    /// unfiltered mouse movement is reported by `MouseRawDeltaEvent`. A widget that enables raw input
+    /// should disable it again in `State::cleanup`.
+    pub fn set_raw_input(&mut self, raw_input: bool) {
+        self.window().get_mut::<Global>("global").raw_input = raw_input;
+    }
+
+    /// Gets the window service to change the title and icon of the window.
+    pub fn window_service(&mut self) -> WindowService<'_> {
+        WindowService::new(self.window())
+    }
+
+    /// Gets the animation service of the window.
+    pub fn animation_service(&mut self) -> &mut AnimationService {
+        let root = self.ecm.entity_store().root();
+
+        self.ecm
+            .component_store_mut()
+            .get_mut::<AnimationService>("animation_service", root)
+            .expect("Context.animation_service: the window has no animation service.")
+    }
+
+    /// Gets the undo service of the window.
+    pub fn undo_service(&self) -> UndoService {
+        self.provider.undo_service.clone()
+    }
+
+    /// Gets the clipboard service of the window.
+    pub fn clipboard_service(&self) -> ClipboardService {
+        self.provider.clipboard_service.clone()
+    }
+
+    /// Gets the font service of the window.
+    pub fn font_service(&self) -> FontService {
+        self.provider.font_service.clone()
+    }
+
+    /// Gets the screen service of the window.
+    pub fn screen_service(&self) -> ScreenService {
+        self.provider.screen_service.clone()
+    }
+
+    /// Reverts the last change on the undo stack of the window. Returns `false` if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.provider.undo_service.clone().undo(self.ecm)
+    }
+
+    /// Applies the last reverted change of the window again. Returns `false` if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        self.provider.undo_service.clone().redo(self.ecm)
+    }
+
+    /// Enables or disables the drawing of the layout boundaries of all widgets of the window.
+    pub fn set_layout_debug(&mut self, layout_debug: bool) {
+        self.provider.set_layout_debug(layout_debug);
+        self.window().update_dirty(true);
+    }
+
     /// Gets a window request sender.
     pub fn window_sender(&self) -> mpsc::Sender<WindowRequest> {
         self.provider.window_sender.clone()
     }
 
+    /// Gets a task sender that could be moved to other threads to update the widget states on the
+    /// ui thread.
+    pub fn task_sender(&self) -> TaskSender {
+        TaskSender::new(
+            self.provider.task_sender.clone(),
+            self.provider.window_sender.clone(),
+        )
+    }
+
     /// Returns a keys collection of new added states.
     pub fn new_states_keys(&self) -> Vec<Entity> {
         self.new_states.keys().cloned().collect()
@@ -468,6 +593,7 @@ impl<'a> Context<'a> {
 
     /// Switch the current theme.
     pub fn switch_theme(&mut self, theme: Theme) {
+        crate::application::log_theme_errors(&theme);
         self.theme = theme.clone();
 
         self.window().get_mut::<Global>("global").theme = theme;
@@ -475,6 +601,17 @@ impl<'a> Context<'a> {
         // update on window to update all widgets in the tree
         self.window().update_dirty(true);
     }
+
+    /// Gets the icon with the given name from the icon theme of the window.
+    pub fn icon(&self, name: &str) -> Option<IconSource> {
+        self.provider.icon_theme.borrow().get(name).cloned()
+    }
+
+    /// Switch the current icon theme. The icons are looked up when a widget is built, widgets
+    /// that already exist keep their icons.
+    pub fn switch_icon_theme(&mut self, icon_theme: IconTheme) {
+        *self.provider.icon_theme.borrow_mut() = icon_theme;
+    }
 }
 
 // -- Helpers --
@@ -496,11 +633,10 @@ pub fn find_parent(tree: &Tree, target_child: Entity, parent: Entity) -> Option<
     return None;
 }
 
+/// Appends all descendants of the given parent to `children`.
+#[deprecated(note = "Use the lazy `Tree::walk_children` iterator instead.")]
 pub fn get_all_children(children: &mut Vec<Entity>, parent: Entity, tree: &Tree) {
-    for child in &tree.children[&parent] {
-        children.push(*child);
-        get_all_children(children, *child, tree);
-    }
+    children.extend(tree.walk_children(parent));
 }
 
 // -- Helpers --
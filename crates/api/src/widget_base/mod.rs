@@ -9,6 +9,7 @@ pub use self::context::*;
 pub use self::registry::*;
 pub use self::state::*;
 pub use self::states_context::*;
+pub use self::task_sender::*;
 pub use self::template::*;
 pub use self::widget_container::*;
 
@@ -17,6 +18,7 @@ mod context;
 mod registry;
 mod state;
 mod states_context;
+mod task_sender;
 mod template;
 mod widget_container;
 
@@ -32,7 +34,7 @@ pub fn toggle_flag(flag: &str, widget: &mut WidgetContainer) {
         if value {
             selector.set_state(flag);
         } else {
-            selector.clear_state();
+            selector.remove_state(flag);
         }
     }
 }
@@ -1,4 +1,7 @@
-use std::{any::Any, collections::HashMap};
+use std::{
+    any::{type_name, Any},
+    collections::HashMap,
+};
 
 use dces::prelude::Component;
 
@@ -68,6 +71,41 @@ impl Registry {
         None
     }
 
+    /// Registers a service by its type. Typed services are requested by `get_required` and
+    /// `get_or_insert_default` without a key.
+    pub fn insert<C: Component>(&mut self, service: C) {
+        self.register(type_name::<C>(), service);
+    }
+
+    /// Gets the service of the given type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no service of the given type.
+    pub fn get_required<C: Component>(&self) -> &C {
+        self.try_get(type_name::<C>()).unwrap_or_else(|| {
+            panic!(
+                "Registry.get_required(): service of type {} could not be found.",
+                type_name::<C>()
+            )
+        })
+    }
+
+    /// Gets a mutable reference of the service of the given type. If there is no service of the
+    /// type a default instance is inserted.
+    pub fn get_or_insert_default<C: Component + Default>(&mut self) -> &mut C {
+        self.registry
+            .entry(type_name::<C>().to_string())
+            .or_insert_with(|| Box::new(C::default()))
+            .downcast_mut()
+            .unwrap_or_else(|| {
+                panic!(
+                    "Registry.get_or_insert_default(): wrong type for key: {}",
+                    type_name::<C>()
+                )
+            })
+    }
+
     /// Returns the number of elements in the registry.
     pub fn len(&self) -> usize {
         self.registry.len()
@@ -106,6 +144,35 @@ mod tests {
         assert!(registry.try_get_mut::<ServiceTwo>("two").is_some());
     }
 
+    #[derive(Default)]
+    struct Counter(usize);
+
+    #[test]
+    fn get_required() {
+        let mut registry = Registry::new();
+        registry.insert(ServiceOne);
+
+        registry.get_required::<ServiceOne>();
+        assert!(registry.try_get::<ServiceTwo>("two").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "ServiceTwo could not be found")]
+    fn get_required_missing() {
+        let registry = Registry::new();
+        registry.get_required::<ServiceTwo>();
+    }
+
+    #[test]
+    fn get_or_insert_default() {
+        let mut registry = Registry::new();
+        assert_eq!(registry.get_or_insert_default::<Counter>().0, 0);
+
+        registry.get_or_insert_default::<Counter>().0 = 5;
+        assert_eq!(registry.get_or_insert_default::<Counter>().0, 5);
+        assert_eq!(registry.len(), 1);
+    }
+
     #[test]
     fn len() {
         let mut registry = Registry::new();
@@ -63,6 +63,14 @@ pub trait State: AsAny {
     /// * `_ctx`: Represents the context of the current widget.Allows manipulation of the widget tree.
     fn cleanup(&mut self, _registry: &mut Registry, _ctx: &mut Context) {}
 
+    /// Is called before `update` each time the widget is updated. Could be used for concerns like
+    /// logging or validation without touching `update`.
+    ///
+    /// # Arguments
+    /// * `_registry`: Provides access to the global Service Registry.
+    /// * `_ctx`: Represents the context of the current widget.Allows manipulation of the widget tree.
+    fn before_update(&mut self, _registry: &mut Registry, _ctx: &mut Context) {}
+
     /// Updates the state of a widget **before layout is calculated** for the given context when the widget becomes "dirty",
     /// (e.g.: a property of a widget is changed or an [`event`] is fired)
     /// 
@@ -83,4 +91,25 @@ pub trait State: AsAny {
     ///
     /// [`event`]: ../trait.Event.html
     fn update_post_layout(&mut self, _registry: &mut Registry, _ctx: &mut Context) {}
+
+    /// Is called after `update_post_layout` each time the widget is updated after the layout is calculated.
+    ///
+    /// # Arguments
+    /// * `_registry`: Provides access to the global Service Registry.
+    /// * `_ctx`: Represents the context of the current widget.Allows manipulation of the widget tree.
+    fn after_update(&mut self, _registry: &mut Registry, _ctx: &mut Context) {}
+
+    /// Is called when the widget gets the keyboard focus, before its next `update`.
+    ///
+    /// # Arguments
+    /// * `_registry`: Provides access to the global Service Registry.
+    /// * `_ctx`: Represents the context of the current widget.Allows manipulation of the widget tree.
+    fn on_focus_gained(&mut self, _registry: &mut Registry, _ctx: &mut Context) {}
+
+    /// Is called when the widget loses the keyboard focus, before its next `update`.
+    ///
+    /// # Arguments
+    /// * `_registry`: Provides access to the global Service Registry.
+    /// * `_ctx`: Represents the context of the current widget.Allows manipulation of the widget tree.
+    fn on_focus_lost(&mut self, _registry: &mut Registry, _ctx: &mut Context) {}
 }
@@ -0,0 +1,56 @@
+use std::sync::mpsc;
+
+use crate::shell::WindowRequest;
+
+use super::StatesContext;
+
+/// Closure that is sent from another thread and runs on the ui thread with access to the widget
+/// states.
+pub type TaskFn = dyn FnOnce(&mut StatesContext) + Send + 'static;
+
+/// `TaskSender` is used to update widget states from other threads e.g. if an asynchronous
+/// operation is completed. The widget states are not thread safe, because of that the sender does
+/// not access them directly. It posts closures to the queue of the window that runs them on the ui
+/// thread before the next update of the widget states.
+///
+/// The sender could be moved to other threads, it is available via `ctx.task_sender()`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let sender = ctx.task_sender();
+/// let entity = ctx.entity;
+///
+/// std::thread::spawn(move || {
+///     let result = load_data();
+///     sender.send(move |states| states.get_mut::<MyState>(entity).set_data(result));
+/// });
+/// ```
+#[derive(Clone)]
+pub struct TaskSender {
+    task_sender: mpsc::Sender<Box<TaskFn>>,
+    window_sender: mpsc::Sender<WindowRequest>,
+}
+
+impl TaskSender {
+    /// Creates a new task sender.
+    pub fn new(
+        task_sender: mpsc::Sender<Box<TaskFn>>,
+        window_sender: mpsc::Sender<WindowRequest>,
+    ) -> Self {
+        TaskSender {
+            task_sender,
+            window_sender,
+        }
+    }
+
+    /// Posts the given task to the ui thread and requests an update of the window. Returns `false`
+    /// if the window is already closed.
+    pub fn send<F: FnOnce(&mut StatesContext) + Send + 'static>(&self, task: F) -> bool {
+        if self.task_sender.send(Box::new(task)).is_err() {
+            return false;
+        }
+
+        self.window_sender.send(WindowRequest::Redraw).is_ok()
+    }
+}
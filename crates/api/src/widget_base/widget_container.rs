@@ -1,9 +1,10 @@
-use std::{any::type_name, cell::RefCell, rc::Rc};
+use std::{any::type_name, cell::RefCell, collections::HashMap, rc::Rc};
 
 use dces::prelude::*;
 
 use crate::{
-    event::ChangedEvent, event::*, properties::Constraint, theming::*, tree::*, utils::prelude::*,
+    animation::*, event::ChangedEvent, event::*, properties::Constraint,
+    services::AnimationService, theming::*, tree::*, utils::prelude::*,
 };
 
 /// Mark the widget and shared widgets as dirty.
@@ -64,6 +65,24 @@ impl<'a> WidgetContainer<'a> {
         self.current_node
     }
 
+    /// Returns an iterator over all descendants of the given `entity` in depth-first pre-order.
+    pub fn walk_children<'b>(&'b self, entity: Entity) -> impl Iterator<Item = Entity> + 'b {
+        self.ecm.entity_store().walk_children(entity)
+    }
+
+    /// Returns an iterator over all descendants of the given `entity` in breadth-first order.
+    pub fn walk_children_breadth_first<'b>(
+        &'b self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'b {
+        self.ecm.entity_store().walk_children_breadth_first(entity)
+    }
+
+    /// Returns an iterator over all ancestors of the given `entity` from its parent up to the root.
+    pub fn walk_ancestors<'b>(&'b self, entity: Entity) -> impl Iterator<Item = Entity> + 'b {
+        self.ecm.entity_store().walk_ancestors(entity)
+    }
+
     /// Remove the dirty flag from the current widget.
     pub fn clear_dirty(&mut self) {
         let root = self.ecm.entity_store().root();
@@ -244,6 +263,29 @@ impl<'a> WidgetContainer<'a> {
         self.set_non_dirty(key, value);
     }
 
+    /// Sets the property of type `P` like `set`, but doesn't push a `ChangedEvent` for it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the widget does not contains the property.
+    pub fn set_silent<P>(&mut self, key: &str, value: P)
+    where
+        P: Component + Clone + PartialEq,
+    {
+        if self
+            .ecm
+            .component_store()
+            .get::<P>(key, self.current_node)
+            .unwrap()
+            == &value
+        {
+            return;
+        }
+
+        self.mark_as_dirty(key);
+        self.set_non_dirty(key, value);
+    }
+
     /// Sets the property of type `P` without setting the widget dirty.
     ///
     /// # Panics
@@ -354,6 +396,90 @@ impl<'a> WidgetContainer<'a> {
         }
     }
 
+    // Gets the transition config of the given property key if there is one.
+    fn transition_config(&self, key: &str) -> Option<TransitionConfig> {
+        self.ecm
+            .component_store()
+            .get::<HashMap<String, TransitionConfig>>("transition", self.current_node)
+            .ok()?
+            .get(key)
+            .copied()
+    }
+
+    // Adds an animation to the animation service of the window. Returns `false` if there is no service.
+    fn animate<F>(&mut self, f: F) -> bool
+    where
+        F: FnOnce(&mut AnimationService, Entity),
+    {
+        let root = self.ecm.entity_store().root();
+        let entity = self.current_node;
+
+        if let Ok(service) = self
+            .ecm
+            .component_store_mut()
+            .get_mut::<AnimationService>("animation_service", root)
+        {
+            f(service, entity);
+            return true;
+        }
+
+        false
+    }
+
+    fn update_f64_value(&mut self, key: &str, value: Value) {
+        let to: f64 = value.into();
+
+        if let (Some(config), Some(from)) =
+            (self.transition_config(key), self.try_clone::<f64>(key))
+        {
+            if from != to
+                && self
+                    .animate(|service, entity| service.animate_f64(entity, key, from, to, &config))
+            {
+                return;
+            }
+        }
+
+        self.update_value::<f64, f64>(key, to);
+    }
+
+    fn update_f32_value(&mut self, key: &str, value: Value) {
+        let to: f32 = value.into();
+
+        if let (Some(config), Some(from)) =
+            (self.transition_config(key), self.try_clone::<f32>(key))
+        {
+            if from != to
+                && self
+                    .animate(|service, entity| service.animate_f32(entity, key, from, to, &config))
+            {
+                return;
+            }
+        }
+
+        self.update_value::<f32, f32>(key, to);
+    }
+
+    fn update_brush_value(&mut self, key: &str, value: Value) {
+        let to = Brush::from(value);
+
+        if let (Some(config), Some(Brush::SolidColor(from))) =
+            (self.transition_config(key), self.try_clone::<Brush>(key))
+        {
+            if let Brush::SolidColor(to) = to {
+                if from != to
+                    && self.animate(|service, entity| {
+                        service.animate_color(entity, key, from, to, &config)
+                    })
+                {
+                    return;
+                }
+            }
+        }
+
+        self.update_value::<Brush, Brush>(key, to);
+    }
+
     fn update_value<T, V>(&mut self, key: &str, value: V)
     where
         T: Component + Clone,
@@ -378,36 +504,97 @@ impl<'a> WidgetContainer<'a> {
         self.update_widget(self.current_node, force, true);
     }
 
-    /// Update all properties from theme for the given widget.
+    /// Update all properties from theme for the given widget and its children.
     pub fn update_widget(&mut self, entity: Entity, force: bool, should_mark_as_dirty: bool) {
-        self.current_node = entity;
-        if !self.has::<Selector>("selector") {
-            return;
-        }
+        // An explicit stack is used instead of recursion to support trees of any depth. The second
+        // visit of a widget happens after all of its children are updated.
+        let mut stack = vec![(entity, force, false)];
+        let window_size = self.window_size();
+
+        while let Some((node, force, children_updated)) = stack.pop() {
+            self.current_node = node;
+
+            if children_updated {
+                // direct access to prevent initial setting of dirty flag on widget
+                self.ecm
+                    .component_store_mut()
+                    .get_mut::<Selector>("selector", node)
+                    .unwrap()
+                    .set_dirty(false);
+
+                if should_mark_as_dirty {
+                    mark_as_dirty("selector", node, self.ecm);
+                }
 
-        if force {
-            // direct access to prevent initial setting of dirty flag on widget
-            self.ecm
-                .component_store_mut()
-                .get_mut::<Selector>("selector", self.current_node)
-                .unwrap()
-                .set_dirty(true);
-        }
+                continue;
+            }
 
-        let selector = self.clone::<Selector>("selector");
+            if !self.has::<Selector>("selector") {
+                continue;
+            }
 
-        if !selector.dirty() {
-            return;
+            if force {
+                // direct access to prevent initial setting of dirty flag on widget
+                self.ecm
+                    .component_store_mut()
+                    .get_mut::<Selector>("selector", node)
+                    .unwrap()
+                    .set_dirty(true);
+            }
+
+            let selector = self.clone::<Selector>("selector");
+
+            if !selector.dirty() {
+                continue;
+            }
+
+            self.update_properties(&selector, window_size);
+
+            let force = selector.dirty() || force;
+
+            stack.push((node, force, true));
+
+            for child in self.ecm.entity_store().children[&node].iter().rev() {
+                stack.push((*child, force, false));
+            }
         }
 
-        if let Some(props) = self.theme.properties(&selector) {
-            for (key, value) in props {
+        self.current_node = entity;
+    }
+
+    // Gets the size of the window that is used to evaluate the media queries of the theme. Before
+    // the window is created the size is zero.
+    fn window_size(&self) -> (f64, f64) {
+        self.ecm
+            .entity_store()
+            .root
+            .and_then(|root| {
+                self.ecm
+                    .component_store()
+                    .get::<Rectangle>("bounds", root)
+                    .ok()
+            })
+            .map_or((0.0, 0.0), |bounds| (bounds.width(), bounds.height()))
+    }
+
+    // Updates the properties of the current widget from the theme.
+    fn update_properties(&mut self, selector: &Selector, window_size: (f64, f64)) {
+        if let Some(props) = self.theme.properties(selector, window_size) {
+            if let Some(transition) = props.get("transition") {
+                self.ecm.component_store_mut().register(
+                    "transition",
+                    self.current_node,
+                    transitions_from_value(transition.clone()),
+                );
+            }
+
+            for (key, value) in props.iter() {
                 match key.as_str() {
                     "foreground" | "background" | "icon_brush" | "border_brush" => {
-                        self.update_value::<Brush, Value>(key, Value(value.clone()));
+                        self.update_brush_value(key, Value(value.clone()));
                     }
                     "font_size" | "icon_size" | "spacing" | "border_radius" => {
-                        self.update_value::<f64, Value>(key, Value(value.clone()));
+                        self.update_f64_value(key, Value(value.clone()));
                     }
                     "padding" | "border_width" => {
                         self.update_value::<Thickness, Value>(key, Value(value.clone()));
@@ -418,8 +605,11 @@ impl<'a> WidgetContainer<'a> {
                     "font_family" | "icon_family" => {
                         self.update_value::<String, Value>(key, Value(value.clone()));
                     }
+                    "font_fallbacks" => {
+                        self.update_value::<Vec<String>, Value>(key, Value(value.clone()));
+                    }
                     "opacity" => {
-                        self.update_value::<f32, Value>(key, Value(value.clone()));
+                        self.update_f32_value(key, Value(value.clone()));
                     }
                     "width" | "height" | "min_width" | "min_height" | "max_width"
                     | "max_height" => self.update_constraint(key, Value(value.clone())),
@@ -427,25 +617,6 @@ impl<'a> WidgetContainer<'a> {
                 }
             }
         }
-
-        let force = selector.dirty() || force;
-
-        for child in &(self.ecm.entity_store().children.clone())[&entity] {
-            self.update_widget(*child, force, should_mark_as_dirty);
-        }
-
-        self.current_node = entity;
-
-        // direct access to prevent initial setting of dirty flag on widget
-        self.ecm
-            .component_store_mut()
-            .get_mut::<Selector>("selector", self.current_node)
-            .unwrap()
-            .set_dirty(false);
-
-        if should_mark_as_dirty {
-            mark_as_dirty("selector", self.current_node, self.ecm);
-        }
     }
 
     fn get_name(&self) -> String {
@@ -3,7 +3,10 @@ use std::{
     thread,
 };
 
-use crate::{platform, utils::*, PipelineTrait, RenderTarget, TextMetrics};
+use crate::{
+    platform, utils::*, ImageFilter, OffscreenHandle, PipelineTrait, RenderTarget, TextMetrics,
+    TextureId,
+};
 use platform::Image;
 
 #[derive(Clone)]
@@ -117,9 +120,31 @@ enum RenderTask {
     SetAlpha {
         alpha: f32,
     },
+    BeginOffscreen {
+        width: f64,
+        height: f64,
+    },
+    EndOffscreen {
+        handle: OffscreenHandle,
+    },
+    ApplyFilter {
+        texture: TextureId,
+        filter: ImageFilter,
+    },
+    DrawTexture {
+        texture: TextureId,
+        x: f64,
+        y: f64,
+    },
+    ReleaseTexture {
+        texture: TextureId,
+    },
     SetFontFamily {
         family: String,
     },
+    SetFontFallbacks {
+        fallbacks: Vec<String>,
+    },
     SetFontSize {
         size: f64,
     },
@@ -330,9 +355,27 @@ impl RenderWorker {
                             RenderTask::Clip() => {
                                 render_context_2_d.clip();
                             }
+                            RenderTask::BeginOffscreen { width, height } => {
+                                render_context_2_d.begin_offscreen(width, height);
+                            }
+                            RenderTask::EndOffscreen { handle } => {
+                                render_context_2_d.end_offscreen(handle);
+                            }
+                            RenderTask::ApplyFilter { texture, filter } => {
+                                render_context_2_d.apply_filter(texture, filter);
+                            }
+                            RenderTask::DrawTexture { texture, x, y } => {
+                                render_context_2_d.draw_texture(texture, x, y);
+                            }
+                            RenderTask::ReleaseTexture { texture } => {
+                                render_context_2_d.release_texture(texture);
+                            }
                             RenderTask::SetFontFamily { family } => {
                                 render_context_2_d.set_font_family(family);
                             }
+                            RenderTask::SetFontFallbacks { fallbacks } => {
+                                render_context_2_d.set_font_fallbacks(fallbacks);
+                            }
                             RenderTask::SetFontSize { size } => {
                                 render_context_2_d.set_font_size(size);
                             }
@@ -385,6 +428,8 @@ pub struct RenderContext2D {
     finish_receiver: mpsc::Receiver<bool>,
     tasks: Vec<RenderTask>,
     measure_context: platform::RenderContext2D,
+    // mirrors the handle counter of the render thread
+    next_offscreen: u32,
 }
 
 impl Drop for RenderContext2D {
@@ -419,6 +464,7 @@ impl RenderContext2D {
             finish_receiver,
             tasks: vec![],
             measure_context: platform::RenderContext2D::new(width, height),
+            next_offscreen: 0,
         }
     }
 
@@ -511,9 +557,11 @@ impl RenderContext2D {
         &mut self,
         text: &str,
         font_size: f64,
-        family: impl Into<String>,
+        font: impl Into<FontFallbackChain>,
     ) -> TextMetrics {
-        self.measure_context.set_font_family(family);
+        let font = font.into();
+        self.measure_context.set_font_family(font.primary);
+        self.measure_context.set_font_fallbacks(font.fallbacks);
         self.measure_context.set_font_size(font_size);
         self.measure_text(text)
     }
@@ -600,6 +648,40 @@ impl RenderContext2D {
         });
     }
 
+    // Offscreen
+
+    /// Opens an offscreen buffer with the given size. All drawing operations until the matching
+    /// `end_offscreen` draw into the buffer, its origin is at (0, 0). Buffers could be nested.
+    pub fn begin_offscreen(&mut self, width: f64, height: f64) -> OffscreenHandle {
+        let handle = OffscreenHandle(self.next_offscreen);
+        self.next_offscreen += 1;
+        self.tasks
+            .push(RenderTask::BeginOffscreen { width, height });
+        handle
+    }
+
+    /// Closes the given offscreen buffer and returns the texture with its content. The texture
+    /// is kept until it is released with `release_texture`.
+    pub fn end_offscreen(&mut self, handle: OffscreenHandle) -> TextureId {
+        self.tasks.push(RenderTask::EndOffscreen { handle });
+        TextureId::from(handle)
+    }
+
+    /// Applies the filter on the given texture.
+    pub fn apply_filter(&mut self, texture: TextureId, filter: ImageFilter) {
+        self.tasks.push(RenderTask::ApplyFilter { texture, filter });
+    }
+
+    /// Draws the given texture at the given position.
+    pub fn draw_texture(&mut self, texture: TextureId, x: f64, y: f64) {
+        self.tasks.push(RenderTask::DrawTexture { texture, x, y });
+    }
+
+    /// Releases the memory of the given texture.
+    pub fn release_texture(&mut self, texture: TextureId) {
+        self.tasks.push(RenderTask::ReleaseTexture { texture });
+    }
+
     // Draw image
 
     pub fn draw_render_target(&mut self, render_target: &RenderTarget, x: f64, y: f64) {
@@ -678,6 +760,11 @@ impl RenderContext2D {
         self.tasks.push(RenderTask::SetFontFamily { family });
     }
 
+    /// Specifies the fonts that are used for characters that are not covered by the font family.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.tasks.push(RenderTask::SetFontFallbacks { fallbacks });
+    }
+
     /// Specifies the font size.
     pub fn set_font_size(&mut self, size: f64) {
         self.tasks.push(RenderTask::SetFontSize { size });
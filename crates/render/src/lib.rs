@@ -42,8 +42,10 @@ pub mod platform;
 #[cfg(target_arch = "wasm32")]
 pub use platform::RenderContext2D;
 
+pub use self::offscreen::*;
 pub use self::render_target::*;
 
+mod offscreen;
 mod render_target;
 
 /// Defines the current configuration of the render ctx.
@@ -79,12 +81,16 @@ pub struct TextMetrics {
 #[derive(Default, Clone, PartialEq, Debug)]
 pub struct FontConfig {
     pub family: String,
+    pub fallbacks: Vec<String>,
     pub font_size: f64,
 }
 
 impl ToString for FontConfig {
     fn to_string(&self) -> String {
-        format!("{}px {}", self.font_size, self.family)
+        let mut families = vec![self.family.as_str()];
+        families.extend(self.fallbacks.iter().map(|f| f.as_str()));
+
+        format!("{}px {}", self.font_size, families.join(", "))
     }
 }
 
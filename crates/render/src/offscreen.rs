@@ -0,0 +1,129 @@
+use crate::{utils::*, RenderTarget};
+
+/// Identifies an offscreen buffer that is opened with `RenderContext2D::begin_offscreen`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OffscreenHandle(pub u32);
+
+/// Identifies the texture that contains the content of a finished offscreen buffer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TextureId(pub u32);
+
+impl From<OffscreenHandle> for TextureId {
+    fn from(handle: OffscreenHandle) -> Self {
+        TextureId(handle.0)
+    }
+}
+
+/// Describes a filter that could be applied on a texture.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ImageFilter {
+    /// Blurs the texture with the given radius in pixels.
+    Blur { radius: f64 },
+
+    /// Removes the colors of the texture.
+    Grayscale,
+
+    /// Multiplies the colors of the texture with the given color.
+    Tint(Color),
+}
+
+impl ImageFilter {
+    /// Applies the filter on the premultiplied pixels of the given render target.
+    pub fn apply(&self, render_target: &mut RenderTarget) {
+        let width = render_target.width() as usize;
+        let height = render_target.height() as usize;
+        let data = render_target.data_mut();
+
+        match *self {
+            ImageFilter::Blur { radius } => {
+                let radius = radius.round() as usize;
+
+                // three box blur passes approximate a gaussian blur
+                for _ in 0..3 {
+                    box_blur(data, width, height, radius);
+                }
+            }
+            ImageFilter::Grayscale => {
+                for pixel in data.iter_mut() {
+                    let [a, r, g, b] = channels(*pixel);
+                    let gray = (r as u32 * 77 + g as u32 * 150 + b as u32 * 29) >> 8;
+                    *pixel = pack([a as u32, gray, gray, gray]);
+                }
+            }
+            ImageFilter::Tint(color) => {
+                for pixel in data.iter_mut() {
+                    let [a, r, g, b] = channels(*pixel);
+                    *pixel = pack([
+                        a as u32,
+                        r as u32 * color.r() as u32 / 255,
+                        g as u32 * color.g() as u32 / 255,
+                        b as u32 * color.b() as u32 / 255,
+                    ]);
+                }
+            }
+        }
+    }
+}
+
+fn channels(pixel: u32) -> [u8; 4] {
+    pixel.to_be_bytes()
+}
+
+fn pack(channels: [u32; 4]) -> u32 {
+    (channels[0] << 24) | (channels[1] << 16) | (channels[2] << 8) | channels[3]
+}
+
+// Blurs the rows and then the columns with a running sum over 2 * radius + 1 pixels.
+fn box_blur(data: &mut [u32], width: usize, height: usize, radius: usize) {
+    if radius == 0 || width == 0 || height == 0 {
+        return;
+    }
+
+    let mut line = vec![];
+
+    for y in 0..height {
+        line.clear();
+        line.extend((0..width).map(|x| data[y * width + x]));
+        blur_line(&line, radius, |x, pixel| data[y * width + x] = pixel);
+    }
+
+    for x in 0..width {
+        line.clear();
+        line.extend((0..height).map(|y| data[y * width + x]));
+        blur_line(&line, radius, |y, pixel| data[y * width + x] = pixel);
+    }
+}
+
+fn blur_line(line: &[u32], radius: usize, mut set: impl FnMut(usize, u32)) {
+    let len = line.len() as isize;
+    let radius = radius as isize;
+    let count = (2 * radius + 1) as u32;
+    let at = |i: isize| channels(line[i.max(0).min(len - 1) as usize]);
+
+    let mut sum = [0u32; 4];
+
+    for i in -radius..=radius {
+        for (s, c) in sum.iter_mut().zip(at(i).iter()) {
+            *s += *c as u32;
+        }
+    }
+
+    for i in 0..len {
+        set(
+            i as usize,
+            pack([
+                sum[0] / count,
+                sum[1] / count,
+                sum[2] / count,
+                sum[3] / count,
+            ]),
+        );
+
+        let removed = at(i - radius);
+        let added = at(i + radius + 1);
+
+        for (s, (a, r)) in sum.iter_mut().zip(added.iter().zip(removed.iter())) {
+            *s = *s + *a as u32 - *r as u32;
+        }
+    }
+}
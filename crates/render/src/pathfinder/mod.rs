@@ -1,4 +1,7 @@
-use crate::{utils::*, Pipeline, RenderConfig, RenderTarget, TextMetrics};
+use crate::{
+    utils::*, ImageFilter, OffscreenHandle, Pipeline, RenderConfig, RenderTarget, TextMetrics,
+    TextureId,
+};
 
 use font_kit::handle::Handle;
 use pathfinder_canvas::{
@@ -181,9 +184,11 @@ impl RenderContext2D {
         &mut self,
         text: &str,
         font_size: f64,
-        family: impl Into<String>,
+        font: impl Into<FontFallbackChain>,
     ) -> TextMetrics {
-        self.set_font_family(family);
+        let font = font.into();
+        self.set_font_family(font.primary);
+        self.set_font_fallbacks(font.fallbacks);
         self.canvas().set_font_size(font_size as f32);
         let t_m = self.canvas().measure_text(text);
         TextMetrics {
@@ -284,6 +289,28 @@ impl RenderContext2D {
         );
     }
 
+    // Offscreen
+
+    /// Offscreen buffers are not supported by this backend yet. Drawing operations between
+    /// `begin_offscreen` and `end_offscreen` draw directly on the canvas.
+    pub fn begin_offscreen(&mut self, _: f64, _: f64) -> OffscreenHandle {
+        OffscreenHandle(0)
+    }
+
+    /// Returns the id of an empty texture.
+    pub fn end_offscreen(&mut self, handle: OffscreenHandle) -> TextureId {
+        TextureId::from(handle)
+    }
+
+    /// Does nothing, offscreen buffers are not supported by this backend yet.
+    pub fn apply_filter(&mut self, _: TextureId, _: ImageFilter) {}
+
+    /// Does nothing, offscreen buffers are not supported by this backend yet.
+    pub fn draw_texture(&mut self, _: TextureId, _: f64, _: f64) {}
+
+    /// Does nothing, offscreen buffers are not supported by this backend yet.
+    pub fn release_texture(&mut self, _: TextureId) {}
+
     /// Draws a render target.
     pub fn draw_render_target(&mut self, render_target: &RenderTarget, x: f64, y: f64) {}
 
@@ -325,7 +352,24 @@ impl RenderContext2D {
 
     /// Specific the font family.
     pub fn set_font_family(&mut self, family: impl Into<String>) {
-        self.canvas().set_font(family.into().as_str());
+        self.config.font_config.family = family.into();
+        self.apply_font();
+    }
+
+    /// Specifies the fonts that are used for characters that are not covered by the font family.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.config.font_config.fallbacks = fallbacks;
+        self.apply_font();
+    }
+
+    // Sets the font family and its fallbacks as font collection of the canvas.
+    fn apply_font(&mut self) {
+        let font_config = self.config.font_config.clone();
+        let families: Vec<&str> = std::iter::once(font_config.family.as_str())
+            .chain(font_config.fallbacks.iter().map(|f| f.as_str()))
+            .collect();
+
+        self.canvas().set_font(&families[..]);
     }
 
     /// Specifies the font size.
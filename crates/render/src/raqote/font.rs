@@ -12,26 +12,45 @@ impl Font {
         rusttype::Font::from_bytes(bytes).map(|font| Font { inner: font })
     }
 
-    pub fn measure_text(&self, text: &str, size: f64) -> (f64, f64) {
-        let scale = rusttype::Scale::uniform(size as f32);
-        let v_metrics = self.inner.v_metrics(scale);
-        let offset = rusttype::point(0.0, v_metrics.ascent);
+    /// Loads the installed system font that covers the given character. Returns `None` if no
+    /// font could be found.
+    pub fn from_system(c: char) -> Option<Self> {
+        let (bytes, index) = system::font_for_char(c)?;
+
+        rusttype::FontCollection::from_bytes(bytes)
+            .ok()?
+            .font_at(index as usize)
+            .ok()
+            .map(|font| Font { inner: font })
+            .filter(|font| font.has_glyph(c))
+    }
 
-        let pixel_height = size.ceil();
+    /// Returns `true` if the font contains a glyph for the given character.
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.inner.glyph(c).id().0 != 0
+    }
 
-        // Glyphs to draw for "RustType". Feel free to try other strings.
-        let glyphs: Vec<rusttype::PositionedGlyph> =
-            self.inner.layout(text, scale, offset).collect();
+    /// Returns the distance between the baseline and the highest edge of any glyph for the given
+    /// font size.
+    pub fn ascent(&self, size: f64) -> f32 {
+        self.inner
+            .v_metrics(rusttype::Scale::uniform(size as f32))
+            .ascent
+    }
 
-        let width = glyphs
-            .iter()
-            .rev()
-            .map(|g| g.position().x as f32 + g.unpositioned().h_metrics().advance_width)
-            .next()
-            .unwrap_or(0.0)
-            .ceil() as f64;
+    /// Returns the advance width of the given text without rounding.
+    pub fn text_width(&self, text: &str, size: f64) -> f64 {
+        let scale = rusttype::Scale::uniform(size as f32);
+
+        self.inner
+            .layout(text, scale, rusttype::point(0.0, 0.0))
+            .last()
+            .map(|g| g.position().x + g.unpositioned().h_metrics().advance_width)
+            .unwrap_or(0.0) as f64
+    }
 
-        (width, pixel_height)
+    pub fn measure_text(&self, text: &str, size: f64) -> (f64, f64) {
+        (self.text_width(text, size).ceil(), size.ceil())
     }
 
     pub fn render_text(
@@ -63,14 +82,30 @@ impl Font {
         position: (f64, f64),
         clip: Rectangle,
     ) {
-        let scale = rusttype::Scale::uniform(config.0 as f32);
-
         // The origin of a line of text is at the baseline (roughly where non-descending letters sit).
         // We don't want to clip the text, so we shift it down with an offset when laying it out.
-        // v_metrics.ascent is the distance between the baseline and the highest edge of any glyph in
+        // The ascent is the distance between the baseline and the highest edge of any glyph in
         // the font. That's enough to guarantee that there's no clipping.
-        let v_metrics = self.inner.v_metrics(scale);
-        let offset = rusttype::point(0.0, v_metrics.ascent);
+        let ascent = self.ascent(config.0);
+        self.render_text_on_baseline(text, data, width, config, position, ascent, clip);
+    }
+
+    /// Renders the text with the baseline at `ascent` below the position. Used to align the
+    /// glyphs of fallback fonts with the glyphs of the primary font.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_text_on_baseline(
+        &self,
+        text: &str,
+        data: &mut [u32],
+        width: f64,
+        // size, color, alpha
+        config: (f64, Color, f32),
+        position: (f64, f64),
+        ascent: f32,
+        clip: Rectangle,
+    ) {
+        let scale = rusttype::Scale::uniform(config.0 as f32);
+        let offset = rusttype::point(0.0, ascent);
 
         // Glyphs to draw for "RustType". Feel free to try other strings.
         let glyphs: Vec<rusttype::PositionedGlyph> =
@@ -130,3 +165,53 @@ impl Font {
         }
     }
 }
+
+#[cfg(target_os = "linux")]
+mod system {
+    use std::{fs, process::Command};
+
+    // Asks fontconfig for the best installed font that covers the character.
+    pub fn font_for_char(c: char) -> Option<(Vec<u8>, u32)> {
+        let output = Command::new("fc-match")
+            .arg("--format=%{file}\n%{index}")
+            .arg(format!(":charset={:x}", c as u32))
+            .output()
+            .ok()?;
+
+        let output = String::from_utf8(output.stdout).ok()?;
+        let mut lines = output.lines();
+        let path = lines.next()?;
+        let index = lines.next().and_then(|i| i.parse().ok()).unwrap_or(0);
+
+        Some((fs::read(path).ok()?, index))
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod system {
+    use font_kit::{
+        family_name::FamilyName, handle::Handle, properties::Properties, source::SystemSource,
+    };
+
+    // Uses the font fallback of the system (DirectWrite on Windows, the Core Text cascade list on
+    // macOS) to find a font that covers the character.
+    pub fn font_for_char(c: char) -> Option<(Vec<u8>, u32)> {
+        let font = SystemSource::new()
+            .select_best_match(&[FamilyName::SansSerif], &Properties::new())
+            .ok()?
+            .load()
+            .ok()?;
+
+        let fallback = font
+            .get_fallbacks(&c.to_string(), "")
+            .fonts
+            .into_iter()
+            .map(|fallback| fallback.font)
+            .find(|font| font.glyph_for_char(c).is_some())?;
+
+        match fallback.handle()? {
+            Handle::Path { path, font_index } => Some((std::fs::read(path).ok()?, font_index)),
+            Handle::Memory { bytes, font_index } => Some(((*bytes).clone(), font_index)),
+        }
+    }
+}
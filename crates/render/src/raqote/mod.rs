@@ -1,8 +1,15 @@
-use std::{cmp, collections::HashMap};
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+    mem,
+};
 
 use raqote;
 
-use crate::{utils::*, PipelineTrait, RenderConfig, RenderTarget, TextMetrics};
+use crate::{
+    utils::*, FontConfig, ImageFilter, OffscreenHandle, PipelineTrait, RenderConfig, RenderTarget,
+    TextMetrics, TextureId,
+};
 
 pub use self::font::*;
 pub use self::image::Image;
@@ -10,6 +17,14 @@ pub use self::image::Image;
 mod font;
 mod image;
 
+// Stores the draw target that was active before an offscreen buffer was opened.
+struct Offscreen {
+    handle: OffscreenHandle,
+    draw_target: raqote::DrawTarget,
+    clip: bool,
+    clip_rect: Option<Rectangle>,
+}
+
 /// The RenderContext2D trait, provides the rendering ctx. It is used for drawing shapes, text, images, and other objects.
 pub struct RenderContext2D {
     draw_target: raqote::DrawTarget,
@@ -18,6 +33,14 @@ pub struct RenderContext2D {
     saved_config: Option<RenderConfig>,
     fonts: HashMap<String, Font>,
 
+    // system fonts that are loaded for characters not covered by the fallback chain
+    system_fonts: Vec<Font>,
+    missing_glyphs: HashSet<char>,
+
+    offscreen_stack: Vec<Offscreen>,
+    textures: HashMap<TextureId, RenderTarget>,
+    next_offscreen: u32,
+
     // hack / work around for faster text clipping
     clip: bool,
     last_rect: Rectangle,
@@ -38,6 +61,11 @@ impl RenderContext2D {
             config: RenderConfig::default(),
             saved_config: None,
             fonts: HashMap::new(),
+            system_fonts: vec![],
+            missing_glyphs: HashSet::new(),
+            offscreen_stack: vec![],
+            textures: HashMap::new(),
+            next_offscreen: 0,
             clip: false,
             last_rect: Rectangle::new((0.0, 0.0), width, height),
             clip_rect: None,
@@ -105,37 +133,47 @@ impl RenderContext2D {
             return;
         }
 
-        if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            let width = self.draw_target.width() as f64;
-
-            if self.clip {
-                if let Some(rect) = self.clip_rect {
-                    font.render_text_clipped(
-                        text,
-                        self.draw_target.get_data_mut(),
-                        width,
-                        (self.config.font_config.font_size, color, self.config.alpha),
-                        (x, y),
-                        rect,
-                    );
-                } else {
-                    font.render_text(
-                        text,
-                        self.draw_target.get_data_mut(),
-                        width,
-                        (self.config.font_config.font_size, color, self.config.alpha),
-                        (x, y),
-                    );
-                }
-            } else {
-                font.render_text(
-                    text,
-                    self.draw_target.get_data_mut(),
-                    width,
-                    (self.config.font_config.font_size, color, self.config.alpha),
-                    (x, y),
-                );
-            }
+        self.load_system_fonts(text);
+
+        let width = self.draw_target.width() as f64;
+        let font_size = self.config.font_config.font_size;
+
+        let clip = match self.clip_rect {
+            Some(rect) if self.clip => rect,
+            _ => Rectangle::new((0.0, 0.0), width, std::f64::MAX),
+        };
+
+        let runs = font_runs(
+            &self.fonts,
+            &self.system_fonts,
+            &self.config.font_config,
+            text,
+        );
+
+        // all runs share the baseline of the primary font
+        let ascent = match runs.first() {
+            Some((font, _)) => chain_fonts(&self.fonts, &self.config.font_config)
+                .next()
+                .unwrap_or(font)
+                .ascent(font_size),
+            None => return,
+        };
+
+        let data = self.draw_target.get_data_mut();
+        let mut offset = 0.0;
+
+        for (font, run) in runs {
+            font.render_text_on_baseline(
+                run,
+                data,
+                width,
+                (font_size, color, self.config.alpha),
+                (x + offset, y),
+                ascent,
+                clip,
+            );
+
+            offset += font.text_width(run, font_size);
         }
     }
 
@@ -147,16 +185,50 @@ impl RenderContext2D {
             return text_metrics;
         }
 
-        if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            let (width, height) = font.measure_text(text, self.config.font_config.font_size);
+        self.load_system_fonts(text);
 
-            text_metrics.width = width;
-            text_metrics.height = height;
+        let font_size = self.config.font_config.font_size;
+        let runs = font_runs(
+            &self.fonts,
+            &self.system_fonts,
+            &self.config.font_config,
+            text,
+        );
+
+        if !runs.is_empty() {
+            text_metrics.width = runs
+                .iter()
+                .map(|(font, run)| font.text_width(run, font_size))
+                .sum::<f64>()
+                .ceil();
+            text_metrics.height = font_size.ceil();
         }
 
         text_metrics
     }
 
+    // Loads the system fonts for the characters of the text that are not covered by the fonts
+    // of the fallback chain.
+    fn load_system_fonts(&mut self, text: &str) {
+        for c in text.chars() {
+            if c.is_control()
+                || self.missing_glyphs.contains(&c)
+                || chain_fonts(&self.fonts, &self.config.font_config)
+                    .chain(self.system_fonts.iter())
+                    .any(|font| font.has_glyph(c))
+            {
+                continue;
+            }
+
+            match Font::from_system(c) {
+                Some(font) => self.system_fonts.push(font),
+                None => {
+                    self.missing_glyphs.insert(c);
+                }
+            }
+        }
+    }
+
     /// Fills the current or given path with the current file style.
     pub fn fill(&mut self) {
         self.draw_target.fill(
@@ -258,6 +330,81 @@ impl RenderContext2D {
         );
     }
 
+    // Offscreen
+
+    /// Opens an offscreen buffer with the given size. All drawing operations until the matching
+    /// `end_offscreen` draw into the buffer, its origin is at (0, 0). Buffers could be nested.
+    pub fn begin_offscreen(&mut self, width: f64, height: f64) -> OffscreenHandle {
+        let handle = OffscreenHandle(self.next_offscreen);
+        self.next_offscreen += 1;
+
+        let draw_target = mem::replace(
+            &mut self.draw_target,
+            raqote::DrawTarget::new(width as i32, height as i32),
+        );
+
+        self.offscreen_stack.push(Offscreen {
+            handle,
+            draw_target,
+            clip: self.clip,
+            clip_rect: self.clip_rect,
+        });
+        self.clip = false;
+        self.clip_rect = None;
+
+        handle
+    }
+
+    /// Closes the given offscreen buffer and returns the texture with its content. Buffers that
+    /// were opened inside of it and not closed yet are closed too. The texture is kept until it
+    /// is released with `release_texture`.
+    pub fn end_offscreen(&mut self, handle: OffscreenHandle) -> TextureId {
+        if !self.offscreen_stack.iter().any(|o| o.handle == handle) {
+            return TextureId::from(handle);
+        }
+
+        while let Some(offscreen) = self.offscreen_stack.pop() {
+            let draw_target = mem::replace(&mut self.draw_target, offscreen.draw_target);
+            self.clip = offscreen.clip;
+            self.clip_rect = offscreen.clip_rect;
+
+            let render_target = RenderTarget::from_data(
+                draw_target.width() as u32,
+                draw_target.height() as u32,
+                draw_target.get_data().to_vec(),
+            )
+            .unwrap();
+            self.textures
+                .insert(TextureId::from(offscreen.handle), render_target);
+
+            if offscreen.handle == handle {
+                break;
+            }
+        }
+
+        TextureId::from(handle)
+    }
+
+    /// Applies the filter on the given texture.
+    pub fn apply_filter(&mut self, texture: TextureId, filter: ImageFilter) {
+        if let Some(render_target) = self.textures.get_mut(&texture) {
+            filter.apply(render_target);
+        }
+    }
+
+    /// Draws the given texture at the given position.
+    pub fn draw_texture(&mut self, texture: TextureId, x: f64, y: f64) {
+        if let Some(render_target) = self.textures.remove(&texture) {
+            self.draw_render_target(&render_target, x, y);
+            self.textures.insert(texture, render_target);
+        }
+    }
+
+    /// Releases the memory of the given texture.
+    pub fn release_texture(&mut self, texture: TextureId) {
+        self.textures.remove(&texture);
+    }
+
     /// Draws a render target.
     pub fn draw_render_target(&mut self, render_target: &RenderTarget, x: f64, y: f64) {
         self.draw_target.draw_image_at(
@@ -359,6 +506,11 @@ impl RenderContext2D {
         self.config.font_config.family = family.into();
     }
 
+    /// Specifies the fonts that are used for characters that are not covered by the font family.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.config.font_config.fallbacks = fallbacks;
+    }
+
     /// Specifies the font size.
     pub fn set_font_size(&mut self, size: f64) {
         self.config.font_config.font_size = size + 4.0;
@@ -504,3 +656,54 @@ fn brush_to_source<'a>(brush: &Brush) -> raqote::Source<'a> {
         }
     }
 }
+
+// --- Fonts ---
+
+// Returns the registered fonts of the font family and its fallbacks.
+fn chain_fonts<'a>(
+    fonts: &'a HashMap<String, Font>,
+    font_config: &'a FontConfig,
+) -> impl Iterator<Item = &'a Font> {
+    std::iter::once(&font_config.family)
+        .chain(font_config.fallbacks.iter())
+        .filter_map(move |family| fonts.get(family))
+}
+
+// Splits the text into runs of characters that are drawn with the same font. Each character is
+// drawn with the first font of the fallback chain that covers it, then the loaded system fonts
+// are tried. Characters without any glyph are drawn with the first font of the chain.
+fn font_runs<'a>(
+    fonts: &'a HashMap<String, Font>,
+    system_fonts: &'a [Font],
+    font_config: &'a FontConfig,
+    text: &'a str,
+) -> Vec<(&'a Font, &'a str)> {
+    let mut starts: Vec<(&Font, usize)> = vec![];
+
+    for (i, c) in text.char_indices() {
+        let font = match chain_fonts(fonts, font_config)
+            .chain(system_fonts.iter())
+            .find(|font| font.has_glyph(c))
+            .or_else(|| chain_fonts(fonts, font_config).next())
+        {
+            Some(font) => font,
+            None => return vec![],
+        };
+
+        if starts
+            .last()
+            .map_or(true, |(last, _)| !std::ptr::eq(*last, font))
+        {
+            starts.push((font, i));
+        }
+    }
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(n, (font, start))| {
+            let end = starts.get(n + 1).map_or(text.len(), |(_, end)| *end);
+            (*font, &text[*start..end])
+        })
+        .collect()
+}
@@ -5,7 +5,10 @@ use stdweb::{
 };
 
 // pub use crate::image::Image as InnerImage;
-use crate::{utils::*, FontConfig, PipelineTrait, RenderConfig, RenderTarget, TextMetrics};
+use crate::{
+    utils::*, FontConfig, ImageFilter, OffscreenHandle, PipelineTrait, RenderConfig, RenderTarget,
+    TextMetrics, TextureId,
+};
 
 pub use self::image::*;
 
@@ -83,7 +86,7 @@ impl RenderContext2D {
 
     /// Draws a rectangle that is stroked (outlined) according to the current strokeStyle and other ctx settings.
     pub fn stroke_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
-        self.stroke_style(&self.config.fill_style);
+        self.stroke_style(&self.config.stroke_style);
         self.canvas_render_context_2_d
             .stroke_rect(x, y, width, height);
     }
@@ -107,9 +110,11 @@ impl RenderContext2D {
         &mut self,
         text: &str,
         font_size: f64,
-        family: impl Into<String>,
+        font: impl Into<FontFallbackChain>,
     ) -> TextMetrics {
-        self.set_font_family(family);
+        let font = font.into();
+        self.set_font_family(font.primary);
+        self.set_font_fallbacks(font.fallbacks);
         self.set_font_size(font_size);
         self.measure_text(text)
     }
@@ -185,11 +190,39 @@ impl RenderContext2D {
             .bezier_curve_to(cp1x, cp1y, cp2x, cp2y, x, y);
     }
 
+    // Offscreen
+
+    /// Offscreen buffers are not supported by this backend yet. Drawing operations between
+    /// `begin_offscreen` and `end_offscreen` draw directly on the canvas.
+    pub fn begin_offscreen(&mut self, _: f64, _: f64) -> OffscreenHandle {
+        OffscreenHandle(0)
+    }
+
+    /// Returns the id of an empty texture.
+    pub fn end_offscreen(&mut self, handle: OffscreenHandle) -> TextureId {
+        TextureId::from(handle)
+    }
+
+    /// Does nothing, offscreen buffers are not supported by this backend yet.
+    pub fn apply_filter(&mut self, _: TextureId, _: ImageFilter) {}
+
+    /// Does nothing, offscreen buffers are not supported by this backend yet.
+    pub fn draw_texture(&mut self, _: TextureId, _: f64, _: f64) {}
+
+    /// Does nothing, offscreen buffers are not supported by this backend yet.
+    pub fn release_texture(&mut self, _: TextureId) {}
+
     // Draw image
 
     /// Draws a render target.
-    pub fn draw_render_target(&mut self, _render_target: &RenderTarget, _x: f64, _y: f64) {
-        // todo
+    pub fn draw_render_target(&mut self, render_target: &RenderTarget, x: f64, y: f64) {
+        self.draw_data(
+            render_target.data(),
+            x,
+            y,
+            render_target.width(),
+            render_target.height(),
+        );
     }
 
     /// Draws the image.
@@ -205,7 +238,7 @@ impl RenderContext2D {
                     }
                 )
             } else {
-                //  @{&self.canvas_render_context_2_d}.drawImage(img, @{&x}, @{&y});
+                @{&self.canvas_render_context_2_d}.drawImage(img, @{&x}, @{&y});
             }
         );
     }
@@ -219,11 +252,11 @@ impl RenderContext2D {
                 img = document.image_store.load_image(@{&image.source});
                 img.then(
                     function(i) {
-                         @{&self.canvas_render_context_2_d}.drawImage(img, @{&clip.x()}, @{&clip.y()}, @{&clip.width()}, @{&clip.height()}, @{&x}, @{&y}, @{&clip.width()}, @{&clip.height()});
+                         @{&self.canvas_render_context_2_d}.drawImage(i, @{&clip.x()}, @{&clip.y()}, @{&clip.width()}, @{&clip.height()}, @{&x}, @{&y}, @{&clip.width()}, @{&clip.height()});
                     }
                 )
             } else {
-                 @{&self.canvas_render_context_2_d}.drawImage(img, @{&x}, @{&y});
+                 @{&self.canvas_render_context_2_d}.drawImage(img, @{&clip.x()}, @{&clip.y()}, @{&clip.width()}, @{&clip.height()}, @{&x}, @{&y}, @{&clip.width()}, @{&clip.height()});
             }
         );
     }
@@ -238,14 +271,21 @@ impl RenderContext2D {
     ) {
         let mut render_target = RenderTarget::new(width as u32, height as u32);
         pipeline.draw_pipeline(&mut render_target);
+        self.draw_data(render_target.data(), x, y, width, height);
+    }
+
+    // Draws the given pixel data (argb) with the given size at the given position.
+    fn draw_data(&mut self, data: &[u32], x: f64, y: f64, width: f64, height: f64) {
+        if data.is_empty() {
+            return;
+        }
 
         let image_data = self
             .canvas_render_context_2_d
             .create_image_data(width, height)
             .unwrap();
 
-        for i in 0..(render_target.data.len() - 1) {
-            let pixel = render_target.data.get(i).unwrap();
+        for (i, pixel) in data.iter().enumerate() {
             let r = ((pixel & 0x00FF0000) >> 16) as u8;
             let g = ((pixel & 0x0000FF00) >> 8) as u8;
             let b = (pixel & 0x000000FF) as u8;
@@ -271,7 +311,7 @@ impl RenderContext2D {
 
         let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
         ctx.put_image_data(image_data, 0.0, 0.0)
-            .expect("Could no draw pipeline.");
+            .expect("Could not draw pixel data.");
 
         // todo: use await after stdweb futures are stable
         js!(
@@ -312,6 +352,13 @@ impl RenderContext2D {
             .set_font(&self.font_config.to_string());
     }
 
+    /// Specifies the fonts that are used for characters that are not covered by the font family.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.font_config.fallbacks = fallbacks;
+        self.canvas_render_context_2_d
+            .set_font(&self.font_config.to_string());
+    }
+
     /// Specifies the font size.
     pub fn set_font_size(&mut self, size: f64) {
         self.font_config.font_size = size;
@@ -397,19 +444,12 @@ impl RenderContext2D {
         let width = self.canvas_render_context_2_d.get_canvas().width();
         let height = self.canvas_render_context_2_d.get_canvas().height();
 
-        // self.canvas_render_context_2_d.set_fill_style_color("#000000");
-        // self.canvas_render_context_2_d.fill_rect(0.0, 0.0, 10.0, height as f64 / 8.0);
-
         let image_data = self
             .canvas_render_context_2_d
             .get_image_data(0.0, 0.0, width as f64, height as f64)
             .unwrap();
 
-        js!(
-            console.log(@{&image_data});
-        );
-
-        for i in 0..(self.export_data.len() - 1) {
+        for i in 0..self.export_data.len() {
             let index = i as u32 * 4;
             let r: u8 = js!(
                 return @{&image_data}.data[@{index}];
@@ -435,13 +475,6 @@ impl RenderContext2D {
             .try_into()
             .unwrap();
 
-            js!(
-                if(@{&g} != 0) {
-                    console.log(@{&g});
-                }
-
-            );
-
             self.export_data[i] =
                 ((a as u32) << 24) | ((r as u32) << 16) | ((g as u32) << 8) | (b as u32);
         }
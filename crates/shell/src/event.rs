@@ -20,9 +20,26 @@ pub enum Key {
     ShiftL,
     ShiftR,
     Alt,
+    Meta,
     Escape,
     Home,
+    End,
+    PageUp,
+    PageDown,
     CapsLock,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    Application,
     A(bool),
     B(bool),
     C(bool),
@@ -398,12 +415,117 @@ pub struct MouseEvent {
     pub state: ButtonState,
 }
 
+/// Represents a scroll event of the mouse wheel or the trackpad.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct MouseScrollEvent {
+    /// Scroll offset x and y.
+    pub delta: Point,
+
+    /// Estimated scroll velocity in pixels per second.
+    pub velocity: Point,
+
+    /// `true` for continuous trackpad scrolling, `false` for discrete mouse wheel steps.
+    pub is_trackpad: bool,
+}
+
+// Events with a larger interval are handled as start of a new scroll gesture.
+const MAX_SCROLL_INTERVAL: f64 = 0.1;
+
+// Interval that is used for the first event of a scroll gesture.
+const DEFAULT_SCROLL_INTERVAL: f64 = 1.0 / 60.0;
+
+/// Estimates the scroll velocity by dividing the delta by the time interval of successive scroll events.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct ScrollVelocityTracker {
+    last_time: Option<f64>,
+}
+
+impl ScrollVelocityTracker {
+    /// Creates a new tracker.
+    pub fn new() -> Self {
+        ScrollVelocityTracker::default()
+    }
+
+    /// Creates a scroll event with the estimated velocity. `time` is the time stamp of the
+    /// event in seconds.
+    pub fn track(&mut self, delta: Point, time: f64, is_trackpad: bool) -> MouseScrollEvent {
+        let interval = match self.last_time {
+            Some(last_time) if time > last_time && time - last_time <= MAX_SCROLL_INTERVAL => {
+                time - last_time
+            }
+            _ => DEFAULT_SCROLL_INTERVAL,
+        };
+
+        self.last_time = Some(time);
+
+        MouseScrollEvent {
+            delta,
+            velocity: Point::new(delta.x() / interval, delta.y() / interval),
+            is_trackpad,
+        }
+    }
+}
+
+/// Code of a physical key. All backends report the USB HID usage id of the key from the
+/// keyboard/keypad page (0x07), e.g. `0x04` for the key at the location of `A` on a QWERTY
+/// keyboard or `0xe0` for the left control key.
+pub type ScanCode = u32;
+
+/// Identifies the physical location of a key on the keyboard, independent of the keyboard layout
+/// and the backend.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PhysicalKey(pub ScanCode);
+
+impl PhysicalKey {
+    /// Used if the backend could not determine the physical key, e.g. for composed characters.
+    pub const UNKNOWN: PhysicalKey = PhysicalKey(ScanCode::MAX);
+}
+
+/// Describes the key as it is interpreted by the current keyboard layout, e.g. the key at the
+/// location of `A` on a QWERTY keyboard is `Q` on an AZERTY layout.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LogicalKey(pub Key);
+
+impl PartialEq<Key> for LogicalKey {
+    fn eq(&self, other: &Key) -> bool {
+        self.0 == *other
+    }
+}
+
 /// Represents a keyboard key event.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct KeyEvent {
-    pub key: Key,
+    /// The physical key that is pressed or released.
+    pub physical: PhysicalKey,
+
+    /// The key according to the keyboard layout. Should be used for shortcuts and navigation.
+    pub logical: LogicalKey,
 
     pub state: ButtonState,
 
+    /// The text produced by the key, including characters composed by an input method. Should be
+    /// used for text input.
     pub text: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_velocity() {
+        let mut tracker = ScrollVelocityTracker::new();
+
+        let event = tracker.track(Point::new(0.0, 10.0), 1.0, false);
+        assert!((event.velocity.y() - 600.0).abs() < 0.001);
+
+        let event = tracker.track(Point::new(0.0, 10.0), 1.05, false);
+        assert!((event.velocity.y() - 200.0).abs() < 0.001);
+
+        // new gesture
+        let event = tracker.track(Point::new(5.0, 0.0), 2.0, true);
+        assert!((event.velocity.x() - 300.0).abs() < 0.001);
+        assert_eq!(event.velocity.y(), 0.0);
+        assert!(event.is_trackpad);
+    }
+}
@@ -4,7 +4,11 @@ use std::sync::mpsc;
 
 pub use super::native::*;
 
-use glutin::event_loop::{ControlFlow, EventLoop};
+use glutin::{
+    event::Event,
+    event_loop::{ControlFlow, EventLoop},
+    platform::desktop::EventLoopExtDesktop,
+};
 use lazy_static;
 
 use crate::prelude::*;
@@ -79,6 +83,49 @@ where
         self.event_loop.get(0).unwrap()
     }
 
+    /// Processes all pending events of the event loop and updates and renders all windows once.
+    /// Could be used to drive the shell by the message loop of a host application instead of `run`.
+    ///
+    /// Returns `false` if all windows are closed.
+    pub fn pump_events(&mut self) -> bool {
+        if self.window_shells.is_empty() {
+            return false;
+        }
+
+        let mut event_loop = self.event_loop.pop().unwrap();
+        let window_shells = &mut self.window_shells;
+
+        event_loop.run_return(|event, _, control_flow| {
+            for i in 0..window_shells.len() {
+                let mut remove = false;
+                if let Some(window_shell) = window_shells.get_mut(i) {
+                    window_shell.render();
+                    window_shell.update();
+                    window_shell.drain_events(control_flow, &event);
+                    window_shell.receive_requests();
+                    if !window_shell.is_open() {
+                        remove = true;
+                    }
+                }
+
+                if remove {
+                    window_shells.remove(i);
+                    break;
+                }
+            }
+
+            // returns the control to the host after all pending events are handled
+            if let Event::MainEventsCleared = event {
+                *control_flow = ControlFlow::Exit;
+            }
+        });
+
+        self.event_loop.push(event_loop);
+        self.receive_requests();
+
+        !self.window_shells.is_empty()
+    }
+
     /// Runs (starts) the application shell and its windows.
     pub fn run(mut self) {
         self.event_loop
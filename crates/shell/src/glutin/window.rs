@@ -1,14 +1,30 @@
-use std::{cell::RefCell, rc::Rc, sync::mpsc};
+use std::{
+    cell::RefCell,
+    mem,
+    path::PathBuf,
+    rc::Rc,
+    sync::mpsc,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-use glutin::{event, event_loop::ControlFlow, window, ContextWrapper, PossiblyCurrent};
+use glutin::{
+    dpi,
+    event::{self, VirtualKeyCode},
+    event_loop::ControlFlow,
+    window, ContextWrapper, PossiblyCurrent,
+};
 
 use derive_more::Constructor;
 
 use crate::{
-    event::{ButtonState, KeyEvent, MouseButton, MouseEvent},
+    event::{
+        ButtonState, Key, KeyEvent, LogicalKey, MouseButton, MouseEvent, PhysicalKey, ScanCode,
+        ScrollVelocityTracker,
+    },
     render::RenderContext2D,
+    utils::{CursorShape, Point, Rectangle},
     window_adapter::WindowAdapter,
-    WindowRequest,
+    ScreenInfo, WindowRequest,
 };
 
 /// Represents a wrapper for a glutin window. It handles events, propagate them to
@@ -27,6 +43,10 @@ where
     close: bool,
     mouse_pos: (f64, f64),
     scale_factor: f64,
+    scroll_velocity: ScrollVelocityTracker,
+    cursor_shape: CursorShape,
+    raw_input: bool,
+    dropped_files: Vec<PathBuf>,
 }
 
 impl<A> Window<A>
@@ -40,7 +60,7 @@ where
 
     /// Check if the window is open.
     pub fn is_open(&self) -> bool {
-        true
+        !self.close
     }
 
     /// Drain events and propagate the events to the adapter.
@@ -58,6 +78,19 @@ where
                 self.update = true;
                 *control_flow = ControlFlow::Wait;
             }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::ScaleFactorChanged { scale_factor, .. },
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.scale_factor = *scale_factor;
+                self.adapter.scale_factor_changed(*scale_factor);
+                self.adapter.screens(screens(self.gl_context.window()));
+                self.update = true;
+                *control_flow = ControlFlow::Wait;
+            }
             event::Event::WindowEvent {
                 event: event::WindowEvent::CloseRequested,
                 window_id,
@@ -65,14 +98,59 @@ where
                 if !window_id.eq(&self.id()) {
                     return;
                 }
-                self.adapter.quit_event();
-                *control_flow = ControlFlow::Exit;
+                if self.adapter.quit_event() {
+                    self.close = true;
+                }
+                self.update = true;
+                *control_flow = ControlFlow::Wait;
             }
             event::Event::WindowEvent {
                 event: event::WindowEvent::KeyboardInput { input, .. },
-                // todo: implement
-                ..
-            } => *control_flow = ControlFlow::Wait,
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+
+                if let Some(virtual_keycode) = input.virtual_keycode {
+                    let state = match input.state {
+                        event::ElementState::Pressed => ButtonState::Down,
+                        event::ElementState::Released => ButtonState::Up,
+                    };
+
+                    // the text of the key is sent by the received character event
+                    self.adapter.key_event(KeyEvent {
+                        physical: PhysicalKey(hid_usage_id(virtual_keycode)),
+                        logical: LogicalKey(key(virtual_keycode)),
+                        state,
+                        text: String::default(),
+                    });
+                    self.update = true;
+                }
+
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::ReceivedCharacter(character),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+
+                // control characters are already handled by the keyboard input event
+                if !character.is_control() {
+                    self.adapter.key_event(KeyEvent {
+                        physical: PhysicalKey::UNKNOWN,
+                        logical: LogicalKey(Key::from(*character)),
+                        state: ButtonState::Down,
+                        text: character.to_string(),
+                    });
+                    self.update = true;
+                }
+
+                *control_flow = ControlFlow::Wait;
+            }
             event::Event::WindowEvent {
                 event: event::WindowEvent::MouseInput { state, button, .. },
                 ..
@@ -114,7 +192,11 @@ where
                 match delta {
                     event::MouseScrollDelta::LineDelta(_, _) => {}
                     event::MouseScrollDelta::PixelDelta(p) => {
-                        self.adapter.scroll(p.x, p.y);
+                        let time = SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .map_or(0.0, |d| d.as_secs_f64());
+                        let event = self.scroll_velocity.track(Point::new(p.x, p.y), time, true);
+                        self.adapter.scroll(event);
                     }
                 }
                 self.redraw = true;
@@ -135,6 +217,53 @@ where
                 self.redraw = true;
                 *control_flow = ControlFlow::Wait;
             }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::HoveredFile(_),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.adapter.files_hovered(true);
+                self.update = true;
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::HoveredFileCancelled,
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.adapter.files_hovered(false);
+                self.update = true;
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::DroppedFile(path),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                // glutin sends one event per file, they are passed together to the adapter on update
+                self.dropped_files.push(path.clone());
+                self.update = true;
+                self.redraw = true;
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::DeviceEvent {
+                event: event::DeviceEvent::MouseMotion { delta },
+                ..
+            } => {
+                if !self.raw_input {
+                    return;
+                }
+                self.adapter.raw_mouse_delta(delta.0, delta.1);
+                self.update = true;
+                self.redraw = true;
+                *control_flow = ControlFlow::Wait;
+            }
             _ => *control_flow = ControlFlow::Wait,
         }
     }
@@ -149,25 +278,102 @@ where
                         self.redraw = true;
                     }
                     WindowRequest::ChangeTitle(title) => {
-                        // todo fix
-                        // self.window.set_title(&title);
+                        self.gl_context.window().set_title(&title);
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::ChangeIcon(icon) => {
+                        self.gl_context.window().set_window_icon(load_icon(&icon));
+                    }
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    WindowRequest::MoveToScreen {
+                        screen_id,
+                        position,
+                    } => {
+                        let window = self.gl_context.window();
+
+                        if let Some(monitor) = window.available_monitors().nth(screen_id as usize) {
+                            let origin = monitor.position();
+                            let scale_factor = monitor.scale_factor();
+
+                            window.set_outer_position(dpi::PhysicalPosition::new(
+                                origin.x as f64 + position.x() * scale_factor,
+                                origin.y as f64 + position.y() * scale_factor,
+                            ));
+                        }
+                    }
                 }
             }
         }
     }
 
+    // Updates the os cursor if the cursor shape of the adapter is changed.
+    fn update_cursor(&mut self) {
+        let cursor_shape = self.adapter.cursor_shape();
+
+        if cursor_shape == self.cursor_shape {
+            return;
+        }
+
+        self.cursor_shape = cursor_shape;
+
+        self.gl_context
+            .window()
+            .set_cursor_icon(match cursor_shape {
+                CursorShape::Default => window::CursorIcon::Default,
+                CursorShape::Pointer => window::CursorIcon::Hand,
+                CursorShape::Text => window::CursorIcon::Text,
+                CursorShape::Crosshair => window::CursorIcon::Crosshair,
+                CursorShape::Grab => window::CursorIcon::Grab,
+                CursorShape::Grabbing => window::CursorIcon::Grabbing,
+                CursorShape::ResizeH => window::CursorIcon::EwResize,
+                CursorShape::ResizeV => window::CursorIcon::NsResize,
+                CursorShape::ResizeNWSE => window::CursorIcon::NwseResize,
+                CursorShape::ResizeNESW => window::CursorIcon::NeswResize,
+                CursorShape::NotAllowed => window::CursorIcon::NotAllowed,
+            });
+    }
+
+    // Grabs, hides and centers the cursor while raw input is requested by the adapter.
+    fn update_raw_input(&mut self) {
+        let raw_input = self.adapter.raw_input();
+
+        if raw_input == self.raw_input {
+            return;
+        }
+
+        self.raw_input = raw_input;
+
+        let window = self.gl_context.window();
+
+        if window.set_cursor_grab(raw_input).is_err() {
+            super::CONSOLE.warn("Could not grab the cursor.");
+        }
+
+        window.set_cursor_visible(!raw_input);
+
+        if raw_input {
+            let size = window.inner_size();
+            let _ = window
+                .set_cursor_position(dpi::PhysicalPosition::new(size.width / 2, size.height / 2));
+        }
+    }
+
     /// Runs update on the adapter.
     pub fn update(&mut self) {
         if !self.update {
             return;
         }
+        if !self.dropped_files.is_empty() {
+            let paths = mem::replace(&mut self.dropped_files, vec![]);
+            self.adapter
+                .files_dropped(paths, self.mouse_pos.0, self.mouse_pos.1);
+        }
         self.adapter.run(&mut self.render_context);
+        self.update_cursor();
+        self.update_raw_input();
         self.update = false;
         self.redraw = true;
     }
@@ -180,3 +386,250 @@ where
         }
     }
 }
+
+// Decodes the given image file to a window icon.
+fn load_icon(icon: &[u8]) -> Option<window::Icon> {
+    let image = image::load_from_memory(icon).ok()?.to_rgba();
+    let (width, height) = image.dimensions();
+
+    window::Icon::from_rgba(image.into_raw(), width, height).ok()
+}
+
+// Reads the monitors of the system. The id of a screen is its index in the monitor list.
+pub(crate) fn screens(window: &window::Window) -> Vec<ScreenInfo> {
+    let primary = window.primary_monitor();
+
+    window
+        .available_monitors()
+        .enumerate()
+        .map(|(id, monitor)| {
+            let scale_factor = monitor.scale_factor();
+            let position = monitor.position().to_logical::<f64>(scale_factor);
+            let size = monitor.size().to_logical::<f64>(scale_factor);
+
+            ScreenInfo {
+                id: id as u64,
+                bounds: Rectangle::new((position.x, position.y), size.width, size.height),
+                scale_factor,
+                primary: monitor == primary,
+                name: monitor.name().unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+// Maps a glutin key to the key of the shell.
+fn key(virtual_keycode: VirtualKeyCode) -> Key {
+    match virtual_keycode {
+        VirtualKeyCode::Back => Key::Backspace,
+        VirtualKeyCode::Up => Key::Up,
+        VirtualKeyCode::Down => Key::Down,
+        VirtualKeyCode::Delete => Key::Delete,
+        VirtualKeyCode::Left => Key::Left,
+        VirtualKeyCode::Right => Key::Right,
+        VirtualKeyCode::Space => Key::Space,
+        VirtualKeyCode::Return => Key::Enter,
+        VirtualKeyCode::LControl | VirtualKeyCode::RControl => Key::Control,
+        VirtualKeyCode::LShift => Key::ShiftL,
+        VirtualKeyCode::RShift => Key::ShiftR,
+        VirtualKeyCode::LAlt | VirtualKeyCode::RAlt => Key::Alt,
+        VirtualKeyCode::LWin | VirtualKeyCode::RWin => Key::Meta,
+        VirtualKeyCode::Escape => Key::Escape,
+        VirtualKeyCode::Home => Key::Home,
+        VirtualKeyCode::End => Key::End,
+        VirtualKeyCode::PageUp => Key::PageUp,
+        VirtualKeyCode::PageDown => Key::PageDown,
+        VirtualKeyCode::Capital => Key::CapsLock,
+        VirtualKeyCode::F1 => Key::F1,
+        VirtualKeyCode::F2 => Key::F2,
+        VirtualKeyCode::F3 => Key::F3,
+        VirtualKeyCode::F4 => Key::F4,
+        VirtualKeyCode::F5 => Key::F5,
+        VirtualKeyCode::F6 => Key::F6,
+        VirtualKeyCode::F7 => Key::F7,
+        VirtualKeyCode::F8 => Key::F8,
+        VirtualKeyCode::F9 => Key::F9,
+        VirtualKeyCode::F10 => Key::F10,
+        VirtualKeyCode::F11 => Key::F11,
+        VirtualKeyCode::F12 => Key::F12,
+        VirtualKeyCode::Apps => Key::Application,
+        VirtualKeyCode::Numpad0 => Key::Numpad0,
+        VirtualKeyCode::Numpad1 => Key::Numpad1,
+        VirtualKeyCode::Numpad2 => Key::Numpad2,
+        VirtualKeyCode::Numpad3 => Key::Numpad3,
+        VirtualKeyCode::Numpad4 => Key::Numpad4,
+        VirtualKeyCode::Numpad5 => Key::Numpad5,
+        VirtualKeyCode::Numpad6 => Key::Numpad6,
+        VirtualKeyCode::Numpad7 => Key::Numpad7,
+        VirtualKeyCode::Numpad8 => Key::Numpad8,
+        VirtualKeyCode::Numpad9 => Key::Numpad9,
+        VirtualKeyCode::Divide => Key::NumpadDivide,
+        VirtualKeyCode::Multiply => Key::NumpadMultiply,
+        VirtualKeyCode::Subtract => Key::NumpadSubtract,
+        VirtualKeyCode::Add => Key::NumpadAdd,
+        VirtualKeyCode::NumpadEnter => Key::NumpadEnter,
+        VirtualKeyCode::Decimal => Key::NumpadDot,
+        VirtualKeyCode::A => Key::A(false),
+        VirtualKeyCode::B => Key::B(false),
+        VirtualKeyCode::C => Key::C(false),
+        VirtualKeyCode::D => Key::D(false),
+        VirtualKeyCode::E => Key::E(false),
+        VirtualKeyCode::F => Key::F(false),
+        VirtualKeyCode::G => Key::G(false),
+        VirtualKeyCode::H => Key::H(false),
+        VirtualKeyCode::I => Key::I(false),
+        VirtualKeyCode::J => Key::J(false),
+        VirtualKeyCode::K => Key::K(false),
+        VirtualKeyCode::L => Key::L(false),
+        VirtualKeyCode::M => Key::M(false),
+        VirtualKeyCode::N => Key::N(false),
+        VirtualKeyCode::O => Key::O(false),
+        VirtualKeyCode::P => Key::P(false),
+        VirtualKeyCode::Q => Key::Q(false),
+        VirtualKeyCode::R => Key::R(false),
+        VirtualKeyCode::S => Key::S(false),
+        VirtualKeyCode::T => Key::T(false),
+        VirtualKeyCode::U => Key::U(false),
+        VirtualKeyCode::V => Key::V(false),
+        VirtualKeyCode::W => Key::W(false),
+        VirtualKeyCode::X => Key::X(false),
+        VirtualKeyCode::Y => Key::Y(false),
+        VirtualKeyCode::Z => Key::Z(false),
+        VirtualKeyCode::Key0 => Key::Zero,
+        VirtualKeyCode::Key1 => Key::One,
+        VirtualKeyCode::Key2 => Key::Two,
+        VirtualKeyCode::Key3 => Key::Three,
+        VirtualKeyCode::Key4 => Key::Four,
+        VirtualKeyCode::Key5 => Key::Five,
+        VirtualKeyCode::Key6 => Key::Six,
+        VirtualKeyCode::Key7 => Key::Seven,
+        VirtualKeyCode::Key8 => Key::Eight,
+        VirtualKeyCode::Key9 => Key::Nine,
+        _ => Key::Unknown,
+    }
+}
+
+// Maps a glutin key to its USB HID usage id.
+fn hid_usage_id(virtual_keycode: VirtualKeyCode) -> ScanCode {
+    match virtual_keycode {
+        VirtualKeyCode::A => 0x04,
+        VirtualKeyCode::B => 0x05,
+        VirtualKeyCode::C => 0x06,
+        VirtualKeyCode::D => 0x07,
+        VirtualKeyCode::E => 0x08,
+        VirtualKeyCode::F => 0x09,
+        VirtualKeyCode::G => 0x0a,
+        VirtualKeyCode::H => 0x0b,
+        VirtualKeyCode::I => 0x0c,
+        VirtualKeyCode::J => 0x0d,
+        VirtualKeyCode::K => 0x0e,
+        VirtualKeyCode::L => 0x0f,
+        VirtualKeyCode::M => 0x10,
+        VirtualKeyCode::N => 0x11,
+        VirtualKeyCode::O => 0x12,
+        VirtualKeyCode::P => 0x13,
+        VirtualKeyCode::Q => 0x14,
+        VirtualKeyCode::R => 0x15,
+        VirtualKeyCode::S => 0x16,
+        VirtualKeyCode::T => 0x17,
+        VirtualKeyCode::U => 0x18,
+        VirtualKeyCode::V => 0x19,
+        VirtualKeyCode::W => 0x1a,
+        VirtualKeyCode::X => 0x1b,
+        VirtualKeyCode::Y => 0x1c,
+        VirtualKeyCode::Z => 0x1d,
+        VirtualKeyCode::Key1 => 0x1e,
+        VirtualKeyCode::Key2 => 0x1f,
+        VirtualKeyCode::Key3 => 0x20,
+        VirtualKeyCode::Key4 => 0x21,
+        VirtualKeyCode::Key5 => 0x22,
+        VirtualKeyCode::Key6 => 0x23,
+        VirtualKeyCode::Key7 => 0x24,
+        VirtualKeyCode::Key8 => 0x25,
+        VirtualKeyCode::Key9 => 0x26,
+        VirtualKeyCode::Key0 => 0x27,
+        VirtualKeyCode::Return => 0x28,
+        VirtualKeyCode::Escape => 0x29,
+        VirtualKeyCode::Back => 0x2a,
+        VirtualKeyCode::Tab => 0x2b,
+        VirtualKeyCode::Space => 0x2c,
+        VirtualKeyCode::Minus => 0x2d,
+        VirtualKeyCode::Equals => 0x2e,
+        VirtualKeyCode::LBracket => 0x2f,
+        VirtualKeyCode::RBracket => 0x30,
+        VirtualKeyCode::Backslash => 0x31,
+        VirtualKeyCode::Semicolon => 0x33,
+        VirtualKeyCode::Apostrophe => 0x34,
+        VirtualKeyCode::Grave => 0x35,
+        VirtualKeyCode::Comma => 0x36,
+        VirtualKeyCode::Period => 0x37,
+        VirtualKeyCode::Slash => 0x38,
+        VirtualKeyCode::Capital => 0x39,
+        VirtualKeyCode::F1 => 0x3a,
+        VirtualKeyCode::F2 => 0x3b,
+        VirtualKeyCode::F3 => 0x3c,
+        VirtualKeyCode::F4 => 0x3d,
+        VirtualKeyCode::F5 => 0x3e,
+        VirtualKeyCode::F6 => 0x3f,
+        VirtualKeyCode::F7 => 0x40,
+        VirtualKeyCode::F8 => 0x41,
+        VirtualKeyCode::F9 => 0x42,
+        VirtualKeyCode::F10 => 0x43,
+        VirtualKeyCode::F11 => 0x44,
+        VirtualKeyCode::F12 => 0x45,
+        VirtualKeyCode::F13 => 0x68,
+        VirtualKeyCode::F14 => 0x69,
+        VirtualKeyCode::F15 => 0x6a,
+        VirtualKeyCode::F16 => 0x6b,
+        VirtualKeyCode::F17 => 0x6c,
+        VirtualKeyCode::F18 => 0x6d,
+        VirtualKeyCode::F19 => 0x6e,
+        VirtualKeyCode::F20 => 0x6f,
+        VirtualKeyCode::F21 => 0x70,
+        VirtualKeyCode::F22 => 0x71,
+        VirtualKeyCode::F23 => 0x72,
+        VirtualKeyCode::F24 => 0x73,
+        VirtualKeyCode::Snapshot => 0x46,
+        VirtualKeyCode::Scroll => 0x47,
+        VirtualKeyCode::Pause => 0x48,
+        VirtualKeyCode::Insert => 0x49,
+        VirtualKeyCode::Home => 0x4a,
+        VirtualKeyCode::PageUp => 0x4b,
+        VirtualKeyCode::Delete => 0x4c,
+        VirtualKeyCode::End => 0x4d,
+        VirtualKeyCode::PageDown => 0x4e,
+        VirtualKeyCode::Right => 0x4f,
+        VirtualKeyCode::Left => 0x50,
+        VirtualKeyCode::Down => 0x51,
+        VirtualKeyCode::Up => 0x52,
+        VirtualKeyCode::Numlock => 0x53,
+        VirtualKeyCode::Divide => 0x54,
+        VirtualKeyCode::Multiply => 0x55,
+        VirtualKeyCode::Subtract => 0x56,
+        VirtualKeyCode::Add => 0x57,
+        VirtualKeyCode::NumpadEnter => 0x58,
+        VirtualKeyCode::Numpad1 => 0x59,
+        VirtualKeyCode::Numpad2 => 0x5a,
+        VirtualKeyCode::Numpad3 => 0x5b,
+        VirtualKeyCode::Numpad4 => 0x5c,
+        VirtualKeyCode::Numpad5 => 0x5d,
+        VirtualKeyCode::Numpad6 => 0x5e,
+        VirtualKeyCode::Numpad7 => 0x5f,
+        VirtualKeyCode::Numpad8 => 0x60,
+        VirtualKeyCode::Numpad9 => 0x61,
+        VirtualKeyCode::Numpad0 => 0x62,
+        VirtualKeyCode::Decimal => 0x63,
+        VirtualKeyCode::OEM102 => 0x64,
+        VirtualKeyCode::Apps => 0x65,
+        VirtualKeyCode::NumpadEquals => 0x67,
+        VirtualKeyCode::LControl => 0xe0,
+        VirtualKeyCode::LShift => 0xe1,
+        VirtualKeyCode::LAlt => 0xe2,
+        VirtualKeyCode::LWin => 0xe3,
+        VirtualKeyCode::RControl => 0xe4,
+        VirtualKeyCode::RShift => 0xe5,
+        VirtualKeyCode::RAlt => 0xe6,
+        VirtualKeyCode::RWin => 0xe7,
+        _ => PhysicalKey::UNKNOWN.0,
+    }
+}
@@ -16,14 +16,14 @@ use pathfinder_renderer::gpu::{
 };
 use pathfinder_resources::embedded::EmbeddedResourceLoader;
 
-use super::{Shell, Window};
+use super::{window::screens, Shell, Window};
 
 use crate::{
-    event::{ButtonState, Key, KeyEvent},
+    event::{ButtonState, Key, KeyEvent, ScrollVelocityTracker},
     render::RenderContext2D,
-    utils::Rectangle,
+    utils::{CursorShape, Rectangle},
     window_adapter::WindowAdapter,
-    WindowRequest, WindowSettings,
+    RawWindowHandle, WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the minifb backend.
@@ -125,6 +125,27 @@ where
         self
     }
 
+    /// Embeds the window as child into the given foreign window instead of creating a top-level window.
+    ///
+    /// Embedding is only supported on Windows, on other platforms a top-level window is created.
+    pub fn with_parent_window(mut self, handle: RawWindowHandle) -> Self {
+        #[cfg(target_os = "windows")]
+        {
+            use glutin::platform::windows::WindowBuilderExtWindows;
+
+            if let RawWindowHandle::Windows(handle) = handle {
+                self.window_builder = self.window_builder.with_parent_window(handle.hwnd as _);
+                return self;
+            }
+        }
+
+        super::CONSOLE.warn(format!(
+            "Parent window {:?} is not supported on this platform.",
+            handle
+        ));
+        self
+    }
+
     /// Builds the window shell and add it to the application `Shell`.
     pub fn build(self) {
         // Create an OpenGL 3.x context for Pathfinder to use.
@@ -164,7 +185,7 @@ where
 
             if let Ok(font) = font.load() {
                 if let Some(name) = font.postscript_name() {
-                    println!("Info: Added font with postscript name {}.", name);
+                    super::CONSOLE.info(format!("Added font with postscript name {}.", name));
                 }
             }
             font_handles.push(font);
@@ -177,9 +198,12 @@ where
             font_handles,
         );
 
+        let mut adapter = self.adapter;
+        adapter.screens(screens(gl_context.window()));
+
         self.shell.window_shells.push(Window::new(
             gl_context,
-            self.adapter,
+            adapter,
             render_context,
             self.request_receiver,
             true,
@@ -187,6 +211,10 @@ where
             false,
             (0.0, 0.0),
             scale_factor,
+            ScrollVelocityTracker::new(),
+            CursorShape::Default,
+            false,
+            vec![],
         ))
     }
 }
@@ -28,6 +28,7 @@ runner.run()
 extern crate lazy_static;
 
 pub mod event;
+pub mod log_level;
 pub mod prelude;
 pub mod window_adapter;
 
@@ -54,6 +55,8 @@ pub mod platform;
 
 pub use orbtk_render::prelude as render;
 
+pub use raw_window_handle::RawWindowHandle;
+
 use std::{collections::HashMap, sync::mpsc};
 
 /// Used to send a request to the window.
@@ -67,6 +70,15 @@ pub enum WindowRequest {
 
     /// Request to change the title of the `Windows`.
     ChangeTitle(String),
+
+    /// Request to change the icon of the `Windows`. Contains the encoded image e.g. a png file.
+    ChangeIcon(Vec<u8>),
+
+    /// Request to move the `Windows` to the given position on the screen with the given id.
+    MoveToScreen {
+        screen_id: u64,
+        position: utils::Point,
+    },
 }
 
 /// Used to send a request to the application shell.
@@ -78,6 +90,25 @@ where
     CreateWindow(W, WindowSettings, mpsc::Receiver<WindowRequest>),
 }
 
+/// Describes a screen (monitor) that is connected to the system.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScreenInfo {
+    /// Id of the screen.
+    pub id: u64,
+
+    /// Position and size of the screen on the virtual desktop in logical pixels.
+    pub bounds: utils::Rectangle,
+
+    /// Scale factor of the screen e.g. `2.0` for a Retina display.
+    pub scale_factor: f64,
+
+    /// `true` if it is the primary screen of the system.
+    pub primary: bool,
+
+    /// Name of the screen.
+    pub name: String,
+}
+
 /// Contains settings of a window.
 #[derive(Clone, Debug, Default)]
 pub struct WindowSettings {
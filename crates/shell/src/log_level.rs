@@ -0,0 +1,53 @@
+use std::fmt;
+
+/// Describes the importance of a message of the `Console`. Messages below the log level of the
+/// console are not printed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// Detailed information for debugging. Is only printed in debug builds.
+    Debug,
+
+    /// General information. Is only printed in debug builds.
+    Info,
+
+    /// Something is unexpected, but the application keeps working.
+    Warn,
+
+    /// Something failed.
+    Error,
+}
+
+impl Default for LogLevel {
+    fn default() -> Self {
+        LogLevel::Debug
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogLevel::Debug => write!(f, "Debug"),
+            LogLevel::Info => write!(f, "Info"),
+            LogLevel::Warn => write!(f, "Warning"),
+            LogLevel::Error => write!(f, "Error"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        assert!(LogLevel::Debug < LogLevel::Info);
+        assert!(LogLevel::Info < LogLevel::Warn);
+        assert!(LogLevel::Warn < LogLevel::Error);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(LogLevel::Warn.to_string(), "Warning");
+        assert_eq!(LogLevel::Error.to_string(), "Error");
+    }
+}
@@ -70,34 +70,42 @@ where
         }
     }
 
-    /// Runs (starts) the application shell and its windows.
-    pub fn run(&mut self) {
-        loop {
-            if self.window_shells.is_empty() {
-                return;
-            }
-
-            for i in 0..self.window_shells.len() {
-                let mut remove = false;
-                if let Some(window_shell) = self.window_shells.get_mut(i) {
-                    window_shell.update();
-                    window_shell.render();
+    /// Runs one iteration of the shell: updates, renders and drains the events of all windows.
+    /// Could be used to drive the shell by the message loop of a host application instead of `run`.
+    ///
+    /// Returns `false` if all windows are closed.
+    pub fn pump_events(&mut self) -> bool {
+        if self.window_shells.is_empty() {
+            return false;
+        }
 
-                    window_shell.drain_events();
-                    window_shell.receive_requests();
+        for i in 0..self.window_shells.len() {
+            let mut remove = false;
+            if let Some(window_shell) = self.window_shells.get_mut(i) {
+                window_shell.update();
+                window_shell.render();
 
-                    if !window_shell.is_open() {
-                        remove = true;
-                    }
-                }
+                window_shell.drain_events();
+                window_shell.receive_requests();
 
-                if remove {
-                    self.window_shells.remove(i);
-                    break;
+                if !window_shell.is_open() {
+                    remove = true;
                 }
             }
 
-            self.receive_requests();
+            if remove {
+                self.window_shells.remove(i);
+                break;
+            }
         }
+
+        self.receive_requests();
+
+        !self.window_shells.is_empty()
+    }
+
+    /// Runs (starts) the application shell and its windows.
+    pub fn run(&mut self) {
+        while self.pump_events() {}
     }
 }
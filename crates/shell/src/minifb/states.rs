@@ -1,4 +1,7 @@
-use crate::event::Key;
+use crate::{
+    event::{Key, ScrollVelocityTracker},
+    utils::CursorShape,
+};
 use derive_more::Constructor;
 use minifb;
 
@@ -9,6 +12,9 @@ pub struct MouseState {
     pub button_left: bool,
     pub button_middle: bool,
     pub button_right: bool,
+    pub scroll_velocity: ScrollVelocityTracker,
+    pub cursor_shape: CursorShape,
+    pub raw_input: bool,
 }
 
 /// Internal helper state to handle current minifb window state.
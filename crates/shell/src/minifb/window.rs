@@ -1,16 +1,21 @@
-use std::{cell::RefCell, rc::Rc, sync::mpsc};
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::mpsc,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use derive_more::Constructor;
 
 use super::{KeyState, MouseState, WindowState};
 use crate::{
-    event::{ButtonState, KeyEvent, MouseButton, MouseEvent},
+    event::{ButtonState, KeyEvent, LogicalKey, MouseButton, MouseEvent, PhysicalKey, ScanCode},
     render::RenderContext2D,
     window_adapter::WindowAdapter,
     WindowRequest,
 };
 
-use orbtk_utils::Point;
+use orbtk_utils::{CursorShape, Point};
 
 /// Represents a wrapper for a minifb window. It handles events, propagate them to
 /// the window adapter and handles the update and render pipeline.
@@ -28,6 +33,7 @@ where
     update: bool,
     redraw: bool,
     close: bool,
+    quit_requested: bool,
     key_states: Vec<KeyState>,
     key_events: Rc<RefCell<Vec<KeyEvent>>>,
 }
@@ -65,8 +71,11 @@ where
             .window
             .is_key_pressed(self.key_states.get(index).unwrap().minifb_key, key_repeat)
         {
+            let key_state = self.key_states.get(index).unwrap();
+
             self.adapter.key_event(KeyEvent {
-                key: self.key_states.get(index).unwrap().key,
+                physical: PhysicalKey(hid_usage_id(key_state.minifb_key)),
+                logical: LogicalKey(key_state.key),
                 state: ButtonState::Down,
                 text: String::default(),
             });
@@ -80,8 +89,11 @@ where
             .window
             .is_key_released(self.key_states.get(index).unwrap().minifb_key)
         {
+            let key_state = self.key_states.get(index).unwrap();
+
             self.adapter.key_event(KeyEvent {
-                key: self.key_states.get(index).unwrap().key,
+                physical: PhysicalKey(hid_usage_id(key_state.minifb_key)),
+                logical: LogicalKey(key_state.key),
                 state: ButtonState::Up,
                 text: String::default(),
             });
@@ -92,15 +104,36 @@ where
 
     /// Check if the window is open.
     pub fn is_open(&self) -> bool {
-        self.window.is_open() && !self.close
+        !self.close
     }
 
     /// Drain events and propagate the events to the adapter.
     pub fn drain_events(&mut self) {
         self.window.update();
 
+        // minifb could not reset its close state, so the close button is reported only once
+        if !self.window.is_open() && !self.quit_requested {
+            self.quit_requested = true;
+            self.close = self.adapter.quit_event();
+            self.update = true;
+        }
+
+        // minifb provides no raw input, the delta is calculated from the unclamped mouse position
+        if self.mouse.raw_input {
+            if let Some(pos) = self.window.get_mouse_pos(minifb::MouseMode::Pass) {
+                let pos = (pos.0.floor(), pos.1.floor());
+                if pos != self.mouse.mouse_pos {
+                    self.adapter.raw_mouse_delta(
+                        (pos.0 - self.mouse.mouse_pos.0) as f64,
+                        (pos.1 - self.mouse.mouse_pos.1) as f64,
+                    );
+                    self.mouse.mouse_pos = pos;
+                    self.update = true;
+                }
+            }
+        }
         // mouse move
-        if let Some(pos) = self.window.get_mouse_pos(minifb::MouseMode::Discard) {
+        else if let Some(pos) = self.window.get_mouse_pos(minifb::MouseMode::Discard) {
             if (pos.0.floor(), pos.1.floor()) != self.mouse.mouse_pos {
                 self.adapter.mouse(pos.0 as f64, pos.1 as f64);
                 self.mouse.mouse_pos = (pos.0.floor(), pos.1.floor());
@@ -145,7 +178,19 @@ where
 
         // scroll
         if let Some(delta) = self.window.get_scroll_wheel() {
-            self.adapter.scroll(delta.0 as f64, delta.1 as f64);
+            let time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0.0, |d| d.as_secs_f64());
+
+            // minifb does not provide the scroll source, mouse wheels scroll only in whole steps.
+            let is_trackpad = delta.0.fract() != 0.0 || delta.1.fract() != 0.0;
+
+            let event = self.mouse.scroll_velocity.track(
+                Point::new(delta.0 as f64, delta.1 as f64),
+                time,
+                is_trackpad,
+            );
+            self.adapter.scroll(event);
             self.update = true;
         }
 
@@ -199,14 +244,56 @@ where
                         self.update = true;
                         self.redraw = true;
                     }
+                    // minifb does not support to change the window icon.
+                    WindowRequest::ChangeIcon(_) => {}
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    // minifb provides no screens, the window is moved on its current screen.
+                    WindowRequest::MoveToScreen { position, .. } => {
+                        self.window
+                            .set_position(position.x() as isize, position.y() as isize);
+                    }
                 }
             }
         }
     }
 
+    // Updates the os cursor if the cursor shape of the adapter is changed.
+    fn update_cursor(&mut self) {
+        let cursor_shape = self.adapter.cursor_shape();
+
+        if cursor_shape == self.mouse.cursor_shape {
+            return;
+        }
+
+        self.mouse.cursor_shape = cursor_shape;
+
+        // minifb supports only a subset of the cursor shapes.
+        self.window.set_cursor_style(match cursor_shape {
+            CursorShape::Text => minifb::CursorStyle::Ibeam,
+            CursorShape::Crosshair => minifb::CursorStyle::Crosshair,
+            CursorShape::Grab => minifb::CursorStyle::OpenHand,
+            CursorShape::Grabbing => minifb::CursorStyle::ClosedHand,
+            CursorShape::ResizeH => minifb::CursorStyle::ResizeLeftRight,
+            CursorShape::ResizeV => minifb::CursorStyle::ResizeUpDown,
+            CursorShape::ResizeNWSE | CursorShape::ResizeNESW => minifb::CursorStyle::ResizeAll,
+            _ => minifb::CursorStyle::Arrow,
+        });
+    }
+
+    // Hides the cursor while raw input is requested by the adapter. minifb could not lock the cursor.
+    fn update_raw_input(&mut self) {
+        let raw_input = self.adapter.raw_input();
+
+        if raw_input == self.mouse.raw_input {
+            return;
+        }
+
+        self.mouse.raw_input = raw_input;
+        self.window.set_cursor_visibility(!raw_input);
+    }
+
     /// Runs update on the adapter.
     pub fn update(&mut self) {
         //super::CONSOLE.time("complete");
@@ -214,6 +301,8 @@ where
             return;
         }
         self.adapter.run(&mut self.render_context);
+        self.update_cursor();
+        self.update_raw_input();
         self.update = false;
         self.redraw = true;
     }
@@ -234,3 +323,116 @@ where
         }
     }
 }
+
+// Maps a minifb key to its USB HID usage id.
+fn hid_usage_id(key: minifb::Key) -> ScanCode {
+    match key {
+        minifb::Key::A => 0x04,
+        minifb::Key::B => 0x05,
+        minifb::Key::C => 0x06,
+        minifb::Key::D => 0x07,
+        minifb::Key::E => 0x08,
+        minifb::Key::F => 0x09,
+        minifb::Key::G => 0x0a,
+        minifb::Key::H => 0x0b,
+        minifb::Key::I => 0x0c,
+        minifb::Key::J => 0x0d,
+        minifb::Key::K => 0x0e,
+        minifb::Key::L => 0x0f,
+        minifb::Key::M => 0x10,
+        minifb::Key::N => 0x11,
+        minifb::Key::O => 0x12,
+        minifb::Key::P => 0x13,
+        minifb::Key::Q => 0x14,
+        minifb::Key::R => 0x15,
+        minifb::Key::S => 0x16,
+        minifb::Key::T => 0x17,
+        minifb::Key::U => 0x18,
+        minifb::Key::V => 0x19,
+        minifb::Key::W => 0x1a,
+        minifb::Key::X => 0x1b,
+        minifb::Key::Y => 0x1c,
+        minifb::Key::Z => 0x1d,
+        minifb::Key::Key1 => 0x1e,
+        minifb::Key::Key2 => 0x1f,
+        minifb::Key::Key3 => 0x20,
+        minifb::Key::Key4 => 0x21,
+        minifb::Key::Key5 => 0x22,
+        minifb::Key::Key6 => 0x23,
+        minifb::Key::Key7 => 0x24,
+        minifb::Key::Key8 => 0x25,
+        minifb::Key::Key9 => 0x26,
+        minifb::Key::Key0 => 0x27,
+        minifb::Key::Enter => 0x28,
+        minifb::Key::Escape => 0x29,
+        minifb::Key::Backspace => 0x2a,
+        minifb::Key::Tab => 0x2b,
+        minifb::Key::Space => 0x2c,
+        minifb::Key::Minus => 0x2d,
+        minifb::Key::Equal => 0x2e,
+        minifb::Key::LeftBracket => 0x2f,
+        minifb::Key::RightBracket => 0x30,
+        minifb::Key::Backslash => 0x31,
+        minifb::Key::Semicolon => 0x33,
+        minifb::Key::Apostrophe => 0x34,
+        minifb::Key::Backquote => 0x35,
+        minifb::Key::Comma => 0x36,
+        minifb::Key::Period => 0x37,
+        minifb::Key::Slash => 0x38,
+        minifb::Key::CapsLock => 0x39,
+        minifb::Key::F1 => 0x3a,
+        minifb::Key::F2 => 0x3b,
+        minifb::Key::F3 => 0x3c,
+        minifb::Key::F4 => 0x3d,
+        minifb::Key::F5 => 0x3e,
+        minifb::Key::F6 => 0x3f,
+        minifb::Key::F7 => 0x40,
+        minifb::Key::F8 => 0x41,
+        minifb::Key::F9 => 0x42,
+        minifb::Key::F10 => 0x43,
+        minifb::Key::F11 => 0x44,
+        minifb::Key::F12 => 0x45,
+        minifb::Key::F13 => 0x68,
+        minifb::Key::F14 => 0x69,
+        minifb::Key::F15 => 0x6a,
+        minifb::Key::ScrollLock => 0x47,
+        minifb::Key::Pause => 0x48,
+        minifb::Key::Insert => 0x49,
+        minifb::Key::Home => 0x4a,
+        minifb::Key::PageUp => 0x4b,
+        minifb::Key::Delete => 0x4c,
+        minifb::Key::End => 0x4d,
+        minifb::Key::PageDown => 0x4e,
+        minifb::Key::Right => 0x4f,
+        minifb::Key::Left => 0x50,
+        minifb::Key::Down => 0x51,
+        minifb::Key::Up => 0x52,
+        minifb::Key::NumLock => 0x53,
+        minifb::Key::NumPadSlash => 0x54,
+        minifb::Key::NumPadAsterisk => 0x55,
+        minifb::Key::NumPadMinus => 0x56,
+        minifb::Key::NumPadPlus => 0x57,
+        minifb::Key::NumPadEnter => 0x58,
+        minifb::Key::NumPad1 => 0x59,
+        minifb::Key::NumPad2 => 0x5a,
+        minifb::Key::NumPad3 => 0x5b,
+        minifb::Key::NumPad4 => 0x5c,
+        minifb::Key::NumPad5 => 0x5d,
+        minifb::Key::NumPad6 => 0x5e,
+        minifb::Key::NumPad7 => 0x5f,
+        minifb::Key::NumPad8 => 0x60,
+        minifb::Key::NumPad9 => 0x61,
+        minifb::Key::NumPad0 => 0x62,
+        minifb::Key::NumPadDot => 0x63,
+        minifb::Key::Menu => 0x65,
+        minifb::Key::LeftCtrl => 0xe0,
+        minifb::Key::LeftShift => 0xe1,
+        minifb::Key::LeftAlt => 0xe2,
+        minifb::Key::LeftSuper => 0xe3,
+        minifb::Key::RightCtrl => 0xe4,
+        minifb::Key::RightShift => 0xe5,
+        minifb::Key::RightAlt => 0xe6,
+        minifb::Key::RightSuper => 0xe7,
+        _ => PhysicalKey::UNKNOWN.0,
+    }
+}
@@ -4,11 +4,11 @@ use minifb;
 
 use super::{KeyState, MouseState, Shell, Window, WindowState};
 use crate::{
-    event::{ButtonState, Key, KeyEvent},
+    event::{ButtonState, Key, KeyEvent, LogicalKey, PhysicalKey},
     render::RenderContext2D,
     utils::Rectangle,
     window_adapter::WindowAdapter,
-    WindowRequest, WindowSettings,
+    RawWindowHandle, WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the minifb backend.
@@ -25,6 +25,7 @@ where
     fonts: HashMap<String, &'static [u8]>,
     bounds: Rectangle,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+    parent_window: Option<RawWindowHandle>,
 }
 
 impl<'a, A> WindowBuilder<'a, A>
@@ -43,6 +44,7 @@ where
             fonts: HashMap::new(),
             bounds: Rectangle::new((0.0, 0.0), 100.0, 75.0),
             request_receiver: None,
+            parent_window: None,
         }
     }
 
@@ -58,6 +60,7 @@ where
             fonts: settings.fonts,
             bounds: Rectangle::new(settings.position, settings.size.0, settings.size.1),
             request_receiver: None,
+            parent_window: None,
         }
     }
 
@@ -103,8 +106,20 @@ where
         self
     }
 
+    /// Embeds the window as child into the given foreign window instead of creating a top-level window.
+    ///
+    /// Embedding is not supported by the minifb backend, the window is created as top-level window.
+    pub fn with_parent_window(mut self, handle: RawWindowHandle) -> Self {
+        self.parent_window = Some(handle);
+        self
+    }
+
     /// Builds the window shell and add it to the application `Shell`.
     pub fn build(self) {
+        if self.parent_window.is_some() {
+            super::CONSOLE.warn("Parent windows are not supported by the minifb backend.");
+        }
+
         let window_options = minifb::WindowOptions {
             resize: self.resizeable,
             topmost: self.always_on_top,
@@ -151,6 +166,7 @@ where
             true,
             true,
             false,
+            false,
             vec![
                 KeyState::new(minifb::Key::Backspace, Key::Backspace),
                 KeyState::new(minifb::Key::Left, Key::Left),
@@ -165,8 +181,26 @@ where
                 KeyState::new(minifb::Key::RightShift, Key::ShiftR),
                 KeyState::new(minifb::Key::LeftAlt, Key::Alt),
                 KeyState::new(minifb::Key::RightAlt, Key::Alt),
+                KeyState::new(minifb::Key::LeftSuper, Key::Meta),
+                KeyState::new(minifb::Key::RightSuper, Key::Meta),
                 KeyState::new(minifb::Key::Escape, Key::Escape),
                 KeyState::new(minifb::Key::Home, Key::Home),
+                KeyState::new(minifb::Key::End, Key::End),
+                KeyState::new(minifb::Key::PageUp, Key::PageUp),
+                KeyState::new(minifb::Key::PageDown, Key::PageDown),
+                KeyState::new(minifb::Key::F1, Key::F1),
+                KeyState::new(minifb::Key::F2, Key::F2),
+                KeyState::new(minifb::Key::F3, Key::F3),
+                KeyState::new(minifb::Key::F4, Key::F4),
+                KeyState::new(minifb::Key::F5, Key::F5),
+                KeyState::new(minifb::Key::F6, Key::F6),
+                KeyState::new(minifb::Key::F7, Key::F7),
+                KeyState::new(minifb::Key::F8, Key::F8),
+                KeyState::new(minifb::Key::F9, Key::F9),
+                KeyState::new(minifb::Key::F10, Key::F10),
+                KeyState::new(minifb::Key::F11, Key::F11),
+                KeyState::new(minifb::Key::F12, Key::F12),
+                KeyState::new(minifb::Key::Menu, Key::Application),
                 KeyState::new(minifb::Key::NumPad0, Key::Numpad0),
                 KeyState::new(minifb::Key::NumPad1, Key::Numpad1),
                 KeyState::new(minifb::Key::NumPad2, Key::Numpad2),
@@ -216,15 +250,21 @@ impl KeyInputCallBack {
             || key == Key::Right
             || key == Key::Backspace
             || key == Key::Control
+            || key == Key::Meta
             || key == Key::Home
+            || key == Key::End
+            || key == Key::PageUp
+            || key == Key::PageDown
             || key == Key::Escape
             || key == Key::Delete
         {
             return;
         }
 
+        // the char callback of minifb doesn't report the physical key
         self.key_events.borrow_mut().push(KeyEvent {
-            key,
+            physical: PhysicalKey::UNKNOWN,
+            logical: LogicalKey(key),
             state: ButtonState::Down,
             text,
         });
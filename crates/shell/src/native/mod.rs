@@ -1,18 +1,27 @@
 use lazy_static;
 
-use std::{collections::HashMap, sync::Mutex, time::Instant};
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::log_level::LogLevel;
 
 lazy_static! {
     pub static ref CONSOLE: Console = Console {
-        instants: Mutex::new(HashMap::new())
+        instants: Mutex::new(HashMap::new()),
+        log_level: Mutex::new(LogLevel::default()),
     };
 }
 
 pub struct Console {
     instants: Mutex<HashMap<String, Instant>>,
+    log_level: Mutex<LogLevel>,
 }
 
 impl Console {
+    /// Starts a timer with the given name.
     pub fn time(&self, name: impl Into<String>) {
         self.instants
             .lock()
@@ -20,11 +29,16 @@ impl Console {
             .insert(name.into(), Instant::now());
     }
 
-    pub fn time_end(&self, name: impl Into<String>) {
-        if let Some((_k, _v)) = self.instants.lock().unwrap().remove_entry(&name.into()) {
-            #[cfg(feature = "log")]
-            println!("{} {}ms - timer ended", _k, _v.elapsed().as_millis());
-        }
+    /// Stops the timer with the given name and returns the elapsed time. Returns `None` if
+    /// there is no timer with the given name.
+    pub fn time_end(&self, name: impl Into<String>) -> Option<Duration> {
+        let (_name, instant) = self.instants.lock().unwrap().remove_entry(&name.into())?;
+        let elapsed = instant.elapsed();
+
+        #[cfg(feature = "log")]
+        self.debug(format!("{} {}ms - timer ended", _name, elapsed.as_millis()));
+
+        Some(elapsed)
     }
 
     #[allow(unused_variables)]
@@ -32,4 +46,55 @@ impl Console {
         #[cfg(feature = "log")]
         println!("{}", message.into());
     }
+
+    /// Sets the minimal level of the messages that are printed.
+    pub fn set_log_level(&self, log_level: LogLevel) {
+        *self.log_level.lock().unwrap() = log_level;
+    }
+
+    /// Gets the minimal level of the messages that are printed.
+    pub fn log_level(&self) -> LogLevel {
+        *self.log_level.lock().unwrap()
+    }
+
+    /// Prints a debug message. Does nothing in release builds.
+    #[allow(unused_variables)]
+    pub fn debug(&self, message: impl Into<String>) {
+        #[cfg(debug_assertions)]
+        self.print(LogLevel::Debug, message.into());
+    }
+
+    /// Prints an info message. Does nothing in release builds.
+    #[allow(unused_variables)]
+    pub fn info(&self, message: impl Into<String>) {
+        #[cfg(debug_assertions)]
+        self.print(LogLevel::Info, message.into());
+    }
+
+    /// Prints a warning to the standard error.
+    pub fn warn(&self, message: impl Into<String>) {
+        self.print(LogLevel::Warn, message.into());
+    }
+
+    /// Prints an error to the standard error.
+    pub fn error(&self, message: impl Into<String>) {
+        self.print(LogLevel::Error, message.into());
+    }
+
+    // Prints the message if the `log` feature is enabled and the level of the message is not
+    // below the log level of the console. Warnings and errors are printed to the standard error.
+    #[allow(unused_variables)]
+    fn print(&self, level: LogLevel, message: String) {
+        #[cfg(feature = "log")]
+        {
+            if level < self.log_level() {
+                return;
+            }
+
+            match level {
+                LogLevel::Warn | LogLevel::Error => eprintln!("{}: {}", level, message),
+                _ => println!("{}: {}", level, message),
+            }
+        }
+    }
 }
@@ -1,3 +1,4 @@
 pub use crate::{
-    event::*, platform::*, window_adapter::*, ShellRequest, WindowRequest, WindowSettings,
+    event::*, log_level::*, platform::*, window_adapter::*, ScreenInfo, ShellRequest,
+    WindowRequest, WindowSettings,
 };
@@ -1,6 +1,9 @@
 //! This module contains a platform specific implementation of the window shell.
-use std::sync::mpsc;
-use stdweb::web::window;
+use std::{
+    collections::HashMap,
+    sync::{mpsc, Mutex},
+};
+use stdweb::{unstable::TryInto, web::window};
 
 use crate::prelude::*;
 
@@ -82,54 +85,88 @@ where
         }
     }
 
-    /// Runs (starts) the application shell and its windows.
-    pub fn run(mut self) {
-        window().request_animation_frame(move |_| {
-            if self.window_shells.is_empty() {
-                return;
-            }
+    /// Runs one iteration of the shell: renders, updates and drains the events of all windows.
+    /// Could be used to drive the shell by the animation loop of a host application instead of `run`.
+    ///
+    /// Returns `false` if all windows are closed.
+    pub fn pump_events(&mut self) -> bool {
+        if self.window_shells.is_empty() {
+            return false;
+        }
 
-            for i in 0..self.window_shells.len() {
-                let mut remove = false;
-                if let Some(window_shell) = self.window_shells.get_mut(i) {
-                    window_shell.render();
-                    window_shell.update();
-                    window_shell.drain_events();
-                    window_shell.receive_requests();
-                    if !window_shell.is_open() {
-                        remove = true;
-                    }
+        for i in 0..self.window_shells.len() {
+            let mut remove = false;
+            if let Some(window_shell) = self.window_shells.get_mut(i) {
+                window_shell.render();
+                window_shell.update();
+                window_shell.drain_events();
+                window_shell.receive_requests();
+                if !window_shell.is_open() {
+                    remove = true;
                 }
+            }
 
-                if remove {
-                    self.window_shells.remove(i);
-                    break;
-                }
+            if remove {
+                self.window_shells.remove(i);
+                break;
             }
+        }
+
+        self.receive_requests();
 
-            self.receive_requests();
-            self.run();
+        !self.window_shells.is_empty()
+    }
+
+    /// Runs (starts) the application shell and its windows.
+    pub fn run(mut self) {
+        window().request_animation_frame(move |_| {
+            if self.pump_events() {
+                self.run();
+            }
         });
     }
 }
 
 lazy_static! {
-    pub static ref CONSOLE: Console = Console;
+    pub static ref CONSOLE: Console = Console {
+        instants: Mutex::new(HashMap::new()),
+        log_level: Mutex::new(LogLevel::default()),
+    };
+}
+
+// Returns the current high resolution time stamp of the browser in milliseconds.
+fn performance_now() -> f64 {
+    js! {
+        return performance.now();
+    }
+    .try_into()
+    .unwrap_or(0.0)
 }
 
-pub struct Console;
+pub struct Console {
+    instants: Mutex<HashMap<String, f64>>,
+    log_level: Mutex<LogLevel>,
+}
 
 impl Console {
-    pub fn time(&self, _name: impl Into<String>) {
-        // js! {
-        //     console.time(@{&name.into()})
-        // }
+    /// Starts a timer with the given name.
+    pub fn time(&self, name: impl Into<String>) {
+        self.instants
+            .lock()
+            .unwrap()
+            .insert(name.into(), performance_now());
     }
 
-    pub fn time_end(&self, _name: impl Into<String>) {
-        // js! {
-        //     console.timeEnd(@{&name.into()})
-        // }
+    /// Stops the timer with the given name and returns the elapsed time in milliseconds.
+    /// Returns `None` if there is no timer with the given name.
+    pub fn time_end(&self, name: impl Into<String>) -> Option<f64> {
+        let (_name, start) = self.instants.lock().unwrap().remove_entry(&name.into())?;
+        let elapsed = performance_now() - start;
+
+        #[cfg(feature = "log")]
+        self.debug(format!("{} {}ms - timer ended", _name, elapsed));
+
+        Some(elapsed)
     }
 
     pub fn log(&self, message: impl Into<String>) {
@@ -138,4 +175,66 @@ impl Console {
             console.log(@{&message.into()});
         }
     }
+
+    /// Sets the minimal level of the messages that are printed.
+    pub fn set_log_level(&self, log_level: LogLevel) {
+        *self.log_level.lock().unwrap() = log_level;
+    }
+
+    /// Gets the minimal level of the messages that are printed.
+    pub fn log_level(&self) -> LogLevel {
+        *self.log_level.lock().unwrap()
+    }
+
+    /// Prints a debug message by `console.debug`. Does nothing in release builds.
+    #[allow(unused_variables)]
+    pub fn debug(&self, message: impl Into<String>) {
+        #[cfg(all(debug_assertions, feature = "log"))]
+        {
+            if self.log_level() <= LogLevel::Debug {
+                js! {
+                    console.debug(@{&message.into()});
+                }
+            }
+        }
+    }
+
+    /// Prints an info message by `console.info`. Does nothing in release builds.
+    #[allow(unused_variables)]
+    pub fn info(&self, message: impl Into<String>) {
+        #[cfg(all(debug_assertions, feature = "log"))]
+        {
+            if self.log_level() <= LogLevel::Info {
+                js! {
+                    console.info(@{&message.into()});
+                }
+            }
+        }
+    }
+
+    /// Prints a warning by `console.warn`.
+    #[allow(unused_variables)]
+    pub fn warn(&self, message: impl Into<String>) {
+        #[cfg(feature = "log")]
+        {
+            if self.log_level() <= LogLevel::Warn {
+                js! {
+                    console.warn(@{&message.into()});
+                }
+            }
+        }
+    }
+
+    /// Prints an error by `console.error`.
+    #[allow(unused_variables)]
+    pub fn error(&self, message: impl Into<String>) {
+        #[cfg(feature = "log")]
+        {
+            if self.log_level() <= LogLevel::Error {
+                js! {
+                    console.error(@{&message.into()});
+                }
+            }
+        }
+    }
 }
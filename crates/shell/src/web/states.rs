@@ -2,6 +2,8 @@ use std::{cell::RefCell, rc::Rc};
 
 use stdweb::web::event;
 
+use crate::event::ScrollVelocityTracker;
+
 /// Used to store and read web events.
 pub struct EventState {
     pub mouse_move_events: Rc<RefCell<Vec<event::MouseMoveEvent>>>,
@@ -14,4 +16,5 @@ pub struct EventState {
     pub key_up_events: Rc<RefCell<Vec<event::KeyUpEvent>>>,
     pub key_down_events: Rc<RefCell<Vec<event::KeyDownEvent>>>,
     pub resize_events: Rc<RefCell<Vec<event::ResizeEvent>>>,
+    pub scroll_velocity: ScrollVelocityTracker,
 }
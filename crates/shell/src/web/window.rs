@@ -4,20 +4,25 @@ use stdweb::{
     js,
     traits::*,
     unstable::TryInto,
-    web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d},
+    web::{
+        document, event, event::MouseWheelDeltaMode, html_element::CanvasElement, window,
+        CanvasRenderingContext2d, TypedArray,
+    },
 };
 
 use derive_more::Constructor;
 
 use super::EventState;
 use crate::{
-    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
+    event::{
+        ButtonState, Key, KeyEvent, LogicalKey, MouseButton, MouseEvent, PhysicalKey, ScanCode,
+    },
     render::RenderContext2D,
     window_adapter::WindowAdapter,
     WindowRequest,
 };
 
-use orbtk_utils::Point;
+use orbtk_utils::{CursorShape, Point};
 
 /// Represents a wrapper for a web window. It handles events, propagate them to
 /// the window adapter and handles the update and render pipeline.
@@ -35,6 +40,8 @@ where
     update: bool,
     redraw: bool,
     close: bool,
+    cursor_shape: CursorShape,
+    raw_input: bool,
 }
 
 impl<A> Window<A>
@@ -49,8 +56,13 @@ where
     /// Drain events and propagate the events to the adapter.
     pub fn drain_events(&mut self) {
         while let Some(event) = self.event_state.mouse_move_events.borrow_mut().pop() {
-            self.adapter
-                .mouse(event.client_x() as f64, event.client_y() as f64);
+            if self.raw_input {
+                self.adapter
+                    .raw_mouse_delta(event.movement_x() as f64, event.movement_y() as f64);
+            } else {
+                self.adapter
+                    .mouse(event.client_x() as f64, event.client_y() as f64);
+            }
             self.update = true;
         }
 
@@ -73,7 +85,16 @@ where
         }
 
         while let Some(event) = self.event_state.scroll_events.borrow_mut().pop() {
-            self.adapter.scroll(event.delta_x(), event.delta_y());
+            // pixel based deltas are send by trackpads, line and page based deltas by mouse wheels.
+            let is_trackpad = event.delta_mode() == MouseWheelDeltaMode::Pixel;
+            let time = event.time_stamp().unwrap_or(0.0) / 1000.0;
+
+            let event = self.event_state.scroll_velocity.track(
+                Point::new(event.delta_x(), event.delta_y()),
+                time,
+                is_trackpad,
+            );
+            self.adapter.scroll(event);
             self.update = true;
         }
 
@@ -115,7 +136,8 @@ where
             let key = get_key(event.code().as_str(), event.key());
 
             self.adapter.key_event(KeyEvent {
-                key: key.0,
+                physical: PhysicalKey(get_scan_code(event.code().as_str())),
+                logical: LogicalKey(key.0),
                 state: ButtonState::Down,
                 text: key.1,
             });
@@ -126,7 +148,8 @@ where
             let key = get_key(event.code().as_str(), event.key());
 
             self.adapter.key_event(KeyEvent {
-                key: key.0,
+                physical: PhysicalKey(get_scan_code(event.code().as_str())),
+                logical: LogicalKey(key.0),
                 state: ButtonState::Up,
                 text: key.1,
             });
@@ -209,20 +232,77 @@ where
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::ChangeIcon(icon) => {
+                        set_favicon(icon);
+                    }
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    // the browser window could not be moved.
+                    WindowRequest::MoveToScreen { .. } => {}
                 }
             }
         }
     }
 
+    // Updates the css cursor of the canvas if the cursor shape of the adapter is changed.
+    fn update_cursor(&mut self) {
+        let cursor_shape = self.adapter.cursor_shape();
+
+        if cursor_shape == self.cursor_shape {
+            return;
+        }
+
+        self.cursor_shape = cursor_shape;
+
+        let cursor = match cursor_shape {
+            CursorShape::Default => "default",
+            CursorShape::Pointer => "pointer",
+            CursorShape::Text => "text",
+            CursorShape::Crosshair => "crosshair",
+            CursorShape::Grab => "grab",
+            CursorShape::Grabbing => "grabbing",
+            CursorShape::ResizeH => "ew-resize",
+            CursorShape::ResizeV => "ns-resize",
+            CursorShape::ResizeNWSE => "nwse-resize",
+            CursorShape::ResizeNESW => "nesw-resize",
+            CursorShape::NotAllowed => "not-allowed",
+        };
+
+        js! {
+            @{&self.canvas}.style.cursor = @{cursor};
+        }
+    }
+
+    // Locks the pointer to the canvas while raw input is requested by the adapter.
+    fn update_raw_input(&mut self) {
+        let raw_input = self.adapter.raw_input();
+
+        if raw_input == self.raw_input {
+            return;
+        }
+
+        self.raw_input = raw_input;
+
+        if raw_input {
+            js! {
+                @{&self.canvas}.requestPointerLock();
+            }
+        } else {
+            js! {
+                document.exitPointerLock();
+            }
+        }
+    }
+
     /// Runs update on the adapter.
     pub fn update(&mut self) {
         if !self.update {
             return;
         }
         self.adapter.run(&mut self.render_context);
+        self.update_cursor();
+        self.update_raw_input();
         self.update = false;
         self.redraw = true;
     }
@@ -271,8 +351,25 @@ fn get_key(code: &str, key: String) -> (Key, String) {
         "ArrowDown" => Key::Down,
         "Escape" => Key::Escape,
         "Enter" => Key::Enter,
-        "OSLeft" | "OSRight" => Key::Home,
+        "OSLeft" | "OSRight" | "MetaLeft" | "MetaRight" => Key::Meta,
+        "Home" => Key::Home,
+        "End" => Key::End,
+        "PageUp" => Key::PageUp,
+        "PageDown" => Key::PageDown,
         "CapsLock" => Key::CapsLock,
+        "F1" => Key::F1,
+        "F2" => Key::F2,
+        "F3" => Key::F3,
+        "F4" => Key::F4,
+        "F5" => Key::F5,
+        "F6" => Key::F6,
+        "F7" => Key::F7,
+        "F8" => Key::F8,
+        "F9" => Key::F9,
+        "F10" => Key::F10,
+        "F11" => Key::F11,
+        "F12" => Key::F12,
+        "ContextMenu" => Key::Application,
         _ => {
             text = key.clone();
             Key::from(key.chars().next().unwrap())
@@ -282,4 +379,111 @@ fn get_key(code: &str, key: String) -> (Key, String) {
     (code, text)
 }
 
+// Maps the layout independent `code` of a keyboard event to its USB HID usage id.
+fn get_scan_code(code: &str) -> ScanCode {
+    let last = code.bytes().last().unwrap_or_default();
+
+    if code.len() == 4 && code.starts_with("Key") && last.is_ascii_uppercase() {
+        return 0x04 + (last - b'A') as ScanCode;
+    }
+
+    if code.len() == 6 && code.starts_with("Digit") && last.is_ascii_digit() {
+        return match last {
+            b'0' => 0x27,
+            digit => 0x1e + (digit - b'1') as ScanCode,
+        };
+    }
+
+    match code {
+        "Enter" => 0x28,
+        "Escape" => 0x29,
+        "Backspace" => 0x2a,
+        "Tab" => 0x2b,
+        "Space" => 0x2c,
+        "Minus" => 0x2d,
+        "Equal" => 0x2e,
+        "BracketLeft" => 0x2f,
+        "BracketRight" => 0x30,
+        "Backslash" => 0x31,
+        "Semicolon" => 0x33,
+        "Quote" => 0x34,
+        "Backquote" => 0x35,
+        "Comma" => 0x36,
+        "Period" => 0x37,
+        "Slash" => 0x38,
+        "CapsLock" => 0x39,
+        "F1" => 0x3a,
+        "F2" => 0x3b,
+        "F3" => 0x3c,
+        "F4" => 0x3d,
+        "F5" => 0x3e,
+        "F6" => 0x3f,
+        "F7" => 0x40,
+        "F8" => 0x41,
+        "F9" => 0x42,
+        "F10" => 0x43,
+        "F11" => 0x44,
+        "F12" => 0x45,
+        "PrintScreen" => 0x46,
+        "ScrollLock" => 0x47,
+        "Pause" => 0x48,
+        "Insert" => 0x49,
+        "Home" => 0x4a,
+        "PageUp" => 0x4b,
+        "Delete" => 0x4c,
+        "End" => 0x4d,
+        "PageDown" => 0x4e,
+        "ArrowRight" => 0x4f,
+        "ArrowLeft" => 0x50,
+        "ArrowDown" => 0x51,
+        "ArrowUp" => 0x52,
+        "NumLock" => 0x53,
+        "NumpadDivide" => 0x54,
+        "NumpadMultiply" => 0x55,
+        "NumpadSubtract" => 0x56,
+        "NumpadAdd" => 0x57,
+        "NumpadEnter" => 0x58,
+        "Numpad1" => 0x59,
+        "Numpad2" => 0x5a,
+        "Numpad3" => 0x5b,
+        "Numpad4" => 0x5c,
+        "Numpad5" => 0x5d,
+        "Numpad6" => 0x5e,
+        "Numpad7" => 0x5f,
+        "Numpad8" => 0x60,
+        "Numpad9" => 0x61,
+        "Numpad0" => 0x62,
+        "NumpadDecimal" => 0x63,
+        "IntlBackslash" => 0x64,
+        "ContextMenu" => 0x65,
+        "ControlLeft" => 0xe0,
+        "ShiftLeft" => 0xe1,
+        "AltLeft" => 0xe2,
+        "OSLeft" | "MetaLeft" => 0xe3,
+        "ControlRight" => 0xe4,
+        "ShiftRight" => 0xe5,
+        "AltRight" => 0xe6,
+        "OSRight" | "MetaRight" => 0xe7,
+        _ => PhysicalKey::UNKNOWN.0,
+    }
+}
+
 // -- Helpers --
+
+// Uses the given image file as favicon of the page.
+fn set_favicon(icon: Vec<u8>) {
+    let icon: TypedArray<u8> = icon.as_slice().into();
+
+    js! {
+        var url = URL.createObjectURL(new Blob([@{icon}]));
+        var link = document.querySelector("link[rel~='icon']");
+
+        if (!link) {
+            link = document.createElement("link");
+            link.rel = "icon";
+            document.head.appendChild(link);
+        }
+
+        link.href = url;
+    }
+}
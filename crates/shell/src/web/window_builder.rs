@@ -15,8 +15,11 @@ use stdweb::{
 
 use super::{EventState, Shell, Window};
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
-    WindowSettings,
+    event::ScrollVelocityTracker,
+    render::RenderContext2D,
+    utils::{CursorShape, Rectangle},
+    window_adapter::WindowAdapter,
+    RawWindowHandle, WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the web backend.
@@ -33,6 +36,7 @@ where
     fonts: HashMap<String, &'static [u8]>,
     bounds: Rectangle,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+    parent_window: Option<RawWindowHandle>,
 }
 
 impl<'a, A> WindowBuilder<'a, A>
@@ -51,6 +55,7 @@ where
             fonts: HashMap::new(),
             bounds: Rectangle::new((0.0, 0.0), 100.0, 75.0),
             request_receiver: None,
+            parent_window: None,
         }
     }
 
@@ -66,6 +71,7 @@ where
             fonts: settings.fonts,
             bounds: Rectangle::new(settings.position, settings.size.0, settings.size.1),
             request_receiver: None,
+            parent_window: None,
         }
     }
 
@@ -111,8 +117,20 @@ where
         self
     }
 
+    /// Embeds the window as child into the given foreign window instead of creating a top-level window.
+    ///
+    /// Embedding is not supported by the web backend, the canvas is always added to the body of the document.
+    pub fn with_parent_window(mut self, handle: RawWindowHandle) -> Self {
+        self.parent_window = Some(handle);
+        self
+    }
+
     /// Builds the window shell and add it to the application `Shell`.
     pub fn build(mut self) {
+        if self.parent_window.is_some() {
+            super::CONSOLE.warn("Parent windows are not supported by the web backend.");
+        }
+
         let canvas: CanvasElement = document()
             .create_element("canvas")
             .unwrap()
@@ -291,12 +309,15 @@ where
                 key_down_events: key_down,
                 key_up_events: key_up,
                 resize_events: resize,
+                scroll_velocity: ScrollVelocityTracker::new(),
             },
             canvas,
             None,
             true,
             true,
             false,
+            CursorShape::Default,
+            false,
         ));
     }
 }
@@ -1,7 +1,13 @@
 //! This module contains traits to inject custom logic into the window shell.
 
+use std::path::PathBuf;
+
 use crate::render::RenderContext2D;
-use crate::{event::*, utils::Point};
+use crate::{
+    event::*,
+    utils::{CursorShape, Point},
+    ScreenInfo,
+};
 
 /// The `WindowAdapter` represents the bridge to the `Shell` backend.
 /// It receives events from the `Window` and runs it's own logic.  
@@ -16,17 +22,51 @@ pub trait WindowAdapter {
     fn mouse_event(&mut self, _event: MouseEvent) {}
 
     /// Is called if mouse wheel or trackpad detect scroll event.
-    fn scroll(&mut self, _delta_x: f64, _delta_y: f64) {}
+    fn scroll(&mut self, _event: MouseScrollEvent) {}
+
+    /// Is called with the unfiltered movement of the mouse while raw input is enabled.
+    fn raw_mouse_delta(&mut self, _dx: f64, _dy: f64) {}
+
+    /// Is called if files from the file manager are dragged over the window (`true`) or if the
+    /// drag leaves the window or is cancelled (`false`).
+    fn files_hovered(&mut self, _hovered: bool) {}
+
+    /// Is called after files from the file manager are dropped on the window. The drop ends
+    /// the drag reported by `files_hovered`.
+    fn files_dropped(&mut self, _paths: Vec<PathBuf>, _x: f64, _y: f64) {}
 
     /// Is called after the state of a keyboard key is changed.
     fn key_event(&mut self, _event: KeyEvent) {}
 
-    /// Is called after the quit event of the window is called.
-    fn quit_event(&mut self) {}
+    /// Is called if the window should be closed e.g. by its close button. Returns `true` if the
+    /// window could be closed immediately or `false` if the adapter closes the window later by
+    /// `WindowRequest::Close`.
+    fn quit_event(&mut self) -> bool {
+        true
+    }
+
+    /// Is called with the available screens after the window is created and if the screens are
+    /// changed.
+    fn screens(&mut self, _screens: Vec<ScreenInfo>) {}
+
+    /// Is called after the scale factor of the window is changed e.g. if the window is moved to a
+    /// screen with another scale factor.
+    fn scale_factor_changed(&mut self, _scale_factor: f64) {}
 
     /// Gets the current mouse position.
     fn mouse_position(&self) -> Point;
 
+    /// Gets the cursor shape that should be displayed. Is read after each update.
+    fn cursor_shape(&mut self) -> CursorShape {
+        CursorShape::Default
+    }
+
+    /// Gets whether raw mouse input is requested. If `true` the cursor is hidden and locked
+    /// and mouse movement is reported by `raw_mouse_delta`. Is read after each update.
+    fn raw_input(&mut self) -> bool {
+        false
+    }
+
     /// Is called if active state of the window is changed.
     fn active(&mut self, active: bool);
 
@@ -0,0 +1,20 @@
+/*!
+   Test utilities for OrbTk widgets like visual snapshot testing.
+*/
+
+pub(crate) use orbtk_api as api;
+pub(crate) use orbtk_render as render;
+pub(crate) use orbtk_shell as shell;
+pub(crate) use orbtk_theme as theme;
+pub(crate) use orbtk_tree as tree;
+pub(crate) use orbtk_widgets as widgets;
+
+pub mod prelude;
+
+pub use self::snapshot::*;
+pub use self::test_bed::*;
+
+#[macro_use]
+mod macros;
+mod snapshot;
+mod test_bed;
@@ -0,0 +1,20 @@
+/// Renders the given widget and compares it with the reference image `tests/snapshots/<name>.png`
+/// of the current crate.
+///
+/// If there is no reference image yet, it is written and the assertion passes.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// assert_snapshot!(Button::new().text("Click me"), "button");
+/// ```
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($widget:expr, $name:expr) => {
+        $crate::assert_widget_snapshot(
+            $widget,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots"),
+            $name,
+        )
+    };
+}
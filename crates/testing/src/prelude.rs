@@ -0,0 +1,5 @@
+pub use crate::*;
+pub use orbtk_api::prelude::*;
+pub use orbtk_widgets::prelude::*;
+
+pub use crate::assert_snapshot;
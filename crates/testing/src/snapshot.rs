@@ -0,0 +1,179 @@
+use std::{fs, path::Path};
+
+use image::{DynamicImage, ImageOutputFormat, Rgba, RgbaImage};
+
+use crate::{api::prelude::*, render::RenderContext2D, tree::Tree, TestBed};
+
+/// `WidgetSnapshot` is used to capture the rendered output of a widget.
+pub struct WidgetSnapshot;
+
+impl WidgetSnapshot {
+    /// Crops the bounds of the given widget out of the last rendered frame of the render context
+    /// and returns it encoded as png.
+    pub fn capture(
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        render_context: &mut RenderContext2D,
+    ) -> Vec<u8> {
+        let root = ecm.entity_store().root();
+
+        let frame_width = ecm
+            .component_store()
+            .get::<Rectangle>("bounds", root)
+            .map(|b| b.width())
+            .unwrap_or(0.0) as usize;
+        let bounds = *ecm
+            .component_store()
+            .get::<Rectangle>("bounds", entity)
+            .expect("WidgetSnapshot::capture: widget has no bounds.");
+        let position = *ecm
+            .component_store()
+            .get::<Point>("position", entity)
+            .expect("WidgetSnapshot::capture: widget has no position.");
+
+        let data = render_context.data_mut();
+        let frame_height = if frame_width > 0 {
+            data.len() / frame_width
+        } else {
+            0
+        };
+
+        let x = (position.x().max(0.0) as usize).min(frame_width);
+        let y = (position.y().max(0.0) as usize).min(frame_height);
+        let width = (bounds.width().max(0.0) as usize).min(frame_width - x);
+        let height = (bounds.height().max(0.0) as usize).min(frame_height - y);
+
+        let image = RgbaImage::from_fn(width as u32, height as u32, |col, row| {
+            argb_to_rgba(data[(y + row as usize) * frame_width + x + col as usize])
+        });
+
+        encode_png(image)
+    }
+}
+
+/// Renders the given widget in a `TestBed` and compares it with the reference png `<name>.png`
+/// in the given directory.
+///
+/// If there is no reference yet it is written. If the snapshot differs from the reference
+/// a diff image `<name>.diff.png` is written and the function panics with the percentage of
+/// different pixels.
+pub fn assert_widget_snapshot<W: Widget + 'static>(widget: W, directory: &str, name: &str) {
+    let png = TestBed::build(widget).snapshot();
+
+    let directory = Path::new(directory);
+    let reference_path = directory.join(format!("{}.png", name));
+
+    if !reference_path.exists() {
+        fs::create_dir_all(directory)
+            .expect("assert_snapshot: could not create snapshot directory.");
+        fs::write(&reference_path, &png)
+            .expect("assert_snapshot: could not write reference snapshot.");
+        return;
+    }
+
+    let reference = image::open(&reference_path)
+        .expect("assert_snapshot: could not read reference snapshot.")
+        .to_rgba();
+    let snapshot = image::load_from_memory(&png)
+        .expect("assert_snapshot: could not read snapshot.")
+        .to_rgba();
+
+    let (difference, diff) = compare_images(&reference, &snapshot);
+
+    if difference > 0.0 {
+        let diff_path = directory.join(format!("{}.diff.png", name));
+        diff.save(&diff_path)
+            .expect("assert_snapshot: could not write diff image.");
+
+        panic!(
+            "assert_snapshot: snapshot `{}` differs from its reference in {:.2}% of the pixels, see {}",
+            name,
+            difference,
+            diff_path.display()
+        );
+    }
+}
+
+/// Compares the two images pixel by pixel. Returns the percentage of different pixels and a
+/// diff image that marks the different pixels red.
+pub fn compare_images(reference: &RgbaImage, snapshot: &RgbaImage) -> (f64, RgbaImage) {
+    let (width, height) = snapshot.dimensions();
+
+    if reference.dimensions() != snapshot.dimensions() {
+        return (
+            100.0,
+            RgbaImage::from_pixel(width, height, Rgba([255, 0, 0, 255])),
+        );
+    }
+
+    if width == 0 || height == 0 {
+        return (0.0, RgbaImage::new(width, height));
+    }
+
+    let mut different_pixels = 0;
+
+    let diff = RgbaImage::from_fn(width, height, |x, y| {
+        let pixel = snapshot.get_pixel(x, y);
+
+        if reference.get_pixel(x, y) != pixel {
+            different_pixels += 1;
+            return Rgba([255, 0, 0, 255]);
+        }
+
+        // unchanged pixels are drawn faded
+        let gray = ((pixel[0] as u32 + pixel[1] as u32 + pixel[2] as u32) / 3) as u8;
+        Rgba([gray, gray, gray, 64])
+    });
+
+    (
+        different_pixels as f64 / (width * height) as f64 * 100.0,
+        diff,
+    )
+}
+
+// --- Helpers --
+
+fn argb_to_rgba(pixel: u32) -> Rgba<u8> {
+    Rgba([
+        ((pixel & 0x00FF_0000) >> 16) as u8,
+        ((pixel & 0x0000_FF00) >> 8) as u8,
+        (pixel & 0x0000_00FF) as u8,
+        ((pixel & 0xFF00_0000) >> 24) as u8,
+    ])
+}
+
+fn encode_png(image: RgbaImage) -> Vec<u8> {
+    let mut png = vec![];
+    DynamicImage::ImageRgba8(image)
+        .write_to(&mut png, ImageOutputFormat::Png)
+        .expect("WidgetSnapshot: could not encode png.");
+    png
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_images() {
+        let reference = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 255]));
+        let mut snapshot = reference.clone();
+
+        assert_eq!(0.0, compare_images(&reference, &snapshot).0);
+
+        snapshot.put_pixel(1, 1, Rgba([255, 255, 255, 255]));
+        let (difference, diff) = compare_images(&reference, &snapshot);
+        assert_eq!(25.0, difference);
+        assert_eq!(&Rgba([255, 0, 0, 255]), diff.get_pixel(1, 1));
+
+        let small = RgbaImage::new(1, 1);
+        assert_eq!(100.0, compare_images(&reference, &small).0);
+    }
+
+    #[test]
+    fn test_argb_to_rgba() {
+        assert_eq!(Rgba([0x11, 0x22, 0x33, 0xff]), argb_to_rgba(0xff11_2233));
+    }
+}
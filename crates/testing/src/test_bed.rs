@@ -0,0 +1,179 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    sync::mpsc,
+    time::Duration,
+};
+
+use crate::{
+    api::prelude::*,
+    render::RenderContext2D,
+    shell::prelude::{ShellRequest, WindowAdapter as _, WindowRequest},
+    theme,
+    widgets::prelude::*,
+    WidgetSnapshot,
+};
+
+/// Default width of the test bed window.
+pub const TEST_BED_WIDTH: f64 = 400.0;
+
+/// Default height of the test bed window.
+pub const TEST_BED_HEIGHT: f64 = 300.0;
+
+// Maximum time to wait for the render thread to finish a frame.
+const FRAME_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The `TestBed` builds a widget inside of an offscreen window and runs the complete
+/// update, layout and render pipeline on it without a shell.
+pub struct TestBed {
+    adapter: WindowAdapter,
+    render_context: RenderContext2D,
+    widget: Entity,
+    _request_receiver: mpsc::Receiver<ShellRequest<WindowAdapter>>,
+    _window_receiver: mpsc::Receiver<WindowRequest>,
+}
+
+impl TestBed {
+    /// Builds the given widget in a window with the default test bed size and renders the first frame.
+    pub fn build<W: Widget + 'static>(widget: W) -> Self {
+        TestBed::build_with_size(widget, TEST_BED_WIDTH, TEST_BED_HEIGHT)
+    }
+
+    /// Builds the given widget in a window with the given size and renders the first frame.
+    pub fn build_with_size<W: Widget + 'static>(widget: W, width: f64, height: f64) -> Self {
+        let widget = RefCell::new(Some(widget));
+        let widget_entity = Rc::new(Cell::new(Entity::default()));
+        let widget_entity_ref = widget_entity.clone();
+
+        let (request_sender, request_receiver) = mpsc::channel();
+
+        let (adapter, settings, window_receiver) = create_window(
+            "orbtk_test_bed",
+            theme::dark_theme(),
+            theme::material_icon_theme(),
+            request_sender,
+            move |ctx| {
+                let widget = widget
+                    .borrow_mut()
+                    .take()
+                    .expect("TestBed: the widget could only be build once.")
+                    .build(ctx);
+                widget_entity_ref.set(widget);
+
+                Window::new()
+                    .title("TestBed")
+                    .size(width, height)
+                    .child(widget)
+                    .build(ctx)
+            },
+        );
+
+        let mut render_context = RenderContext2D::new(width, height);
+
+        for (family, font) in settings.fonts {
+            render_context.register_font(&family, font);
+        }
+
+        let mut test_bed = TestBed {
+            adapter,
+            render_context,
+            widget: widget_entity.get(),
+            _request_receiver: request_receiver,
+            _window_receiver: window_receiver,
+        };
+
+        test_bed.run();
+        test_bed
+    }
+
+    /// Gets the entity of the tested widget.
+    pub fn widget(&self) -> Entity {
+        self.widget
+    }
+
+    /// Gets the entity component manager of the test bed window.
+    pub fn entity_component_manager(
+        &mut self,
+    ) -> &mut EntityComponentManager<crate::tree::Tree, StringComponentStore> {
+        self.adapter.entity_component_manager()
+    }
+
+    /// Runs one iteration of the update, layout and render pipeline and waits until the frame is rendered.
+    pub fn run(&mut self) {
+        self.adapter.run(&mut self.render_context);
+
+        if self
+            .render_context
+            .finish_receiver()
+            .recv_timeout(FRAME_TIMEOUT)
+            .is_ok()
+        {
+            // moves the rendered frame into the output buffer of the render context
+            let _ = self.render_context.data();
+        }
+    }
+
+    /// Captures the tested widget of the last rendered frame as png.
+    pub fn snapshot(&mut self) -> Vec<u8> {
+        WidgetSnapshot::capture(
+            self.widget,
+            self.adapter.entity_component_manager(),
+            &mut self.render_context,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use orbtk_proc_macros::*;
+
+    use super::*;
+
+    #[derive(Default, AsAny)]
+    struct RemoveSelfState;
+
+    impl State for RemoveSelfState {
+        fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+            ctx.widget().set("remove", true);
+        }
+
+        fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+            if !*ctx.widget().get::<bool>("remove") {
+                return;
+            }
+
+            // puts the child and the widget itself behind the current position of the dirty widgets
+            let child = ctx.entity_of_child("child").unwrap();
+            ctx.get_widget(child).set("text", String16::from("removed"));
+            ctx.widget().set("remove", false);
+
+            let parent = ctx.entity_of_parent().unwrap();
+            ctx.remove_child_from(ctx.entity, parent);
+        }
+    }
+
+    widget!(RemoveSelf<RemoveSelfState> { remove: bool });
+
+    impl Template for RemoveSelf {
+        fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+            self.child(TextBlock::new().id("child").build(ctx))
+        }
+    }
+
+    #[test]
+    fn test_remove_dirty_widget() {
+        let mut test_bed = TestBed::build(RemoveSelf::new());
+        test_bed.run();
+
+        let widget = test_bed.widget();
+        let ecm = test_bed.entity_component_manager();
+        let root = ecm.entity_store().root();
+
+        assert!(!ecm.entity_store().children.contains_key(&widget));
+        assert!(!ecm
+            .component_store()
+            .get::<Vec<Entity>>("dirty_widgets", root)
+            .unwrap()
+            .contains(&widget));
+    }
+}
@@ -3,3 +3,4 @@ pub const LYNCH_COLOR: &str = "#647b91";
 pub const BOMBAY_COLOR: &str = "#adb3B8";
 pub const SLATE_GRAY_COLOR: &str = "#6c7a90";
 pub const BRIGHT_GRAY_COLOR: &str = "#3b434a";
+pub const CARNATION_COLOR: &str = "#f25c54";
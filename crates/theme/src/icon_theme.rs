@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use crate::vector_graphics::material_icons_font;
+
+/// The font of the default material icon theme.
+pub const MATERIAL_ICONS_FONT: &str = "MaterialIcons-Regular";
+
+/// Describes where the graphic of an icon comes from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IconSource {
+    /// The icon is a glyph of an icon font.
+    FontGlyph { font: String, codepoint: char },
+
+    /// The icon is described by the path data of a svg file.
+    SvgPath { path: String },
+}
+
+impl IconSource {
+    /// Creates an icon source from a glyph of the given font. `glyph` is a string that contains
+    /// the glyph e.g. `material_icons_font::MD_SEARCH`.
+    pub fn font_glyph(font: impl Into<String>, glyph: &str) -> Self {
+        IconSource::FontGlyph {
+            font: font.into(),
+            codepoint: glyph.chars().next().unwrap_or_default(),
+        }
+    }
+
+    /// Returns the font and the text of a font glyph. Returns `None` for svg icons.
+    pub fn glyph(&self) -> Option<(String, String)> {
+        match self {
+            IconSource::FontGlyph { font, codepoint } => {
+                Some((font.clone(), codepoint.to_string()))
+            }
+            IconSource::SvgPath { .. } => None,
+        }
+    }
+}
+
+/// Maps icon names like `"search"` to the graphics of an icon set. Widgets look up their icons by
+/// name, so the icon set of an application could be swapped without touching the widgets.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IconTheme {
+    pub icons: HashMap<String, IconSource>,
+}
+
+impl IconTheme {
+    /// Creates an empty icon theme.
+    pub fn new() -> Self {
+        IconTheme::default()
+    }
+
+    /// Builder method that adds an icon with the given name.
+    pub fn icon(mut self, name: impl Into<String>, source: IconSource) -> Self {
+        self.icons.insert(name.into(), source);
+        self
+    }
+
+    /// Gets the icon with the given name.
+    pub fn get(&self, name: &str) -> Option<&IconSource> {
+        self.icons.get(name)
+    }
+
+    /// Adds the icons of the given theme. Icons with the same name are replaced.
+    pub fn extend(mut self, other: IconTheme) -> Self {
+        self.icons.extend(other.icons);
+        self
+    }
+}
+
+/// Creates the default icon theme, that maps the icon names used by OrbTks widgets to the
+/// Material Icons font.
+pub fn material_icon_theme() -> IconTheme {
+    [
+        ("arrow_drop_down", material_icons_font::MD_ARROW_DROP_DOWN),
+        ("check", material_icons_font::MD_CHECK),
+        ("close", material_icons_font::MD_CLOSE),
+        (
+            "keyboard_arrow_down",
+            material_icons_font::MD_KEYBOARD_ARROW_DOWN,
+        ),
+        (
+            "keyboard_arrow_right",
+            material_icons_font::MD_KEYBOARD_ARROW_RIGHT,
+        ),
+        (
+            "keyboard_arrow_up",
+            material_icons_font::MD_KEYBOARD_ARROW_UP,
+        ),
+        ("search", material_icons_font::MD_SEARCH),
+    ]
+    .iter()
+    .fold(IconTheme::new(), |theme, (name, glyph)| {
+        theme.icon(*name, IconSource::font_glyph(MATERIAL_ICONS_FONT, glyph))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_material_icon_theme() {
+        let theme = material_icon_theme();
+
+        assert_eq!(
+            theme.get("search"),
+            Some(&IconSource::FontGlyph {
+                font: MATERIAL_ICONS_FONT.to_string(),
+                codepoint: '\u{e57f}',
+            })
+        );
+        assert_eq!(theme.get("unknown"), None);
+    }
+
+    #[test]
+    fn test_extend() {
+        let svg = IconSource::SvgPath {
+            path: "M 0 0 L 10 10".to_string(),
+        };
+        let theme = material_icon_theme().extend(IconTheme::new().icon("search", svg.clone()));
+
+        assert_eq!(theme.get("search"), Some(&svg));
+        assert_eq!(svg.glyph(), None);
+        assert!(theme.get("check").unwrap().glyph().is_some());
+    }
+}
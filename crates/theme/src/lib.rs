@@ -7,10 +7,13 @@ It provides fonts, icons and colors.
 
 use orbtk_theming::{config::ThemeConfig, prelude::*};
 
+pub use self::icon_theme::*;
+
 /// provides `constants` to reference colors.
 pub mod colors;
 /// provides `constants` associated to fonts.
 pub mod fonts;
+mod icon_theme;
 pub mod prelude;
 /// provides information processed by the `graphic render` (e.g. glyphs, icons).
 pub mod vector_graphics;
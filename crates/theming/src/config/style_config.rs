@@ -3,8 +3,9 @@ use std::collections::HashMap;
 use ron::Value;
 use serde_derive::{Deserialize, Serialize};
 
-/// Defines a style. A style could be base on other styles and contains a list for properties
-/// and a list of state properties.
+/// Defines a style. A style could be base on other styles and contains a list for properties,
+/// a list of state properties and a list of properties that are used if a media query like
+/// `"(max_width: 600)"` matches the window size.
 #[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct StyleConfig {
     // set default string to base style
@@ -14,4 +15,6 @@ pub struct StyleConfig {
     pub states: HashMap<String, HashMap<String, Value>>,
     #[serde(default)]
     pub properties: HashMap<String, Value>,
+    #[serde(default)]
+    pub media: HashMap<String, HashMap<String, Value>>,
 }
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use ron::{de::from_str, Value};
 use serde_derive::{Deserialize, Serialize};
 
-use crate::{config::StyleConfig, Selector};
+use crate::{compound_state_key, config::StyleConfig, Selector};
 
 pub static BASE_STYLE: &str = "base";
 pub static RESOURCE_KEY: &str = "$";
@@ -57,20 +57,24 @@ impl<'a> ThemeConfig {
         style: &'a StyleConfig,
         selector: &Selector,
     ) -> Option<Value> {
-        // state properties has the most priority
-        if let Some(state) = &selector.state {
-            if let Some(properties) = style.states.get(state) {
+        // state properties has the most priority, the compound state before the single states
+        for state in selector.state_keys() {
+            if let Some(properties) = find_state(style, &state) {
                 return self.get_property_value(property, properties);
             }
+        }
 
+        if !selector.states.is_empty() {
             // load state properties from based style if there are no other states (recursive through base style).
             if style.base.is_empty() {
                 return None;
             }
 
             if let Some(base_style) = self.styles.get(&style.base) {
-                if let Some(properties) = base_style.states.get(state) {
-                    return self.get_property_value(property, properties);
+                for state in selector.state_keys() {
+                    if let Some(properties) = find_state(base_style, &state) {
+                        return self.get_property_value(property, properties);
+                    }
                 }
             }
         }
@@ -116,3 +120,12 @@ impl From<&str> for ThemeConfig {
         from_str(s).unwrap()
     }
 }
+
+// Gets the properties of the given state key, the keys of compound states are compared normalized.
+fn find_state<'a>(style: &'a StyleConfig, state: &str) -> Option<&'a HashMap<String, Value>> {
+    style
+        .states
+        .iter()
+        .find(|(key, _)| compound_state_key(key) == state)
+        .map(|(_, properties)| properties)
+}
@@ -1,9 +1,13 @@
+pub use self::media_query::*;
 pub use self::selector::*;
 pub use self::style::*;
 pub use self::theme::*;
+pub use self::validation::*;
 
 pub mod config;
+mod media_query;
 pub mod prelude;
 mod selector;
 mod style;
 mod theme;
+mod validation;
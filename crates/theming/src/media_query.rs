@@ -0,0 +1,122 @@
+use std::{fmt, str::FromStr};
+
+/// Describes a condition on the window size. Properties of a style that are wrapped in a media
+/// query are only used if the query matches the current window size.
+///
+/// A media query is written like `"(max_width: 600)"`, multiple conditions are combined with
+/// `and` e.g. `"(min_width: 400) and (max_height: 300)"`.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+pub struct MediaQuery {
+    pub min_width: Option<f64>,
+    pub max_width: Option<f64>,
+    pub min_height: Option<f64>,
+    pub max_height: Option<f64>,
+}
+
+impl MediaQuery {
+    /// Checks if the query matches the given window size.
+    pub fn matches(&self, width: f64, height: f64) -> bool {
+        self.min_width.map_or(true, |min_width| width >= min_width)
+            && self.max_width.map_or(true, |max_width| width <= max_width)
+            && self
+                .min_height
+                .map_or(true, |min_height| height >= min_height)
+            && self
+                .max_height
+                .map_or(true, |max_height| height <= max_height)
+    }
+}
+
+impl FromStr for MediaQuery {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut query = MediaQuery::default();
+
+        for condition in s.split(" and ") {
+            let condition = condition.trim();
+
+            if !condition.starts_with('(') || !condition.ends_with(')') {
+                return Err(format!("MediaQuery: missing parentheses in {}.", condition));
+            }
+
+            let mut parts = condition[1..condition.len() - 1].splitn(2, ':');
+            let key = parts.next().unwrap_or_default().trim();
+            let value = parts
+                .next()
+                .and_then(|value| value.trim().parse::<f64>().ok())
+                .ok_or_else(|| format!("MediaQuery: invalid value in {}.", condition))?;
+
+            match key {
+                "min_width" => query.min_width = Some(value),
+                "max_width" => query.max_width = Some(value),
+                "min_height" => query.min_height = Some(value),
+                "max_height" => query.max_height = Some(value),
+                _ => return Err(format!("MediaQuery: unknown key {}.", key)),
+            }
+        }
+
+        Ok(query)
+    }
+}
+
+impl fmt::Display for MediaQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let conditions: Vec<String> = [
+            ("min_width", self.min_width),
+            ("max_width", self.max_width),
+            ("min_height", self.min_height),
+            ("max_height", self.max_height),
+        ]
+        .iter()
+        .filter_map(|(key, value)| value.map(|value| format!("({}: {})", key, value)))
+        .collect();
+
+        write!(f, "{}", conditions.join(" and "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "(max_width: 600)".parse::<MediaQuery>(),
+            Ok(MediaQuery {
+                max_width: Some(600.0),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            "(min_width: 400) and (max_height: 300.5)".parse::<MediaQuery>(),
+            Ok(MediaQuery {
+                min_width: Some(400.0),
+                max_height: Some(300.5),
+                ..Default::default()
+            })
+        );
+        assert!("max_width: 600".parse::<MediaQuery>().is_err());
+        assert!("(max_width: big)".parse::<MediaQuery>().is_err());
+        assert!("(width: 600)".parse::<MediaQuery>().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let query = "(min_width: 400) and (max_height: 300.5)";
+
+        assert_eq!(query.parse::<MediaQuery>().unwrap().to_string(), query);
+    }
+
+    #[test]
+    fn test_matches() {
+        let query: MediaQuery = "(min_width: 400) and (max_width: 600)".parse().unwrap();
+
+        assert!(query.matches(400.0, 100.0));
+        assert!(query.matches(600.0, 1000.0));
+        assert!(!query.matches(399.0, 100.0));
+        assert!(!query.matches(601.0, 100.0));
+        assert!(MediaQuery::default().matches(0.0, 0.0));
+    }
+}
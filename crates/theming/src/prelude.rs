@@ -1 +1 @@
-pub use crate::{Selector, Style, Theme};
+pub use crate::{MediaQuery, PropertyType, Selector, Style, Theme, ThemeError};
@@ -6,8 +6,9 @@ pub struct Selector {
     /// Represents the key of a style.
     pub style: Option<String>,
 
-    /// Used to reference the state property list of the given style.
-    pub state: Option<String>,
+    /// Used to reference the state property lists of the given style. The states are stored in
+    /// the order they are set.
+    pub states: Vec<String>,
 
     /// Check if the selector is dirty.
     dirty: bool,
@@ -18,23 +19,47 @@ impl Selector {
     pub fn new(style: impl Into<String>) -> Self {
         Selector {
             style: Some(style.into()),
-            state: None,
+            states: vec![],
             dirty: true,
         }
     }
 
-    /// Set the current state of the selector.
+    /// Adds the given state to the current states of the selector.
     pub fn set_state(&mut self, state: impl Into<String>) {
-        self.state = Some(state.into());
+        let state = state.into();
+        self.states.retain(|s| *s != state);
+        self.states.push(state);
         self.dirty = true;
     }
 
-    /// Clears the current state and reset to default.
+    /// Removes the given state from the current states of the selector.
+    pub fn remove_state(&mut self, state: &str) {
+        if self.has_state(state) {
+            self.states.retain(|s| s != state);
+            self.dirty = true;
+        }
+    }
+
+    /// Clears all current states and reset to default.
     pub fn clear_state(&mut self) {
-        self.state = None;
+        self.states.clear();
         self.dirty = true;
     }
 
+    /// Returns the keys of the theme states that are looked up for the current states, ordered by
+    /// priority: the compound key of all states e.g. `"focused+hovered"` followed by the single
+    /// states from the last to the first set one.
+    pub fn state_keys(&self) -> Vec<String> {
+        let mut keys = vec![];
+
+        if self.states.len() > 1 {
+            keys.push(compound_state_key(&self.states.join("+")));
+        }
+
+        keys.extend(self.states.iter().rev().cloned());
+        keys
+    }
+
     /// Gets the dirty flag.
     pub fn dirty(&self) -> bool {
         self.dirty
@@ -47,16 +72,18 @@ impl Selector {
 
     /// Check if the selector has the given state.
     pub fn has_state(&self, state: &str) -> bool {
-        if let Some(st) = &self.state {
-            if st.as_str() == state {
-                return true;
-            }
-        }
-
-        return false;
+        self.states.iter().any(|s| s == state)
     }
 }
 
+/// Normalizes a state key of a theme. The parts of a compound key like `"hovered+focused"` are
+/// sorted, so the key is the same for each order of the states e.g. `"focused+hovered"`.
+pub fn compound_state_key(key: &str) -> String {
+    let mut states: Vec<&str> = key.split('+').map(str::trim).collect();
+    states.sort_unstable();
+    states.join("+")
+}
+
 impl fmt::Display for Selector {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(style) = &self.style {
@@ -77,3 +104,37 @@ impl From<String> for Selector {
         Selector::new(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_states() {
+        let mut selector = Selector::new("button");
+        assert!(selector.state_keys().is_empty());
+
+        selector.set_state("hovered");
+        selector.set_state("focused");
+        assert!(selector.has_state("hovered"));
+        assert!(selector.has_state("focused"));
+        assert_eq!(
+            selector.state_keys(),
+            vec!["focused+hovered", "focused", "hovered"]
+        );
+
+        selector.remove_state("focused");
+        assert!(!selector.has_state("focused"));
+        assert_eq!(selector.state_keys(), vec!["hovered"]);
+
+        selector.clear_state();
+        assert!(selector.states.is_empty());
+    }
+
+    #[test]
+    fn test_compound_state_key() {
+        assert_eq!(compound_state_key("hovered+focused"), "focused+hovered");
+        assert_eq!(compound_state_key("focused + hovered"), "focused+hovered");
+        assert_eq!(compound_state_key("pressed"), "pressed");
+    }
+}
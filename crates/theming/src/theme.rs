@@ -1,20 +1,23 @@
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap};
 
 use ron::Value;
 
 use crate::{
+    compound_state_key,
     config::{ThemeConfig, RESOURCE_KEY},
-    Selector, Style,
+    expected_type, MediaQuery, Selector, Style, ThemeError,
 };
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Theme {
     styles: HashMap<String, Style>,
+    media_errors: Vec<String>,
 }
 
 impl Theme {
     pub fn from_config(theme: ThemeConfig) -> Self {
         let mut styles = HashMap::new();
+        let mut media_errors = vec![];
 
         for style_key in theme.styles.keys() {
             let mut properties = HashMap::new();
@@ -27,41 +30,155 @@ impl Theme {
             if let Some(base) = theme.styles.get(&base_key) {
                 for state_key in base.states.keys() {
                     let mut state = HashMap::new();
-                    Theme::read_states(&base_key, state_key, &theme, &mut state);
-                    states.insert(state_key.clone(), state);
+                    Theme::read_states(style_key, state_key, &theme, &mut state);
+                    states.insert(compound_state_key(state_key), state);
                 }
             }
 
             for state_key in theme.styles.get(style_key).unwrap().states.keys() {
                 let mut state = HashMap::new();
                 Theme::read_states(style_key, state_key, &theme, &mut state);
-                states.insert(state_key.clone(), state);
+                states.insert(compound_state_key(state_key), state);
             }
 
-            styles.insert(style_key.clone(), Style { properties, states });
+            let mut media = vec![];
+            Theme::read_media(style_key, &theme, &mut media, &mut media_errors);
+
+            styles.insert(
+                style_key.clone(),
+                Style {
+                    properties,
+                    states,
+                    media,
+                },
+            );
         }
 
-        Theme { styles }
+        // the media queries of a base style are read again for each derived style
+        media_errors.sort();
+        media_errors.dedup();
+
+        Theme {
+            styles,
+            media_errors,
+        }
     }
 
     pub fn style(&self, key: &str) -> Option<&Style> {
         self.styles.get(key)
     }
 
-    pub fn properties<'a>(&'a self, selector: &Selector) -> Option<&'a HashMap<String, Value>> {
+    /// Gets the properties of the given selector. The properties of all current states override
+    /// the default properties of the style and properties of media queries that match the given
+    /// window size override the other properties.
+    pub fn properties<'a>(
+        &'a self,
+        selector: &Selector,
+        window_size: (f64, f64),
+    ) -> Option<Cow<'a, HashMap<String, Value>>> {
         if !selector.dirty() {
             return None;
         }
 
-        if let Some(style) = &selector.style {
-            if let Some(state) = &selector.state {
-                return self.styles.get(style)?.states.get(state);
+        let style = self.styles.get(selector.style.as_ref()?)?;
+
+        let mut properties = Cow::Borrowed(&style.properties);
+
+        // the properties of the states are merged from the least to the most specific state, so
+        // e.g. `focused+hovered` overrides `hovered` and `hovered` overrides the default properties
+        for key in selector.state_keys().iter().rev() {
+            if let Some(state_properties) = style.states.get(key) {
+                properties.to_mut().extend(
+                    state_properties
+                        .iter()
+                        .map(|(key, value)| (key.clone(), value.clone())),
+                );
             }
+        }
+
+        for (query, media_properties) in &style.media {
+            if query.matches(window_size.0, window_size.1) {
+                properties.to_mut().extend(
+                    media_properties
+                        .iter()
+                        .map(|(key, value)| (key.clone(), value.clone())),
+                );
+            }
+        }
+
+        Some(properties)
+    }
+
+    /// Checks if a media query of the theme matches only one of the given window sizes. If so the
+    /// theme properties of the widgets should be updated.
+    pub fn media_changed(&self, old_size: (f64, f64), new_size: (f64, f64)) -> bool {
+        self.styles.values().any(|style| {
+            style.media.iter().any(|(query, _)| {
+                query.matches(old_size.0, old_size.1) != query.matches(new_size.0, new_size.1)
+            })
+        })
+    }
+
+    /// Checks the values of all properties against their expected types and returns a list of
+    /// the properties with invalid values. Properties without a known type are not checked.
+    pub fn validate(&self) -> Vec<ThemeError> {
+        let mut errors = vec![];
+
+        for (style_key, style) in &self.styles {
+            let maps = std::iter::once((None, &style.properties))
+                .chain(
+                    style
+                        .states
+                        .iter()
+                        .map(|(state, properties)| (Some(state.clone()), properties)),
+                )
+                .chain(
+                    style
+                        .media
+                        .iter()
+                        .map(|(query, properties)| (Some(query.to_string()), properties)),
+                );
+
+            for (state, properties) in maps {
+                for (property, value) in properties {
+                    if let Some(expected) = expected_type(property) {
+                        if !expected.matches(value) {
+                            errors.push(ThemeError {
+                                style: style_key.clone(),
+                                state: state.clone(),
+                                property: property.clone(),
+                                expected,
+                                value: value.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        errors.sort_by(|a, b| {
+            (&a.style, &a.state, &a.property).cmp(&(&b.style, &b.state, &b.property))
+        });
+
+        errors
+    }
+
+    /// Returns the media queries of the theme that could not be parsed. The properties of these
+    /// queries are never used.
+    pub fn media_errors(&self) -> &[String] {
+        &self.media_errors
+    }
+
+    /// Like `validate` but returns an error if the theme contains invalid values, e.g. to check
+    /// theme files in an asset pipeline.
+    pub fn validate_strict(&self) -> Result<(), Vec<ThemeError>> {
+        let errors = self.validate();
 
-            return Some(&self.styles.get(style)?.properties);
+        if errors.is_empty() {
+            return Ok(());
         }
 
-        return None;
+        Err(errors)
     }
 
     fn read_properties(key: &String, theme: &ThemeConfig, properties: &mut HashMap<String, Value>) {
@@ -78,6 +195,43 @@ impl Theme {
         }
     }
 
+    fn read_media(
+        key: &String,
+        theme: &ThemeConfig,
+        media: &mut Vec<(MediaQuery, HashMap<String, Value>)>,
+        errors: &mut Vec<String>,
+    ) {
+        if key.is_empty() {
+            return;
+        }
+
+        if let Some(style) = theme.styles.get(key) {
+            Theme::read_media(&style.base, theme, media, errors);
+
+            // sorted to get the same order of overrides on each run
+            let mut queries: Vec<&String> = style.media.keys().collect();
+            queries.sort();
+
+            for query in queries {
+                let media_query = match query.parse::<MediaQuery>() {
+                    Ok(media_query) => media_query,
+                    Err(error) => {
+                        errors.push(format!("Theme: style {}: {}", key, error));
+                        continue;
+                    }
+                };
+
+                let mut properties = HashMap::new();
+
+                for (key, value) in &style.media[query] {
+                    Theme::read_property(key, value, theme, &mut properties);
+                }
+
+                media.push((media_query, properties));
+            }
+        }
+    }
+
     fn read_states(
         style_key: &String,
         state_key: &String,
@@ -88,10 +242,23 @@ impl Theme {
             return;
         }
 
+        // only the properties that the state changes are stored, they are merged with the
+        // properties of the style and of the other states by `properties`
+        Theme::read_state_properties(style_key, state_key, theme, states);
+    }
+
+    fn read_state_properties(
+        style_key: &String,
+        state_key: &String,
+        theme: &ThemeConfig,
+        states: &mut HashMap<String, Value>,
+    ) {
+        if style_key.is_empty() {
+            return;
+        }
+
         if let Some(style) = theme.styles.get(style_key) {
-            for (key, value) in &style.properties {
-                Theme::read_property(key, value, theme, states);
-            }
+            Theme::read_state_properties(&style.base, state_key, theme, states);
 
             if let Some(state) = style.states.get(state_key) {
                 for (key, value) in state {
@@ -120,3 +287,343 @@ impl Theme {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PropertyType;
+
+    const THEME: &str = r##"
+        Theme (
+            styles: {
+                "body": (
+                    properties: {
+                        "font_size": 16.0,
+                        "foreground": "$FOREGROUND",
+                    },
+                    media: {
+                        "(max_width: 600)": {
+                            "font_size": 12.0,
+                        },
+                    },
+                ),
+                "header": (
+                    base: "body",
+                    media: {
+                        "(max_width: 400)": {
+                            "font_size": 10.0,
+                        },
+                    },
+                ),
+            },
+            resources: {
+                "FOREGROUND": "#ffffff",
+            },
+        )
+    "##;
+
+    fn font_size(theme: &Theme, style: &str, window_size: (f64, f64)) -> f64 {
+        theme
+            .properties(&Selector::new(style), window_size)
+            .unwrap()
+            .get("font_size")
+            .unwrap()
+            .clone()
+            .into_rust::<f64>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_media_properties() {
+        let theme = Theme::from_config(ThemeConfig::from(THEME));
+
+        assert_eq!(font_size(&theme, "body", (800.0, 600.0)), 16.0);
+        assert_eq!(font_size(&theme, "body", (600.0, 600.0)), 12.0);
+        assert_eq!(font_size(&theme, "header", (500.0, 600.0)), 12.0);
+        assert_eq!(font_size(&theme, "header", (300.0, 600.0)), 10.0);
+    }
+
+    #[test]
+    fn test_validate() {
+        let theme = Theme::from_config(ThemeConfig::from(THEME));
+        assert!(theme.validate_strict().is_ok());
+
+        let theme = Theme::from_config(ThemeConfig::from(
+            r#"
+            Theme (
+                styles: {
+                    "body": (
+                        properties: {
+                            "font_size": "big",
+                            "unknown": 1.0,
+                        },
+                        states: {
+                            "pressed": {
+                                "background": 2.0,
+                            },
+                        },
+                    ),
+                },
+            )
+            "#,
+        ));
+
+        let errors = theme.validate();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].property, "font_size");
+        assert_eq!(errors[0].state, None);
+        assert_eq!(errors[0].expected, PropertyType::F64);
+        assert_eq!(errors[1].property, "background");
+        assert_eq!(errors[1].state, Some("pressed".to_string()));
+        assert!(theme.validate_strict().is_err());
+    }
+
+    #[test]
+    fn test_validate_font_fallbacks() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r#"
+            Theme (
+                styles: {
+                    "base": (
+                        properties: {
+                            "font_fallbacks": ["NotoSansCJK-Regular", "NotoSansArabic-Regular"],
+                        },
+                    ),
+                    "body": (
+                        properties: {
+                            "font_fallbacks": "NotoSansCJK-Regular",
+                        },
+                    ),
+                },
+            )
+            "#,
+        ));
+
+        let errors = theme.validate();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].style, "body");
+        assert_eq!(errors[0].expected, PropertyType::StringList);
+    }
+
+    #[test]
+    fn test_media_errors() {
+        let theme = Theme::from_config(ThemeConfig::from(THEME));
+        assert!(theme.media_errors().is_empty());
+
+        let theme = Theme::from_config(ThemeConfig::from(
+            r#"
+            Theme (
+                styles: {
+                    "body": (
+                        media: {
+                            "(width: 600)": {
+                                "font_size": 12.0,
+                            },
+                        },
+                    ),
+                    "header": (
+                        base: "body",
+                    ),
+                },
+            )
+            "#,
+        ));
+
+        assert_eq!(
+            theme.media_errors(),
+            &["Theme: style body: MediaQuery: unknown key width.".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_media_changed() {
+        let theme = Theme::from_config(ThemeConfig::from(THEME));
+
+        assert!(!theme.media_changed((800.0, 600.0), (700.0, 300.0)));
+        assert!(theme.media_changed((800.0, 600.0), (500.0, 600.0)));
+        assert!(theme.media_changed((500.0, 600.0), (300.0, 600.0)));
+    }
+
+    #[test]
+    fn test_compound_state_properties() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r#"
+            Theme (
+                styles: {
+                    "button": (
+                        properties: {
+                            "font_size": 12.0,
+                        },
+                        states: {
+                            "focused": {
+                                "font_size": 14.0,
+                            },
+                            "hovered+focused": {
+                                "font_size": 16.0,
+                            },
+                        },
+                    ),
+                },
+            )
+            "#,
+        ));
+
+        let font_size = |selector: &Selector| {
+            theme
+                .properties(selector, (800.0, 600.0))
+                .unwrap()
+                .get("font_size")
+                .unwrap()
+                .clone()
+                .into_rust::<f64>()
+                .unwrap()
+        };
+
+        let mut selector = Selector::new("button");
+        assert_eq!(font_size(&selector), 12.0);
+
+        selector.set_state("focused");
+        assert_eq!(font_size(&selector), 14.0);
+
+        selector.set_state("hovered");
+        assert_eq!(font_size(&selector), 16.0);
+
+        // a state without an entry falls back to the default properties
+        selector.remove_state("focused");
+        assert_eq!(font_size(&selector), 12.0);
+    }
+
+    #[test]
+    fn test_merge_state_properties() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r##"
+            Theme (
+                styles: {
+                    "button": (
+                        properties: {
+                            "font_size": 12.0,
+                            "background": "#000000",
+                            "foreground": "#ffffff",
+                        },
+                        states: {
+                            "focused": {
+                                "background": "#ff0000",
+                                "foreground": "#00ff00",
+                            },
+                            "hovered": {
+                                "font_size": 14.0,
+                                "foreground": "#0000ff",
+                            },
+                        },
+                    ),
+                },
+            )
+            "##,
+        ));
+
+        let mut selector = Selector::new("button");
+        selector.set_state("focused");
+        selector.set_state("hovered");
+
+        let properties = theme.properties(&selector, (800.0, 600.0)).unwrap();
+        let property = |key: &str| properties.get(key).cloned().unwrap();
+
+        assert_eq!(property("font_size").into_rust::<f64>().unwrap(), 14.0);
+        assert_eq!(
+            property("background").into_rust::<String>().unwrap(),
+            "#ff0000"
+        );
+
+        // the last set state is the most specific one
+        assert_eq!(
+            property("foreground").into_rust::<String>().unwrap(),
+            "#0000ff"
+        );
+    }
+
+    #[test]
+    fn test_state_properties_of_derived_style() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r##"
+            Theme (
+                styles: {
+                    "base": (
+                        properties: {
+                            "font_size": 12.0,
+                            "foreground": "$FOREGROUND",
+                        },
+                        states: {
+                            "pressed": {
+                                "font_size": 13.0,
+                            },
+                        },
+                    ),
+                    "button": (
+                        base: "base",
+                        properties: {
+                            "background": "#000000",
+                        },
+                        states: {
+                            "focused": {
+                                "background": "$ACCENT",
+                            },
+                        },
+                    ),
+                },
+                resources: {
+                    "FOREGROUND": "#ffffff",
+                    "ACCENT": "#ff0000",
+                },
+            )
+            "##,
+        ));
+
+        let property = |selector: &Selector, key: &str| {
+            theme
+                .properties(selector, (800.0, 600.0))
+                .unwrap()
+                .get(key)
+                .cloned()
+                .and_then(|value| value.into_rust::<String>().ok())
+        };
+
+        let mut selector = Selector::new("button");
+        selector.set_state("focused");
+
+        let properties = theme.properties(&selector, (800.0, 600.0)).unwrap();
+        assert_eq!(properties.len(), 3);
+        assert_eq!(
+            property(&selector, "background"),
+            Some("#ff0000".to_string())
+        );
+        assert_eq!(
+            property(&selector, "foreground"),
+            Some("#ffffff".to_string())
+        );
+
+        let mut selector = Selector::new("button");
+        selector.set_state("pressed");
+
+        assert_eq!(
+            property(&selector, "background"),
+            Some("#000000".to_string())
+        );
+        assert_eq!(
+            property(&selector, "foreground"),
+            Some("#ffffff".to_string())
+        );
+        assert_eq!(
+            theme
+                .properties(&selector, (800.0, 600.0))
+                .unwrap()
+                .get("font_size")
+                .unwrap()
+                .clone()
+                .into_rust::<f64>()
+                .unwrap(),
+            13.0
+        );
+    }
+}
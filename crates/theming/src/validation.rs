@@ -0,0 +1,116 @@
+use std::fmt;
+
+use ron::Value;
+
+/// Describes the type that is expected for the value of a theme property.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PropertyType {
+    F64,
+    F32,
+    String,
+    Brush,
+    Thickness,
+    StringList,
+}
+
+impl PropertyType {
+    /// Checks if the given ron value could be read as this type.
+    pub fn matches(self, value: &Value) -> bool {
+        match self {
+            PropertyType::F64 | PropertyType::F32 => matches!(value, Value::Number(_)),
+            PropertyType::String | PropertyType::Brush => matches!(value, Value::String(_)),
+            PropertyType::Thickness => matches!(value, Value::Number(_) | Value::Map(_)),
+            PropertyType::StringList => match value {
+                Value::Seq(values) => values.iter().all(|v| matches!(v, Value::String(_))),
+                _ => false,
+            },
+        }
+    }
+}
+
+impl fmt::Display for PropertyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            PropertyType::F64 => "f64",
+            PropertyType::F32 => "f32",
+            PropertyType::String => "string",
+            PropertyType::Brush => "brush (color string)",
+            PropertyType::Thickness => "thickness (number or map)",
+            PropertyType::StringList => "list of strings",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Expected value types of the properties that are read from a theme.
+pub static EXPECTED_TYPES: &[(&str, PropertyType)] = &[
+    ("foreground", PropertyType::Brush),
+    ("background", PropertyType::Brush),
+    ("icon_brush", PropertyType::Brush),
+    ("border_brush", PropertyType::Brush),
+    ("font_size", PropertyType::F64),
+    ("icon_size", PropertyType::F64),
+    ("spacing", PropertyType::F64),
+    ("border_radius", PropertyType::F64),
+    ("padding", PropertyType::Thickness),
+    ("border_width", PropertyType::Thickness),
+    ("padding_left", PropertyType::F64),
+    ("padding_top", PropertyType::F64),
+    ("padding_right", PropertyType::F64),
+    ("padding_bottom", PropertyType::F64),
+    ("font_family", PropertyType::String),
+    ("icon_family", PropertyType::String),
+    ("font_fallbacks", PropertyType::StringList),
+    ("opacity", PropertyType::F32),
+    ("width", PropertyType::F64),
+    ("height", PropertyType::F64),
+    ("min_width", PropertyType::F64),
+    ("min_height", PropertyType::F64),
+    ("max_width", PropertyType::F64),
+    ("max_height", PropertyType::F64),
+];
+
+/// Returns the expected value type of the given theme property.
+pub fn expected_type(property: &str) -> Option<PropertyType> {
+    EXPECTED_TYPES
+        .iter()
+        .find(|(key, _)| *key == property)
+        .map(|(_, property_type)| *property_type)
+}
+
+/// Describes a theme property with a value of an unexpected type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeError {
+    /// Key of the style that contains the property.
+    pub style: String,
+
+    /// Key of the state or media query that contains the property. `None` for the base properties
+    /// of the style.
+    pub state: Option<String>,
+
+    /// Name of the property.
+    pub property: String,
+
+    /// The expected type of the value.
+    pub expected: PropertyType,
+
+    /// The invalid value.
+    pub value: Value,
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Theme: property {} of style {}",
+            self.property, self.style
+        )?;
+
+        if let Some(state) = &self.state {
+            write!(f, " ({})", state)?;
+        }
+
+        write!(f, " expects {} but is {:?}.", self.expected, self.value)
+    }
+}
@@ -19,7 +19,10 @@ tree.append_child(0, 1);
 
  */
 
-use std::{cell::Cell, collections::BTreeMap};
+use std::{
+    cell::Cell,
+    collections::{BTreeMap, VecDeque},
+};
 
 use dces::{entity::EntityStore, prelude::Entity};
 
@@ -130,6 +133,69 @@ impl Tree {
         Ok(child)
     }
 
+    /// Moves the `child` entity of the given `parent` to the given `index` of its children. An
+    /// index that is out of bounds moves the child to the end.
+    /// Raised `NotFound` error if the parent is not part of the tree or `child` is not a child of it.
+    pub fn move_child(
+        &mut self,
+        parent: impl Into<Entity>,
+        child: impl Into<Entity>,
+        index: usize,
+    ) -> Result<Entity, NotFound> {
+        let parent = parent.into();
+        let child = child.into();
+
+        let children = self
+            .children
+            .get_mut(&parent)
+            .ok_or(NotFound::Parent(parent))?;
+        let old_index = children
+            .iter()
+            .position(|c| *c == child)
+            .ok_or(NotFound::Child(child))?;
+
+        children.remove(old_index);
+        children.insert(index.min(children.len()), child);
+
+        Ok(child)
+    }
+
+    /// Returns an iterator over all descendants of the given `entity` in depth-first pre-order.
+    /// The `entity` itself is not part of the iteration.
+    pub fn walk_children(&self, entity: impl Into<Entity>) -> WalkChildren<'_> {
+        let mut stack = vec![];
+
+        if let Some(children) = self.children.get(&entity.into()) {
+            stack.extend(children.iter().rev());
+        }
+
+        WalkChildren { tree: self, stack }
+    }
+
+    /// Returns an iterator over all descendants of the given `entity` in breadth-first order.
+    /// The `entity` itself is not part of the iteration.
+    pub fn walk_children_breadth_first(
+        &self,
+        entity: impl Into<Entity>,
+    ) -> WalkChildrenBreadthFirst<'_> {
+        let mut queue = VecDeque::new();
+
+        if let Some(children) = self.children.get(&entity.into()) {
+            queue.extend(children.iter());
+        }
+
+        WalkChildrenBreadthFirst { tree: self, queue }
+    }
+
+    /// Returns an iterator over all ancestors of the given `entity` starting with its parent
+    /// and ending with the root.
+    pub fn walk_ancestors(&self, entity: impl Into<Entity>) -> WalkAncestors<'_> {
+        WalkAncestors {
+            tree: self,
+            current_node: entity.into(),
+        }
+    }
+
     /// Returns the number of all entities in the tree.
     pub fn len(&self) -> usize {
         self.children.len()
@@ -234,6 +300,62 @@ impl<'a> Iterator for TreeIterator<'a> {
     }
 }
 
+/// Iterates over the descendants of an entity in depth-first pre-order.
+pub struct WalkChildren<'a> {
+    tree: &'a Tree,
+    stack: Vec<Entity>,
+}
+
+impl<'a> Iterator for WalkChildren<'a> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Entity> {
+        let node = self.stack.pop()?;
+
+        if let Some(children) = self.tree.children.get(&node) {
+            self.stack.extend(children.iter().rev());
+        }
+
+        Some(node)
+    }
+}
+
+/// Iterates over the descendants of an entity in breadth-first order.
+pub struct WalkChildrenBreadthFirst<'a> {
+    tree: &'a Tree,
+    queue: VecDeque<Entity>,
+}
+
+impl<'a> Iterator for WalkChildrenBreadthFirst<'a> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Entity> {
+        let node = self.queue.pop_front()?;
+
+        if let Some(children) = self.tree.children.get(&node) {
+            self.queue.extend(children.iter());
+        }
+
+        Some(node)
+    }
+}
+
+/// Iterates over the ancestors of an entity up to the root.
+pub struct WalkAncestors<'a> {
+    tree: &'a Tree,
+    current_node: Entity,
+}
+
+impl<'a> Iterator for WalkAncestors<'a> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Entity> {
+        let parent = (*self.tree.parent.get(&self.current_node)?)?;
+        self.current_node = parent;
+        Some(parent)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use dces::entity::EntityStore;
@@ -267,6 +389,43 @@ mod tests {
         assert_eq!(tree.parent.get(&child).unwrap().unwrap(), parent);
     }
 
+    #[test]
+    fn test_move_child() {
+        let parent = Entity(0);
+
+        let mut tree = Tree::new();
+        tree.register_node(parent);
+
+        for child in 1..4 {
+            tree.register_node(child);
+            tree.append_child(parent, child).unwrap();
+        }
+
+        tree.move_child(parent, 1, 2).unwrap();
+        assert_eq!(
+            tree.children[&parent],
+            vec![Entity(2), Entity(3), Entity(1)]
+        );
+
+        tree.move_child(parent, 1, 0).unwrap();
+        assert_eq!(
+            tree.children[&parent],
+            vec![Entity(1), Entity(2), Entity(3)]
+        );
+
+        tree.move_child(parent, 2, 10).unwrap();
+        assert_eq!(
+            tree.children[&parent],
+            vec![Entity(1), Entity(3), Entity(2)]
+        );
+
+        assert_eq!(tree.move_child(4, 1, 0), Err(NotFound::Parent(Entity(4))));
+        assert_eq!(
+            tree.move_child(parent, 4, 0),
+            Err(NotFound::Child(Entity(4)))
+        );
+    }
+
     #[test]
     fn test_len() {
         let mut tree = Tree::new();
@@ -343,4 +502,92 @@ mod tests {
         assert_eq!(Entity(5), iterator.next().unwrap());
         assert_eq!(Entity(6), iterator.next().unwrap());
     }
+
+    // 0
+    // ├── 1
+    // │   ├── 3
+    // │   └── 4
+    // │       └── 7
+    // └── 2
+    //     ├── 5
+    //     └── 6
+    fn walk_tree() -> Tree {
+        let mut tree = Tree::new();
+
+        for i in 0..8 {
+            tree.register_entity(i);
+        }
+
+        tree.append_child(0, 1).unwrap();
+        tree.append_child(0, 2).unwrap();
+        tree.append_child(1, 3).unwrap();
+        tree.append_child(1, 4).unwrap();
+        tree.append_child(4, 7).unwrap();
+        tree.append_child(2, 5).unwrap();
+        tree.append_child(2, 6).unwrap();
+
+        tree
+    }
+
+    fn walk(iterator: impl Iterator<Item = Entity>) -> Vec<Entity> {
+        iterator.collect()
+    }
+
+    #[test]
+    fn test_walk_children() {
+        let tree = walk_tree();
+
+        assert_eq!(
+            walk(tree.walk_children(0)),
+            vec![
+                Entity(1),
+                Entity(3),
+                Entity(4),
+                Entity(7),
+                Entity(2),
+                Entity(5),
+                Entity(6)
+            ]
+        );
+        assert_eq!(
+            walk(tree.walk_children(1)),
+            vec![Entity(3), Entity(4), Entity(7)]
+        );
+        assert!(tree.walk_children(7).next().is_none());
+        assert!(tree.walk_children(42).next().is_none());
+    }
+
+    #[test]
+    fn test_walk_children_breadth_first() {
+        let tree = walk_tree();
+
+        assert_eq!(
+            walk(tree.walk_children_breadth_first(0)),
+            vec![
+                Entity(1),
+                Entity(2),
+                Entity(3),
+                Entity(4),
+                Entity(5),
+                Entity(6),
+                Entity(7)
+            ]
+        );
+        assert_eq!(
+            walk(tree.walk_children_breadth_first(2)),
+            vec![Entity(5), Entity(6)]
+        );
+    }
+
+    #[test]
+    fn test_walk_ancestors() {
+        let tree = walk_tree();
+
+        assert_eq!(
+            walk(tree.walk_ancestors(7)),
+            vec![Entity(4), Entity(1), Entity(0)]
+        );
+        assert!(tree.walk_ancestors(0).next().is_none());
+        assert!(tree.walk_ancestors(42).next().is_none());
+    }
 }
@@ -0,0 +1,49 @@
+/// Is used to control how the children of a widget with an opacity below `1.0` are drawn.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CompositeMode {
+    /// Uses `Group` if the children of the widget overlap each other, otherwise `Individual`.
+    Auto,
+
+    /// Each child is drawn with the opacity of the widget, overlapping children show through
+    /// each other.
+    Individual,
+
+    /// The widget and its children are drawn together into an offscreen buffer that is drawn
+    /// with the opacity of the widget.
+    Group,
+}
+
+impl Default for CompositeMode {
+    fn default() -> CompositeMode {
+        CompositeMode::Auto
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for CompositeMode {
+    fn from(t: &str) -> Self {
+        match t {
+            "Individual" | "individual" => CompositeMode::Individual,
+            "Group" | "group" => CompositeMode::Group,
+            _ => CompositeMode::Auto,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let composite_mode: CompositeMode = "Individual".into();
+        assert_eq!(composite_mode, CompositeMode::Individual);
+
+        let composite_mode: CompositeMode = "group".into();
+        assert_eq!(composite_mode, CompositeMode::Group);
+
+        let composite_mode: CompositeMode = "other".into();
+        assert_eq!(composite_mode, CompositeMode::Auto);
+    }
+}
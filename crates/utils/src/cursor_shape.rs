@@ -0,0 +1,82 @@
+/// Describes the appearance of the mouse cursor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CursorShape {
+    /// The default cursor of the operating system, usually an arrow.
+    Default,
+
+    /// A pointing hand, used for links and clickable elements.
+    Pointer,
+
+    /// A text cursor (I-beam).
+    Text,
+
+    /// A crosshair.
+    Crosshair,
+
+    /// An open hand, indicates that an element could be dragged.
+    Grab,
+
+    /// A closed hand, indicates that an element is dragged.
+    Grabbing,
+
+    /// Horizontal resize arrows.
+    ResizeH,
+
+    /// Vertical resize arrows.
+    ResizeV,
+
+    /// Diagonal resize arrows from north west to south east.
+    ResizeNWSE,
+
+    /// Diagonal resize arrows from north east to south west.
+    ResizeNESW,
+
+    /// Indicates that the action is not allowed.
+    NotAllowed,
+}
+
+impl Default for CursorShape {
+    fn default() -> CursorShape {
+        CursorShape::Default
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for CursorShape {
+    fn from(t: &str) -> Self {
+        match t {
+            "Pointer" | "pointer" => CursorShape::Pointer,
+            "Text" | "text" => CursorShape::Text,
+            "Crosshair" | "crosshair" => CursorShape::Crosshair,
+            "Grab" | "grab" => CursorShape::Grab,
+            "Grabbing" | "grabbing" => CursorShape::Grabbing,
+            "ResizeH" | "resize_h" => CursorShape::ResizeH,
+            "ResizeV" | "resize_v" => CursorShape::ResizeV,
+            "ResizeNWSE" | "resize_nwse" => CursorShape::ResizeNWSE,
+            "ResizeNESW" | "resize_nesw" => CursorShape::ResizeNESW,
+            "NotAllowed" | "not_allowed" => CursorShape::NotAllowed,
+            _ => CursorShape::Default,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let cursor_shape: CursorShape = "Text".into();
+        assert_eq!(cursor_shape, CursorShape::Text);
+
+        let cursor_shape: CursorShape = "grabbing".into();
+        assert_eq!(cursor_shape, CursorShape::Grabbing);
+
+        let cursor_shape: CursorShape = "resize_nwse".into();
+        assert_eq!(cursor_shape, CursorShape::ResizeNWSE);
+
+        let cursor_shape: CursorShape = "other".into();
+        assert_eq!(cursor_shape, CursorShape::Default);
+    }
+}
@@ -1,9 +1,26 @@
+/// Describes why a `DirtySize` is dirty.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DirtyReason {
+    /// The size or a property of the widget itself is changed.
+    pub self_dirty: bool,
+
+    /// The desired size of a child is changed.
+    pub child_dirty: bool,
+}
+
+impl DirtyReason {
+    /// Returns `true` if the widget itself or one of its children is dirty.
+    pub fn is_dirty(&self) -> bool {
+        self.self_dirty || self.child_dirty
+    }
+}
+
 /// Size with width, height and dirty flag.
 #[derive(Copy, Clone, PartialEq)]
 pub struct DirtySize {
     width: f64,
     height: f64,
-    dirty: bool,
+    reason: DirtyReason,
 }
 
 impl Default for DirtySize {
@@ -11,7 +28,10 @@ impl Default for DirtySize {
         DirtySize {
             width: 0.0,
             height: 0.0,
-            dirty: true,
+            reason: DirtyReason {
+                self_dirty: true,
+                child_dirty: false,
+            },
         }
     }
 }
@@ -28,7 +48,7 @@ impl DirtySize {
 
     pub fn set_width(&mut self, width: f64) {
         if (self.width - width).abs() > std::f64::EPSILON {
-            self.dirty = true;
+            self.reason.self_dirty = true;
         }
 
         self.width = width;
@@ -40,7 +60,7 @@ impl DirtySize {
 
     pub fn set_height(&mut self, height: f64) {
         if (self.height - height).abs() > std::f64::EPSILON {
-            self.dirty = true;
+            self.reason.self_dirty = true;
         }
 
         self.height = height;
@@ -54,21 +74,36 @@ impl DirtySize {
         if (self.width - width).abs() > std::f64::EPSILON
             && (self.height - height).abs() > std::f64::EPSILON
         {
-            self.dirty = true
+            self.reason.self_dirty = true;
         }
 
         self.width = width;
         self.height = height;
     }
 
-    /// Gets the dirty flag.
+    /// Gets the dirty flag. Is `true` if the widget itself or one of its children is dirty.
     pub fn dirty(&self) -> bool {
-        self.dirty
+        self.reason.is_dirty()
     }
 
-    /// Sets the dirty flag.
+    /// Marks the widget itself as dirty. `false` resets the dirty flag of the widget and of its
+    /// children.
     pub fn set_dirty(&mut self, dirty: bool) {
-        self.dirty = dirty;
+        self.reason.self_dirty = dirty;
+
+        if !dirty {
+            self.reason.child_dirty = false;
+        }
+    }
+
+    /// Marks the size as dirty because the desired size of a child is changed.
+    pub fn set_child_dirty(&mut self, dirty: bool) {
+        self.reason.child_dirty = dirty;
+    }
+
+    /// Gets the reason why the size is dirty.
+    pub fn reason(&self) -> DirtyReason {
+        self.reason
     }
 }
 
@@ -119,4 +154,26 @@ mod tests {
 
         assert!(!dirty_size.dirty());
     }
+
+    #[test]
+    fn test_reason() {
+        let mut dirty_size = DirtySize::default();
+        dirty_size.set_dirty(false);
+
+        dirty_size.set_child_dirty(true);
+        assert!(dirty_size.dirty());
+        assert_eq!(
+            dirty_size.reason(),
+            DirtyReason {
+                self_dirty: false,
+                child_dirty: true
+            }
+        );
+
+        dirty_size.set_width(10.0);
+        assert!(dirty_size.reason().self_dirty);
+
+        dirty_size.set_dirty(false);
+        assert_eq!(dirty_size.reason(), DirtyReason::default());
+    }
 }
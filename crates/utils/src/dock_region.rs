@@ -0,0 +1,55 @@
+/// Describes the edge of a dock a child is docked to.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DockRegion {
+    /// Docks the child to the top edge with the full remaining width.
+    Top,
+
+    /// Docks the child to the bottom edge with the full remaining width.
+    Bottom,
+
+    /// Docks the child to the left edge with the full remaining height.
+    Left,
+
+    /// Docks the child to the right edge with the full remaining height.
+    Right,
+
+    /// Places the child in the space that is left after docking the other children.
+    Fill,
+}
+
+impl Default for DockRegion {
+    fn default() -> DockRegion {
+        DockRegion::Fill
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for DockRegion {
+    fn from(t: &str) -> Self {
+        match t {
+            "Top" | "top" => DockRegion::Top,
+            "Bottom" | "bottom" => DockRegion::Bottom,
+            "Left" | "left" => DockRegion::Left,
+            "Right" | "right" => DockRegion::Right,
+            _ => DockRegion::Fill,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let dock_region: DockRegion = "Top".into();
+        assert_eq!(dock_region, DockRegion::Top);
+
+        let dock_region: DockRegion = "right".into();
+        assert_eq!(dock_region, DockRegion::Right);
+
+        let dock_region: DockRegion = "other".into();
+        assert_eq!(dock_region, DockRegion::Fill);
+    }
+}
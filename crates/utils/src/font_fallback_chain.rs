@@ -0,0 +1,80 @@
+/// Describes the font families that are used to draw a text. Characters that are not covered by
+/// the `primary` font are drawn with the first font of `fallbacks` that covers them.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct FontFallbackChain {
+    pub primary: String,
+    pub fallbacks: Vec<String>,
+}
+
+impl FontFallbackChain {
+    /// Creates a new chain with the given primary font and without fallbacks.
+    pub fn new(primary: impl Into<String>) -> Self {
+        FontFallbackChain {
+            primary: primary.into(),
+            fallbacks: vec![],
+        }
+    }
+
+    /// Builder method that appends a fallback font to the chain.
+    pub fn fallback(mut self, family: impl Into<String>) -> Self {
+        self.fallbacks.push(family.into());
+        self
+    }
+
+    /// Returns the primary font followed by the fallbacks.
+    pub fn families(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.primary.as_str()).chain(self.fallbacks.iter().map(|f| f.as_str()))
+    }
+}
+
+impl From<&str> for FontFallbackChain {
+    fn from(s: &str) -> Self {
+        FontFallbackChain::new(s)
+    }
+}
+
+impl From<String> for FontFallbackChain {
+    fn from(s: String) -> Self {
+        FontFallbackChain::new(s)
+    }
+}
+
+impl From<&String> for FontFallbackChain {
+    fn from(s: &String) -> Self {
+        FontFallbackChain::new(s.as_str())
+    }
+}
+
+impl From<(String, Vec<String>)> for FontFallbackChain {
+    fn from(t: (String, Vec<String>)) -> Self {
+        FontFallbackChain {
+            primary: t.0,
+            fallbacks: t.1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_families() {
+        let chain = FontFallbackChain::new("Roboto-Regular")
+            .fallback("NotoSansCJK-Regular")
+            .fallback("NotoSansArabic-Regular");
+
+        assert_eq!(
+            chain.families().collect::<Vec<&str>>(),
+            vec![
+                "Roboto-Regular",
+                "NotoSansCJK-Regular",
+                "NotoSansArabic-Regular"
+            ]
+        );
+        assert_eq!(
+            FontFallbackChain::from("Roboto-Regular").families().count(),
+            1
+        );
+    }
+}
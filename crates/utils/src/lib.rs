@@ -2,10 +2,16 @@ pub use self::alignment::*;
 pub use self::border::*;
 pub use self::brush::*;
 pub use self::color::*;
+pub use self::composite_mode::*;
+pub use self::cursor_shape::*;
 pub use self::dirty_size::*;
+pub use self::dock_region::*;
 pub use self::filter::*;
+pub use self::font_fallback_chain::*;
 pub use self::orientation::*;
 pub use self::point::*;
+pub use self::popup_alignment::*;
+pub use self::popup_side::*;
 pub use self::rectangle::*;
 pub use self::selection_mode::*;
 pub use self::string16::*;
@@ -19,10 +25,16 @@ mod alignment;
 mod border;
 mod brush;
 mod color;
+mod composite_mode;
+mod cursor_shape;
 mod dirty_size;
+mod dock_region;
 mod filter;
+mod font_fallback_chain;
 mod orientation;
 mod point;
+mod popup_alignment;
+mod popup_side;
 pub mod prelude;
 mod rectangle;
 mod selection_mode;
@@ -0,0 +1,48 @@
+/// Describes how a popup is aligned horizontally to its target.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PopupAlignment {
+    /// Aligns the left edges of the popup and its target and the right edges if the popup would
+    /// exceed the right edge of the window.
+    Auto,
+
+    /// Aligns the left edges of the popup and its target.
+    Start,
+
+    /// Aligns the right edges of the popup and its target.
+    End,
+}
+
+impl Default for PopupAlignment {
+    fn default() -> PopupAlignment {
+        PopupAlignment::Auto
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for PopupAlignment {
+    fn from(t: &str) -> Self {
+        match t {
+            "Start" | "start" => PopupAlignment::Start,
+            "End" | "end" => PopupAlignment::End,
+            _ => PopupAlignment::Auto,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let popup_alignment: PopupAlignment = "Start".into();
+        assert_eq!(popup_alignment, PopupAlignment::Start);
+
+        let popup_alignment: PopupAlignment = "end".into();
+        assert_eq!(popup_alignment, PopupAlignment::End);
+
+        let popup_alignment: PopupAlignment = "other".into();
+        assert_eq!(popup_alignment, PopupAlignment::Auto);
+    }
+}
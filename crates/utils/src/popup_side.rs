@@ -0,0 +1,48 @@
+/// Describes on which side of its target a popup is placed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PopupSide {
+    /// Places the popup below its target and above it if the popup would exceed the bottom of
+    /// the window.
+    Auto,
+
+    /// Places the popup below its target.
+    Bottom,
+
+    /// Places the popup above its target.
+    Top,
+}
+
+impl Default for PopupSide {
+    fn default() -> PopupSide {
+        PopupSide::Auto
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for PopupSide {
+    fn from(t: &str) -> Self {
+        match t {
+            "Bottom" | "bottom" => PopupSide::Bottom,
+            "Top" | "top" => PopupSide::Top,
+            _ => PopupSide::Auto,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let popup_side: PopupSide = "Bottom".into();
+        assert_eq!(popup_side, PopupSide::Bottom);
+
+        let popup_side: PopupSide = "top".into();
+        assert_eq!(popup_side, PopupSide::Top);
+
+        let popup_side: PopupSide = "other".into();
+        assert_eq!(popup_side, PopupSide::Auto);
+    }
+}
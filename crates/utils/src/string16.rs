@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, ops::Range};
 
 /// A UTF-16 encoded, growable string.
 ///
@@ -70,9 +70,88 @@ impl String16 {
         self.utf16.is_empty()
     }
 
+    /// Returns `true` if this `String16` starts with the given string slice, or `false` otherwise.
+    pub fn starts_with(&self, pat: &str) -> bool {
+        let pat: Vec<u16> = pat.encode_utf16().collect();
+        self.utf16.starts_with(&pat)
+    }
+
     /// Returns `true` if this `String16` ends with the given string slice, or `false` otherwise.
     pub fn ends_with(&self, pat: &str) -> bool {
-        self.as_string().ends_with(pat)
+        let pat: Vec<u16> = pat.encode_utf16().collect();
+        self.utf16.ends_with(&pat)
+    }
+
+    /// Returns the position of the first match of the given pattern in code units, or `None` if
+    /// the pattern does not match.
+    pub fn find(&self, pat: &str) -> Option<usize> {
+        let pat: Vec<u16> = pat.encode_utf16().collect();
+
+        if pat.is_empty() {
+            return Some(0);
+        }
+
+        self.utf16
+            .windows(pat.len())
+            .position(|w| w == pat.as_slice())
+    }
+
+    /// Replaces the given range of code units with the given string slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn replace_range(&mut self, range: Range<usize>, replacement: &str) {
+        self.utf16.splice(range, replacement.encode_utf16());
+    }
+
+    /// Divides this `String16` into two at the given code unit position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is greater than the length.
+    pub fn split_at(&self, idx: usize) -> (String16, String16) {
+        let (first, second) = self.utf16.split_at(idx);
+        (String16::from(first), String16::from(second))
+    }
+
+    /// Returns the lines of this `String16`. Like `str::lines` the lines are split at `\n` or
+    /// `\r\n` and the line endings are not included.
+    pub fn lines(&self) -> Vec<String16> {
+        let mut lines: Vec<String16> = self
+            .utf16
+            .split(|u| *u == u16::from(b'\n'))
+            .map(|line| match line.split_last() {
+                Some((last, line)) if *last == u16::from(b'\r') => String16::from(line),
+                _ => String16::from(line),
+            })
+            .collect();
+
+        // a line ending at the end does not start a new line
+        if self.utf16.is_empty() || self.utf16.last() == Some(&u16::from(b'\n')) {
+            lines.pop();
+        }
+
+        lines
+    }
+
+    /// Returns a `String16` with leading and trailing whitespace removed.
+    pub fn trim(&self) -> String16 {
+        let is_whitespace =
+            |u: &u16| std::char::from_u32(u32::from(*u)).map_or(false, |c| c.is_whitespace());
+
+        let start = self
+            .utf16
+            .iter()
+            .position(|u| !is_whitespace(u))
+            .unwrap_or_else(|| self.utf16.len());
+        let end = self
+            .utf16
+            .iter()
+            .rposition(|u| !is_whitespace(u))
+            .map_or(start, |i| i + 1);
+
+        String16::from(&self.utf16[start..end])
     }
 
     /// Truncates this `String16`, removing all contents.
@@ -99,6 +178,14 @@ impl From<&str> for String16 {
     }
 }
 
+impl From<&[u16]> for String16 {
+    fn from(utf16: &[u16]) -> Self {
+        String16 {
+            utf16: utf16.to_vec(),
+        }
+    }
+}
+
 impl From<String> for String16 {
     fn from(string: String) -> Self {
         String16 {
@@ -152,4 +239,59 @@ mod tests {
         string16.push('𝕊');
         assert_eq!(string16, String16::from("Bar𝕊"));
     }
+
+    #[test]
+    fn starts_and_ends_with() {
+        let string16 = String16::from("𝕊tart end𝕊");
+        assert!(string16.starts_with("𝕊t"));
+        assert!(string16.ends_with("d𝕊"));
+        assert!(!string16.starts_with("end"));
+        assert!(!string16.ends_with("𝕊t"));
+    }
+
+    #[test]
+    fn find() {
+        let string16 = String16::from("a𝕊b𝕊");
+        assert_eq!(string16.find("b"), Some(3));
+        assert_eq!(string16.find("𝕊"), Some(1));
+        assert_eq!(string16.find(""), Some(0));
+        assert_eq!(string16.find("c"), None);
+    }
+
+    #[test]
+    fn replace_range() {
+        let mut string16 = String16::from("a𝕊b");
+        string16.replace_range(1..3, "cd");
+        assert_eq!(string16, String16::from("acdb"));
+    }
+
+    #[test]
+    fn split_at() {
+        let (first, second) = String16::from("a𝕊b").split_at(3);
+        assert_eq!(first, String16::from("a𝕊"));
+        assert_eq!(second, String16::from("b"));
+    }
+
+    #[test]
+    fn lines() {
+        let lines = String16::from("one\r\ntwo\n\nfour\n").lines();
+        assert_eq!(
+            lines,
+            vec![
+                String16::from("one"),
+                String16::from("two"),
+                String16::new(),
+                String16::from("four")
+            ]
+        );
+
+        assert!(String16::new().lines().is_empty());
+        assert_eq!(String16::from("one").lines(), vec![String16::from("one")]);
+    }
+
+    #[test]
+    fn trim() {
+        assert_eq!(String16::from(" \t𝕊 a \n").trim(), String16::from("𝕊 a"));
+        assert_eq!(String16::from("  ").trim(), String16::new());
+    }
 }
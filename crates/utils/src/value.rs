@@ -1,5 +1,6 @@
 use serde::de::DeserializeOwned;
 /// Wraps a ron value and is used to support conversion to different types.
+#[derive(Clone, Debug, PartialEq)]
 pub struct Value(pub ron::Value);
 
 impl Value {
@@ -22,6 +23,24 @@ impl From<ron::Value> for Value {
     }
 }
 
+impl From<String> for Value {
+    fn from(v: String) -> Self {
+        Value(ron::Value::String(v))
+    }
+}
+
+impl From<f64> for Value {
+    fn from(v: f64) -> Self {
+        Value(ron::Value::Number(ron::Number::new(v)))
+    }
+}
+
+impl From<bool> for Value {
+    fn from(v: bool) -> Self {
+        Value(ron::Value::Bool(v))
+    }
+}
+
 impl Into<String> for Value {
     fn into(self) -> String {
         self.get::<String>()
@@ -34,6 +53,12 @@ impl Into<f64> for Value {
     }
 }
 
+impl Into<Vec<String>> for Value {
+    fn into(self) -> Vec<String> {
+        self.get::<Vec<String>>()
+    }
+}
+
 impl Into<f32> for Value {
     fn into(self) -> f32 {
         self.get::<f32>()
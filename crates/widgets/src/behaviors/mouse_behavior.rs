@@ -1,10 +1,19 @@
+use std::time::{Duration, Instant};
+
 use crate::{api::prelude::*, proc_macros::*};
 
+// maximum time and distance between two clicks of a double click
+const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(400);
+const DOUBLE_CLICK_DISTANCE: f64 = 4.0;
+
 #[derive(Debug, Copy, Clone)]
 enum Action {
     Press(Mouse),
     Release(Mouse),
+    Move(Point),
     Scroll(Point),
+    Enter,
+    Leave,
 }
 
 /// The `MouseBehaviorState` handles the `MouseBehavior` widget.
@@ -12,12 +21,64 @@ enum Action {
 pub struct MouseBehaviorState {
     action: Option<Action>,
     has_delta: bool,
+    last_click_time: Option<Instant>,
+    last_click_position: Point,
+    press_position: Point,
+    pressed: bool,
+    dragging: bool,
 }
 
 impl MouseBehaviorState {
     fn action(&mut self, action: Action) {
         self.action = Some(action);
     }
+
+    // a drag could only start while the mouse is pressed and no drag is running
+    fn could_start_drag(&self) -> bool {
+        self.pressed && !self.dragging
+    }
+
+    // checks if the click is the second click of a double click, a third click starts a new one
+    fn is_double_click(&mut self, position: Point) -> bool {
+        let now = Instant::now();
+
+        let double_click = self.last_click_time.map_or(false, |time| {
+            now.duration_since(time) < DOUBLE_CLICK_TIME
+                && self.last_click_position.distance(position) < DOUBLE_CLICK_DISTANCE
+        });
+
+        self.last_click_time = if double_click { None } else { Some(now) };
+        self.last_click_position = position;
+
+        double_click
+    }
+
+    // starts a drag of the parent if it has a payload and the mouse is moved far enough
+    fn start_drag(&mut self, position: Point, ctx: &mut Context) {
+        if self.dragging || self.press_position.distance(position) <= DRAG_START_DISTANCE {
+            return;
+        }
+
+        let parent = ctx.entity_of_parent().unwrap();
+
+        let payload = match ctx
+            .get_widget(parent)
+            .try_clone::<DragPayload>("drag_payload")
+        {
+            Some(payload) => payload,
+            None => return,
+        };
+
+        self.dragging = true;
+        ctx.push_event_by_entity(
+            DragStartEvent {
+                source: parent,
+                position,
+                payload,
+            },
+            parent,
+        );
+    }
 }
 
 impl State for MouseBehaviorState {
@@ -30,11 +91,16 @@ impl State for MouseBehaviorState {
             let target: Entity = (*mouse_behavior(ctx.widget()).target()).into();
 
             match action {
-                Action::Press(_) => {
+                Action::Press(p) => {
+                    self.press_position = p.position;
+                    self.pressed = true;
+                    self.dragging = false;
                     ctx.get_widget(target).set("pressed", true);
                     toggle_flag("pressed", &mut ctx.get_widget(target));
                 }
                 Action::Release(p) => {
+                    self.pressed = false;
+
                     if !*mouse_behavior(ctx.widget()).pressed() {
                         self.action = None;
                         return;
@@ -43,20 +109,56 @@ impl State for MouseBehaviorState {
                     ctx.get_widget(target).set("pressed", false);
                     toggle_flag("pressed", &mut ctx.get_widget(target));
 
-                    if check_mouse_condition(p.position, &ctx.widget()) {
+                    if *mouse_behavior(ctx.widget()).hovered() {
+                        set_hovered_state(true, &mut ctx.get_widget(target));
+                    }
+
+                    // a drag ends with a drop instead of a click
+                    if self.dragging {
+                        self.dragging = false;
+                    } else if check_mouse_condition(p.position, &ctx.widget()) {
                         let parent = ctx.entity_of_parent().unwrap();
                         ctx.push_event_by_entity(
                             ClickEvent {
                                 position: p.position,
                             },
                             parent,
-                        )
+                        );
+
+                        if self.is_double_click(p.position) {
+                            ctx.push_event_by_entity(
+                                DoubleClickEvent {
+                                    position: p.position,
+                                },
+                                parent,
+                            );
+                        }
+                    }
+                }
+                Action::Move(p) => {
+                    // the mouse could be released outside of the widget
+                    if *mouse_behavior(ctx.widget()).pressed() {
+                        self.start_drag(p, ctx);
+                    } else {
+                        self.pressed = false;
                     }
+
+                    // a move doesn't change the target
+                    self.action = None;
+                    return;
                 }
                 Action::Scroll(p) => {
                     mouse_behavior(ctx.widget()).set_position(p);
                     self.has_delta = true;
                 }
+                Action::Enter => {
+                    mouse_behavior(ctx.widget()).set_hovered(true);
+                    set_hovered_state(true, &mut ctx.get_widget(target));
+                }
+                Action::Leave => {
+                    mouse_behavior(ctx.widget()).set_hovered(false);
+                    set_hovered_state(false, &mut ctx.get_widget(target));
+                }
             };
 
             ctx.get_widget(target).update(false);
@@ -73,9 +175,28 @@ impl State for MouseBehaviorState {
     }
 }
 
+// Sets the `hovered` selector state of the widget. Other states like `pressed` or `focused` are
+// not overwritten.
+fn set_hovered_state(hovered: bool, widget: &mut WidgetContainer) {
+    if widget.has::<bool>("hovered") {
+        widget.set("hovered", hovered);
+    }
+
+    if let Some(selector) = widget.try_get_mut::<Selector>("selector") {
+        if hovered && selector.states.is_empty() {
+            selector.set_state("hovered");
+        } else if !hovered && selector.has_state("hovered") {
+            selector.remove_state("hovered");
+        }
+    }
+}
+
 widget!(
     /// The `MouseBehavior` widget is used to handle internal the pressed behavior of a widget.
     ///
+    /// If the parent of the behavior has a drag payload, a drag is started when the mouse is
+    /// moved with the pressed button.
+    ///
     /// **style:** `check-box`
     MouseBehavior<MouseBehaviorState>: MouseHandler {
         /// Sets or shares the target of the behavior.
@@ -85,7 +206,10 @@ widget!(
         pressed: bool,
 
         /// Sets or shares the (wheel, scroll) delta property.
-        delta: Point
+        delta: Point,
+
+        /// Sets or shares the hovered property. It is `true` while the mouse is over the behavior.
+        hovered: bool
     }
 );
 
@@ -94,6 +218,7 @@ impl Template for MouseBehavior {
         self.name("MouseBehavior")
             .delta(0.0)
             .pressed(false)
+            .hovered(false)
             .on_mouse_down(move |states, m| {
                 states
                     .get_mut::<MouseBehaviorState>(id)
@@ -106,11 +231,32 @@ impl Template for MouseBehavior {
                     .action(Action::Release(m));
                 false
             })
+            .on_mouse_move(move |states, p| {
+                // only moves that could start a drag are handled, to not mark the state as dirty
+                if states.get::<MouseBehaviorState>(id).could_start_drag() {
+                    states
+                        .get_mut::<MouseBehaviorState>(id)
+                        .action(Action::Move(p));
+                }
+                false
+            })
             .on_scroll(move |states, p| {
                 states
                     .get_mut::<MouseBehaviorState>(id)
                     .action(Action::Scroll(p));
                 false
             })
+            .on_mouse_enter(move |states, _| {
+                states
+                    .get_mut::<MouseBehaviorState>(id)
+                    .action(Action::Enter);
+                false
+            })
+            .on_mouse_leave(move |states, _| {
+                states
+                    .get_mut::<MouseBehaviorState>(id)
+                    .action(Action::Leave);
+                false
+            })
     }
 }
@@ -1,9 +1,10 @@
-use crate::{api::prelude::*, proc_macros::*};
+use crate::{api::prelude::*, proc_macros::*, shell::prelude::Key};
 
 /// The `SelectionBehaviorState` handles the `SelectionBehavior` widget.
 #[derive(Default, AsAny)]
 pub struct SelectionBehaviorState {
     toggle_selection: bool,
+    toggle_selection_by_key: bool,
     selected: bool,
 }
 
@@ -11,6 +12,17 @@ impl SelectionBehaviorState {
     fn toggle_selection(&mut self) {
         self.toggle_selection = true;
     }
+
+    fn toggle_selection_by_key(&mut self) {
+        self.toggle_selection_by_key = true;
+    }
+}
+
+// Checks if the target could be focused and is focused.
+fn is_focused(target: Entity, ctx: &mut Context) -> bool {
+    ctx.get_widget(target)
+        .try_clone::<bool>("focused")
+        .unwrap_or_default()
 }
 
 impl State for SelectionBehaviorState {
@@ -25,6 +37,17 @@ impl State for SelectionBehaviorState {
         let selected = *selection_behavior(ctx.widget()).selected();
         let target: Entity = (*selection_behavior(ctx.widget()).target()).into();
 
+        // the space key toggles the selection only if the target is focused
+        if self.toggle_selection_by_key {
+            self.toggle_selection_by_key = false;
+            self.toggle_selection = is_focused(target, ctx);
+        } else if self.toggle_selection
+            && ctx.get_widget(target).has::<bool>("focused")
+            && !is_focused(target, ctx)
+        {
+            ctx.push_event_by_window(FocusEvent::RequestFocus(target));
+        }
+
         if self.selected == selected && !self.toggle_selection {
             return;
         }
@@ -45,8 +68,11 @@ impl State for SelectionBehaviorState {
 widget!(
     /// The `SelectionBehavior` widget is used to handle internal the pressed behavior of a widget.
     ///
+    /// If the target has a `focused` property, it is focused on click and the selection could be
+    /// toggled with the space key while the target is focused.
+    ///
     /// **style:** `check-box`
-    SelectionBehavior<SelectionBehaviorState>: MouseHandler {
+    SelectionBehavior<SelectionBehaviorState>: MouseHandler, KeyDownHandler {
         /// Sets or shares the target of the behavior.
         target: u32,
 
@@ -68,5 +94,13 @@ impl Template for SelectionBehavior {
                     .toggle_selection();
                 false
             })
+            .on_key_down(move |states, event| {
+                if event.logical == Key::Space {
+                    states
+                        .get_mut::<SelectionBehaviorState>(id)
+                        .toggle_selection_by_key();
+                }
+                false
+            })
     }
 }
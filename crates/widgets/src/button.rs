@@ -1,12 +1,137 @@
+use std::{future::Future, rc::Rc, thread};
+
+use futures::{executor::block_on, future::BoxFuture, FutureExt};
+
 use super::behaviors::MouseBehavior;
 
 use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
 
+// --- KEYS --
+static ID_CONTENT: &'static str = "button_content";
+static ID_SPINNER: &'static str = "button_spinner";
+// --- KEYS --
+
+/// Used to define the asynchronous click callback of a button.
+pub type ClickAsyncFn = dyn Fn(TaskSender) -> BoxFuture<'static, ()> + 'static;
+
+#[derive(Copy, Clone)]
+enum ButtonAction {
+    StartClickAsync,
+    FinishClickAsync,
+}
+
+/// The `ButtonState` shows a spinner instead of the content of the button while it is loading and
+/// runs the asynchronous click callback.
+#[derive(Default, AsAny)]
+pub struct ButtonState {
+    action: Option<ButtonAction>,
+    click_async: Option<Rc<ClickAsyncFn>>,
+    loading: bool,
+    enabled_before_loading: bool,
+    content: Entity,
+    spinner: Entity,
+}
+
+impl ButtonState {
+    // register an action
+    fn action(&mut self, action: ButtonAction) {
+        self.action = Some(action);
+    }
+
+    // runs the future of the click callback on a worker thread, the loading state is reset on the
+    // ui thread after the future is completed
+    fn start_click_async(&self, ctx: &mut Context) {
+        if *ctx.widget().get::<bool>("loading") {
+            return;
+        }
+
+        if let Some(click_async) = &self.click_async {
+            let sender = ctx.task_sender();
+            let future = click_async(sender.clone());
+            let entity = ctx.entity;
+
+            ctx.widget().set("loading", true);
+
+            thread::spawn(move || {
+                block_on(future);
+                sender.send(move |states| {
+                    states
+                        .get_mut::<ButtonState>(entity)
+                        .action(ButtonAction::FinishClickAsync)
+                });
+            });
+        }
+    }
+
+    fn update_loading(&mut self, ctx: &mut Context) {
+        let loading = *ctx.widget().get::<bool>("loading");
+
+        if loading == self.loading {
+            return;
+        }
+
+        self.loading = loading;
+
+        // a button that was disabled before it started loading stays disabled afterwards
+        if loading {
+            self.enabled_before_loading = *ctx.widget().get::<bool>("enabled");
+            ctx.widget().set("enabled", false);
+            ctx.widget()
+                .get_mut::<Selector>("selector")
+                .set_state("loading");
+            ctx.get_widget(self.content)
+                .set("visibility", Visibility::Collapsed);
+            ctx.get_widget(self.spinner)
+                .set("visibility", Visibility::Visible);
+        } else {
+            ctx.widget().set("enabled", self.enabled_before_loading);
+            ctx.widget()
+                .get_mut::<Selector>("selector")
+                .remove_state("loading");
+            ctx.get_widget(self.content)
+                .set("visibility", Visibility::Visible);
+            ctx.get_widget(self.spinner)
+                .set("visibility", Visibility::Collapsed);
+        }
+
+        ctx.widget().update(false);
+    }
+}
+
+impl State for ButtonState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.content = ctx
+            .entity_of_child(ID_CONTENT)
+            .expect("ButtonState.init: Content child could not be found.");
+        self.spinner = ctx
+            .entity_of_child(ID_SPINNER)
+            .expect("ButtonState.init: Spinner child could not be found.");
+        self.update_loading(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action {
+            match action {
+                ButtonAction::StartClickAsync => self.start_click_async(ctx),
+                ButtonAction::FinishClickAsync => ctx.widget().set("loading", false),
+            }
+
+            self.action = None;
+        }
+
+        self.update_loading(ctx);
+    }
+}
+
 widget!(
     /// The `Button` widget can be clicked by user. It's used to perform an action.
     ///
+    /// If `loading` is `true`, the button is disabled and shows a spinner instead of its icon and
+    /// text. The `loading` selector state is set while it is loading. Afterwards the `enabled`
+    /// value from before the loading is restored.
+    ///
     /// **style:** `button`
-    Button: MouseHandler {
+    Button<ButtonState>: MouseHandler, DragHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -28,6 +153,10 @@ widget!(
         /// Sets or shares the text property.
         text: String16,
 
+        /// Sets or shares the name that describes the button for assistive technologies. Is only
+        /// needed if the button has no text and no icon.
+        accessible_name: String,
+
         /// Sets or share the font size property.
         font_size: f64,
 
@@ -49,14 +178,54 @@ widget!(
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the loading property.
+        loading: bool,
+
         /// Sets or shares the spacing between icon and text.
         spacing: f64
     }
 );
 
+impl Button {
+    /// Sets a callback that is called on click and returns a future. The future runs on a worker
+    /// thread, the button is `loading` until the future is completed. Widget states could be
+    /// updated from the future with the given `TaskSender`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Button::new()
+    ///     .text("Submit")
+    ///     .on_click_async(move |sender| async move {
+    ///         let result = submit(form_data).await;
+    ///         sender.send(move |states| states.get_mut::<FormState>(form).set_result(result));
+    ///     })
+    ///     .build(ctx)
+    /// ```
+    pub fn on_click_async<F, R>(mut self, click_async: F) -> Self
+    where
+        F: Fn(TaskSender) -> R + 'static,
+        R: Future<Output = ()> + Send + 'static,
+    {
+        self.state_mut().click_async = Some(Rc::new(move |sender| click_async(sender).boxed()));
+        self
+    }
+}
+
 impl Template for Button {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
-        self.name("Button")
+        let this = if self.state().click_async.is_some() {
+            self.on_click(move |states, _| {
+                states
+                    .get_mut::<ButtonState>(id)
+                    .action(ButtonAction::StartClickAsync);
+                false
+            })
+        } else {
+            self
+        };
+
+        this.name("Button")
             .style("button")
             .height(36.0)
             .min_width(64.0)
@@ -74,6 +243,7 @@ impl Template for Button {
             .icon_size(fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
             .pressed(false)
+            .loading(false)
             .spacing(8.0)
             .child(
                 MouseBehavior::new()
@@ -90,6 +260,7 @@ impl Template for Button {
                             .opacity(id)
                             .child(
                                 Stack::new()
+                                    .id(ID_CONTENT)
                                     .orientation("horizontal")
                                     .spacing(id)
                                     .h_align("center")
@@ -115,6 +286,16 @@ impl Template for Button {
                                     )
                                     .build(ctx),
                             )
+                            .child(
+                                IndeterminateProgressBar::new()
+                                    .id(ID_SPINNER)
+                                    .foreground(id)
+                                    .width(48.0)
+                                    .v_align("center")
+                                    .h_align("center")
+                                    .visibility("collapsed")
+                                    .build(ctx),
+                            )
                             .build(ctx),
                     )
                     .build(ctx),
@@ -4,6 +4,9 @@ use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
 widget!(
     /// The `CheckBox` widget can be switch its selected state. It contains a selection box and a text.
     ///
+    /// The check box is focused on click. If it is focused, the space key switches its selected
+    /// state.
+    ///
     /// **style:** `check-box`
     CheckBox: MouseHandler {
         /// Sets or shares the background property.
@@ -48,6 +51,9 @@ widget!(
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the focused property.
+        focused: bool,
+
         /// Sets or shares the selected property.
         selected: bool
     }
@@ -55,6 +61,8 @@ widget!(
 
 impl Template for CheckBox {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let (icon_font, icon) = ctx.icon_glyph("check");
+
         self.name("CheckBox")
             .style("check_box")
             .on_changed_filter(vec!["selected"])
@@ -69,11 +77,12 @@ impl Template for CheckBox {
             .text("")
             .font_size(fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
-            .icon(material_icons_font::MD_CHECK)
-            .icon_font("MaterialIcons-Regular")
+            .icon(icon)
+            .icon_font(icon_font)
             .icon_size(fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
             .pressed(false)
+            .focused(false)
             .child(
                 MouseBehavior::new()
                     .pressed(id)
@@ -45,7 +45,11 @@ impl State for ComboBoxItemState {
             .clone_or_default::<i32>("selected_index");
         let selected: bool = *ctx.widget().get("selected");
 
-        if selected_index >= 0 && (selected_index as usize) == self.index && !selected {
+        // a selection by the selected index property is not reported as change
+        let selected_by_index =
+            selected_index >= 0 && (selected_index as usize) == self.index && !selected;
+
+        if selected_by_index {
             self.request_selection_toggle.set(true);
         }
 
@@ -64,7 +68,7 @@ impl State for ComboBoxItemState {
             ctx.get_widget(item).set("selected", false);
             ctx.get_widget(item)
                 .get_mut::<Selector>("selector")
-                .clear_state();
+                .remove_state("selected");
             ctx.get_widget(item).update(false);
         }
 
@@ -87,6 +91,14 @@ impl State for ComboBoxItemState {
             let selected_content = builder.borrow()(build_context, index);
             build_context.append_child(selected_container, selected_content);
         }
+
+        if !selected_by_index && !selected {
+            ctx.push_event_strategy_by_entity(
+                SelectionChangedEvent(self.combo_box, vec![index]),
+                self.combo_box,
+                EventStrategy::Direct,
+            );
+        }
     }
 }
 
@@ -219,7 +231,9 @@ impl ComboBoxState {
 
         if !combo_box_global_bounds.contains(p) {
             ctx.widget().set("selected", false);
-            ctx.widget().get_mut::<Selector>("selector").clear_state();
+            ctx.widget()
+                .get_mut::<Selector>("selector")
+                .remove_state("selected");
             ctx.get_widget(self.popup)
                 .set("visibility", Visibility::Collapsed);
             ctx.get_widget(self.popup).update(false);
@@ -306,8 +320,21 @@ impl State for ComboBoxState {
 widget!(
     /// The `ComboBox` represents an selection widget with a drop-down list.
     ///
+    /// The `on_selection_changed` handler is called with the index of the item the user selected
+    /// from the list.
+    ///
     /// **style:** `combo_box`
-    ComboBox<ComboBoxState>: MouseHandler {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// ComboBox::new()
+    ///     .items(vec!["Red".to_string(), "Green".to_string(), "Blue".to_string()])
+    ///     .selected_index(0)
+    ///     .on_selection_changed(|_, _, indices| println!("selected {:?}", indices))
+    ///     .build(ctx)
+    /// ```
+    ComboBox<ComboBoxState>: MouseHandler, SelectionChangedHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -370,6 +397,18 @@ impl ComboBox {
         self.state_mut().builder = Some(Arc::new(RefCell::new(builder)));
         self
     }
+
+    /// Displays the given texts as items. It's a shortcut for `count` and `items_builder`.
+    pub fn items(self, items: Vec<String>) -> Self {
+        let count = items.len();
+
+        self.count(count).items_builder(move |ctx, index| {
+            TextBlock::new()
+                .text(items[index].as_str())
+                .v_align("center")
+                .build(ctx)
+        })
+    }
 }
 
 impl Template for ComboBox {
@@ -434,10 +473,12 @@ impl Template for ComboBox {
 
         let _ = ctx.append_child_to_overlay(popup);
 
+        let (icon_font, icon) = ctx.icon_glyph("arrow_drop_down");
+
         self.name("ComboBox")
             .style("combo_box")
-            .icon(material_icons_font::MD_ARROW_DROP_DOWN)
-            .icon_font("MaterialIcons-Regular")
+            .icon(icon)
+            .icon_font(icon_font)
             .icon_size(fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
             .height(32.0)
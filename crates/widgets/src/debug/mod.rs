@@ -0,0 +1,260 @@
+//! This module contains widgets that helps to debug the widget tree of an application.
+//!
+//! It is only available in debug builds with the `debug-overlay` feature enabled.
+
+use std::{collections::BTreeMap, fmt::Debug};
+
+use crate::{
+    api::prelude::*, proc_macros::*, render::RenderContext2D, shell::prelude::Key, tree::Tree,
+};
+
+// --- KEYS --
+pub static STYLE_DEBUG_OVERLAY: &'static str = "debug_overlay";
+// --- KEYS --
+
+static BOUNDS_BRUSH: &'static str = "#800033cc";
+static HOVER_BRUSH: &'static str = "#ccff3366";
+static PANEL_BRUSH: &'static str = "#e0202428";
+static PANEL_FOREGROUND: &'static str = "#ffffff";
+const PANEL_FONT_SIZE: f64 = 12.0;
+const PANEL_LINE_HEIGHT: f64 = 16.0;
+const PANEL_PADDING: f64 = 4.0;
+
+/// The `DebugOverlayState` toggles the overlay on `Ctrl+Shift+D` and tracks the mouse position.
+#[derive(Default, AsAny)]
+pub struct DebugOverlayState {
+    mouse_position: Point,
+    shortcut_down: bool,
+}
+
+impl DebugOverlayState {
+    fn mouse_move(&mut self, position: Point) {
+        self.mouse_position = position;
+    }
+
+    // checks if the toggle shortcut `Ctrl+Shift+D` is pressed.
+    fn is_shortcut_down(ctx: &mut Context) -> bool {
+        let window = ctx.window();
+        let keyboard_state = &window.get::<Global>("global").keyboard_state;
+
+        keyboard_state.is_ctrl_down()
+            && keyboard_state.is_shift_down()
+            && (keyboard_state.is_key_down(Key::D(false))
+                || keyboard_state.is_key_down(Key::D(true)))
+    }
+}
+
+impl State for DebugOverlayState {
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let shortcut_down = DebugOverlayState::is_shortcut_down(ctx);
+
+        // toggle only once per key press
+        if shortcut_down && !self.shortcut_down {
+            let enabled = *ctx.widget().get::<bool>("enabled");
+            ctx.widget().set("enabled", !enabled);
+        }
+
+        self.shortcut_down = shortcut_down;
+
+        if *ctx.widget().get::<bool>("enabled") {
+            ctx.widget().set("mouse_position", self.mouse_position);
+        }
+    }
+}
+
+widget!(
+    /// The `DebugOverlay` draws the bounds of all widgets of the window and shows a panel with
+    /// the name, properties and layout sizes of the widget under the mouse.
+    ///
+    /// Add it as last child of the window. It can be toggled with `Ctrl+Shift+D` or by its
+    /// `enabled` property.
+    ///
+    /// **style:** `debug_overlay`
+    DebugOverlay<DebugOverlayState>: MouseHandler {
+        /// Sets or shares the current mouse position on the overlay.
+        mouse_position: Point
+    }
+);
+
+impl Template for DebugOverlay {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("DebugOverlay")
+            .style(STYLE_DEBUG_OVERLAY)
+            .enabled(false)
+            .h_align("stretch")
+            .v_align("stretch")
+            .on_mouse_move(move |states, p| {
+                states.get_mut::<DebugOverlayState>(id).mouse_move(p);
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(DebugOverlayRenderObject)
+    }
+}
+
+/// Draws the bounds of all widgets and the property panel of the hovered widget.
+pub struct DebugOverlayRenderObject;
+
+impl Into<Box<dyn RenderObject>> for DebugOverlayRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for DebugOverlayRenderObject {
+    fn render_children(
+        &self,
+        render_context: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        _: &ContextProvider,
+        _: &Theme,
+        _: &mut BTreeMap<Entity, (f64, f64)>,
+        _: bool,
+    ) {
+        if !*ecm
+            .component_store()
+            .get::<bool>("enabled", entity)
+            .unwrap_or(&false)
+        {
+            return;
+        }
+
+        let mouse_position = *ecm
+            .component_store()
+            .get::<Point>("mouse_position", entity)
+            .unwrap_or(&Point::default());
+
+        let root = ecm.entity_store().root();
+        let entities: Vec<Entity> = ecm.entity_store().start_node(root).into_iter().collect();
+
+        let mut hovered = None;
+
+        render_context.begin_path();
+        render_context.set_line_width(1.0);
+        render_context.set_stroke_style(Brush::from(BOUNDS_BRUSH));
+
+        for e in entities {
+            if e == entity {
+                continue;
+            }
+
+            if let Some(rect) = global_bounds(e, ecm) {
+                render_context.stroke_rect(rect.x(), rect.y(), rect.width(), rect.height());
+
+                // the tree is iterated top down, so the last match is the deepest widget
+                if rect.contains(mouse_position) {
+                    hovered = Some((e, rect));
+                }
+            }
+        }
+
+        render_context.close_path();
+
+        if let Some((hovered, rect)) = hovered {
+            render_context.begin_path();
+            render_context.set_line_width(2.0);
+            render_context.set_stroke_style(Brush::from(HOVER_BRUSH));
+            render_context.stroke_rect(rect.x(), rect.y(), rect.width(), rect.height());
+            render_context.close_path();
+
+            render_panel(render_context, mouse_position, &describe(hovered, ecm));
+        }
+    }
+}
+
+// --- Helpers --
+
+// Gets the bounds of the given entity in window coordinates if the entity is visible.
+fn global_bounds(
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+) -> Option<Rectangle> {
+    let store = ecm.component_store();
+
+    if *store.get::<Visibility>("visibility", entity).ok()? != Visibility::Visible {
+        return None;
+    }
+
+    let bounds = store.get::<Rectangle>("bounds", entity).ok()?;
+    let position = store.get::<Point>("position", entity).ok()?;
+
+    Some(Rectangle::new(*position, bounds.width(), bounds.height()))
+}
+
+// Formats the component with the given key if the entity has a component of type `T`.
+fn describe_component<T: Component + Debug>(
+    key: &str,
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    lines: &mut Vec<String>,
+) {
+    if let Ok(value) = ecm.component_store().get::<T>(key, entity) {
+        lines.push(format!("{}: {:?}", key, value));
+    }
+}
+
+// Collects the lines of the property panel of the given entity.
+fn describe(
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+) -> Vec<String> {
+    let mut lines = vec![];
+
+    let name = ecm
+        .component_store()
+        .get::<String>("name", entity)
+        .map(|n| n.clone())
+        .unwrap_or_else(|_| String::from("unknown"));
+    lines.push(format!("{} ({:?})", name, entity));
+
+    describe_component::<String>("id", entity, ecm, &mut lines);
+    describe_component::<Selector>("selector", entity, ecm, &mut lines);
+    describe_component::<Rectangle>("bounds", entity, ecm, &mut lines);
+    describe_component::<Point>("position", entity, ecm, &mut lines);
+    describe_component::<Constraint>("constraint", entity, ecm, &mut lines);
+    describe_component::<Thickness>("margin", entity, ecm, &mut lines);
+    describe_component::<Thickness>("padding", entity, ecm, &mut lines);
+    describe_component::<Alignment>("h_align", entity, ecm, &mut lines);
+    describe_component::<Alignment>("v_align", entity, ecm, &mut lines);
+    describe_component::<Visibility>("visibility", entity, ecm, &mut lines);
+    describe_component::<bool>("enabled", entity, ecm, &mut lines);
+    describe_component::<f32>("opacity", entity, ecm, &mut lines);
+    describe_component::<String16>("text", entity, ecm, &mut lines);
+
+    lines
+}
+
+// Draws a floating panel with the given lines next to the mouse position.
+fn render_panel(render_context: &mut RenderContext2D, position: Point, lines: &[String]) {
+    render_context.set_font_family("Roboto-Regular");
+    render_context.set_font_size(PANEL_FONT_SIZE);
+
+    let width = lines
+        .iter()
+        .map(|line| render_context.measure_text(line).width)
+        .fold(0.0, f64::max)
+        + 2.0 * PANEL_PADDING;
+    let height = lines.len() as f64 * PANEL_LINE_HEIGHT + 2.0 * PANEL_PADDING;
+    let x = position.x() + 16.0;
+    let y = position.y() + 16.0;
+
+    render_context.begin_path();
+    render_context.set_fill_style(Brush::from(PANEL_BRUSH));
+    render_context.fill_rect(x, y, width, height);
+    render_context.set_fill_style(Brush::from(PANEL_FOREGROUND));
+
+    for (i, line) in lines.iter().enumerate() {
+        render_context.fill_text(
+            line,
+            x + PANEL_PADDING,
+            y + PANEL_PADDING + i as f64 * PANEL_LINE_HEIGHT,
+        );
+    }
+
+    render_context.close_path();
+}
+
+// --- Helpers --
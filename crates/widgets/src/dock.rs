@@ -0,0 +1,42 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `Dock` docks its children to its edges in the order they are added. Children that are
+    /// docked to the same edge are stacked, a child with the `Fill` region gets the space that
+    /// is left.
+    ///
+    /// **style:** `dock`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Dock::new()
+    ///     .child(TextBlock::new().text("Header").attach(Dock::dock("top")).build(ctx))
+    ///     .child(Container::new().width(120).attach(Dock::dock("left")).build(ctx))
+    ///     .child(Container::new().attach(Dock::dock("fill")).build(ctx))
+    ///     .build(ctx)
+    /// ```
+    Dock {
+        /// Sets or shares the background property.
+        background: Brush
+
+        attached_properties: {
+            /// Attach the dock region to a widget.
+            dock: DockRegion
+        }
+    }
+);
+
+impl Template for Dock {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("Dock").style("dock").background("transparent")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(DockLayout::new())
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+/// The `ErrorBoundaryState` replaces the content of the boundary by the fallback if the update of
+/// a descendant panics.
+#[derive(Default, AsAny)]
+pub struct ErrorBoundaryState {
+    reset: bool,
+    has_error: bool,
+    error_message: String,
+    fallback: Option<Entity>,
+}
+
+impl ErrorBoundaryState {
+    /// Returns `true` if the update of a descendant has panicked.
+    pub fn has_error(&self) -> bool {
+        self.has_error
+    }
+
+    /// Gets the message of the caught panic.
+    pub fn error_message(&self) -> &str {
+        &self.error_message
+    }
+
+    /// Hides the fallback and shows the content again with the next update.
+    pub fn reset(&mut self) {
+        self.reset = true;
+    }
+
+    // shows the fallback or the content of the boundary.
+    fn show_fallback(&self, show: bool, ctx: &mut Context) {
+        let mut index = 0;
+
+        while let Some(child) = ctx.try_child_from_index(index).map(|child| child.entity()) {
+            index += 1;
+
+            let visibility = if (Some(child) == self.fallback) == show {
+                Visibility::Visible
+            } else {
+                Visibility::Collapsed
+            };
+
+            ctx.get_widget(child).set("visibility", visibility);
+        }
+    }
+}
+
+impl State for ErrorBoundaryState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let fallback = *ctx.widget().get::<u32>("error_fallback");
+
+        // the fallback is created by the user and is added to the boundary on init
+        if fallback > 0 {
+            let fallback = Entity::from(fallback);
+
+            if ctx.index_as_child(fallback).is_none() {
+                ctx.append_child_entity_to(fallback, ctx.entity);
+            }
+
+            self.fallback = Some(fallback);
+        }
+
+        self.show_fallback(false, ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.reset {
+            self.reset = false;
+            self.has_error = false;
+            self.error_message.clear();
+
+            ctx.widget().set("error_message", String::default());
+            ctx.widget().set("has_error", false);
+            self.show_fallback(false, ctx);
+            return;
+        }
+
+        let error_message = ctx.widget().clone::<String>("error_message");
+
+        if self.has_error || error_message.is_empty() {
+            return;
+        }
+
+        self.has_error = true;
+        self.error_message = error_message;
+
+        ctx.widget().set("has_error", true);
+        self.show_fallback(true, ctx);
+    }
+}
+
+widget!(
+    /// The `ErrorBoundary` catches panics in the updates of the states of its descendants. If a
+    /// state panics, the message is logged, the content of the boundary is collapsed and the
+    /// `error_fallback` widget is shown instead. The rest of the application keeps running.
+    ///
+    /// Only panics of `State::update` and `State::before_update` are caught. A panicked state
+    /// could be left in an inconsistent state, the boundary could be reset by
+    /// `ErrorBoundaryState::reset` to show the content again.
+    ///
+    /// ```rust
+    /// let fallback = TextBlock::new().text("Something went wrong.").build(ctx);
+    ///
+    /// ErrorBoundary::new()
+    ///     .error_fallback(fallback.0)
+    ///     .child(Chart::new().build(ctx))
+    ///     .build(ctx)
+    /// ```
+    ErrorBoundary<ErrorBoundaryState> {
+        /// Sets or shares the entity of the widget that is shown if a descendant panics.
+        error_fallback: u32,
+
+        /// Sets or shares the flag that enables catching the panics of the descendants.
+        catch_panics: bool,
+
+        /// Sets or shares the message of the caught panic (read only).
+        error_message: String,
+
+        /// Indicates if a descendant has panicked (read only).
+        has_error: bool,
+
+        /// Sets or shares the padding property.
+        padding: Thickness
+    }
+);
+
+impl Template for ErrorBoundary {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("ErrorBoundary").catch_panics(true).padding(0.0)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
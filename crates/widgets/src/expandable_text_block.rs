@@ -0,0 +1,113 @@
+use crate::{api::prelude::*, proc_macros::*, theme::prelude::*};
+
+/// The `ExpandableTextBlockState` expands a truncated text block on mouse down.
+#[derive(Default, AsAny)]
+pub struct ExpandableTextBlockState {
+    expanded: bool,
+    expandable: bool,
+    request_expand: bool,
+}
+
+impl ExpandableTextBlockState {
+    fn is_expandable(&self) -> bool {
+        self.expandable && !self.expanded
+    }
+
+    fn expand(&mut self) {
+        self.request_expand = true;
+    }
+
+    // checks if the text is truncated by max_lines and could be expanded
+    fn update_expandable(&mut self, ctx: &mut Context) {
+        let max_lines = *ctx.widget().get::<Option<usize>>("max_lines");
+        let expandable = *ctx.widget().get::<bool>("expandable");
+        let lines = ctx.widget().get::<String16>("text").lines().len();
+
+        // a text could be truncated again by setting max_lines after it was expanded
+        if max_lines.is_some() {
+            self.expanded = false;
+        }
+
+        self.expandable = expandable && max_lines.map_or(false, |max_lines| lines > max_lines);
+    }
+}
+
+impl State for ExpandableTextBlockState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_expandable(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.request_expand {
+            self.request_expand = false;
+            self.expanded = true;
+            ctx.widget().set::<Option<usize>>("max_lines", None);
+        }
+
+        self.update_expandable(ctx);
+    }
+}
+
+widget!(
+    /// The `ExpandableTextBlock` draws a text that is truncated after `max_lines` lines. While it
+    /// is truncated, `… more` is appended and a click on the text block shows the full text.
+    ///
+    /// Use a `TextBlock` for text that is not interactive.
+    ///
+    /// ```rust
+    /// ExpandableTextBlock::new().text(description).max_lines(3).build(ctx)
+    /// ```
+    ///
+    /// **style:** `text-block`
+    ExpandableTextBlock<ExpandableTextBlockState>: MouseHandler {
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the fonts that are used for characters that are not covered by `font`.
+        font_fallbacks: Vec<String>,
+
+        /// Sets or shares the maximum number of shown lines. With `None` all lines are shown.
+        max_lines: Option<usize>,
+
+        /// Sets or shares the flag that allows to expand the truncated text by a click.
+        expandable: bool
+    }
+);
+
+impl Template for ExpandableTextBlock {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("ExpandableTextBlock")
+            .text("")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .font_fallbacks(Vec::<String>::new())
+            .max_lines(None)
+            .expandable(true)
+            .on_mouse_down(move |states, _| {
+                if !states.get::<ExpandableTextBlockState>(id).is_expandable() {
+                    return false;
+                }
+
+                states.get_mut::<ExpandableTextBlockState>(id).expand();
+                true
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(TextRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(FixedSizeLayout::new())
+    }
+}
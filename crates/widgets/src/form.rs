@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use crate::{api::prelude::*, proc_macros::*};
+
+#[derive(Copy, Clone)]
+enum FormAction {
+    Validate,
+    Submit,
+}
+
+/// The `FormState` aggregates the validation state of all descendants of a `Form`, validates them
+/// on request and submits the values of the fields.
+#[derive(Default, AsAny)]
+pub struct FormState {
+    action: Option<FormAction>,
+    valid: bool,
+    submitted: bool,
+    validation_errors: HashMap<String, Vec<String>>,
+}
+
+impl FormState {
+    /// Returns `true` if none of the descendants of the form has a validation error.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Returns `true` if the form was submitted at least once.
+    pub fn is_submitted(&self) -> bool {
+        self.submitted
+    }
+
+    /// Gets the errors of the last validation by the id of the field.
+    pub fn validation_errors(&self) -> &HashMap<String, Vec<String>> {
+        &self.validation_errors
+    }
+
+    /// Requests a validation of all fields of the form. The result is available via `is_valid`
+    /// and `validation_errors` after the next update.
+    pub fn validate(&mut self) {
+        self.action = Some(FormAction::Validate);
+    }
+
+    /// Requests a submit of the form. All fields are validated and if they are valid the
+    /// `on_submit` handler is called with the values of the fields.
+    pub fn submit(&mut self) {
+        self.action = Some(FormAction::Submit);
+    }
+
+    // runs the validators of all descendants and collects their errors
+    fn validate_fields(&mut self, ctx: &mut Context) -> bool {
+        self.validation_errors.clear();
+
+        for child in ctx.descendants() {
+            let mut widget = ctx.get_widget(child);
+
+            let validators = match widget.try_clone::<Validators>("validators") {
+                Some(validators) if !validators.is_empty() => validators,
+                _ => continue,
+            };
+
+            let text = widget
+                .try_get::<String16>("text")
+                .map(|text| text.as_string())
+                .unwrap_or_default();
+
+            let errors: Vec<String> = validators
+                .0
+                .iter()
+                .filter_map(|validator| validator.validate(&text).err())
+                .collect();
+
+            if errors.is_empty() {
+                widget
+                    .get_mut::<Selector>("selector")
+                    .remove_state("invalid");
+            } else {
+                widget.get_mut::<Selector>("selector").set_state("invalid");
+            }
+
+            widget.set::<ErrorMessage>("validation_error", errors.first().cloned());
+            widget.update(false);
+
+            if !errors.is_empty() {
+                self.validation_errors
+                    .insert(field_name(child, ctx), errors);
+            }
+        }
+
+        self.valid = self.validation_errors.is_empty();
+        ctx.widget().set("valid", self.valid);
+
+        self.valid
+    }
+
+    // collects the values of the fields of the form
+    fn values(&self, ctx: &mut Context) -> HashMap<String, Value> {
+        let mut fields = vec![];
+        collect_fields(ctx.entity, ctx, &mut fields);
+
+        let mut values = HashMap::new();
+
+        for field in fields {
+            let widget = ctx.get_widget(field);
+
+            let id = match widget.try_clone::<String>("id") {
+                Some(id) => id,
+                None => continue,
+            };
+
+            let value = if let Some(selected) = widget.try_clone::<bool>("selected") {
+                Value::from(selected)
+            } else if let Some(val) = widget.try_clone::<f64>("val") {
+                Value::from(val)
+            } else if let Some(text) = widget.try_get::<String16>("text") {
+                Value::from(text.as_string())
+            } else {
+                continue;
+            };
+
+            values.insert(id, value);
+        }
+
+        values
+    }
+}
+
+// Collects the descendants that are fields of the form. The children of a field are part of its
+// template and are not collected.
+fn collect_fields(entity: Entity, ctx: &mut Context, fields: &mut Vec<Entity>) {
+    for child in ctx.children_of(entity) {
+        if is_field(child, ctx) {
+            fields.push(child);
+        } else {
+            collect_fields(child, ctx, fields);
+        }
+    }
+}
+
+// A field has an id and validators or is marked by the `form_field` attached property.
+fn is_field(entity: Entity, ctx: &mut Context) -> bool {
+    let widget = ctx.get_widget(entity);
+
+    if !widget.has::<String>("id") {
+        return false;
+    }
+
+    widget
+        .try_get::<Validators>("validators")
+        .map_or(false, |validators| !validators.is_empty())
+        || widget.try_clone::<bool>("form_field").unwrap_or_default()
+}
+
+// Returns the id of the field or the entity if the field has no id.
+fn field_name(entity: Entity, ctx: &mut Context) -> String {
+    ctx.get_widget(entity)
+        .try_clone::<String>("id")
+        .unwrap_or_else(|| entity.0.to_string())
+}
+
+impl State for FormState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.valid = true;
+        ctx.widget().set("valid", true);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action.take() {
+            match action {
+                FormAction::Validate => {
+                    self.validate_fields(ctx);
+                }
+                FormAction::Submit => {
+                    self.submitted = true;
+
+                    if self.validate_fields(ctx) {
+                        let values = self.values(ctx);
+                        ctx.push_event_strategy_by_entity(
+                            SubmitEvent(ctx.entity, values),
+                            ctx.entity,
+                            EventStrategy::Direct,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let valid = ctx.descendants().into_iter().all(|child| {
+            ctx.get_widget(child)
+                .try_get::<ErrorMessage>("validation_error")
+                .map_or(true, |error| error.is_none())
+        });
+
+        if valid != self.valid {
+            self.valid = valid;
+            ctx.widget().set("valid", valid);
+        }
+    }
+}
+
+widget!(
+    /// The `Form` stacks its children like a `Stack` and aggregates the validation state of
+    /// all descendants that provide a `validation_error` property, e.g. `TextBox`.
+    ///
+    /// A submit runs the validators of all descendants. If all of them are valid, the
+    /// `on_submit` handler is called with the values of the fields by their id. Fields are
+    /// descendants with an id that have validators or are marked with `Form::form_field(true)`.
+    /// The value is read from the `selected`, `val` or `text` property of the field.
+    ///
+    /// ```rust,ignore
+    /// let form = ctx.entity_of_child("login_form").unwrap();
+    ///
+    /// Form::new()
+    ///     .id("login_form")
+    ///     .child(TextBox::new().id("user").validators(vec![required()]).build(ctx))
+    ///     .child(
+    ///         CheckBox::new()
+    ///             .id("remember")
+    ///             .attach(Form::form_field(true))
+    ///             .build(ctx),
+    ///     )
+    ///     .child(
+    ///         Button::new()
+    ///             .text("Login")
+    ///             .on_click(move |states, _| {
+    ///                 states.get_mut::<FormState>(form).submit();
+    ///                 true
+    ///             })
+    ///             .build(ctx),
+    ///     )
+    ///     .on_submit(|states, _, values| login(values))
+    ///     .build(ctx)
+    /// ```
+    ///
+    /// **style:** `form`
+    Form<FormState>: SubmitHandler {
+        /// Sets or shares the orientation property.
+        orientation: Orientation,
+
+        /// Margin between widgets in the form.
+        spacing: f64,
+
+        /// Indicates if all descendants of the form are valid (read only).
+        valid: bool
+
+        attached_properties: {
+            /// Marks a widget with an id as field of the form, its value is submitted.
+            form_field: bool
+        }
+    }
+);
+
+impl Template for Form {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("Form")
+            .style("form")
+            .orientation("vertical")
+            .valid(true)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(StackLayout::new())
+    }
+}
@@ -11,7 +11,10 @@ widget!(
         /// * &str: `Image::new().image("path/to/image.png").build(xt)`
         /// * String: `Image::new().image(String::from()).build(xt)`
         /// * (width: u32, height: u32, data: Vec<u32>): `Image::new().image((width, height, vec![0; width * height]));`
-        image: Image
+        image: Image,
+
+        /// Sets or shares the text that describes the image for assistive technologies.
+        alt_text: String
     }
 );
 
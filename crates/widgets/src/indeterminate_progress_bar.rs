@@ -0,0 +1,136 @@
+use std::time::Instant;
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*};
+
+// --- KEYS --
+pub static STYLE_INDETERMINATE_PROGRESS_BAR: &'static str = "indeterminate_progress_bar";
+static ID_INDICATOR: &'static str = "indeterminate_progress_bar_indicator";
+// --- KEYS --
+
+// duration of one move of the indicator from the start to the end of the bar in seconds.
+const MOVE_TIME: f64 = 1.0;
+
+/// The `IndeterminateProgressBarState` moves the indicator back and forth while the bar is
+/// visible.
+#[derive(Default, AsAny)]
+pub struct IndeterminateProgressBarState {
+    indicator: Entity,
+    start: Option<Instant>,
+}
+
+impl State for IndeterminateProgressBarState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.indicator = ctx
+            .entity_of_child(ID_INDICATOR)
+            .expect("IndeterminateProgressBarState.init: Indicator child could not be found.");
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if *ctx.widget().get::<Visibility>("visibility") != Visibility::Visible {
+            self.start = None;
+            return;
+        }
+
+        let start = *self.start.get_or_insert_with(Instant::now);
+
+        let width = ctx.widget().get::<Rectangle>("bounds").width()
+            - ctx.widget().get::<Thickness>("padding").left()
+            - ctx.widget().get::<Thickness>("padding").right();
+        let indicator_width = ctx
+            .get_widget(self.indicator)
+            .get::<Rectangle>("bounds")
+            .width();
+
+        let mut margin = *ctx.get_widget(self.indicator).get::<Thickness>("margin");
+        margin.set_left(calculate_indicator_x(
+            start.elapsed().as_secs_f64(),
+            (width - indicator_width).max(0.0),
+        ));
+        ctx.get_widget(self.indicator).set("margin", margin);
+
+        // only the indicator is dirty after its margin is changed, so the next move has to be
+        // requested
+        ctx.request_update();
+    }
+}
+
+// Returns the position of the indicator that moves from the start to the end of the range and
+// back again.
+fn calculate_indicator_x(elapsed: f64, range: f64) -> f64 {
+    let progress = (elapsed / MOVE_TIME) % 2.0;
+
+    if progress <= 1.0 {
+        progress * range
+    } else {
+        (2.0 - progress) * range
+    }
+}
+
+widget!(
+    /// The `IndeterminateProgressBar` shows that an operation is running if its duration is
+    /// unknown. An indicator moves back and forth while the bar is visible.
+    ///
+    /// ```rust
+    /// IndeterminateProgressBar::new().width(48.0).build(ctx)
+    /// ```
+    ///
+    /// **style:** `indeterminate_progress_bar`
+    IndeterminateProgressBar<IndeterminateProgressBarState> {
+        /// Sets or shares the background color property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the color of the indicator.
+        foreground: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness
+    }
+);
+
+impl Template for IndeterminateProgressBar {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("IndeterminateProgressBar")
+            .style(STYLE_INDETERMINATE_PROGRESS_BAR)
+            .background("transparent")
+            .border_radius(2.0)
+            .foreground(colors::LINK_WATER_COLOR)
+            .padding(0.0)
+            .height(4.0)
+            .child(
+                Container::new()
+                    .id(ID_INDICATOR)
+                    .background(("foreground", id))
+                    .border_radius(id)
+                    .width(12.0)
+                    .v_align("stretch")
+                    .h_align("start")
+                    .build(ctx),
+            )
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(PaddingLayout::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_indicator_x() {
+        assert_eq!(0.0, calculate_indicator_x(0.0, 100.0));
+        assert_eq!(50.0, calculate_indicator_x(MOVE_TIME / 2.0, 100.0));
+        assert_eq!(100.0, calculate_indicator_x(MOVE_TIME, 100.0));
+        assert_eq!(50.0, calculate_indicator_x(MOVE_TIME * 1.5, 100.0));
+        assert_eq!(0.0, calculate_indicator_x(MOVE_TIME * 2.0, 100.0));
+        assert_eq!(0.0, calculate_indicator_x(1.0, 0.0));
+    }
+}
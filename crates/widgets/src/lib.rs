@@ -9,6 +9,7 @@ pub(crate) use orbtk_proc_macros as proc_macros;
 pub(crate) use orbtk_render as render;
 pub(crate) use orbtk_shell as shell;
 pub(crate) use orbtk_theme as theme;
+pub(crate) use orbtk_tree as tree;
 
 pub use self::button::*;
 pub use self::canvas::*;
@@ -16,14 +17,23 @@ pub use self::check_box::*;
 pub use self::combo_box::*;
 pub use self::container::*;
 pub use self::cursor::*;
+#[cfg(all(debug_assertions, feature = "debug-overlay"))]
+pub use self::debug::*;
+pub use self::dock::*;
+pub use self::error_boundary::*;
+pub use self::expandable_text_block::*;
 pub use self::font_icon_block::*;
+pub use self::form::*;
 pub use self::grid::*;
 pub use self::image_widget::*;
+pub use self::indeterminate_progress_bar::*;
 pub use self::items_widget::*;
 pub use self::list_view::*;
+pub use self::masonry::*;
 pub use self::numeric_box::*;
 pub use self::popup::*;
 pub use self::progress_bar::*;
+pub use self::range_slider::*;
 pub use self::scroll_bar::*;
 pub use self::scroll_indicator::*;
 pub use self::scroll_viewer::*;
@@ -34,7 +44,10 @@ pub use self::tab_widget::*;
 pub use self::text_block::*;
 pub use self::text_box::*;
 pub use self::toggle_button::*;
+pub use self::tree_view::*;
+pub use self::validation_message::*;
 pub use self::window::*;
+pub use self::wrap::*;
 
 pub mod behaviors;
 mod button;
@@ -43,14 +56,23 @@ mod check_box;
 mod combo_box;
 mod container;
 mod cursor;
+#[cfg(all(debug_assertions, feature = "debug-overlay"))]
+pub mod debug;
+mod dock;
+mod error_boundary;
+mod expandable_text_block;
 mod font_icon_block;
+mod form;
 mod grid;
 mod image_widget;
+mod indeterminate_progress_bar;
 mod items_widget;
 mod list_view;
+mod masonry;
 mod numeric_box;
 mod popup;
 mod progress_bar;
+mod range_slider;
 mod scroll_bar;
 mod scroll_indicator;
 mod scroll_viewer;
@@ -61,4 +83,7 @@ mod tab_widget;
 mod text_block;
 mod text_box;
 mod toggle_button;
+mod tree_view;
+mod validation_message;
 mod window;
+mod wrap;
@@ -93,7 +93,9 @@ impl State for ListViewState {
             if selected {
                 widget.get_mut::<Selector>("selector").set_state("selected");
             } else {
-                widget.get_mut::<Selector>("selector").clear_state();
+                widget
+                    .get_mut::<Selector>("selector")
+                    .remove_state("selected");
             }
 
             widget.update(false);
@@ -197,7 +199,7 @@ widget!(
     /// The `ListViewItem` describes an item inside of a `ListView`.
     ///
     /// **style:** `list-view``
-    ListViewItem<ListViewItemState>: MouseHandler {
+    ListViewItem<ListViewItemState>: MouseHandler, DragHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -0,0 +1,52 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+/// The `MasonryState` reports changes of the content size of the `Masonry` after the layout.
+#[derive(Default, AsAny)]
+pub struct MasonryState {
+    size: (f64, f64),
+}
+
+impl State for MasonryState {
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let bounds = *ctx.widget().get::<Rectangle>("bounds");
+        let size = (bounds.width(), bounds.height());
+
+        if size == self.size {
+            return;
+        }
+
+        self.size = size;
+        ctx.push_event_strategy_by_entity(
+            LayoutCompleteEvent(ctx.entity),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+}
+
+widget!(
+    /// The `Masonry` orders its children in columns of the same width, each child is placed in the
+    /// lowest column. It's used for items with different heights like image galleries or cards.
+    ///
+    /// The `on_layout_complete` handler is called each time the layout changes the size of the
+    /// masonry e.g. to load more items.
+    ///
+    /// **style:** `masonry`
+    Masonry<MasonryState>: LayoutCompleteHandler {
+        /// Sets or shares the number of columns.
+        columns: usize,
+
+        /// Sets or shares the space between the columns and the children of a column.
+        gap: f64
+    }
+);
+
+impl Template for Masonry {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("Masonry").style("masonry").columns(2).gap(0.0)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(MasonryLayout::new())
+    }
+}
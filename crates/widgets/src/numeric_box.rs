@@ -1,5 +1,9 @@
 use core::f64::MAX;
 use rust_decimal::prelude::*;
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
 use super::behaviors::MouseBehavior;
 
@@ -19,6 +23,17 @@ pub enum InputAction {
     ChangeByKey(KeyEvent),
     ChangeByMouseScroll(Point),
     Focus,
+    TextChanged,
+    InputFocusChanged,
+}
+
+// Describes the text of the input.
+#[derive(Debug, PartialEq)]
+enum InputValue {
+    Value(Decimal),
+    // the text could be the start of a number e.g. `-` or `1.`
+    Incomplete,
+    Invalid,
 }
 
 #[derive(Default, AsAny)]
@@ -29,6 +44,9 @@ pub struct NumericBoxState {
     pub max: Decimal,
     pub step: Decimal,
     pub current_value: Decimal,
+    pub last_change_instant: Option<Instant>,
+    // last text of the input that is a number or the start of a number
+    input_text: String16,
 }
 
 impl NumericBoxState {
@@ -37,19 +55,84 @@ impl NumericBoxState {
     }
 
     fn change_val(&mut self, new_value: Decimal, ctx: &mut Context) {
+        if *ctx.widget().get::<bool>("wrap") {
+            let new_value = self.wrap(new_value);
+            self.set_val(new_value, ctx);
+            self.reset_input_text(ctx);
+            return;
+        }
+
         if self.current_value == self.min && new_value < self.min
             || self.current_value == self.max && new_value > self.max
         {
             return;
         }
 
+        self.set_val(new_value, ctx);
+        self.reset_input_text(ctx);
+    }
+
+    // sets val without changing the text of the input
+    fn set_val(&mut self, new_value: Decimal, ctx: &mut Context) {
+        let old_value = self.current_value.to_f64();
         self.current_value = self.max(self.min(new_value));
         if let Some(val) = self.current_value.to_f64() {
-            ctx.widget().set("val", val);
+            // with a commit delay the changed event is pushed by `commit` after the last change
+            if *ctx.widget().get::<u64>("commit_delay_ms") > 0 {
+                ctx.widget().set_silent("val", val);
+                self.last_change_instant = Some(Instant::now());
+            } else {
+                ctx.widget().set("val", val);
+            }
+
+            if let Some(old_value) = old_value {
+                if old_value != val {
+                    ctx.undo_service().push(UndoAction::property(
+                        "Change value",
+                        ctx.entity,
+                        "val",
+                        old_value,
+                        val,
+                    ));
+                }
+            }
         }
+    }
 
+    // shows the current value in its canonical format
+    fn reset_input_text(&mut self, ctx: &mut Context) {
+        let precision = *ctx.widget().get::<Option<usize>>("precision");
+        self.input_text = String16::from(format_value(self.current_value, precision));
         ctx.get_widget(self.input)
-            .set::<String16>("text", String16::from(self.current_value.to_string()));
+            .set::<String16>("text", self.input_text.clone());
+    }
+
+    // applies a value that is typed into the input
+    fn change_by_text(&mut self, ctx: &mut Context) {
+        let text = ctx.get_widget(self.input).clone::<String16>("text");
+
+        if text == self.input_text {
+            return;
+        }
+
+        match parse_input(&text.as_string()) {
+            InputValue::Value(value) => {
+                self.input_text = text;
+
+                // a value out of range could be the start of a valid value, it is corrected if
+                // the input loses the focus
+                if value >= self.min && value <= self.max && value != self.current_value {
+                    self.set_val(value, ctx);
+                }
+            }
+            InputValue::Incomplete => {
+                self.input_text = text;
+            }
+            InputValue::Invalid => {
+                ctx.get_widget(self.input)
+                    .set::<String16>("text", self.input_text.clone());
+            }
+        }
     }
 
     fn min(&self, d: Decimal) -> Decimal {
@@ -68,6 +151,51 @@ impl NumericBoxState {
         }
     }
 
+    // continues on the other bound if the value is stepped out of range
+    fn wrap(&self, d: Decimal) -> Decimal {
+        if d > self.max {
+            return self.min + (d - self.max - self.step);
+        }
+
+        if d < self.min {
+            return self.max - (self.min - d - self.step);
+        }
+
+        d
+    }
+
+    // synchronize the current value if val is changed from outside e.g. by undo
+    fn check_outside_update(&mut self, ctx: &mut Context) {
+        let val = *ctx.widget().get::<f64>("val");
+
+        if Some(val) == self.current_value.to_f64() {
+            return;
+        }
+
+        if let Some(val) = Decimal::from_f64(val) {
+            self.current_value = self.max(self.min(val));
+            self.reset_input_text(ctx);
+        }
+    }
+
+    // pushes the changed event of val if the commit delay is elapsed since the last change
+    fn commit(&mut self, ctx: &mut Context) {
+        if let Some(last_change_instant) = self.last_change_instant {
+            let delay = Duration::from_millis(*ctx.widget().get::<u64>("commit_delay_ms"));
+
+            if last_change_instant.elapsed() < delay {
+                return;
+            }
+
+            self.last_change_instant = None;
+            ctx.push_event_strategy_by_entity(
+                ChangedEvent(ctx.entity, String::from("val")),
+                ctx.entity,
+                EventStrategy::Direct,
+            );
+        }
+    }
+
     fn request_focus(&self, ctx: &mut Context) {
         if !ctx.widget().get::<bool>("focused") {
             ctx.widget().set::<bool>("focused", true);
@@ -76,6 +204,37 @@ impl NumericBoxState {
     }
 }
 
+// Parses the text of the input.
+fn parse_input(text: &str) -> InputValue {
+    let text = text.trim();
+
+    if let Ok(value) = Decimal::from_str(text) {
+        return InputValue::Value(value);
+    }
+
+    if text.is_empty() || text == "-" {
+        return InputValue::Incomplete;
+    }
+
+    if text.ends_with('.') {
+        let number = &text[..text.len() - 1];
+
+        if number == "-" || (!number.contains('.') && Decimal::from_str(number).is_ok()) {
+            return InputValue::Incomplete;
+        }
+    }
+
+    InputValue::Invalid
+}
+
+// rounds the value to the given number of decimal places
+fn format_value(value: Decimal, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => value.round_dp(precision as u32).to_string(),
+        None => value.to_string(),
+    }
+}
+
 fn default_or(key: &str, default_value: f64, ctx: &mut Context) -> Decimal {
     let property = ctx.widget().clone_or_default(key);
 
@@ -96,13 +255,13 @@ impl State for NumericBoxState {
         self.step = default_or("step", 1.0, ctx);
         self.current_value = default_or("val", 0.0, ctx);
 
-        let init_value = String16::from(self.current_value.to_string());
-        ctx.get_widget(self.input)
-            .set::<String16>("text", init_value);
+        self.reset_input_text(ctx);
     }
 
-    // TODO: let the user type the value, or select it for cut, copy, paste operations
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.check_outside_update(ctx);
+        self.commit(ctx);
+
         if let Some(action) = &self.action {
             match action {
                 InputAction::Inc => {
@@ -111,7 +270,7 @@ impl State for NumericBoxState {
                 InputAction::Dec => {
                     self.change_val(self.current_value - self.step, ctx);
                 }
-                InputAction::ChangeByKey(key_event) => match key_event.key {
+                InputAction::ChangeByKey(key_event) => match key_event.logical.0 {
                     Key::Up | Key::NumpadAdd => {
                         self.change_val(self.current_value + self.step, ctx);
                     }
@@ -141,10 +300,25 @@ impl State for NumericBoxState {
                 InputAction::Focus => {
                     self.request_focus(ctx);
                 }
+                InputAction::TextChanged => {
+                    self.change_by_text(ctx);
+                }
+                InputAction::InputFocusChanged => {
+                    if !*ctx.get_widget(self.input).get::<bool>("focused") {
+                        self.reset_input_text(ctx);
+                    }
+                }
             }
             self.action = None;
         }
     }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // polls the commit until the delay is elapsed
+        if self.last_change_instant.is_some() {
+            ctx.request_update();
+        }
+    }
 }
 
 widget!(
@@ -152,7 +326,9 @@ widget!(
     /// the value of the input by a given, fixed value called `step` until it reaches the upper or
     /// lower bounds.
     /// The widget can be controlled by clicking on the two control buttons, or the keybaord's
-    /// Up and Down, Numpad+ and Numpad- keys, or the mouse scroll.
+    /// Up and Down, Numpad+ and Numpad- keys, or the mouse scroll. The value could also be typed,
+    /// it is applied if it is a number between min and max. If the input loses the focus, the
+    /// current value is shown again.
     /// Note: after the widget is initialized, changing the min, max or step properties has no effect.
     ///
     /// # Examples:
@@ -165,6 +341,21 @@ widget!(
     /// ```rust
     /// NumericBox::new().min(10.0).max(100.0).val(50.0).step(5.0).build(ctx)
     /// ```
+    ///
+    /// Create a NumericBox that shows a unit next to the value:
+    /// ```rust
+    /// NumericBox::new().max(500.0).suffix("kg").build(ctx)
+    /// ```
+    ///
+    /// Create a NumericBox for the hours of a day:
+    /// ```rust
+    /// NumericBox::new().max(23.0).wrap(true).build(ctx)
+    /// ```
+    ///
+    /// Create a NumericBox that shows the value with two decimal places:
+    /// ```rust
+    /// NumericBox::new().step(0.01).precision(2).build(ctx)
+    /// ```
     NumericBox<NumericBoxState>: ActivateHandler, KeyDownHandler {
         /// Sets or shares the background color property
         background: Brush,
@@ -181,6 +372,12 @@ widget!(
         /// Sets or shares the focused property
         focused: bool,
 
+        /// Sets or shares the text that is shown left of the value e.g. `$`.
+        prefix: String16,
+
+        /// Sets or shares the text that is shown right of the value e.g. `kg` or `%`.
+        suffix: String16,
+
         /// Sets or shares the foreground color property
         foreground: Brush,
 
@@ -197,12 +394,59 @@ widget!(
         step: f64,
 
         /// Sets or shares the current value property
-        val: f64
+        val: f64,
+
+        /// Sets or shares the value that describes if the value continues on the other bound if it
+        /// is stepped over min or max, e.g. for hours or degrees.
+        wrap: bool,
+
+        /// Sets or shares the number of decimal places of the displayed value. With `None` the
+        /// value is shown with all of its decimal places.
+        precision: Option<usize>,
+
+        /// Sets or shares the time in milliseconds after the last change of val until the changed
+        /// callback of val is called. With `0` the callback is called on each change.
+        commit_delay_ms: u64
     }
 );
 
 impl Template for NumericBox {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let (up_icon_font, up_icon) = ctx.icon_glyph("keyboard_arrow_up");
+        let (down_icon_font, down_icon) = ctx.icon_glyph("keyboard_arrow_down");
+
+        let input = TextBox::new()
+            .id(ID_INPUT)
+            .style("")
+            .attach(Grid::column(1))
+            .attach(Grid::row_span(2))
+            .attach(Grid::row(0))
+            .foreground(id)
+            .border_brush("transparent")
+            .border_width(0)
+            .background("transparent")
+            .h_align("stretch")
+            .max_width(96.)
+            .text("0")
+            .lost_focus_on_activation(id)
+            .on_changed(move |states, _, key| {
+                let action = if key == "focused" {
+                    InputAction::InputFocusChanged
+                } else {
+                    InputAction::TextChanged
+                };
+
+                states.get_mut::<NumericBoxState>(id).action(action);
+            })
+            .build(ctx);
+
+        // the changed filter of the text box template does not contain the focus
+        ctx.register_property(
+            "on_changed_filter",
+            input,
+            Filter::List(vec!["text".to_string(), "focused".to_string()]),
+        );
+
         self.name("NumericBox")
             .style("numeric_box")
             .on_changed_filter(vec!["val"])
@@ -212,12 +456,17 @@ impl Template for NumericBox {
             .border_width(1.0)
             .border_radius(3.0)
             .focused(false)
+            .prefix("")
+            .suffix("")
             .height(32.0)
             .lost_focus_on_activation(true)
             .min(0.0)
             .max(200.0)
             .step(1.0)
             .val(0.0)
+            .precision(None)
+            .wrap(false)
+            .commit_delay_ms(0)
             .min_width(128.0)
             .child(
                 MouseBehavior::new()
@@ -237,34 +486,40 @@ impl Template for NumericBox {
             )
             .child(
                 Grid::new()
-                    .columns(Columns::new().add("*").add(32.))
+                    .columns(Columns::new().add("auto").add("*").add("auto").add(32.))
                     .rows(Rows::new().add(16.0).add(16.0))
                     .child(
-                        TextBox::new()
-                            .id(ID_INPUT)
-                            .style("")
+                        TextBlock::new()
                             .attach(Grid::column(0))
                             .attach(Grid::row_span(2))
                             .attach(Grid::row(0))
+                            .v_align("center")
+                            .margin((4, 0, 0, 0))
+                            .foreground(id)
+                            .text(("prefix", id))
+                            .build(ctx),
+                    )
+                    .child(input)
+                    .child(
+                        TextBlock::new()
+                            .attach(Grid::column(2))
+                            .attach(Grid::row_span(2))
+                            .attach(Grid::row(0))
+                            .v_align("center")
+                            .margin((0, 0, 4, 0))
                             .foreground(id)
-                            .border_brush("transparent")
-                            .border_width(0)
-                            .background("transparent")
-                            .h_align("stretch")
-                            .enabled(false)
-                            .max_width(96.)
-                            .text("0")
-                            .lost_focus_on_activation(id)
+                            .text(("suffix", id))
                             .build(ctx),
                     )
                     .child(
                         Button::new()
                             .style("button_small")
-                            .attach(Grid::column(1))
+                            .attach(Grid::column(3))
                             .attach(Grid::row(0))
                             .min_width(14)
                             .height(15)
-                            .icon(material_icons_font::MD_KEYBOARD_ARROW_UP)
+                            .icon(up_icon)
+                            .icon_font(up_icon_font)
                             .margin(1)
                             .on_click(move |states, _| {
                                 states
@@ -277,13 +532,14 @@ impl Template for NumericBox {
                     .child(
                         Button::new()
                             .style("button_small")
-                            .attach(Grid::column(1))
+                            .attach(Grid::column(3))
                             .attach(Grid::row(1))
                             .min_width(14)
                             .height(15)
                             .padding(0.0)
                             .margin(1)
-                            .icon(material_icons_font::MD_KEYBOARD_ARROW_DOWN)
+                            .icon(down_icon)
+                            .icon_font(down_icon_font)
                             .on_click(move |states, _| {
                                 states
                                     .get_mut::<NumericBoxState>(id)
@@ -306,3 +562,95 @@ impl Template for NumericBox {
         Box::new(RectangleRenderObject)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("12.5"), InputValue::Value(Decimal::new(125, 1)));
+        assert_eq!(parse_input(" -3 "), InputValue::Value(Decimal::new(-3, 0)));
+
+        assert_eq!(parse_input(""), InputValue::Incomplete);
+        assert_eq!(parse_input("-"), InputValue::Incomplete);
+        assert_ne!(parse_input("12."), InputValue::Invalid);
+
+        assert_eq!(parse_input("abc"), InputValue::Invalid);
+        assert_eq!(parse_input("1.2."), InputValue::Invalid);
+        assert_eq!(parse_input("1a"), InputValue::Invalid);
+    }
+
+    #[test]
+    fn test_wrap() {
+        let state = NumericBoxState {
+            min: Decimal::new(0, 0),
+            max: Decimal::new(23, 0),
+            step: Decimal::new(1, 0),
+            ..Default::default()
+        };
+
+        assert_eq!(state.wrap(Decimal::new(24, 0)), Decimal::new(0, 0));
+        assert_eq!(state.wrap(Decimal::new(-1, 0)), Decimal::new(23, 0));
+        assert_eq!(state.wrap(Decimal::new(12, 0)), Decimal::new(12, 0));
+    }
+
+    #[test]
+    fn test_format_value() {
+        let value = Decimal::new(30000000000000004, 17);
+
+        assert_eq!(format_value(value, Some(1)), "0.3");
+        assert_eq!(format_value(value, Some(0)), "0");
+        assert_eq!(format_value(Decimal::new(125, 2), Some(1)), "1.2");
+        assert_eq!(format_value(value, None), "0.30000000000000004");
+    }
+}
+
+#[cfg(test)]
+mod prop_tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Creates a state with the given bounds and step in hundredths.
+    fn state(min: i64, max: i64, step: i64) -> NumericBoxState {
+        NumericBoxState {
+            min: Decimal::new(min, 2),
+            max: Decimal::new(max, 2),
+            step: Decimal::new(step, 2),
+            ..Default::default()
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_step_stays_in_range(
+            min in -100_000i64..100_000,
+            range in 0i64..100_000,
+            offset in 0i64..100_000,
+            step in 1i64..10_000
+        ) {
+            let state = state(min, min + range, step);
+            let current = state.max(state.min(Decimal::new(min + offset, 2)));
+
+            let inc = state.max(state.min(current + state.step));
+            let dec = state.max(state.min(current - state.step));
+
+            prop_assert!(inc >= state.min && inc <= state.max);
+            prop_assert!(dec >= state.min && dec <= state.max);
+            prop_assert!(dec <= current && current <= inc);
+        }
+
+        #[test]
+        fn test_clamp_idempotent(
+            min in -100_000i64..100_000,
+            range in 0i64..100_000,
+            val in -300_000i64..300_000
+        ) {
+            let state = state(min, min + range, 1);
+            let clamped = state.max(state.min(Decimal::new(val, 2)));
+
+            prop_assert_eq!(clamped, state.max(state.min(clamped)));
+        }
+    }
+}
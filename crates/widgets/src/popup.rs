@@ -25,24 +25,6 @@ impl State for PopupState {
             }
         }
     }
-
-    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if *ctx.widget().get::<Visibility>("visibility") != Visibility::Visible {
-            return;
-        }
-
-        if let Some(target) = ctx.widget().try_clone::<u32>("target") {
-            let target_position: Point = ctx.get_widget(target.into()).clone("position");
-            let target_bounds: Rectangle = ctx.get_widget(target.into()).clone("bounds");
-
-            ctx.widget()
-                .get_mut::<Rectangle>("bounds")
-                .set_x(target_position.x() + target_bounds.x());
-            ctx.widget()
-                .get_mut::<Rectangle>("bounds")
-                .set_y(1.0 + target_position.y() + target_bounds.y() + target_bounds.height());
-        }
-    }
 }
 
 widget!(
@@ -63,11 +45,18 @@ widget!(
         /// Sets or shares the padding property.
         padding: Thickness,
 
-        /// Sets or shares the target id to place the popup.
+        /// Sets or shares the id of the widget the popup is placed at. The target could be anywhere
+        /// in the tree, the popup itself is usually added to the overlay.
         target: u32,
 
         /// Sets or shares the value if the popup is open and visible.
-        open: bool
+        open: bool,
+
+        /// Sets or shares the side of the target the popup is placed on.
+        preferred_side: PopupSide,
+
+        /// Sets or shares the horizontal alignment of the popup to its target.
+        preferred_alignment: PopupAlignment
     }
 );
 
@@ -76,6 +65,8 @@ impl Template for Popup {
         self.name("Popup")
             .style("popup")
             .open(false)
+            .preferred_side("auto")
+            .preferred_alignment("auto")
             .padding(0.0)
             .background("transparent")
             .border_radius(0.0)
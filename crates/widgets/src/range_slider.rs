@@ -0,0 +1,416 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*};
+
+// --- KEYS --
+pub static STYLE_RANGE_SLIDER: &'static str = "range_slider";
+static ID_LO_THUMB: &'static str = "id_lo_thumb";
+static ID_HI_THUMB: &'static str = "id_hi_thumb";
+static ID_TRACK: &'static str = "id_track";
+static ID_FILL: &'static str = "id_fill";
+// --- KEYS --
+
+#[derive(Copy, Clone)]
+enum RangeSliderAction {
+    Move { mouse_x: f64 },
+}
+
+/// The `RangeSliderState` moves the thumbs of the range slider and keeps them from crossing each
+/// other.
+#[derive(Default, AsAny)]
+pub struct RangeSliderState {
+    action: Option<RangeSliderAction>,
+    lo_thumb: Entity,
+    hi_thumb: Entity,
+    track: Entity,
+    fill: Entity,
+    lo_val: f64,
+    hi_val: f64,
+    min: f64,
+    max: f64,
+    dragged_thumb: Option<Entity>,
+}
+
+impl RangeSliderState {
+    // register an action
+    fn action(&mut self, action: RangeSliderAction) {
+        self.action = Some(action);
+    }
+
+    // adjust min, max, lo_val and hi_val, returns `true` if lo_val or hi_val is changed
+    fn adjust(&mut self, ctx: &mut Context) -> bool {
+        let min = *ctx.widget().get::<f64>("min");
+        let max = (*ctx.widget().get::<f64>("max")).max(min);
+        let (lo_val, hi_val) = adjust_range(
+            *ctx.widget().get::<f64>("lo_val"),
+            *ctx.widget().get::<f64>("hi_val"),
+            min,
+            max,
+        );
+
+        if *ctx.widget().get::<f64>("max") != max {
+            ctx.widget().set("max", max);
+        }
+
+        if *ctx.widget().get::<f64>("lo_val") != lo_val {
+            ctx.widget().set("lo_val", lo_val);
+        }
+
+        if *ctx.widget().get::<f64>("hi_val") != hi_val {
+            ctx.widget().set("hi_val", hi_val);
+        }
+
+        let has_changes = lo_val != self.lo_val || hi_val != self.hi_val;
+
+        self.min = min;
+        self.max = max;
+        self.lo_val = lo_val;
+        self.hi_val = hi_val;
+
+        has_changes
+    }
+
+    // moves the thumbs to the positions of lo_val and hi_val
+    fn adjust_thumbs(&self, ctx: &mut Context) {
+        let thumb_width = ctx
+            .get_widget(self.lo_thumb)
+            .get::<Rectangle>("bounds")
+            .width();
+        let track_width = ctx
+            .get_widget(self.track)
+            .get::<Rectangle>("bounds")
+            .width();
+
+        for (thumb, val) in &[(self.lo_thumb, self.lo_val), (self.hi_thumb, self.hi_val)] {
+            let thumb_x =
+                calculate_thumb_x_from_val(*val, self.min, self.max, track_width, thumb_width);
+
+            if ctx.get_widget(*thumb).get::<Thickness>("margin").left() != thumb_x {
+                ctx.get_widget(*thumb)
+                    .get_mut::<Thickness>("margin")
+                    .set_left(thumb_x);
+            }
+        }
+
+        self.update_fill(ctx);
+    }
+
+    // the fill covers the track between the centers of the thumbs
+    fn update_fill(&self, ctx: &mut Context) {
+        let lo_x = ctx
+            .get_widget(self.lo_thumb)
+            .get::<Thickness>("margin")
+            .left();
+        let hi_x = ctx
+            .get_widget(self.hi_thumb)
+            .get::<Thickness>("margin")
+            .left();
+        let thumb_width = ctx
+            .get_widget(self.lo_thumb)
+            .get::<Rectangle>("bounds")
+            .width();
+
+        let fill_x = lo_x + thumb_width / 2.0;
+        let fill_width = hi_x - lo_x;
+
+        if ctx.get_widget(self.fill).get::<Thickness>("margin").left() != fill_x {
+            ctx.get_widget(self.fill)
+                .get_mut::<Thickness>("margin")
+                .set_left(fill_x);
+        }
+
+        if ctx
+            .get_widget(self.fill)
+            .get::<Constraint>("constraint")
+            .width()
+            != fill_width
+        {
+            ctx.get_widget(self.fill)
+                .get_mut::<Constraint>("constraint")
+                .set_width(fill_width);
+        }
+    }
+
+    // moves the dragged thumb to the mouse, a thumb could not be moved over the other one
+    fn move_thumb(&mut self, mouse_x: f64, ctx: &mut Context) {
+        let lo_pressed = *ctx.get_widget(self.lo_thumb).get::<bool>("pressed");
+        let hi_pressed = *ctx.get_widget(self.hi_thumb).get::<bool>("pressed");
+
+        if !lo_pressed && !hi_pressed {
+            self.dragged_thumb = None;
+            return;
+        }
+
+        let thumb_width = ctx
+            .get_widget(self.lo_thumb)
+            .get::<Rectangle>("bounds")
+            .width();
+        let track_width = ctx
+            .get_widget(self.track)
+            .get::<Rectangle>("bounds")
+            .width();
+        let track_x = ctx.get_widget(self.track).get::<Point>("position").x();
+
+        let thumb_x = calculate_thumb_x(mouse_x, thumb_width, track_x, track_width);
+        let val = calculate_val(thumb_x, self.min, self.max, thumb_width, track_width);
+
+        // if both thumbs are on the same position the high thumb is on top, in this case the
+        // thumb that is dragged is selected by the direction of the first move
+        let dragged_thumb = match self.dragged_thumb {
+            Some(thumb) => thumb,
+            None if self.lo_val == self.hi_val && val < self.lo_val => self.lo_thumb,
+            None if hi_pressed => self.hi_thumb,
+            None => self.lo_thumb,
+        };
+        self.dragged_thumb = Some(dragged_thumb);
+
+        if dragged_thumb == self.lo_thumb {
+            ctx.widget().set("lo_val", val.min(self.hi_val));
+        } else {
+            ctx.widget().set("hi_val", val.max(self.lo_val));
+        }
+    }
+}
+
+impl State for RangeSliderState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.lo_thumb = ctx
+            .entity_of_child(ID_LO_THUMB)
+            .expect("RangeSliderState.init: Low thumb child could not be found.");
+        self.hi_thumb = ctx
+            .entity_of_child(ID_HI_THUMB)
+            .expect("RangeSliderState.init: High thumb child could not be found.");
+        self.track = ctx
+            .entity_of_child(ID_TRACK)
+            .expect("RangeSliderState.init: Track child could not be found.");
+        self.fill = ctx
+            .entity_of_child(ID_FILL)
+            .expect("RangeSliderState.init: Fill child could not be found.");
+
+        // the initial values are not reported as change
+        self.lo_val = *ctx.widget().get::<f64>("lo_val");
+        self.hi_val = *ctx.widget().get::<f64>("hi_val");
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action.take() {
+            match action {
+                RangeSliderAction::Move { mouse_x } => {
+                    self.move_thumb(mouse_x, ctx);
+                }
+            }
+        }
+
+        if self.adjust(ctx) {
+            ctx.push_event_strategy_by_entity(
+                RangeChangedEvent {
+                    entity: ctx.entity,
+                    lo: self.lo_val,
+                    hi: self.hi_val,
+                },
+                ctx.entity,
+                EventStrategy::Direct,
+            );
+        }
+
+        self.adjust_thumbs(ctx);
+    }
+}
+
+widget!(
+    /// The `RangeSlider` allows to select a range between `min` and `max` with two thumbs. The
+    /// thumbs could not cross each other, so `lo_val` is never greater than `hi_val`.
+    ///
+    /// ```rust
+    /// RangeSlider::new()
+    ///     .min(0.0)
+    ///     .max(1000.0)
+    ///     .lo_val(200.0)
+    ///     .hi_val(800.0)
+    ///     .on_range_changed(|_, _, lo, hi| println!("{} - {}", lo, hi))
+    ///     .build(ctx)
+    /// ```
+    ///
+    /// **style:** `range_slider`
+    RangeSlider<RangeSliderState>: MouseHandler, RangeChangedHandler {
+        /// Sets or shares the min val of the range.
+        min: f64,
+
+        /// Sets or shares the max val of the range.
+        max: f64,
+
+        /// Sets or shares the low val of the selected range.
+        lo_val: f64,
+
+        /// Sets or shares the high val of the selected range.
+        hi_val: f64,
+
+        /// Sets or shares the name that describes the range slider for assistive technologies.
+        accessible_name: String,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the brush of the track between the thumbs.
+        fill_brush: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush
+    }
+);
+
+impl Template for RangeSlider {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("RangeSlider")
+            .style(STYLE_RANGE_SLIDER)
+            .on_changed_filter(vec!["lo_val", "hi_val"])
+            .min(0.0)
+            .max(100.0)
+            .lo_val(0.0)
+            .hi_val(100.0)
+            .height(24.0)
+            .border_radius(2.0)
+            .child(
+                Grid::new()
+                    .margin((8, 0))
+                    .id(ID_TRACK)
+                    .child(
+                        Container::new()
+                            .border_radius(id)
+                            .background(id)
+                            .v_align("center")
+                            .height(2.0)
+                            .build(ctx),
+                    )
+                    .child(
+                        Container::new()
+                            .id(ID_FILL)
+                            .border_radius(id)
+                            .background(("fill_brush", id))
+                            .v_align("center")
+                            .h_align("start")
+                            .width(0.0)
+                            .height(2.0)
+                            .enabled(false)
+                            .build(ctx),
+                    )
+                    .child(build_thumb(ID_LO_THUMB, id, ctx))
+                    .child(build_thumb(ID_HI_THUMB, id, ctx))
+                    .build(ctx),
+            )
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<RangeSliderState>(id)
+                    .action(RangeSliderAction::Move { mouse_x: p.x() });
+                false
+            })
+    }
+}
+
+// --- Helpers --
+
+fn build_thumb(id: &str, range_slider: Entity, ctx: &mut BuildContext) -> Entity {
+    Button::new()
+        .style("thumb")
+        .id(id)
+        .accessible_name(range_slider)
+        .v_align("center")
+        .h_align("start")
+        .max_width(24.0)
+        .max_height(24.0)
+        .border_radius(12.0)
+        .build(ctx)
+}
+
+// Clamps both values to min and max, the low value is clamped to the high value.
+fn adjust_range(lo_val: f64, hi_val: f64, min: f64, max: f64) -> (f64, f64) {
+    let hi_val = hi_val.max(min).min(max);
+    let lo_val = lo_val.max(min).min(hi_val);
+
+    (lo_val, hi_val)
+}
+
+// Returns the left of the thumb that is centered on the mouse.
+fn calculate_thumb_x(mouse_x: f64, thumb_width: f64, track_x: f64, track_width: f64) -> f64 {
+    (mouse_x - track_x - thumb_width / 2.0)
+        .min(track_width - thumb_width)
+        .max(0.0)
+}
+
+fn calculate_val(thumb_x: f64, min: f64, max: f64, thumb_width: f64, track_width: f64) -> f64 {
+    if track_width <= thumb_width {
+        return min;
+    }
+
+    min + thumb_x / (track_width - thumb_width) * (max - min)
+}
+
+fn calculate_thumb_x_from_val(
+    val: f64,
+    min: f64,
+    max: f64,
+    track_width: f64,
+    thumb_width: f64,
+) -> f64 {
+    if max <= min {
+        return 0.0;
+    }
+
+    (val - min) / (max - min) * (track_width - thumb_width).max(0.0)
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjust_range() {
+        assert_eq!((20.0, 80.0), adjust_range(20.0, 80.0, 0.0, 100.0));
+        assert_eq!((0.0, 100.0), adjust_range(-20.0, 180.0, 0.0, 100.0));
+        assert_eq!((50.0, 50.0), adjust_range(80.0, 50.0, 0.0, 100.0));
+        assert_eq!((10.0, 10.0), adjust_range(5.0, 5.0, 10.0, 100.0));
+    }
+
+    #[test]
+    fn test_calculate_thumb_x() {
+        assert_eq!(0.0, calculate_thumb_x(-1000.0, 32.0, 0.0, 100.0));
+        assert_eq!(34.0, calculate_thumb_x(50.0, 32.0, 0.0, 100.0));
+        assert_eq!(34.0, calculate_thumb_x(60.0, 32.0, 10.0, 100.0));
+        assert_eq!(68.0, calculate_thumb_x(1000.0, 32.0, 0.0, 100.0));
+    }
+
+    #[test]
+    fn test_calculate_val() {
+        assert_eq!(0.0, calculate_val(0.0, 0.0, 100.0, 32.0, 100.0));
+        assert_eq!(50.0, calculate_val(34.0, 0.0, 100.0, 32.0, 100.0));
+        assert_eq!(-50.0, calculate_val(0.0, -50.0, 50.0, 32.0, 100.0));
+        assert_eq!(50.0, calculate_val(68.0, -50.0, 50.0, 32.0, 100.0));
+        assert_eq!(10.0, calculate_val(10.0, 10.0, 100.0, 32.0, 20.0));
+    }
+
+    #[test]
+    fn test_calculate_thumb_x_from_val() {
+        assert_eq!(
+            0.0,
+            calculate_thumb_x_from_val(-50.0, -50.0, 50.0, 100.0, 32.0)
+        );
+        assert_eq!(
+            34.0,
+            calculate_thumb_x_from_val(0.0, -50.0, 50.0, 100.0, 32.0)
+        );
+        assert_eq!(
+            68.0,
+            calculate_thumb_x_from_val(100.0, 0.0, 100.0, 100.0, 32.0)
+        );
+        assert_eq!(
+            0.0,
+            calculate_thumb_x_from_val(10.0, 10.0, 10.0, 100.0, 32.0)
+        );
+    }
+}
@@ -1,57 +1,118 @@
 use crate::{api::prelude::*, proc_macros::*};
 
+// Inertia scrolling stops below this velocity (pixels per second).
+const MIN_INERTIA_VELOCITY: f64 = 10.0;
+
+// Exponential decay rate of the inertia velocity per second.
+const INERTIA_FRICTION: f64 = 4.0;
+
 /// The `ScrollViewerState` handles the `ScrollViewer` widget.
 #[derive(Default, AsAny)]
 pub struct ScrollViewerState {
-    delta: Option<Point>,
+    scroll: Option<Scroll>,
+    velocity: Point,
+    scrolled: bool,
 }
 
 impl ScrollViewerState {
-    fn scroll(&mut self, delta: Point) {
-        self.delta = Some(delta);
+    fn scroll(&mut self, scroll: Scroll) {
+        self.scroll = Some(scroll);
+    }
+
+    // Scrolls the first child by the given delta. Returns `false` if the child could not be scrolled.
+    fn scroll_by(&self, delta: Point, ctx: &mut Context) -> bool {
+        let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
+
+        if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
+            return false;
+        }
+
+        let size = ctx.widget().get::<Rectangle>("bounds").size();
+        let speed = *ctx.widget().get::<f64>("speed");
+        let mut padding = *ctx.widget().get::<Thickness>("padding");
+
+        if let Some(child) = &mut ctx.try_child_from_index(0) {
+            let child_size = child.get::<Rectangle>("bounds").size();
+
+            if mode.vertical == ScrollMode::Auto && child_size.1 > size.1 {
+                padding.set_top(offset(
+                    size.1,
+                    child_size.1,
+                    padding.top(),
+                    delta.y() * speed,
+                ));
+            }
+
+            if mode.horizontal == ScrollMode::Auto && child_size.0 > size.0 {
+                padding.set_left(offset(
+                    size.0,
+                    child_size.0,
+                    padding.left(),
+                    delta.x() * speed,
+                ));
+            }
+        } else {
+            return false;
+        }
+
+        if padding == *ctx.widget().get::<Thickness>("padding") {
+            return false;
+        }
+
+        ctx.widget().set("padding", padding);
+        true
     }
 }
 
 impl State for ScrollViewerState {
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if let Some(delta) = self.delta {
-            self.delta = None;
-            let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
+        if let Some(scroll) = self.scroll {
+            self.scroll = None;
+            self.scrolled = true;
+            self.velocity = Point::default();
 
-            if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
+            if !self.scroll_by(scroll.delta, ctx) {
                 return;
             }
 
-            let size = ctx.widget().get::<Rectangle>("bounds").size();
-            let speed = *ctx.widget().get::<f64>("speed");
-            let mut padding = *ctx.widget().get::<Thickness>("padding");
-
-            if let Some(child) = &mut ctx.try_child_from_index(0) {
-                let child_size = child.get::<Rectangle>("bounds").size();
-
-                if mode.vertical == ScrollMode::Auto && child_size.1 > size.1 {
-                    padding.set_top(offset(
-                        size.1,
-                        child_size.1,
-                        padding.top(),
-                        delta.y() * speed,
-                    ));
-                }
-
-                if mode.horizontal == ScrollMode::Auto && child_size.0 > size.0 {
-                    padding.set_left(offset(
-                        size.0,
-                        child_size.0,
-                        padding.left(),
-                        delta.x() * speed,
-                    ));
-                }
-            } else {
-                return;
+            // trackpad scrolling gets its momentum already from the operating system.
+            if *ctx.widget().get::<bool>("inertia") && !scroll.is_trackpad {
+                self.velocity = scroll.velocity;
             }
+        }
+    }
 
-            ctx.widget().set("padding", padding);
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // inertia starts with the frame after the last scroll event.
+        if self.scrolled {
+            self.scrolled = false;
+            if self.velocity != Point::default() {
+                ctx.request_update();
+            }
+            return;
         }
+
+        if self.velocity.x().abs() < MIN_INERTIA_VELOCITY
+            && self.velocity.y().abs() < MIN_INERTIA_VELOCITY
+        {
+            self.velocity = Point::default();
+            return;
+        }
+
+        let frame_time = ctx.animation_service().frame_time();
+        let delta = Point::new(
+            self.velocity.x() * frame_time,
+            self.velocity.y() * frame_time,
+        );
+
+        if !self.scroll_by(delta, ctx) {
+            self.velocity = Point::default();
+            return;
+        }
+
+        let decay = (-INERTIA_FRICTION * frame_time).exp();
+        self.velocity = Point::new(self.velocity.x() * decay, self.velocity.y() * decay);
+        ctx.request_update();
     }
 }
 
@@ -66,7 +127,11 @@ widget!(
         speed: f64,
 
         /// Sets or shares padding, that is used to scroll the first child.
-        padding: Thickness
+        padding: Thickness,
+
+        /// Sets or shares a value that describes if mouse wheel scrolling continues with decreasing
+        /// velocity after the last scroll event.
+        inertia: bool
     }
 );
 
@@ -77,8 +142,9 @@ impl Template for ScrollViewer {
             .speed(2)
             .clip(true)
             .mode(ScrollViewerMode::default())
-            .on_scroll(move |states, p| {
-                states.get_mut::<ScrollViewerState>(id).scroll(p);
+            .inertia(false)
+            .on_scroll_with_velocity(move |states, scroll| {
+                states.get_mut::<ScrollViewerState>(id).scroll(scroll);
                 false
             })
     }
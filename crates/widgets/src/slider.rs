@@ -1,14 +1,23 @@
-use crate::{api::prelude::*, prelude::*, proc_macros::*};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*};
 
 // --- KEYS --
 pub static STYLE_SLIDER: &'static str = "slider";
+pub static STYLE_SLIDER_VERTICAL: &'static str = "slider_vertical";
 static ID_THUMB: &'static str = "id_thumb";
 static ID_TRACK: &'static str = "id_track";
+static ID_FILL: &'static str = "id_fill";
+static ID_VALUE_LABEL: &'static str = "id_value_label";
 // --- KEYS --
 
+// distance between the center of the track and the ticks
+const TICK_OFFSET: f64 = 3.0;
+
 #[derive(Copy, Clone)]
 enum SliderAction {
-    Move { mouse_x: f64 },
+    Move { mouse_position: Point },
+    Release,
+    Key(Key),
+    Focus,
 }
 
 /// The `SliderState` is used to manipulate the position of the thumb of the slider widget.
@@ -20,6 +29,11 @@ pub struct SliderState {
     max: f64,
     thumb: Entity,
     track: Entity,
+    fill: Entity,
+    value_label: Entity,
+    drag_start_val: Option<f64>,
+    thumb_hovered: bool,
+    cursor_shape: CursorShape,
 }
 
 impl SliderState {
@@ -28,6 +42,196 @@ impl SliderState {
         self.action = Some(action);
     }
 
+    fn set_thumb_hovered(&mut self, hovered: bool) {
+        self.thumb_hovered = hovered;
+    }
+
+    // shows a grab cursor over the thumb and a grabbing cursor while the thumb is dragged
+    fn update_cursor(&mut self, ctx: &mut Context) {
+        let cursor_shape = if *ctx.get_widget(self.thumb).get::<bool>("pressed") {
+            CursorShape::Grabbing
+        } else if self.thumb_hovered {
+            CursorShape::Grab
+        } else {
+            CursorShape::Default
+        };
+
+        if cursor_shape != self.cursor_shape {
+            self.cursor_shape = cursor_shape;
+            ctx.set_cursor(cursor_shape);
+        }
+    }
+
+    fn request_focus(&self, ctx: &mut Context) {
+        if !ctx.widget().get::<bool>("focused") {
+            ctx.widget().set::<bool>("focused", true);
+            ctx.push_event_by_window(FocusEvent::RequestFocus(ctx.entity));
+        }
+    }
+
+    // changes val by the given key, each key press is pushed as one change to the undo stack
+    fn change_val_by_key(&mut self, key: Key, ctx: &mut Context) {
+        let val = *ctx.widget().get::<f64>("val");
+
+        if let Some(new_val) = calculate_val_by_key(
+            key,
+            val,
+            *ctx.widget().get::<f64>("min"),
+            *ctx.widget().get::<f64>("max"),
+            *ctx.widget().get::<f64>("step"),
+            *ctx.widget().get::<f64>("large_step"),
+        ) {
+            if new_val == val {
+                return;
+            }
+
+            ctx.widget().set("val", new_val);
+            ctx.undo_service().push(UndoAction::property(
+                "Change value",
+                ctx.entity,
+                "val",
+                val,
+                new_val,
+            ));
+
+            self.adjust(ctx);
+            self.adjust_thumb(ctx);
+        }
+    }
+
+    fn is_vertical(&self, ctx: &mut Context) -> bool {
+        *ctx.widget().get::<Orientation>("orientation") == Orientation::Vertical
+    }
+
+    // the fill covers the track from its start to the center of the thumb
+    fn update_fill(&self, ctx: &mut Context) {
+        let visibility = if *ctx.widget().get::<bool>("show_fill") {
+            Visibility::Visible
+        } else {
+            Visibility::Collapsed
+        };
+
+        if *ctx.get_widget(self.fill).get::<Visibility>("visibility") != visibility {
+            ctx.get_widget(self.fill).set("visibility", visibility);
+        }
+
+        if visibility != Visibility::Visible {
+            return;
+        }
+
+        if self.is_vertical(ctx) {
+            self.update_fill_height(ctx);
+            return;
+        }
+
+        let thumb_x = ctx.get_widget(self.thumb).get::<Thickness>("margin").left();
+        let thumb_width = ctx
+            .get_widget(self.thumb)
+            .get::<Rectangle>("bounds")
+            .width();
+        let fill_width = calculate_fill_width(thumb_x, thumb_width);
+
+        if ctx
+            .get_widget(self.fill)
+            .get::<Constraint>("constraint")
+            .width()
+            != fill_width
+        {
+            ctx.get_widget(self.fill)
+                .get_mut::<Constraint>("constraint")
+                .set_width(fill_width);
+        }
+    }
+
+    // the fill of a vertical slider covers the track from its bottom to the center of the thumb
+    fn update_fill_height(&self, ctx: &mut Context) {
+        let thumb_y = ctx.get_widget(self.thumb).get::<Thickness>("margin").top();
+        let thumb_height = ctx
+            .get_widget(self.thumb)
+            .get::<Rectangle>("bounds")
+            .height();
+        let track_height = ctx
+            .get_widget(self.track)
+            .get::<Rectangle>("bounds")
+            .height();
+        let fill_height = calculate_fill_height(thumb_y, thumb_height, track_height);
+
+        if ctx
+            .get_widget(self.fill)
+            .get::<Constraint>("constraint")
+            .height()
+            != fill_height
+        {
+            ctx.get_widget(self.fill)
+                .get_mut::<Constraint>("constraint")
+                .set_height(fill_height);
+        }
+    }
+
+    // shows the value label above the center of the thumb while the thumb is pressed
+    fn update_value_label(&self, ctx: &mut Context) {
+        let visible = *ctx.widget().get::<bool>("show_value_label")
+            && *ctx.get_widget(self.thumb).get::<bool>("pressed");
+
+        let visibility = if visible {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+
+        if *ctx
+            .get_widget(self.value_label)
+            .get::<Visibility>("visibility")
+            != visibility
+        {
+            ctx.get_widget(self.value_label)
+                .set("visibility", visibility);
+        }
+
+        if !visible {
+            return;
+        }
+
+        let text = String16::from(
+            format_value_label(
+                *ctx.widget().get::<f64>("val"),
+                *ctx.widget().get::<usize>("label_precision"),
+            )
+            .as_str(),
+        );
+
+        if *ctx.get_widget(self.value_label).get::<String16>("text") != text {
+            ctx.get_widget(self.value_label).set("text", text);
+        }
+
+        let thumb_margin = *ctx.get_widget(self.thumb).get::<Thickness>("margin");
+        let thumb_bounds = *ctx.get_widget(self.thumb).get::<Rectangle>("bounds");
+        let label_bounds = *ctx.get_widget(self.value_label).get::<Rectangle>("bounds");
+
+        let mut margin = *ctx.get_widget(self.value_label).get::<Thickness>("margin");
+
+        // the label of a vertical slider is shown left of the thumb
+        if self.is_vertical(ctx) {
+            margin.set_left(-label_bounds.width());
+            margin.set_top(calculate_value_label_x(
+                thumb_margin.top(),
+                thumb_bounds.height(),
+                label_bounds.height(),
+            ));
+        } else {
+            margin.set_left(calculate_value_label_x(
+                thumb_margin.left(),
+                thumb_bounds.width(),
+                label_bounds.width(),
+            ));
+            margin.set_top(-label_bounds.height());
+        }
+
+        if *ctx.get_widget(self.value_label).get::<Thickness>("margin") != margin {
+            ctx.get_widget(self.value_label).set("margin", margin);
+        }
+    }
+
     // adjust min, max and val
     fn adjust(&mut self, ctx: &mut Context) -> bool {
         let mut has_changes = false;
@@ -67,6 +271,14 @@ impl SliderState {
     }
 
     // adjust the thump position
+    fn adjust_thumb(&self, ctx: &mut Context) {
+        if self.is_vertical(ctx) {
+            self.adjust_thumb_y(ctx);
+        } else {
+            self.adjust_thumb_x(ctx);
+        }
+    }
+
     fn adjust_thumb_x(&self, ctx: &mut Context) {
         let val = *ctx.widget().get::<f64>("val");
         let min = *ctx.widget().get::<f64>("min");
@@ -92,6 +304,80 @@ impl SliderState {
                 thumb_width,
             ));
     }
+
+    fn adjust_thumb_y(&self, ctx: &mut Context) {
+        let val = *ctx.widget().get::<f64>("val");
+        let min = *ctx.widget().get::<f64>("min");
+        let max = *ctx.widget().get::<f64>("max");
+
+        let thumb_height = ctx
+            .get_widget(self.thumb)
+            .get::<Rectangle>("bounds")
+            .height();
+
+        let track_height = ctx
+            .get_widget(self.track)
+            .get::<Rectangle>("bounds")
+            .height();
+
+        ctx.get_widget(self.thumb)
+            .get_mut::<Thickness>("margin")
+            .set_top(calculate_thumb_y_from_val(
+                val,
+                min,
+                max,
+                track_height,
+                thumb_height,
+            ));
+    }
+
+    // moves the thumb of a horizontal slider to the mouse and returns the new val
+    fn move_thumb_x(&self, mouse_x: f64, ctx: &mut Context) -> f64 {
+        let thumb_width = ctx
+            .get_widget(self.thumb)
+            .get::<Rectangle>("bounds")
+            .width();
+        let track_width = ctx
+            .get_widget(self.track)
+            .get::<Rectangle>("bounds")
+            .width();
+        let slider_x = ctx.widget().get::<Point>("position").x();
+
+        let thumb_x = calculate_thumb_x(mouse_x, thumb_width, slider_x, track_width);
+
+        ctx.get_widget(self.thumb)
+            .get_mut::<Thickness>("margin")
+            .set_left(thumb_x);
+
+        let min = *ctx.widget().get("min");
+        let max = *ctx.widget().get("max");
+
+        calculate_val(thumb_x, min, max, thumb_width, track_width)
+    }
+
+    // moves the thumb of a vertical slider to the mouse and returns the new val
+    fn move_thumb_y(&self, mouse_y: f64, ctx: &mut Context) -> f64 {
+        let thumb_height = ctx
+            .get_widget(self.thumb)
+            .get::<Rectangle>("bounds")
+            .height();
+        let track_height = ctx
+            .get_widget(self.track)
+            .get::<Rectangle>("bounds")
+            .height();
+        let slider_y = ctx.widget().get::<Point>("position").y();
+
+        let thumb_y = calculate_thumb_y(mouse_y, thumb_height, slider_y, track_height);
+
+        ctx.get_widget(self.thumb)
+            .get_mut::<Thickness>("margin")
+            .set_top(thumb_y);
+
+        let min = *ctx.widget().get("min");
+        let max = *ctx.widget().get("max");
+
+        calculate_val_from_thumb_y(thumb_y, min, max, thumb_height, track_height)
+    }
 }
 
 impl State for SliderState {
@@ -102,58 +388,91 @@ impl State for SliderState {
         self.track = ctx
             .entity_of_child(ID_TRACK)
             .expect("SliderState.init: Track child could not be found.");
+        self.fill = ctx
+            .entity_of_child(ID_FILL)
+            .expect("SliderState.init: Fill child could not be found.");
+        self.value_label = ctx
+            .entity_of_child(ID_VALUE_LABEL)
+            .expect("SliderState.init: Value label child could not be found.");
     }
 
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_cursor(ctx);
+
         if let Some(action) = self.action {
             match action {
-                SliderAction::Move { mouse_x } => {
+                SliderAction::Move { mouse_position } => {
                     if *ctx.get_widget(self.thumb).get::<bool>("pressed") {
-                        let thumb_width = ctx
-                            .get_widget(self.thumb)
-                            .get::<Rectangle>("bounds")
-                            .width();
-                        let track_width = ctx
-                            .get_widget(self.track)
-                            .get::<Rectangle>("bounds")
-                            .width();
-                        let slider_x = ctx.widget().get::<Point>("position").x();
-
-                        let thumb_x =
-                            calculate_thumb_x(mouse_x, thumb_width, slider_x, track_width);
-
-                        ctx.get_widget(self.thumb)
-                            .get_mut::<Thickness>("margin")
-                            .set_left(thumb_x);
-
-                        let min = *ctx.widget().get("min");
-                        let max = *ctx.widget().get("max");
-
-                        ctx.widget().set(
-                            "val",
-                            calculate_val(thumb_x, min, max, thumb_width, track_width),
-                        );
+                        if self.drag_start_val.is_none() {
+                            self.drag_start_val = Some(*ctx.widget().get::<f64>("val"));
+                        }
+
+                        let val = if self.is_vertical(ctx) {
+                            self.move_thumb_y(mouse_position.y(), ctx)
+                        } else {
+                            self.move_thumb_x(mouse_position.x(), ctx)
+                        };
+
+                        ctx.widget().set("val", val);
                     } else {
                         ctx.widget().clear_dirty();
                     }
                 }
+                SliderAction::Release => {
+                    // a finished drag is pushed as one change to the undo stack
+                    if let Some(start_val) = self.drag_start_val.take() {
+                        let val = *ctx.widget().get::<f64>("val");
+
+                        if start_val != val {
+                            ctx.undo_service().push(UndoAction::property(
+                                "Change value",
+                                ctx.entity,
+                                "val",
+                                start_val,
+                                val,
+                            ));
+                        }
+                    }
+                }
+                SliderAction::Key(key) => {
+                    self.change_val_by_key(key, ctx);
+                }
+                SliderAction::Focus => {
+                    self.request_focus(ctx);
+                }
             }
 
             self.action = None;
+            self.update_fill(ctx);
+            self.update_value_label(ctx);
             return;
         }
 
         if self.adjust(ctx) {
-            self.adjust_thumb_x(ctx);
+            self.adjust_thumb(ctx);
         }
+
+        self.update_fill(ctx);
+        self.update_value_label(ctx);
     }
 }
 
 widget!(
     /// The `Slider` allows to use a val in a range of values.
     ///
-    /// **style:** `slider`
-    Slider<SliderState>: MouseHandler {
+    /// If the slider is focused, `val` could be changed by `step` with the arrow keys and by
+    /// `large_step` with Page Up and Page Down. Home and End jump to `min` and `max`.
+    ///
+    /// A slider with `Vertical` orientation has its `min` at the bottom and its `max` at the top.
+    /// The orientation is used to build the layout of the slider, so it has to be set as value
+    /// and could not be changed afterwards.
+    ///
+    /// ```rust
+    /// Slider::new().orientation("vertical").height(200.0).build(ctx)
+    /// ```
+    ///
+    /// **style:** `slider`, `slider_vertical`
+    Slider<SliderState>: MouseHandler, KeyDownHandler {
         /// Sets or shares the min val of the range.
         min: f64,
 
@@ -163,9 +482,48 @@ widget!(
         /// Sets or shares the current val of the range.
         val: f64,
 
+        /// Sets or shares the name that describes the slider for assistive technologies.
+        accessible_name: String,
+
+        /// Sets or shares the value that is added or subtracted by the arrow keys.
+        step: f64,
+
+        /// Sets or shares the value that is added or subtracted by Page Up and Page Down. If it
+        /// is `0.0` a tenth of the range is used.
+        large_step: f64,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Sets or shares the orientation of the slider. Default is `Horizontal`.
+        orientation: Orientation,
+
+        /// Sets or shares the flag that shows the current val above the thumb while it is
+        /// dragged.
+        show_value_label: bool,
+
+        /// Sets or shares the number of decimal places of the value label.
+        label_precision: usize,
+
+        /// Sets or shares the flag that shows a tick below the track for each `step`.
+        show_ticks: bool,
+
+        /// Sets or shares the brush of the ticks.
+        tick_color: Brush,
+
+        /// Sets or shares the length of the ticks.
+        tick_height: f64,
+
         /// Sets or shares the background property.
         background: Brush,
 
+        /// Sets or shares the flag that fills the track between its start and the thumb with
+        /// `fill_brush`.
+        show_fill: bool,
+
+        /// Sets or shares the brush of the track between its start and the thumb.
+        fill_brush: Brush,
+
         /// Sets or shares the border radius property.
         border_radius: f64,
 
@@ -179,35 +537,120 @@ widget!(
 
 impl Template for Slider {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
-        self.name("Slider")
-            .style(STYLE_SLIDER)
+        let vertical = if let Some(PropertySource::Value(orientation)) = &self.orientation {
+            *orientation == Orientation::Vertical
+        } else {
+            false
+        };
+
+        // the track of a vertical slider is rotated, the fill grows from its bottom
+        let (track, fill) = if vertical {
+            (
+                Container::new().h_align("center").width(2.0),
+                Container::new()
+                    .h_align("center")
+                    .v_align("end")
+                    .width(2.0)
+                    .height(0.0),
+            )
+        } else {
+            (
+                Container::new().v_align("center").height(2.0),
+                Container::new()
+                    .v_align("center")
+                    .h_align("start")
+                    .width(0.0)
+                    .height(2.0),
+            )
+        };
+
+        // a vertical slider has a fixed width instead of a fixed height
+        let slider = if vertical {
+            self.width(24.0)
+        } else {
+            self.height(24.0)
+        };
+
+        slider
+            .name("Slider")
+            .style(if vertical {
+                STYLE_SLIDER_VERTICAL
+            } else {
+                STYLE_SLIDER
+            })
+            .orientation("horizontal")
             .on_changed_filter(vec!["val"])
             .min(0.0)
             .max(100.0)
             .val(0.0)
-            .height(24.0)
+            .step(1.0)
+            .large_step(0.0)
+            .focused(false)
+            .show_value_label(false)
+            .label_precision(0)
+            .show_fill(true)
+            .show_ticks(false)
+            .tick_color("#647b91")
+            .tick_height(4.0)
             .border_radius(2.0)
             .child(
                 Grid::new()
-                    .margin((8, 0))
+                    .margin(if vertical { (0, 8) } else { (8, 0) })
                     .id(ID_TRACK)
+                    .child(track.border_radius(id).background(id).build(ctx))
                     .child(
-                        Container::new()
+                        fill.id(ID_FILL)
                             .border_radius(id)
-                            .background(id)
-                            .v_align("center")
-                            .height(2.0)
+                            .background(("fill_brush", id))
+                            .enabled(false)
+                            .build(ctx),
+                    )
+                    .child(
+                        // the ticks are placed between the centers of the thumb at min and max
+                        SliderTicks::new()
+                            .margin(if vertical { (0, 12) } else { (12, 0) })
+                            .min(id)
+                            .max(id)
+                            .step(id)
+                            .orientation(id)
+                            .show_ticks(id)
+                            .tick_color(id)
+                            .tick_height(id)
+                            .enabled(false)
                             .build(ctx),
                     )
                     .child(
                         Button::new()
                             .style("thumb")
                             .id(ID_THUMB)
-                            .v_align("center")
-                            .h_align("start")
+                            .accessible_name(id)
+                            .v_align(if vertical { "start" } else { "center" })
+                            .h_align(if vertical { "center" } else { "start" })
                             .max_width(24.0)
                             .max_height(24.0)
                             .border_radius(12.0)
+                            .on_mouse_enter(move |states, _| {
+                                states.get_mut::<SliderState>(id).set_thumb_hovered(true);
+                                false
+                            })
+                            .on_mouse_leave(move |states, _| {
+                                states.get_mut::<SliderState>(id).set_thumb_hovered(false);
+                                false
+                            })
+                            .on_mouse_down(move |states, _| {
+                                states
+                                    .get_mut::<SliderState>(id)
+                                    .action(SliderAction::Focus);
+                                false
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        TextBlock::new()
+                            .id(ID_VALUE_LABEL)
+                            .v_align("start")
+                            .h_align("start")
+                            .visibility("hidden")
                             .build(ctx),
                     )
                     .build(ctx),
@@ -215,12 +658,122 @@ impl Template for Slider {
             .on_mouse_move(move |states, p| {
                 states
                     .get_mut::<SliderState>(id)
-                    .action(SliderAction::Move { mouse_x: p.x() });
+                    .action(SliderAction::Move { mouse_position: p });
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                states
+                    .get_mut::<SliderState>(id)
+                    .action(SliderAction::Release);
+            })
+            .on_key_down(move |states, event| -> bool {
+                states
+                    .get_mut::<SliderState>(id)
+                    .action(SliderAction::Key(event.logical.0));
                 false
             })
     }
 }
 
+widget!(
+    /// The `SliderTicks` widget draws the ticks of a `Slider`. It is used inside of the slider
+    /// template.
+    SliderTicks {
+        /// Sets or shares the min val of the range.
+        min: f64,
+
+        /// Sets or shares the max val of the range.
+        max: f64,
+
+        /// Sets or shares the distance between two ticks.
+        step: f64,
+
+        /// Sets or shares the orientation of the slider.
+        orientation: Orientation,
+
+        /// Sets or shares the flag that shows the ticks.
+        show_ticks: bool,
+
+        /// Sets or shares the brush of the ticks.
+        tick_color: Brush,
+
+        /// Sets or shares the length of the ticks.
+        tick_height: f64
+    }
+);
+
+impl Template for SliderTicks {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("SliderTicks")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(SliderTicksRenderObject)
+    }
+}
+
+/// Draws a tick line for each step of a slider next to its track.
+pub struct SliderTicksRenderObject;
+
+impl Into<Box<dyn RenderObject>> for SliderTicksRenderObject {
+    fn into(self) -> Box<dyn RenderObject> {
+        Box::new(self)
+    }
+}
+
+impl RenderObject for SliderTicksRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, min, max, step, vertical, show_ticks, tick_color, tick_height) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Rectangle>("bounds"),
+                *widget.get::<f64>("min"),
+                *widget.get::<f64>("max"),
+                *widget.get::<f64>("step"),
+                *widget.get::<Orientation>("orientation") == Orientation::Vertical,
+                *widget.get::<bool>("show_ticks"),
+                widget.get::<Brush>("tick_color").clone(),
+                *widget.get::<f64>("tick_height"),
+            )
+        };
+
+        if !show_ticks || tick_color.is_transparent() || tick_height <= 0.0 {
+            return;
+        }
+
+        let x = global_position.x() + bounds.x();
+        let y = global_position.y() + bounds.y();
+        let length = if vertical {
+            bounds.height()
+        } else {
+            bounds.width()
+        };
+
+        let render_context = ctx.render_context_2_d();
+        render_context.begin_path();
+        render_context.set_line_width(1.0);
+        render_context.set_stroke_style(tick_color);
+
+        // the ticks start next to the track in the center of the widget
+        for position in calculate_tick_positions(min, max, step, length) {
+            if vertical {
+                let tick_x = x + bounds.width() / 2.0 + TICK_OFFSET;
+                let tick_y = y + bounds.height() - position;
+                render_context.move_to(tick_x, tick_y);
+                render_context.line_to(tick_x + tick_height, tick_y);
+            } else {
+                let tick_x = x + position;
+                let tick_y = y + bounds.height() / 2.0 + TICK_OFFSET;
+                render_context.move_to(tick_x, tick_y);
+                render_context.line_to(tick_x, tick_y + tick_height);
+            }
+        }
+
+        render_context.stroke();
+        render_context.close_path();
+    }
+}
+
 // --- Helpers --
 
 fn adjust_val(val: f64, min: f64, max: f64) -> f64 {
@@ -271,6 +824,104 @@ fn calculate_thumb_x_from_val(
     (val / (max - min)) * (track_width - thumb_width)
 }
 
+fn calculate_thumb_y(mouse_y: f64, thumb_height: f64, slider_y: f64, track_height: f64) -> f64 {
+    (mouse_y - slider_y - thumb_height)
+        .max(0.0)
+        .min(track_height - thumb_height)
+}
+
+// The top of the track is max, so the val is calculated from the distance to the bottom.
+fn calculate_val_from_thumb_y(
+    thumb_y: f64,
+    min: f64,
+    max: f64,
+    thumb_height: f64,
+    track_height: f64,
+) -> f64 {
+    calculate_val(
+        track_height - thumb_height - thumb_y,
+        min,
+        max,
+        thumb_height,
+        track_height,
+    )
+}
+
+fn calculate_thumb_y_from_val(
+    val: f64,
+    min: f64,
+    max: f64,
+    track_height: f64,
+    thumb_height: f64,
+) -> f64 {
+    track_height
+        - thumb_height
+        - calculate_thumb_x_from_val(val, min, max, track_height, thumb_height)
+}
+
+fn calculate_fill_width(thumb_x: f64, thumb_width: f64) -> f64 {
+    thumb_x + thumb_width / 2.0
+}
+
+fn calculate_fill_height(thumb_y: f64, thumb_height: f64, track_height: f64) -> f64 {
+    track_height - thumb_y - thumb_height / 2.0
+}
+
+// Returns the offsets of the ticks from the start of the track. A tick is placed at min and at
+// each step up to max, if the ticks would be closer than a pixel no tick is returned.
+fn calculate_tick_positions(min: f64, max: f64, step: f64, track_length: f64) -> Vec<f64> {
+    if step <= 0.0 || max <= min || track_length <= 0.0 {
+        return vec![];
+    }
+
+    let count = ((max - min) / step).floor() as usize;
+
+    if count as f64 > track_length {
+        return vec![];
+    }
+
+    (0..=count)
+        .map(|step_index| step_index as f64 * step / (max - min) * track_length)
+        .collect()
+}
+
+// Returns the left margin of the value label that centers it above the thumb.
+fn calculate_value_label_x(thumb_x: f64, thumb_width: f64, label_width: f64) -> f64 {
+    thumb_x + (thumb_width - label_width) / 2.0
+}
+
+fn format_value_label(val: f64, label_precision: usize) -> String {
+    format!("{:.precision$}", val, precision = label_precision)
+}
+
+// Returns the new val for the given key or `None` if the key doesn't change the val.
+fn calculate_val_by_key(
+    key: Key,
+    val: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    large_step: f64,
+) -> Option<f64> {
+    let large_step = if large_step > 0.0 {
+        large_step
+    } else {
+        (max - min) / 10.0
+    };
+
+    let val = match key {
+        Key::Up | Key::Right => val + step,
+        Key::Down | Key::Left => val - step,
+        Key::PageUp => val + large_step,
+        Key::PageDown => val - large_step,
+        Key::Home => min,
+        Key::End => max,
+        _ => return None,
+    };
+
+    Some(adjust_val(val, min, max))
+}
+
 // --- Helpers --
 
 #[cfg(test)]
@@ -333,4 +984,220 @@ mod tests {
             calculate_thumb_x_from_val(100.0, 0.0, 100.0, 100.0, 32.0)
         );
     }
+
+    #[test]
+    fn test_calculate_thumb_y() {
+        assert_eq!(0.0, calculate_thumb_y(-1000.0, 32.0, 0.0, 100.0));
+        assert_eq!(18.0, calculate_thumb_y(50.0, 32.0, 0.0, 100.0));
+        assert_eq!(18.0, calculate_thumb_y(60.0, 32.0, 10.0, 100.0));
+        assert_eq!(68.0, calculate_thumb_y(1000.0, 32.0, 0.0, 100.0));
+    }
+
+    #[test]
+    fn test_calculate_val_from_thumb_y() {
+        assert_eq!(
+            100.0,
+            calculate_val_from_thumb_y(0.0, 0.0, 100.0, 32.0, 100.0)
+        );
+        assert_eq!(
+            50.0,
+            calculate_val_from_thumb_y(34.0, 0.0, 100.0, 32.0, 100.0)
+        );
+        assert_eq!(
+            0.0,
+            calculate_val_from_thumb_y(68.0, 0.0, 100.0, 32.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn test_calculate_thumb_y_from_val() {
+        assert_eq!(
+            68.0,
+            calculate_thumb_y_from_val(0.0, 0.0, 100.0, 100.0, 32.0)
+        );
+        assert_eq!(
+            34.0,
+            calculate_thumb_y_from_val(50.0, 0.0, 100.0, 100.0, 32.0)
+        );
+        assert_eq!(
+            0.0,
+            calculate_thumb_y_from_val(100.0, 0.0, 100.0, 100.0, 32.0)
+        );
+    }
+
+    #[test]
+    fn test_calculate_fill_height() {
+        assert_eq!(88.0, calculate_fill_height(0.0, 24.0, 100.0));
+        assert_eq!(12.0, calculate_fill_height(76.0, 24.0, 100.0));
+    }
+
+    #[test]
+    fn test_calculate_tick_positions() {
+        assert_eq!(
+            vec![0.0, 25.0, 50.0, 75.0, 100.0],
+            calculate_tick_positions(0.0, 100.0, 25.0, 100.0)
+        );
+        assert_eq!(
+            vec![0.0, 40.0, 80.0],
+            calculate_tick_positions(-50.0, 50.0, 40.0, 100.0)
+        );
+        assert!(calculate_tick_positions(0.0, 100.0, 0.0, 100.0).is_empty());
+        assert!(calculate_tick_positions(0.0, 1000.0, 1.0, 100.0).is_empty());
+        assert!(calculate_tick_positions(10.0, 10.0, 1.0, 100.0).is_empty());
+    }
+
+    #[test]
+    fn test_calculate_fill_width() {
+        assert_eq!(12.0, calculate_fill_width(0.0, 24.0));
+        assert_eq!(62.0, calculate_fill_width(50.0, 24.0));
+    }
+
+    #[test]
+    fn test_calculate_value_label_x() {
+        assert_eq!(0.0, calculate_value_label_x(0.0, 24.0, 24.0));
+        assert_eq!(-4.0, calculate_value_label_x(0.0, 24.0, 32.0));
+        assert_eq!(54.0, calculate_value_label_x(50.0, 24.0, 16.0));
+    }
+
+    #[test]
+    fn test_format_value_label() {
+        assert_eq!("50", format_value_label(50.4, 0));
+        assert_eq!("51", format_value_label(50.6, 0));
+        assert_eq!("50.40", format_value_label(50.4, 2));
+    }
+
+    #[test]
+    fn test_calculate_val_by_key() {
+        assert_eq!(
+            Some(51.0),
+            calculate_val_by_key(Key::Right, 50.0, 0.0, 100.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            Some(49.0),
+            calculate_val_by_key(Key::Down, 50.0, 0.0, 100.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            Some(60.0),
+            calculate_val_by_key(Key::PageUp, 50.0, 0.0, 100.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            Some(45.0),
+            calculate_val_by_key(Key::PageDown, 50.0, 0.0, 100.0, 1.0, 5.0)
+        );
+        assert_eq!(
+            Some(100.0),
+            calculate_val_by_key(Key::PageUp, 95.0, 0.0, 100.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            Some(0.0),
+            calculate_val_by_key(Key::Home, 50.0, 0.0, 100.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            Some(100.0),
+            calculate_val_by_key(Key::End, 50.0, 0.0, 100.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            None,
+            calculate_val_by_key(Key::Enter, 50.0, 0.0, 100.0, 1.0, 0.0)
+        );
+    }
+}
+
+#[cfg(test)]
+mod prop_tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Generates a range with `min < max` and a value inside of it.
+    fn range_and_val() -> impl Strategy<Value = (f64, f64, f64)> {
+        (-1000.0..1000.0f64, 1.0..1000.0f64)
+            .prop_flat_map(|(min, range)| (Just(min), Just(min + range), min..=min + range))
+    }
+
+    // Generates a thumb width and a track width that is larger than the thumb.
+    fn thumb_and_track() -> impl Strategy<Value = (f64, f64)> {
+        (1.0..64.0f64, 1.0..1000.0f64)
+            .prop_map(|(thumb_width, delta)| (thumb_width, thumb_width + delta))
+    }
+
+    proptest! {
+        #[test]
+        fn test_thumb_x_from_val_round_trip(
+            (min, max, val) in range_and_val(),
+            (thumb_width, track_width) in thumb_and_track()
+        ) {
+            let thumb_x = calculate_thumb_x_from_val(val, min, max, track_width, thumb_width);
+            let result = calculate_val(thumb_x, min, max, thumb_width, track_width);
+
+            prop_assert!((result - val).abs() <= 1e-9 * val.abs().max(1.0));
+        }
+
+        #[test]
+        fn test_thumb_y_from_val_round_trip(
+            (min, max, val) in range_and_val(),
+            (thumb_height, track_height) in thumb_and_track()
+        ) {
+            let thumb_y = calculate_thumb_y_from_val(val, min, max, track_height, thumb_height);
+            let result = calculate_val_from_thumb_y(thumb_y, min, max, thumb_height, track_height);
+
+            prop_assert!((result - val).abs() <= 1e-6 * val.abs().max(1.0));
+        }
+
+        #[test]
+        fn test_calculate_thumb_x_stays_on_track(
+            mouse_x in -2000.0..2000.0f64,
+            slider_x in -500.0..500.0f64,
+            (thumb_width, track_width) in thumb_and_track()
+        ) {
+            let thumb_x = calculate_thumb_x(mouse_x, thumb_width, slider_x, track_width);
+
+            prop_assert!(thumb_x >= 0.0);
+            prop_assert!(thumb_x <= track_width - thumb_width);
+        }
+
+        #[test]
+        fn test_calculate_thumb_x_monotonic(
+            a in -2000.0..2000.0f64,
+            b in -2000.0..2000.0f64,
+            (thumb_width, track_width) in thumb_and_track()
+        ) {
+            let (low, high) = if a <= b { (a, b) } else { (b, a) };
+
+            prop_assert!(
+                calculate_thumb_x(low, thumb_width, 0.0, track_width)
+                    <= calculate_thumb_x(high, thumb_width, 0.0, track_width)
+            );
+        }
+
+        #[test]
+        fn test_adjust_val_in_range(val in -5000.0..5000.0f64, (min, max, _) in range_and_val()) {
+            let adjusted = adjust_val(val, min, max);
+
+            prop_assert!(adjusted >= min && adjusted <= max);
+            prop_assert_eq!(adjusted, adjust_val(adjusted, min, max));
+        }
+
+        #[test]
+        fn test_adjust_val_monotonic(
+            a in -5000.0..5000.0f64,
+            b in -5000.0..5000.0f64,
+            (min, max, _) in range_and_val()
+        ) {
+            let (low, high) = if a <= b { (a, b) } else { (b, a) };
+
+            prop_assert!(adjust_val(low, min, max) <= adjust_val(high, min, max));
+        }
+
+        #[test]
+        fn test_adjust_min_max(min in -5000.0..5000.0f64, max in -5000.0..5000.0f64) {
+            let adjusted_min = adjust_min(min, max);
+            let adjusted_max = adjust_max(min, max);
+
+            prop_assert!(adjusted_min <= max);
+            prop_assert!(adjusted_max >= min);
+            prop_assert_eq!(adjusted_min, adjust_min(adjusted_min, max));
+            prop_assert_eq!(adjusted_max, adjust_max(min, adjusted_max));
+        }
+    }
 }
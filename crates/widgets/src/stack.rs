@@ -1,24 +1,490 @@
+use std::collections::BTreeMap;
+
 use crate::{api::prelude::*, proc_macros::*};
 
+// Distance in pixels a new child slides along the orientation of the stack into its place.
+const INSERT_OFFSET: f64 = 16.0;
+
+// Distance in pixels the mouse has to move before a pressed child is dragged.
+const DRAG_THRESHOLD: f64 = 5.0;
+
+// Opacity of the dragged child.
+const DRAG_OPACITY: f32 = 0.5;
+
+// Distance in pixels to the border of the parent scroll viewer that starts edge scrolling.
+const EDGE_SCROLL_DISTANCE: f64 = 24.0;
+
+// Scroll delta of one edge scrolling frame.
+const EDGE_SCROLL_DELTA: f64 = 2.0;
+
+#[derive(Debug, Copy, Clone)]
+enum ReorderAction {
+    Press(Point),
+    Move(Point),
+    Release,
+}
+
+// Describes a child that is pressed or dragged to reorder it.
+#[derive(Debug, Copy, Clone)]
+struct Drag {
+    child: Entity,
+    from_index: usize,
+    start: Point,
+    current: Point,
+    // offset of the mouse to the origin of the child on press
+    grab_offset: Point,
+    opacity: f32,
+    active: bool,
+}
+
+/// The `StackState` animates the changes of the children of the `Stack` if `animate_changes` is
+/// set and handles the reordering of the children if `reorderable` is set.
+#[derive(Default, AsAny)]
+pub struct StackState {
+    positions: BTreeMap<Entity, Point>,
+    remove_requests: Vec<Entity>,
+    removing: Vec<Entity>,
+    initialized: bool,
+    actions: Vec<ReorderAction>,
+    drag: Option<Drag>,
+    edge_scroll: Option<(Entity, Point)>,
+}
+
+impl StackState {
+    /// Removes the given child from the stack. If `animate_changes` is set the child fades out
+    /// before it is removed.
+    pub fn remove_child(&mut self, child: Entity) {
+        self.remove_requests.push(child);
+    }
+
+    fn action(&mut self, action: ReorderAction) {
+        self.actions.push(action);
+    }
+
+    fn transition_config(ctx: &mut Context) -> TransitionConfig {
+        TransitionConfig {
+            duration_ms: *ctx.widget().get::<u64>("change_animation_ms"),
+            ..Default::default()
+        }
+    }
+
+    fn children_positions(ctx: &mut Context) -> BTreeMap<Entity, Point> {
+        let mut positions = BTreeMap::new();
+        let mut index = 0;
+
+        while let Some(mut child) = ctx.try_child_from_index(index) {
+            let bounds = child.clone::<Rectangle>("bounds");
+            positions.insert(child.entity(), Point::new(bounds.x(), bounds.y()));
+            index += 1;
+        }
+
+        positions
+    }
+
+    fn reorder(&mut self, action: ReorderAction, ctx: &mut Context) {
+        match action {
+            ReorderAction::Press(position) => self.press(position, ctx),
+            ReorderAction::Move(position) => self.drag_to(position, ctx),
+            ReorderAction::Release => self.release(ctx),
+        }
+    }
+
+    fn press(&mut self, position: Point, ctx: &mut Context) {
+        if !*ctx.widget().get::<bool>("reorderable") || !self.removing.is_empty() {
+            return;
+        }
+
+        let mut index = 0;
+
+        while let Some(child) = ctx.try_child_from_index(index) {
+            if check_mouse_condition(position, &child) {
+                let origin = *child.get::<Point>("position");
+
+                self.drag = Some(Drag {
+                    child: child.entity(),
+                    from_index: index,
+                    start: position,
+                    current: position,
+                    grab_offset: Point::new(position.x() - origin.x(), position.y() - origin.y()),
+                    opacity: *child.get::<f32>("opacity"),
+                    active: false,
+                });
+                return;
+            }
+
+            index += 1;
+        }
+    }
+
+    fn drag_to(&mut self, position: Point, ctx: &mut Context) {
+        let mut drag = match self.drag {
+            Some(drag) => drag,
+            None => return,
+        };
+
+        drag.current = position;
+
+        if !drag.active && drag.start.distance(position) >= DRAG_THRESHOLD {
+            drag.active = true;
+            ctx.get_widget(drag.child).set("opacity", DRAG_OPACITY);
+        }
+
+        self.drag = Some(drag);
+
+        if !drag.active {
+            return;
+        }
+
+        // moves the dragged child between the children whose centers are before the mouse
+        let orientation = *ctx.widget().get::<Orientation>("orientation");
+        let origin = *ctx.widget().get::<Point>("position");
+        let mut centers = vec![];
+        let mut index = 0;
+
+        while let Some(child) = ctx.try_child_from_index(index) {
+            index += 1;
+
+            if child.entity() == drag.child {
+                continue;
+            }
+
+            let bounds = child.get::<Rectangle>("bounds");
+
+            centers.push(match orientation {
+                Orientation::Horizontal => origin.x() + bounds.x() + bounds.width() / 2.0,
+                _ => origin.y() + bounds.y() + bounds.height() / 2.0,
+            });
+        }
+
+        let position = match orientation {
+            Orientation::Horizontal => position.x(),
+            _ => position.y(),
+        };
+
+        let mut index = insertion_index(&centers, position);
+
+        // the children of a reversed stack are arranged from the end to the start
+        if *ctx.widget().get::<bool>("reverse") {
+            index = centers.len() - index;
+        }
+
+        if ctx.index_as_child(drag.child) != Some(index) {
+            ctx.move_child(drag.child, index);
+        }
+    }
+
+    fn release(&mut self, ctx: &mut Context) {
+        let drag = match self.drag.take() {
+            Some(drag) => drag,
+            None => return,
+        };
+
+        self.edge_scroll = None;
+
+        if !drag.active {
+            return;
+        }
+
+        // the dropped child moves from the mouse position to its new place
+        let mut child = ctx.get_widget(drag.child);
+        let bounds = child.clone::<Rectangle>("bounds");
+        child.set("opacity", drag.opacity);
+        self.positions
+            .insert(drag.child, Point::new(bounds.x(), bounds.y()));
+
+        if let Some(to_index) = ctx.index_as_child(drag.child) {
+            if to_index != drag.from_index {
+                ctx.push_event_strategy_by_entity(
+                    ReorderEvent(ctx.entity, drag.from_index, to_index),
+                    ctx.entity,
+                    EventStrategy::Direct,
+                );
+            }
+        }
+    }
+
+    // Scrolls the parent scroll viewer if the dragged child is near to its border.
+    fn scroll_to_edge(&mut self, ctx: &mut Context) {
+        self.edge_scroll = None;
+
+        let drag = match self.drag {
+            Some(drag) if drag.active => drag,
+            _ => return,
+        };
+
+        let scroll_viewer = ctx
+            .ancestors()
+            .into_iter()
+            .find(|a| ctx.get_widget(*a).has::<ScrollViewerMode>("mode"));
+
+        if let Some(scroll_viewer) = scroll_viewer {
+            let widget = ctx.get_widget(scroll_viewer);
+            let origin = *widget.get::<Point>("position");
+            let bounds = widget.get::<Rectangle>("bounds");
+
+            let delta = Point::new(
+                edge_scroll_delta(drag.current.x(), origin.x(), bounds.width()),
+                edge_scroll_delta(drag.current.y(), origin.y(), bounds.height()),
+            );
+
+            if delta != Point::default() {
+                self.edge_scroll = Some((scroll_viewer, delta));
+            }
+        }
+    }
+}
+
+impl State for StackState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        for action in std::mem::take(&mut self.actions) {
+            self.reorder(action, ctx);
+        }
+
+        if let Some((scroll_viewer, delta)) = self.edge_scroll {
+            ctx.push_event_strategy_by_entity(
+                ScrollEvent {
+                    delta,
+                    velocity: Point::default(),
+                    is_trackpad: true,
+                },
+                scroll_viewer,
+                EventStrategy::Direct,
+            );
+
+            // the children are moved by the scrolling
+            if let Some(drag) = self.drag {
+                self.drag_to(drag.current, ctx);
+            }
+        }
+
+        let animate_changes = *ctx.widget().get::<bool>("animate_changes");
+        let config = StackState::transition_config(ctx);
+
+        for child in self.remove_requests.drain(..) {
+            if !animate_changes {
+                ctx.remove_child(child);
+                continue;
+            }
+
+            let opacity = *ctx.get_widget(child).get::<f32>("opacity");
+            ctx.animation_service()
+                .animate_f32(child, "opacity", opacity, 0.0, &config);
+            self.removing.push(child);
+        }
+
+        // removes the children that are faded out
+        let mut removed = vec![];
+
+        for child in &self.removing {
+            if *ctx.get_widget(*child).get::<f32>("opacity") <= 0.0 {
+                removed.push(*child);
+            }
+        }
+
+        for child in removed {
+            self.removing.retain(|c| *c != child);
+            self.positions.remove(&child);
+            ctx.remove_child(child);
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // polls the fade out of the removed children
+        if !self.removing.is_empty() {
+            ctx.request_update();
+        }
+
+        let mut positions = StackState::children_positions(ctx);
+
+        // the dragged child follows the mouse and is not animated
+        if let Some(drag) = self.drag.filter(|d| d.active) {
+            positions.remove(&drag.child);
+
+            let origin = *ctx.widget().get::<Point>("position");
+            let orientation = *ctx.widget().get::<Orientation>("orientation");
+            let mut child = ctx.get_widget(drag.child);
+            let bounds = child.get_mut::<Rectangle>("bounds");
+
+            match orientation {
+                Orientation::Horizontal => {
+                    bounds.set_x(drag.current.x() - drag.grab_offset.x() - origin.x())
+                }
+                _ => bounds.set_y(drag.current.y() - drag.grab_offset.y() - origin.y()),
+            }
+
+            self.scroll_to_edge(ctx);
+
+            if self.edge_scroll.is_some() {
+                ctx.request_update();
+            }
+        }
+
+        if self.initialized && *ctx.widget().get::<bool>("animate_changes") {
+            let config = StackState::transition_config(ctx);
+            let orientation = *ctx.widget().get::<Orientation>("orientation");
+
+            for (child, position) in &positions {
+                if self.removing.contains(child) {
+                    continue;
+                }
+
+                match self.positions.get(child) {
+                    Some(old_position) if old_position != position => {
+                        ctx.animation_service().animate_position(
+                            *child,
+                            *old_position,
+                            *position,
+                            &config,
+                        );
+                    }
+                    None => {
+                        let from = match orientation {
+                            Orientation::Horizontal => {
+                                Point::new(position.x() - INSERT_OFFSET, position.y())
+                            }
+                            _ => Point::new(position.x(), position.y() - INSERT_OFFSET),
+                        };
+
+                        let service = ctx.animation_service();
+                        service.animate_position(*child, from, *position, &config);
+                        service.animate_f32(*child, "opacity", 0.0, 1.0, &config);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        self.positions = positions;
+        self.initialized = true;
+    }
+}
+
 widget!(
     /// The `Stack` defines a layout that is used to stack its children vertical or horizontal.
     ///
+    /// If `animate_changes` is set, new children fade in and slide into their place and the other
+    /// children move to their new positions. Children that are removed by
+    /// `StackState::remove_child` fade out before they are removed.
+    ///
+    /// If `reorderable` is set, a child could be dragged with the mouse to a new position. The
+    /// dragged child is drawn semi-transparent and follows the mouse, the other children make room
+    /// for it. If the stack is inside of a `ScrollViewer` it scrolls while the mouse is near to its
+    /// border. After the drop `on_reorder` is called with the old and the new index of the child.
+    ///
+    /// If `reverse` is set, the first child is placed at the bottom (or at the right of a
+    /// horizontal stack) e.g. for chat messages that grow upwards. The desired size of the stack
+    /// is the same in both directions.
+    ///
     /// **style:** `stack`
-    Stack {
+    Stack<StackState>: MouseHandler, ReorderHandler {
         /// Sets or shares the orientation property.
         orientation: Orientation,
 
         /// Margin between widgets in the stack.
-        spacing: f64
+        spacing: f64,
+
+        /// Sets or shares the value that describes if the children are stacked in reverse order.
+        reverse: bool,
+
+        /// Sets or shares the value that describes if changes of the children are animated.
+        animate_changes: bool,
+
+        /// Sets or shares the duration of the change animations in milliseconds.
+        change_animation_ms: u64,
+
+        /// Sets or shares the value that describes if the children could be reordered by drag and
+        /// drop.
+        reorderable: bool
     }
 );
 
 impl Template for Stack {
-    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
-        self.name("Stack").orientation("vertical").style("stack")
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        // the mouse handlers are only needed if the children could be reordered, a shared value
+        // could change later
+        let reorderable = match &self.reorderable {
+            Some(PropertySource::Value(reorderable)) => *reorderable,
+            Some(_) => true,
+            None => false,
+        };
+
+        let stack = self
+            .name("Stack")
+            .orientation("vertical")
+            .style("stack")
+            .animate_changes(false)
+            .change_animation_ms(200)
+            .reorderable(false)
+            .reverse(false);
+
+        if !reorderable {
+            return stack;
+        }
+
+        stack
+            .on_mouse_down(move |states, m| {
+                states
+                    .get_mut::<StackState>(id)
+                    .action(ReorderAction::Press(m.position));
+                false
+            })
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<StackState>(id)
+                    .action(ReorderAction::Move(p));
+                false
+            })
+            .on_global_mouse_up(move |states, _| {
+                states
+                    .get_mut::<StackState>(id)
+                    .action(ReorderAction::Release);
+            })
     }
 
     fn layout(&self) -> Box<dyn Layout> {
         Box::new(StackLayout::new())
     }
 }
+
+// --- Helpers --
+
+// Returns the index of a dragged child, that is the count of the other children whose centers are
+// before the given position.
+fn insertion_index(centers: &[f64], position: f64) -> usize {
+    centers.iter().filter(|c| **c < position).count()
+}
+
+// Returns the scroll delta if the position is near to the start or the end of the given range.
+fn edge_scroll_delta(position: f64, start: f64, length: f64) -> f64 {
+    if position < start + EDGE_SCROLL_DISTANCE {
+        EDGE_SCROLL_DELTA
+    } else if position > start + length - EDGE_SCROLL_DISTANCE {
+        -EDGE_SCROLL_DELTA
+    } else {
+        0.0
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insertion_index() {
+        let centers = [5.0, 15.0, 25.0];
+
+        assert_eq!(insertion_index(&centers, 0.0), 0);
+        assert_eq!(insertion_index(&centers, 10.0), 1);
+        assert_eq!(insertion_index(&centers, 24.0), 2);
+        assert_eq!(insertion_index(&centers, 40.0), 3);
+    }
+
+    #[test]
+    fn test_edge_scroll_delta() {
+        assert_eq!(edge_scroll_delta(110.0, 100.0, 200.0), EDGE_SCROLL_DELTA);
+        assert_eq!(edge_scroll_delta(200.0, 100.0, 200.0), 0.0);
+        assert_eq!(edge_scroll_delta(290.0, 100.0, 200.0), -EDGE_SCROLL_DELTA);
+    }
+}
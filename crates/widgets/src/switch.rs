@@ -1,8 +1,9 @@
 use super::behaviors::MouseBehavior;
 
-use crate::{api::prelude::*, prelude::*, proc_macros::*};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::Key};
 
 // --- KEYS --
+static ID_SWITCH_GRID: &'static str = "switch_grid";
 static ID_SWITCH_TRACK: &'static str = "switch_track";
 static ID_SWITCH_TOGGLE: &'static str = "switch_toggle";
 // --- KEYS --
@@ -11,23 +12,68 @@ static ID_SWITCH_TOGGLE: &'static str = "switch_toggle";
 #[derive(Default, AsAny)]
 pub struct SwitchState {
     selected: bool,
+    request_focus: bool,
+    toggle_selection_by_key: bool,
+    switch_grid: Entity,
     switch_toggle: Entity,
+    spring: Option<RunningSpring>,
 }
 
 impl SwitchState {
     fn toggle_selection(&mut self) {
         self.selected = !self.selected;
     }
+
+    fn click(&mut self) {
+        self.toggle_selection();
+        self.request_focus = true;
+    }
+
+    fn toggle_selection_by_key(&mut self) {
+        self.toggle_selection_by_key = true;
+    }
+
+    // starts a spring animation that moves the toggle to the position of the current selection.
+    fn start_spring(&mut self) {
+        let target = if self.selected { 1.0 } else { 0.0 };
+
+        if let Some(spring) = &mut self.spring {
+            spring.set_target(target);
+            return;
+        }
+
+        self.spring = Some(SpringAnimation::new(target).start(1.0 - target));
+    }
 }
 
 impl State for SwitchState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.switch_grid = ctx
+            .entity_of_child(ID_SWITCH_GRID)
+            .expect("SwitchState.init: Switch grid child could not be found.");
         self.switch_toggle = ctx
             .entity_of_child(ID_SWITCH_TOGGLE)
             .expect("SwitchState.init: Switch toggle child could not be found.");
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.request_focus {
+            self.request_focus = false;
+
+            if !*ctx.widget().get::<bool>("focused") {
+                ctx.push_event_by_window(FocusEvent::RequestFocus(ctx.entity));
+            }
+        }
+
+        // the space key toggles the selection only if the switch is focused
+        if self.toggle_selection_by_key {
+            self.toggle_selection_by_key = false;
+
+            if *ctx.widget().get::<bool>("focused") {
+                self.toggle_selection();
+            }
+        }
+
         if *ctx.widget().get::<bool>("selected") == self.selected {
             return;
         }
@@ -44,27 +90,60 @@ impl State for SwitchState {
             let mut switch_toggle = ctx.get_widget(self.switch_toggle);
 
             if self.selected {
-                switch_toggle.set("h_align", Alignment::from("end"));
                 switch_toggle
                     .get_mut::<Selector>("selector")
                     .set_state("selected");
             } else {
-                switch_toggle.set("h_align", Alignment::from("start"));
-                switch_toggle.get_mut::<Selector>("selector").clear_state();
+                switch_toggle
+                    .get_mut::<Selector>("selector")
+                    .remove_state("selected");
             }
 
             switch_toggle.update(true);
         }
 
         ctx.get_widget(self.switch_toggle).update(false);
+
+        self.start_spring();
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(spring) = &mut self.spring {
+            let pos = spring.step(ctx.animation_service().frame_time());
+
+            let grid_width = ctx
+                .get_widget(self.switch_grid)
+                .get::<Rectangle>("bounds")
+                .width();
+            let toggle_width = ctx
+                .get_widget(self.switch_toggle)
+                .get::<Rectangle>("bounds")
+                .width();
+
+            let mut margin = *ctx
+                .get_widget(self.switch_toggle)
+                .get::<Thickness>("margin");
+            margin.set_left(pos * (grid_width - toggle_width).max(0.0));
+            ctx.get_widget(self.switch_toggle).set("margin", margin);
+
+            // the switch is not dirty after the margin of the toggle is changed, so the next
+            // step of the spring has to be requested
+            if spring.is_finished() {
+                self.spring = None;
+            } else {
+                ctx.request_update();
+            }
+        }
     }
 }
 
 widget!(
     /// The `Switch` widget can be switch between `on` and `off`.
     ///
+    /// The switch is focused on click. If it is focused, the space key switches it.
+    ///
     /// **style:** `switch`
-    Switch<SwitchState>: MouseHandler {
+    Switch<SwitchState>: MouseHandler, KeyDownHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -83,6 +162,9 @@ widget!(
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the focused property.
+        focused: bool,
+
         /// Sets or shares the selected property.
         selected: bool
     }
@@ -93,6 +175,7 @@ impl Template for Switch {
         self.name("Switch")
             .style("switch")
             .pressed(false)
+            .focused(false)
             .selected(false)
             .width(36.0)
             .height(30.0)
@@ -106,11 +189,12 @@ impl Template for Switch {
                     .enabled(id)
                     .target(id.0)
                     .on_click(move |states, _| {
-                        states.get_mut::<SwitchState>(id).toggle_selection();
+                        states.get_mut::<SwitchState>(id).click();
                         false
                     })
                     .child(
                         Grid::new()
+                            .id(ID_SWITCH_GRID)
                             .child(
                                 Container::new()
                                     .style(ID_SWITCH_TRACK)
@@ -133,5 +217,11 @@ impl Template for Switch {
                     )
                     .build(ctx),
             )
+            .on_key_down(move |states, event| {
+                if event.logical == Key::Space {
+                    states.get_mut::<SwitchState>(id).toggle_selection_by_key();
+                }
+                false
+            })
     }
 }
@@ -128,9 +128,12 @@ impl TabHeader {
 
 impl Template for TabHeader {
     fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let (icon_font, icon) = ctx.icon_glyph("close");
+
         let mut button = Button::new()
             .style("button_icon_only")
-            .icon(material_icons_font::MD_CLOSE)
+            .icon(icon)
+            .icon_font(icon_font)
             .visibility(("close_button", id));
 
         if let Some(callback) = self.state.on_close_click_callback.take() {
@@ -3,6 +3,13 @@ use crate::{api::prelude::*, proc_macros::*, theme::prelude::*};
 widget!(
     /// The `TextBlock` widget is used to draw text. It is not interactive.
     ///
+    /// If `max_lines` is set, only the given number of lines is shown followed by an ellipsis. Use
+    /// an `ExpandableTextBlock` to show the full text on a click.
+    ///
+    /// ```rust
+    /// TextBlock::new().text(description).max_lines(3).build(ctx)
+    /// ```
+    ///
     /// **style:** `text-block`
     TextBlock {
         /// Sets or shares the text property.
@@ -18,7 +25,19 @@ widget!(
         font_size: f64,
 
         /// Sets or shares the font property.
-        font: String
+        font: String,
+
+        /// Sets or shares the fonts that are used for characters that are not covered by `font`.
+        font_fallbacks: Vec<String>,
+
+        /// Sets or shares the maximum number of shown lines. With `None` all lines are shown.
+        max_lines: Option<usize>,
+
+        /// Sets or shares the flag that replaces each character of the text by `mask_char`.
+        password_mode: bool,
+
+        /// Sets or shares the character that is shown instead of the text in `password_mode`.
+        mask_char: String
     }
 );
 
@@ -29,6 +48,10 @@ impl Template for TextBlock {
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
+            .font_fallbacks(Vec::<String>::new())
+            .max_lines(None)
+            .password_mode(false)
+            .mask_char(DEFAULT_MASK_CHAR)
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
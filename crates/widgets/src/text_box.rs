@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use super::behaviors::MouseBehavior;
 
 use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
@@ -5,12 +7,19 @@ use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, them
 // --- KEYS --
 pub static STYLE_TEXT_BOX: &'static str = "text_box";
 static ID_CURSOR: &'static str = "id_cursor";
+static TEXT_STATES: [&str; 4] = ["empty", "empty_focused", "focused", "invalid"];
+static STATE_MAX_LENGTH_REACHED: &str = "max_length_reached";
 // --- KEYS --
 
+// maximum time and distance between the clicks of a double click
+const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(500);
+const DOUBLE_CLICK_DISTANCE: f64 = 5.0;
+
 #[derive(Clone)]
 enum TextBoxAction {
     Key(KeyEvent),
     Mouse(Mouse),
+    Hover(bool),
 }
 
 /// The `TextBoxState` handles the text processing of the `TextBox` widget.
@@ -20,6 +29,19 @@ pub struct TextBoxState {
     len: usize,
     cursor: Entity,
     focused: bool,
+    // text of the last undo step
+    undo_text: String16,
+    // selection of the last undo step
+    undo_selection: TextSelection,
+    // text after the last change by input
+    edit_text: String16,
+    // fixed end of a selection that is extended by the keyboard
+    selection_anchor: usize,
+    // time and position of the last click
+    last_click: Option<(Instant, Point)>,
+    // compiled input filter and its pattern
+    #[cfg(feature = "regex-filter")]
+    input_filter: Option<(String, regex::Regex)>,
 }
 
 impl TextBoxState {
@@ -32,13 +54,41 @@ impl TextBoxState {
             return;
         }
 
-        match key_event.key {
+        // navigation and shortcuts use the key of the keyboard layout, text input uses the
+        // produced text
+        match key_event.logical.0 {
+            Key::Left if is_word_modifier_down(ctx) => {
+                self.jump_word_left(ctx);
+            }
+            Key::Right if is_word_modifier_down(ctx) => {
+                self.jump_word_right(ctx);
+            }
+            Key::Left if is_shift_down(ctx) => {
+                self.extend_selection_left(ctx);
+            }
             Key::Left => {
                 self.move_cursor_left(ctx);
             }
+            Key::Right if is_shift_down(ctx) => {
+                self.extend_selection_right(ctx);
+            }
             Key::Right => {
                 self.move_cursor_right(ctx);
             }
+            Key::Home if is_shift_down(ctx) => {
+                self.extend_selection_to(0, ctx);
+            }
+            Key::Home => {
+                self.move_cursor_to(0, ctx);
+            }
+            Key::End if is_shift_down(ctx) => {
+                let len = ctx.widget().get::<String16>("text").len();
+                self.extend_selection_to(len, ctx);
+            }
+            Key::End => {
+                let len = ctx.widget().get::<String16>("text").len();
+                self.move_cursor_to(len, ctx);
+            }
             Key::Backspace => {
                 self.back_space(ctx);
             }
@@ -46,39 +96,106 @@ impl TextBoxState {
                 self.delete(ctx);
             }
             Key::Enter => {
+                self.commit_undo(ctx);
                 self.activate(ctx);
             }
-            Key::A(..) => {
-                // if cfg!(mac_os) {
-                //     if ctx
-                //         .window()
-                //         .get::<Global>("global")
-                //         .keyboard_state
-                //         .is_home_down()
-                //     {
-                //         self.select_all(ctx);
-                //     } else {
-                //         self.insert_char(key_event, ctx);
-                //     }
-                // } else {
-                if ctx
-                    .window()
-                    .get::<Global>("global")
-                    .keyboard_state
-                    .is_ctrl_down()
-                {
-                    self.select_all(ctx);
-                } else {
-                    self.insert_char(key_event, ctx);
-                }
-                // }
+            Key::A(..) if is_shortcut_modifier_down(ctx) => {
+                self.select_all(ctx);
+            }
+            Key::C(..) if is_shortcut_modifier_down(ctx) => {
+                self.copy(ctx);
+            }
+            Key::X(..) if is_shortcut_modifier_down(ctx) => {
+                self.cut(ctx);
+            }
+            Key::V(..) if is_shortcut_modifier_down(ctx) => {
+                self.paste(ctx);
+            }
+            Key::Z(..) if is_shortcut_modifier_down(ctx) && is_shift_down(ctx) => {
+                self.redo(ctx);
+            }
+            Key::Z(..) if is_shortcut_modifier_down(ctx) => {
+                self.undo(ctx);
+            }
+            Key::Y(..) if is_shortcut_modifier_down(ctx) => {
+                self.redo(ctx);
             }
             _ => {
-                self.insert_char(key_event, ctx);
+                let word_boundary = key_event.text.chars().any(char::is_whitespace);
+                self.insert_text(&key_event.text, ctx);
+
+                if word_boundary {
+                    self.commit_undo(ctx);
+                }
             }
         }
     }
 
+    // Pushes the text changes since the last undo step as one step to the undo stack.
+    fn commit_undo(&mut self, ctx: &mut Context) {
+        let text = ctx.widget().clone::<String16>("text");
+        let selection = ctx.widget().clone::<TextSelection>("text_selection");
+
+        if text == self.undo_text {
+            return;
+        }
+
+        // a password is never stored in the undo history
+        if *ctx.widget().get::<bool>("password_mode") {
+            self.undo_text = text;
+            self.undo_selection = selection;
+            return;
+        }
+
+        ctx.undo_service().push(UndoAction::group(
+            "Change text",
+            vec![
+                UndoAction::property(
+                    "Change text",
+                    ctx.entity,
+                    "text",
+                    self.undo_text.clone(),
+                    text.clone(),
+                ),
+                UndoAction::property(
+                    "Change selection",
+                    ctx.entity,
+                    "text_selection",
+                    self.undo_selection,
+                    selection,
+                ),
+            ],
+        ));
+
+        self.undo_text = text;
+        self.undo_selection = selection;
+    }
+
+    // Reverts the last undo step of the window, pending text changes are committed before.
+    fn undo(&mut self, ctx: &mut Context) {
+        self.commit_undo(ctx);
+
+        if ctx.undo() {
+            self.sync_undo(ctx);
+        }
+    }
+
+    // Applies the last reverted undo step of the window again.
+    fn redo(&mut self, ctx: &mut Context) {
+        if ctx.redo() {
+            self.sync_undo(ctx);
+        }
+    }
+
+    // Takes the restored text and selection as the last undo step.
+    fn sync_undo(&mut self, ctx: &mut Context) {
+        self.undo_text = ctx.widget().clone("text");
+        self.undo_selection = ctx.widget().clone("text_selection");
+
+        let expanded = ctx.widget().get::<TextSelection>("text_selection").length > 0;
+        ctx.get_widget(self.cursor).set("expanded", expanded);
+    }
+
     fn request_focus(&self, ctx: &mut Context, p: Mouse) {
         ctx.push_event_by_window(FocusEvent::RequestFocus(ctx.entity));
 
@@ -104,6 +221,42 @@ impl TextBoxState {
         }
     }
 
+    // Returns `true` if the given click follows the last click close enough in time and space.
+    fn is_double_click(&mut self, p: Mouse) -> bool {
+        let now = Instant::now();
+
+        let double_click = self.last_click.map_or(false, |(instant, position)| {
+            now.duration_since(instant) < DOUBLE_CLICK_TIME
+                && (position.x() - p.position.x()).abs() < DOUBLE_CLICK_DISTANCE
+                && (position.y() - p.position.y()).abs() < DOUBLE_CLICK_DISTANCE
+        });
+
+        // a third click starts a new double click
+        self.last_click = if double_click {
+            None
+        } else {
+            Some((now, p.position))
+        };
+
+        double_click
+    }
+
+    // Selects the word under the given mouse position.
+    fn select_word_at(&mut self, ctx: &mut Context, p: Mouse) {
+        let index = self.get_new_caret_position(ctx, p);
+        let (start, end) = word_range_at(&word_text(ctx), index);
+
+        self.selection_anchor = start;
+        ctx.widget().set(
+            "text_selection",
+            TextSelection {
+                start_index: start,
+                length: end - start,
+            },
+        );
+        ctx.get_widget(self.cursor).set("expanded", end > start);
+    }
+
     // Get new position for the caret based on current mouse position
     fn get_new_caret_position(&self, ctx: &mut Context, p: Mouse) -> usize {
         if let Some((index, _x)) = self
@@ -119,7 +272,9 @@ impl TextBoxState {
 
     // Returns a vector with a tuple of each char's starting index (usize) and position (f64)
     fn map_chars_index_to_position(&self, ctx: &mut Context) -> Vec<(usize, f64)> {
-        let text: String16 = ctx.widget().clone("text");
+        // the mask of a password is measured, the indices are the same as of the text
+        let text = ctx.widget().clone::<String16>("text");
+        let text = displayed_text(&ctx.widget(), text);
         // start x position of the cursor is start position of the text element + padding left
         let start_position: f64 = ctx.widget().get::<Point>("position").x()
             + ctx.widget().get::<Thickness>("padding").left;
@@ -132,12 +287,7 @@ impl TextBoxState {
 
         for i in 0..text.len() {
             let bound_width: f64 = ctx
-                .render_context_2_d()
-                .measure(
-                    &text.get_string(0, i + 1).unwrap().as_str(),
-                    font_size,
-                    &font,
-                )
+                .measure_text(&text.get_string(0, i + 1).unwrap(), font_size, &font)
                 .width;
             let next_position: f64 = start_position + bound_width;
 
@@ -149,15 +299,55 @@ impl TextBoxState {
         position_index
     }
 
-    // Reset selection and offset if text is changed from outside
-    fn reset(&self, ctx: &mut Context) {
-        ctx.widget().set("text_selection", TextSelection::default());
+    // Runs the validators of the text box and updates the validation error and the `invalid` state.
+    fn validate(&self, ctx: &mut Context) {
+        let validators = ctx.widget().clone::<Validators>("validators");
+
+        if validators.is_empty() {
+            return;
+        }
+
+        let text = ctx.widget().get::<String16>("text").as_string();
+        let error = validators.validate(&text).err();
+
+        if error.is_some() {
+            set_text_state(ctx, "invalid");
+        } else if ctx
+            .widget()
+            .get::<Selector>("selector")
+            .has_state("invalid")
+        {
+            ctx.widget()
+                .get_mut::<Selector>("selector")
+                .remove_state("invalid");
+        }
+
+        ctx.widget().set::<ErrorMessage>("validation_error", error);
+        ctx.widget().update(false);
+    }
+
+    // Keeps the caret inside of the text if the text is shortened from outside
+    fn reset(&self, len: usize, ctx: &mut Context) {
+        let start_index = ctx
+            .widget()
+            .get::<TextSelection>("text_selection")
+            .start_index
+            .min(len);
+
+        ctx.widget().set(
+            "text_selection",
+            TextSelection {
+                start_index,
+                length: 0,
+            },
+        );
+        ctx.get_widget(self.cursor).set("expanded", false);
     }
 
     fn check_outside_update(&self, ctx: &mut Context) {
         let len = ctx.widget().get::<String16>("text").len();
         if self.len != len && self.len > len {
-            self.reset(ctx);
+            self.reset(len, ctx);
         }
     }
 
@@ -173,14 +363,18 @@ impl TextBoxState {
     }
 
     fn move_cursor_left(&mut self, ctx: &mut Context) {
+        // a selection is collapsed to its start
         if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
             if let Some(selection) = ctx
                 .get_widget(self.cursor)
                 .try_get_mut::<TextSelection>("text_selection")
             {
-                selection.start_index = 0;
                 selection.length = 0;
             }
+
+            ctx.get_widget(self.cursor).set("expanded", false);
+
+            return;
         }
 
         if let Some(selection) = ctx
@@ -197,12 +391,13 @@ impl TextBoxState {
     fn move_cursor_right(&mut self, ctx: &mut Context) {
         let text_len = ctx.widget().get::<String16>("text").len();
 
+        // a selection is collapsed to its end
         if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
             if let Some(selection) = ctx
                 .get_widget(self.cursor)
                 .try_get_mut::<TextSelection>("text_selection")
             {
-                selection.start_index = text_len;
+                selection.start_index = (selection.start_index + selection.length).min(text_len);
                 selection.length = 0;
             }
 
@@ -224,6 +419,70 @@ impl TextBoxState {
         ctx.get_widget(self.cursor).set("expanded", false);
     }
 
+    fn move_cursor_to(&mut self, index: usize, ctx: &mut Context) {
+        ctx.widget().set(
+            "text_selection",
+            TextSelection {
+                start_index: index,
+                length: 0,
+            },
+        );
+        ctx.get_widget(self.cursor).set("expanded", false);
+    }
+
+    fn extend_selection_left(&mut self, ctx: &mut Context) {
+        let selection = ctx.widget().clone::<TextSelection>("text_selection");
+        let caret = caret_index(selection, self.selection_anchor);
+
+        self.extend_selection_to(caret.saturating_sub(1), ctx);
+    }
+
+    fn extend_selection_right(&mut self, ctx: &mut Context) {
+        let selection = ctx.widget().clone::<TextSelection>("text_selection");
+        let caret = caret_index(selection, self.selection_anchor);
+        let len = ctx.widget().get::<String16>("text").len();
+
+        self.extend_selection_to((caret + 1).min(len), ctx);
+    }
+
+    // Moves the caret to the start of the previous word, extends the selection if shift is down.
+    fn jump_word_left(&mut self, ctx: &mut Context) {
+        let text = word_text(ctx);
+        let selection = ctx.widget().clone::<TextSelection>("text_selection");
+
+        if is_shift_down(ctx) {
+            let caret = caret_index(selection, self.selection_anchor);
+            self.extend_selection_to(find_word_boundary_left(&text, caret), ctx);
+        } else {
+            self.move_cursor_to(find_word_boundary_left(&text, selection.start_index), ctx);
+        }
+    }
+
+    // Moves the caret to the end of the next word, extends the selection if shift is down.
+    fn jump_word_right(&mut self, ctx: &mut Context) {
+        let text = word_text(ctx);
+        let selection = ctx.widget().clone::<TextSelection>("text_selection");
+
+        if is_shift_down(ctx) {
+            let caret = caret_index(selection, self.selection_anchor);
+            self.extend_selection_to(find_word_boundary_right(&text, caret), ctx);
+        } else {
+            let end = selection.start_index + selection.length;
+            self.move_cursor_to(find_word_boundary_right(&text, end), ctx);
+        }
+    }
+
+    // Moves the caret to the given index and selects the text between the anchor and the caret.
+    fn extend_selection_to(&mut self, caret: usize, ctx: &mut Context) {
+        let selection = ctx.widget().clone::<TextSelection>("text_selection");
+        self.selection_anchor = anchor_index(selection, self.selection_anchor);
+
+        let selection = select_range(self.selection_anchor, caret);
+        ctx.widget().set("text_selection", selection);
+        ctx.get_widget(self.cursor)
+            .set("expanded", selection.length > 0);
+    }
+
     fn clear_selection(&mut self, ctx: &mut Context) {
         let selection = ctx.widget().clone::<TextSelection>("text_selection");
         let mut text = ctx.widget().clone::<String16>("text");
@@ -280,6 +539,123 @@ impl TextBoxState {
         }
     }
 
+    // Gets the selected text.
+    fn selected_text(&self, ctx: &mut Context) -> Option<String> {
+        let selection = ctx.widget().clone::<TextSelection>("text_selection");
+
+        if selection.length == 0 {
+            return None;
+        }
+
+        ctx.widget().get::<String16>("text").get_string(
+            selection.start_index,
+            selection.start_index + selection.length,
+        )
+    }
+
+    fn copy(&self, ctx: &mut Context) {
+        // a password is never copied to the clipboard
+        if *ctx.widget().get::<bool>("password_mode") {
+            return;
+        }
+
+        if let Some(text) = self.selected_text(ctx) {
+            ctx.clipboard_service().set(&text);
+        }
+    }
+
+    fn cut(&mut self, ctx: &mut Context) {
+        if *ctx.widget().get::<bool>("password_mode") {
+            return;
+        }
+
+        if let Some(text) = self.selected_text(ctx) {
+            ctx.clipboard_service().set(&text);
+            self.commit_undo(ctx);
+            self.clear_selection(ctx);
+            self.commit_undo(ctx);
+        }
+    }
+
+    // Inserts the text of the clipboard at the caret and replaces the selected text.
+    fn paste(&mut self, ctx: &mut Context) {
+        let text = match ctx.clipboard_service().get() {
+            Some(text) => text,
+            None => return,
+        };
+
+        // the text box is single line
+        let text = text.lines().next().unwrap_or_default();
+
+        if text.is_empty() {
+            return;
+        }
+
+        self.commit_undo(ctx);
+
+        if ctx.widget().get::<TextSelection>("text_selection").length > 0 {
+            self.clear_selection(ctx);
+        }
+
+        self.insert_text(text, ctx);
+        self.commit_undo(ctx);
+    }
+
+    // Removes the characters that do not match the input filter from the given text.
+    #[cfg(feature = "regex-filter")]
+    fn filter_input(&mut self, text: &str, ctx: &mut Context) -> String {
+        let pattern = match ctx.widget().clone::<Option<String>>("input_filter") {
+            Some(pattern) => pattern,
+            None => return text.to_string(),
+        };
+
+        // the filter is compiled again only if the pattern is changed
+        if self.input_filter.as_ref().map(|(p, _)| p) != Some(&pattern) {
+            match regex::Regex::new(&pattern) {
+                Ok(regex) => self.input_filter = Some((pattern, regex)),
+                Err(error) => {
+                    CONSOLE.warn(format!("TextBox: invalid input filter: {}", error));
+                    self.input_filter = None;
+                    return text.to_string();
+                }
+            }
+        }
+
+        match &self.input_filter {
+            Some((_, regex)) => filter_chars(regex, text),
+            None => text.to_string(),
+        }
+    }
+
+    #[cfg(not(feature = "regex-filter"))]
+    fn filter_input(&mut self, text: &str, _: &mut Context) -> String {
+        text.to_string()
+    }
+
+    // Sets the `max_length_reached` state if the text has the max length.
+    fn update_max_length_state(&self, ctx: &mut Context) {
+        let mut widget = ctx.widget();
+        let reached = self.len >= *widget.get::<usize>("max_length");
+
+        let has_state = widget
+            .get::<Selector>("selector")
+            .has_state(STATE_MAX_LENGTH_REACHED);
+
+        if reached == has_state {
+            return;
+        }
+
+        let selector = widget.get_mut::<Selector>("selector");
+
+        if reached {
+            selector.set_state(STATE_MAX_LENGTH_REACHED);
+        } else {
+            selector.remove_state(STATE_MAX_LENGTH_REACHED);
+        }
+
+        widget.update(false);
+    }
+
     fn activate(&self, ctx: &mut Context) {
         if *ctx.widget().get::<bool>("lost_focus_on_activation") {
             ctx.push_event_by_window(FocusEvent::RemoveFocus(ctx.entity));
@@ -292,37 +668,41 @@ impl TextBoxState {
         )
     }
 
-    fn insert_char(&mut self, key_event: KeyEvent, ctx: &mut Context) {
-        if key_event.text.is_empty() {
+    fn insert_text(&mut self, insert_text: &str, ctx: &mut Context) {
+        let insert_text = self.filter_input(insert_text, ctx);
+
+        if insert_text.is_empty() {
             return;
         }
 
+        // the inserted text replaces the selected text
         if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
-            ctx.widget().set("text", String16::from(key_event.text));
-            if let Some(selection) = ctx
-                .get_widget(self.cursor)
-                .try_get_mut::<TextSelection>("text_selection")
-            {
-                selection.start_index = 1;
-                selection.length = 0
-            }
-            ctx.get_widget(self.cursor).set("expanded", false);
-        } else {
-            let current_selection = *ctx
-                .get_widget(self.cursor)
-                .get::<TextSelection>("text_selection");
+            self.clear_selection(ctx);
+        }
 
-            let mut text = ctx.widget().clone::<String16>("text");
-            text.insert_str(current_selection.start_index, key_event.text.as_str());
-            ctx.widget().set("text", text);
+        // text that exceeds the max length is cut off
+        let max_length = *ctx.widget().get::<usize>("max_length");
+        let capacity = max_length.saturating_sub(ctx.widget().get::<String16>("text").len());
+        let insert_text = fit_text(&insert_text, capacity);
 
-            if let Some(selection) = ctx
-                .get_widget(self.cursor)
-                .try_get_mut::<TextSelection>("text_selection")
-            {
-                selection.start_index =
-                    current_selection.start_index + key_event.text.encode_utf16().count();
-            }
+        if insert_text.is_empty() {
+            return;
+        }
+
+        let current_selection = *ctx
+            .get_widget(self.cursor)
+            .get::<TextSelection>("text_selection");
+
+        let mut text = ctx.widget().clone::<String16>("text");
+        text.insert_str(current_selection.start_index, insert_text);
+        ctx.widget().set("text", text);
+
+        if let Some(selection) = ctx
+            .get_widget(self.cursor)
+            .try_get_mut::<TextSelection>("text_selection")
+        {
+            selection.start_index =
+                current_selection.start_index + insert_text.encode_utf16().count();
         }
     }
 }
@@ -334,25 +714,48 @@ impl State for TextBoxState {
             .expect("TextBoxState.init: cursor child could not be found.");
         self.len = ctx.widget().get::<String16>("text").len();
         self.focused = *ctx.widget().get::<bool>("focused");
+        self.undo_text = ctx.widget().clone("text");
+        self.undo_selection = ctx.widget().clone("text_selection");
+        self.edit_text = self.undo_text.clone();
 
         if self.len == 0 {
-            ctx.widget()
-                .get_mut::<Selector>("selector")
-                .set_state("empty");
+            set_text_state(ctx, "empty");
             ctx.widget().update(false);
         }
     }
 
+    fn on_focus_gained(&mut self, _: &mut Registry, _: &mut Context) {
+        self.focused = true;
+    }
+
+    fn on_focus_lost(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.focused = false;
+        self.validate(ctx);
+    }
+
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
         self.check_outside_update(ctx);
 
+        // text is changed from outside e.g. by undo
+        let text = ctx.widget().clone::<String16>("text");
+        if text != self.edit_text {
+            self.undo_text = text.clone();
+            self.undo_selection = ctx.widget().clone("text_selection");
+            self.edit_text = text;
+        }
+
         let focused = *ctx.widget().get::<bool>("focused");
         let empty = ctx.widget().get::<String16>("text").is_empty();
 
-        if !focused && empty && !ctx.widget().get::<Selector>("selector").has_state("empty") {
-            ctx.widget()
-                .get_mut::<Selector>("selector")
-                .set_state("empty");
+        if !focused
+            && empty
+            && !ctx.widget().get::<Selector>("selector").has_state("empty")
+            && ctx
+                .widget()
+                .get::<ErrorMessage>("validation_error")
+                .is_none()
+        {
+            set_text_state(ctx, "empty");
             ctx.widget().update(false);
         }
 
@@ -362,43 +765,233 @@ impl State for TextBoxState {
             self.select_all(ctx);
         }
 
-        if self.focused != *ctx.widget().get::<bool>("focused") {
-            self.focused = *ctx.widget().get::<bool>("focused");
-        }
-
         if let Some(action) = self.action.clone() {
             match action {
                 TextBoxAction::Key(event) => {
                     self.handle_key_event(event, ctx);
                 }
                 TextBoxAction::Mouse(p) => {
-                    self.request_focus(ctx, p);
+                    if self.is_double_click(p) && *ctx.widget().get::<bool>("focused") {
+                        self.select_word_at(ctx, p);
+                    } else {
+                        self.request_focus(ctx, p);
+                    }
+                }
+                TextBoxAction::Hover(hovered) => {
+                    ctx.set_cursor(if hovered {
+                        CursorShape::Text
+                    } else {
+                        CursorShape::Default
+                    });
                 }
             }
 
             self.action = None;
+            self.edit_text = ctx.widget().clone("text");
             ctx.widget().update(false);
         }
 
         self.len = ctx.widget().get::<String16>("text").len();
+        self.update_max_length_state(ctx);
 
         if self.len == 0 && self.focused {
-            ctx.widget()
-                .get_mut::<Selector>("selector")
-                .set_state("empty_focused");
+            set_text_state(ctx, "empty_focused");
             ctx.widget().update(false);
         } else if self.len > 0 && self.focused {
-            ctx.widget()
-                .get_mut::<Selector>("selector")
-                .set_state("focused");
+            set_text_state(ctx, "focused");
             ctx.widget().update(false);
         }
     }
 }
 
+// The text states exclude each other, only the given one is kept beside other states e.g. hovered.
+fn set_text_state(ctx: &mut Context, state: &str) {
+    let mut widget = ctx.widget();
+    let selector = widget.get_mut::<Selector>("selector");
+
+    for text_state in TEXT_STATES.iter().filter(|s| **s != state) {
+        selector.remove_state(text_state);
+    }
+
+    selector.set_state(state);
+}
+
+// Keeps only the characters of the given text that match the regex.
+#[cfg(feature = "regex-filter")]
+fn filter_chars(regex: &regex::Regex, text: &str) -> String {
+    text.chars()
+        .filter(|c| regex.is_match(c.encode_utf8(&mut [0; 4])))
+        .collect()
+}
+
+// Gets the longest start of the given text that fits in the given count of UTF-16 units.
+fn fit_text(text: &str, capacity: usize) -> &str {
+    let mut len = 0;
+
+    for (index, c) in text.char_indices() {
+        len += c.len_utf16();
+
+        if len > capacity {
+            return &text[..index];
+        }
+    }
+
+    text
+}
+
+// Gets the text that is used to find word boundaries. A password is handled as one word to not
+// reveal its structure.
+fn word_text(ctx: &mut Context) -> String16 {
+    let text = ctx.widget().clone::<String16>("text");
+
+    if *ctx.widget().get::<bool>("password_mode") {
+        return mask_text(&text, "x");
+    }
+
+    text
+}
+
+// Letters, digits and underscores are part of a word, whitespace and punctuation separate words.
+fn is_word_char(c: u16) -> bool {
+    std::char::from_u32(u32::from(c)).map_or(true, |c| c.is_alphanumeric() || c == '_')
+}
+
+// Gets the index of the start of the word before the given index. Whitespace and punctuation
+// before the index are skipped.
+fn find_word_boundary_left(text: &String16, index: usize) -> usize {
+    let chars = text.as_bytes();
+    let mut index = index.min(chars.len());
+
+    while index > 0 && !is_word_char(chars[index - 1]) {
+        index -= 1;
+    }
+
+    while index > 0 && is_word_char(chars[index - 1]) {
+        index -= 1;
+    }
+
+    index
+}
+
+// Gets the index of the end of the word after the given index. Whitespace and punctuation after
+// the index are skipped.
+fn find_word_boundary_right(text: &String16, index: usize) -> usize {
+    let chars = text.as_bytes();
+    let mut index = index.min(chars.len());
+
+    while index < chars.len() && !is_word_char(chars[index]) {
+        index += 1;
+    }
+
+    while index < chars.len() && is_word_char(chars[index]) {
+        index += 1;
+    }
+
+    index
+}
+
+// Gets the start and the end of the word at the given index. Start and end are equal if there is
+// no word at the index.
+fn word_range_at(text: &String16, index: usize) -> (usize, usize) {
+    let chars = text.as_bytes();
+    let mut start = index.min(chars.len());
+    let mut end = start;
+
+    while start > 0 && is_word_char(chars[start - 1]) {
+        start -= 1;
+    }
+
+    while end < chars.len() && is_word_char(chars[end]) {
+        end += 1;
+    }
+
+    (start, end)
+}
+
+// Gets the fixed end of the given selection. Without a selection it is the caret, otherwise it is
+// the last anchor if that is still an end of the selection or the start of the selection.
+fn anchor_index(selection: TextSelection, anchor: usize) -> usize {
+    if selection.length > 0 && anchor == selection.start_index + selection.length {
+        return anchor;
+    }
+
+    selection.start_index
+}
+
+// Gets the moving end of the given selection, it is the opposite end of the anchor.
+fn caret_index(selection: TextSelection, anchor: usize) -> usize {
+    if anchor_index(selection, anchor) == selection.start_index {
+        selection.start_index + selection.length
+    } else {
+        selection.start_index
+    }
+}
+
+// Selects the text between the anchor and the caret, the selection flips if the caret passes the
+// anchor.
+fn select_range(anchor: usize, caret: usize) -> TextSelection {
+    TextSelection {
+        start_index: anchor.min(caret),
+        length: if caret > anchor {
+            caret - anchor
+        } else {
+            anchor - caret
+        },
+    }
+}
+
+// Shift extends the selection and triggers redo together with the shortcut modifier and z.
+fn is_shift_down(ctx: &mut Context) -> bool {
+    ctx.window()
+        .get::<Global>("global")
+        .keyboard_state
+        .is_shift_down()
+}
+
+// Words are jumped with the option key on macOS and the control key on other systems.
+fn is_word_modifier_down(ctx: &mut Context) -> bool {
+    let window = ctx.window();
+    let keyboard_state = &window.get::<Global>("global").keyboard_state;
+
+    if cfg!(target_os = "macos") {
+        keyboard_state.is_alt_down()
+    } else {
+        keyboard_state.is_ctrl_down()
+    }
+}
+
+// Shortcuts like select all use the command key on macOS and the control key on other systems.
+fn is_shortcut_modifier_down(ctx: &mut Context) -> bool {
+    let window = ctx.window();
+    let keyboard_state = &window.get::<Global>("global").keyboard_state;
+
+    if cfg!(target_os = "macos") {
+        keyboard_state.is_meta_down()
+    } else {
+        keyboard_state.is_ctrl_down()
+    }
+}
+
 widget!(
     /// The `TextBox` widget represents a single line text input widget.
     ///
+    /// If `validators` are set, the text is validated each time the text box loses the focus.
+    /// On failure the `invalid` selector state is set and `validation_error` contains the
+    /// message of the first failed validator.
+    ///
+    /// Text changes are pushed word by word to the undo service of the window. `Ctrl+Z` reverts
+    /// the last change, `Ctrl+Y` and `Ctrl+Shift+Z` apply it again (`Cmd` on macOS). The size of
+    /// the history could be changed by `UndoService::set_max_history`.
+    ///
+    /// `Ctrl+C`, `Ctrl+X` and `Ctrl+V` copy, cut and paste the selected text via the clipboard
+    /// service of the window.
+    ///
+    /// `Shift` together with the arrow keys, `Home` and `End` extends the selection. `Ctrl` with
+    /// the arrow keys jumps to the previous or next word (`Option` on macOS). A double click
+    /// selects the word under the mouse.
+    ///
+    /// If the text has `max_length` characters, the `max_length_reached` selector state is set.
+    ///
     /// * style: `text_box`
     TextBox<TextBoxState>: ActivateHandler, KeyDownHandler {
         /// Sets or shares the text property.
@@ -407,6 +1000,10 @@ widget!(
         /// Sets or shares the water_mark text property.
         water_mark: String16,
 
+        /// Sets or shares the name that describes the text box for assistive technologies. Is
+        /// only needed if the text box has no water mark.
+        accessible_name: String,
+
         /// Sets or shares the text selection property.
         text_selection: TextSelection,
 
@@ -441,7 +1038,29 @@ widget!(
         lost_focus_on_activation: bool,
 
         /// Used to request focus from outside. Set to `true` tor request focus.
-        request_focus: bool
+        request_focus: bool,
+
+        /// Sets or shares the validators that are run if the text box loses the focus.
+        validators: Validators,
+
+        /// Sets or shares the message of the first failed validator. `None` if the text is valid.
+        validation_error: ErrorMessage,
+
+        /// Sets or shares the flag that hides the text by replacing each character by
+        /// `mask_char`. Copy and cut are disabled in password mode and the text changes are not
+        /// pushed to the undo service.
+        password_mode: bool,
+
+        /// Sets or shares the character that is shown instead of the text in `password_mode`.
+        mask_char: String,
+
+        /// Sets or shares the maximum length of the text. Input and pasted text that exceeds the
+        /// length is cut off.
+        max_length: usize,
+
+        /// Sets or shares the regular expression that each typed or pasted character has to match,
+        /// e.g. `"[0-9]"`. Other characters are not inserted. Requires the `regex-filter` feature.
+        input_filter: Option<String>
     }
 );
 
@@ -455,6 +1074,8 @@ impl Template for TextBox {
             .water_mark(id)
             .font(id)
             .font_size(id)
+            .password_mode(id)
+            .mask_char(id)
             .build(ctx);
 
         self.name("TextBox")
@@ -474,6 +1095,10 @@ impl Template for TextBox {
             .height(32.0)
             .focused(false)
             .lost_focus_on_activation(true)
+            .password_mode(false)
+            .mask_char(DEFAULT_MASK_CHAR)
+            .max_length(usize::MAX)
+            .input_filter(None)
             .child(
                 MouseBehavior::new()
                     .visibility(id)
@@ -484,6 +1109,18 @@ impl Template for TextBox {
                             .action(TextBoxAction::Mouse(m));
                         true
                     })
+                    .on_mouse_enter(move |states, _| {
+                        states
+                            .get_mut::<TextBoxState>(id)
+                            .action(TextBoxAction::Hover(true));
+                        false
+                    })
+                    .on_mouse_leave(move |states, _| {
+                        states
+                            .get_mut::<TextBoxState>(id)
+                            .action(TextBoxAction::Hover(false));
+                        false
+                    })
                     .child(
                         Container::new()
                             .background(id)
@@ -520,3 +1157,96 @@ impl Template for TextBox {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selection(start_index: usize, length: usize) -> TextSelection {
+        TextSelection {
+            start_index,
+            length,
+        }
+    }
+
+    #[test]
+    fn test_caret_index() {
+        // without selection the caret is the anchor
+        assert_eq!(caret_index(selection(3, 0), 0), 3);
+
+        // extended to the right
+        assert_eq!(caret_index(selection(3, 2), 3), 5);
+
+        // extended to the left
+        assert_eq!(caret_index(selection(1, 2), 3), 1);
+
+        // a selection of the mouse or select all is extended at its end
+        assert_eq!(caret_index(selection(0, 4), 2), 4);
+    }
+
+    #[test]
+    fn test_select_range() {
+        assert_eq!(select_range(3, 5), selection(3, 2));
+        assert_eq!(select_range(3, 3), selection(3, 0));
+
+        // flips if the caret passes the anchor
+        assert_eq!(select_range(3, 1), selection(1, 2));
+    }
+
+    #[test]
+    fn test_find_word_boundary_left() {
+        let text = String16::from("hello big, world");
+
+        assert_eq!(find_word_boundary_left(&text, 16), 11);
+        assert_eq!(find_word_boundary_left(&text, 11), 6);
+        assert_eq!(find_word_boundary_left(&text, 8), 6);
+        assert_eq!(find_word_boundary_left(&text, 6), 0);
+        assert_eq!(find_word_boundary_left(&text, 0), 0);
+    }
+
+    #[test]
+    fn test_find_word_boundary_right() {
+        let text = String16::from("hello big, world");
+
+        assert_eq!(find_word_boundary_right(&text, 0), 5);
+        assert_eq!(find_word_boundary_right(&text, 5), 9);
+        assert_eq!(find_word_boundary_right(&text, 9), 16);
+        assert_eq!(find_word_boundary_right(&text, 16), 16);
+        assert_eq!(find_word_boundary_right(&text, 100), 16);
+    }
+
+    #[test]
+    fn test_word_range_at() {
+        let text = String16::from("hello big, world");
+
+        assert_eq!(word_range_at(&text, 0), (0, 5));
+        assert_eq!(word_range_at(&text, 2), (0, 5));
+        assert_eq!(word_range_at(&text, 5), (0, 5));
+        assert_eq!(word_range_at(&text, 7), (6, 9));
+        assert_eq!(word_range_at(&text, 16), (11, 16));
+
+        // no word between the comma and the space
+        assert_eq!(word_range_at(&text, 10), (10, 10));
+    }
+
+    #[test]
+    fn test_fit_text() {
+        assert_eq!(fit_text("hello", 10), "hello");
+        assert_eq!(fit_text("hello", 3), "hel");
+        assert_eq!(fit_text("hello", 0), "");
+
+        // a character outside of the basic plane needs two units
+        assert_eq!(fit_text("a😀b", 2), "a");
+        assert_eq!(fit_text("a😀b", 3), "a😀");
+    }
+
+    #[cfg(feature = "regex-filter")]
+    #[test]
+    fn test_filter_chars() {
+        let digits = regex::Regex::new("[0-9]").unwrap();
+
+        assert_eq!(filter_chars(&digits, "a1b2"), "12");
+        assert_eq!(filter_chars(&digits, "abc"), "");
+        assert_eq!(filter_chars(&digits, "42"), "42");
+    }
+}
@@ -6,6 +6,9 @@ widget!(
     /// The `ToggleButton` widget can be clicked by user and could switch between selected / not selected.
     /// It's used to perform an action.
     ///
+    /// The toggle button is focused on click. If it is focused, the space key switches its
+    /// selected state.
+    ///
     /// **style:** `toggle-button`
     ToggleButton: MouseHandler {
         /// Sets or shares the background property.
@@ -50,6 +53,9 @@ widget!(
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the focused property.
+        focused: bool,
+
         /// Sets or shares the selected property.
         selected: bool,
 
@@ -79,6 +85,7 @@ impl Template for ToggleButton {
             .icon_size(fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
             .pressed(false)
+            .focused(false)
             .spacing(8.0)
             .child(
                 MouseBehavior::new()
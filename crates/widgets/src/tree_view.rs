@@ -0,0 +1,783 @@
+use std::collections::HashMap;
+
+use super::behaviors::MouseBehavior;
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+static ITEMS_PANEL: &'static str = "items_panel";
+static SCROLL_VIEWER: &'static str = "scroll_viewer";
+
+/// Describes a node of a `TreeView` with its child nodes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TreeNode {
+    /// The id of the node that is reported by the `TreeSelectionEvent`.
+    pub id: String,
+
+    /// The text that is displayed for the node.
+    pub label: String,
+
+    /// The child nodes.
+    pub children: Vec<TreeNode>,
+
+    /// If `true` the child nodes are displayed.
+    pub expanded: bool,
+}
+
+impl TreeNode {
+    /// Creates a collapsed node without child nodes.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        TreeNode {
+            id: id.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a child node.
+    pub fn child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Sets the expanded state of the node.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+}
+
+// Actions that are triggered by the items and the keyboard.
+#[derive(Clone, Debug, PartialEq)]
+enum TreeViewAction {
+    Toggle(String),
+    Select(String),
+    Key(Key),
+}
+
+/// The `TreeViewState` generates the items of the visible nodes and handles the expanded, the
+/// selected and the keyboard focused node.
+#[derive(Default, AsAny)]
+pub struct TreeViewState {
+    nodes: Vec<TreeNode>,
+    items: HashMap<String, Entity>,
+    selected: Option<String>,
+    focused_node: Option<String>,
+    scroll_to_focused_node: bool,
+    request_focus: bool,
+    items_panel: Entity,
+    scroll_viewer: Entity,
+    action: Option<TreeViewAction>,
+}
+
+impl TreeViewState {
+    // register an action
+    fn action(&mut self, action: TreeViewAction) {
+        self.action = Some(action);
+    }
+
+    fn generate_items(&mut self, ctx: &mut Context) {
+        ctx.clear_children_of(self.items_panel);
+
+        let entity = ctx.entity;
+        let indent_width = *ctx.widget().get::<f64>("indent_width");
+        let mut items = HashMap::new();
+
+        for (indent_level, node) in visible_nodes(&self.nodes) {
+            let selected = self.selected.as_ref() == Some(&node.id);
+
+            let item = {
+                let build_context = &mut ctx.build_context();
+                let (icon_font, icon) = build_context.icon_glyph(if node.expanded {
+                    "keyboard_arrow_down"
+                } else {
+                    "keyboard_arrow_right"
+                });
+                let toggle_id = node.id.clone();
+                let select_id = node.id.clone();
+
+                let item = TreeViewItem::new()
+                    .text(node.label.as_str())
+                    .icon(icon)
+                    .icon_font(icon_font)
+                    .indent_level(indent_level)
+                    .margin((indent_level as f64 * indent_width, 0.0, 0.0, 0.0))
+                    .toggle_button(if node.children.is_empty() {
+                        Visibility::Hidden
+                    } else {
+                        Visibility::Visible
+                    })
+                    .selected(selected)
+                    .on_toggle_click(move |states, _| {
+                        states
+                            .get_mut::<TreeViewState>(entity)
+                            .action(TreeViewAction::Toggle(toggle_id.clone()));
+                        true
+                    })
+                    .on_click(move |states, _| {
+                        states
+                            .get_mut::<TreeViewState>(entity)
+                            .action(TreeViewAction::Select(select_id.clone()));
+                        false
+                    })
+                    .build(build_context);
+
+                build_context.register_shared_property::<f32>("opacity", item, entity);
+                build_context.append_child(self.items_panel, item);
+
+                item
+            };
+
+            if selected {
+                ctx.get_widget(item)
+                    .get_mut::<Selector>("selector")
+                    .set_state("selected");
+            }
+
+            if self.focused_node.as_ref() == Some(&node.id) {
+                ctx.get_widget(item)
+                    .get_mut::<Selector>("selector")
+                    .set_state("focused");
+            }
+
+            ctx.get_widget(item).update_widget(entity, false, false);
+            items.insert(node.id.clone(), item);
+        }
+
+        self.items = items;
+    }
+
+    fn toggle(&mut self, id: &str, ctx: &mut Context) {
+        if toggle_node(&mut self.nodes, id) {
+            self.generate_items(ctx);
+        }
+    }
+
+    fn select(&mut self, id: String, ctx: &mut Context) {
+        if self.selected.as_ref() == Some(&id) {
+            return;
+        }
+
+        self.deselect(ctx);
+
+        if let Some(item) = self.items.get(&id) {
+            set_item_state(*item, "selected", true, ctx);
+        }
+
+        self.selected = Some(id.clone());
+        ctx.push_event_strategy_by_entity(
+            TreeSelectionEvent { id },
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    fn deselect(&mut self, ctx: &mut Context) {
+        if let Some(item) = self.selected.take().and_then(|old| self.items.get(&old)) {
+            set_item_state(*item, "selected", false, ctx);
+        }
+    }
+
+    // moves the keyboard focus to the given node and scrolls it into view after the layout
+    fn focus_node(&mut self, id: Option<String>, ctx: &mut Context) {
+        if id.is_none() || id == self.focused_node {
+            return;
+        }
+
+        if let Some(item) = self
+            .focused_node
+            .take()
+            .and_then(|old| self.items.get(&old))
+        {
+            set_item_state(*item, "focused", false, ctx);
+        }
+
+        if let Some(item) = id.as_ref().and_then(|id| self.items.get(id)) {
+            set_item_state(*item, "focused", true, ctx);
+        }
+
+        self.focused_node = id;
+        self.scroll_to_focused_node = true;
+    }
+
+    fn navigate(&mut self, key: Key, ctx: &mut Context) {
+        // the first key moves the focus to the selected or the first node
+        let focused_node = match self.focused_node.clone() {
+            Some(focused_node) => focused_node,
+            None => {
+                let id = self
+                    .selected
+                    .clone()
+                    .or_else(|| self.nodes.first().map(|node| node.id.clone()));
+                self.focus_node(id, ctx);
+                return;
+            }
+        };
+
+        let (has_children, expanded) = match find_node(&self.nodes, &focused_node) {
+            Some(node) => (!node.children.is_empty(), node.expanded),
+            None => return,
+        };
+
+        match key {
+            Key::Up => {
+                let id = visible_neighbor(&self.nodes, &focused_node, false);
+                self.focus_node(id, ctx);
+            }
+            Key::Down => {
+                let id = visible_neighbor(&self.nodes, &focused_node, true);
+                self.focus_node(id, ctx);
+            }
+            Key::Right if has_children && !expanded => self.toggle(&focused_node, ctx),
+            Key::Right if has_children => {
+                let id = visible_neighbor(&self.nodes, &focused_node, true);
+                self.focus_node(id, ctx);
+            }
+            Key::Left if expanded => self.toggle(&focused_node, ctx),
+            Key::Left => {
+                let id = parent_id(&self.nodes, &focused_node);
+                self.focus_node(id, ctx);
+            }
+            Key::Space if self.selected.as_ref() == Some(&focused_node) => self.deselect(ctx),
+            Key::Space => self.select(focused_node, ctx),
+            Key::Enter if self.selected.as_ref() == Some(&focused_node) => {
+                ctx.push_event_strategy_by_entity(
+                    TreeSelectionEvent { id: focused_node },
+                    ctx.entity,
+                    EventStrategy::Direct,
+                );
+            }
+            Key::Enter => self.select(focused_node, ctx),
+            _ => {}
+        }
+    }
+
+    // scrolls the items so that the focused node is visible
+    fn scroll_into_view(&mut self, ctx: &mut Context) {
+        let item = match self.focused_node.as_ref().and_then(|id| self.items.get(id)) {
+            Some(item) => *item,
+            None => return,
+        };
+
+        let item_bounds = *ctx.get_widget(item).get::<Rectangle>("bounds");
+        let view_port_height = ctx
+            .get_widget(self.scroll_viewer)
+            .get::<Rectangle>("bounds")
+            .height();
+        let mut padding = *ctx
+            .get_widget(self.scroll_viewer)
+            .get::<Thickness>("padding");
+
+        let offset = scroll_offset_of_item(
+            item_bounds.y(),
+            item_bounds.height(),
+            view_port_height,
+            padding.top(),
+        );
+
+        if offset != padding.top() {
+            padding.set_top(offset);
+            ctx.get_widget(self.scroll_viewer).set("padding", padding);
+            ctx.request_update();
+        }
+    }
+}
+
+impl State for TreeViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.items_panel = ctx
+            .entity_of_child(ITEMS_PANEL)
+            .expect("TreeViewState.init: ItemsPanel child could not be found.");
+        self.scroll_viewer = ctx
+            .entity_of_child(SCROLL_VIEWER)
+            .expect("TreeViewState.init: ScrollViewer child could not be found.");
+
+        self.generate_items(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.request_focus {
+            self.request_focus = false;
+
+            if !*ctx.widget().get::<bool>("focused") {
+                ctx.push_event_by_window(FocusEvent::RequestFocus(ctx.entity));
+            }
+        }
+
+        if let Some(action) = self.action.take() {
+            match action {
+                TreeViewAction::Toggle(id) => self.toggle(&id, ctx),
+                TreeViewAction::Select(id) => {
+                    self.request_focus = true;
+                    self.focus_node(Some(id.clone()), ctx);
+                    self.select(id, ctx);
+                }
+                // the keys navigate only if the tree view is focused
+                TreeViewAction::Key(key) => {
+                    if *ctx.widget().get::<bool>("focused") {
+                        self.navigate(key, ctx);
+                    }
+                }
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.scroll_to_focused_node {
+            self.scroll_to_focused_node = false;
+            self.scroll_into_view(ctx);
+        }
+    }
+}
+
+// Adds or removes the given state of the item and its property with the same name.
+fn set_item_state(item: Entity, state: &str, value: bool, ctx: &mut Context) {
+    let mut widget = ctx.get_widget(item);
+
+    if widget.has::<bool>(state) {
+        widget.set(state, value);
+    }
+
+    if value {
+        widget.get_mut::<Selector>("selector").set_state(state);
+    } else {
+        widget.get_mut::<Selector>("selector").remove_state(state);
+    }
+
+    widget.update(false);
+}
+
+// Returns the nodes that are not hidden by a collapsed parent with their indent level.
+fn visible_nodes(nodes: &[TreeNode]) -> Vec<(usize, &TreeNode)> {
+    fn collect<'a>(nodes: &'a [TreeNode], level: usize, visible: &mut Vec<(usize, &'a TreeNode)>) {
+        for node in nodes {
+            visible.push((level, node));
+
+            if node.expanded {
+                collect(&node.children, level + 1, visible);
+            }
+        }
+    }
+
+    let mut visible = vec![];
+    collect(nodes, 0, &mut visible);
+    visible
+}
+
+// Toggles the expanded state of the node with the given id. Returns `false` if there is no node
+// with the id.
+fn toggle_node(nodes: &mut [TreeNode], id: &str) -> bool {
+    for node in nodes {
+        if node.id == id {
+            node.expanded = !node.expanded;
+            return true;
+        }
+
+        if toggle_node(&mut node.children, id) {
+            return true;
+        }
+    }
+
+    false
+}
+
+// Returns the node with the given id.
+fn find_node<'a>(nodes: &'a [TreeNode], id: &str) -> Option<&'a TreeNode> {
+    for node in nodes {
+        if node.id == id {
+            return Some(node);
+        }
+
+        if let Some(node) = find_node(&node.children, id) {
+            return Some(node);
+        }
+    }
+
+    None
+}
+
+// Returns the id of the parent of the node with the given id.
+fn parent_id(nodes: &[TreeNode], id: &str) -> Option<String> {
+    for node in nodes {
+        if node.children.iter().any(|child| child.id == id) {
+            return Some(node.id.clone());
+        }
+
+        if let Some(parent_id) = parent_id(&node.children, id) {
+            return Some(parent_id);
+        }
+    }
+
+    None
+}
+
+// Returns the id of the next or the previous visible node of the node with the given id.
+fn visible_neighbor(nodes: &[TreeNode], id: &str, next: bool) -> Option<String> {
+    let visible = visible_nodes(nodes);
+    let index = visible.iter().position(|(_, node)| node.id == id)?;
+
+    let neighbor = if next {
+        visible.get(index + 1)
+    } else if index > 0 {
+        visible.get(index - 1)
+    } else {
+        None
+    };
+
+    neighbor.map(|(_, node)| node.id.clone())
+}
+
+// Returns the scroll offset that shows the whole item. The offset is kept if the item is already
+// visible.
+fn scroll_offset_of_item(item_y: f64, item_height: f64, view_port_height: f64, offset: f64) -> f64 {
+    if item_y + offset < 0.0 {
+        -item_y
+    } else if item_y + item_height + offset > view_port_height {
+        (view_port_height - item_y - item_height).min(0.0)
+    } else {
+        offset
+    }
+}
+
+/// The `TreeViewItemState` stores the toggle callback of a `TreeViewItem` until the template is
+/// built.
+#[derive(Default, AsAny)]
+pub struct TreeViewItemState {
+    on_toggle_click_callback: Option<Box<dyn 'static + Fn(&mut StatesContext, Point) -> bool>>,
+}
+
+impl State for TreeViewItemState {}
+
+widget!(
+    /// The `TreeViewItem` describes a node inside of a `TreeView`. It's indented by its level
+    /// and shows a toggle button if the node has child nodes.
+    ///
+    /// **style:** `tree_view_item`
+    TreeViewItem<TreeViewItemState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the icon of the toggle button.
+        icon: String,
+
+        /// Sets or shares the icon brush property.
+        icon_brush: Brush,
+
+        /// Sets or share the icon font size property.
+        icon_size: f64,
+
+        /// Sets or shares the icon font property.
+        icon_font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the selected property.
+        selected: bool,
+
+        /// Sets or shares the depth of the node inside of the tree.
+        indent_level: usize,
+
+        /// Sets or shares the toggle button visibility.
+        toggle_button: Visibility
+    }
+);
+
+impl TreeViewItem {
+    /// Sets the callback that is called when the user clicks on the toggle button.
+    pub fn on_toggle_click<T: 'static + Fn(&mut StatesContext, Point) -> bool>(
+        mut self,
+        callback: T,
+    ) -> Self {
+        self.state.on_toggle_click_callback = Some(Box::new(callback));
+        self
+    }
+}
+
+impl Template for TreeViewItem {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let mut toggle_button = Button::new()
+            .style("button_icon_only")
+            .icon(id)
+            .icon_font(id)
+            .icon_size(id)
+            .icon_brush(id)
+            .v_align("center")
+            .visibility(("toggle_button", id));
+
+        if let Some(callback) = self.state.on_toggle_click_callback.take() {
+            toggle_button = toggle_button.on_click(callback);
+        }
+
+        self.name("TreeViewItem")
+            .style("tree_view_item")
+            .height(24.0)
+            .selected(false)
+            .pressed(false)
+            .padding(0.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .icon("")
+            .icon_font("MaterialIcons-Regular")
+            .icon_size(fonts::ICON_FONT_SIZE_12)
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .indent_level(0)
+            .toggle_button(Visibility::Hidden)
+            .child(
+                MouseBehavior::new()
+                    .pressed(id)
+                    .enabled(id)
+                    .target(id.0)
+                    .child(
+                        Stack::new()
+                            .margin(("padding", id))
+                            .orientation("horizontal")
+                            .child(toggle_button.build(ctx))
+                            .child(
+                                TextBlock::new()
+                                    .text(id)
+                                    .v_align("center")
+                                    .font(id)
+                                    .font_size(id)
+                                    .foreground(id)
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+}
+
+widget!(
+    /// The `TreeView` displays a hierarchy of nodes. Nodes with child nodes can be expanded and
+    /// collapsed by their toggle button, a click on a node selects it.
+    ///
+    /// If the tree view is focused, the arrow keys move the focus through the visible nodes.
+    /// `Right` expands the focused node or moves to its first child, `Left` collapses it or moves
+    /// to its parent. `Space` toggles the selection of the focused node and `Enter` selects it.
+    ///
+    /// **style:** `tree_view`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// TreeView::new()
+    ///     .items(vec![TreeNode::new("src", "src")
+    ///         .expanded(true)
+    ///         .child(TreeNode::new("src/main.rs", "main.rs"))])
+    ///     .on_tree_selection(|_, id| println!("selected {}", id))
+    ///     .build(ctx)
+    /// ```
+    TreeView<TreeViewState>: KeyDownHandler, TreeSelectionHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the indent of each level of the tree.
+        indent_width: f64,
+
+        /// Sets or shares the focused property.
+        focused: bool
+    }
+);
+
+impl TreeView {
+    /// Sets the root nodes of the tree.
+    pub fn items(mut self, nodes: Vec<TreeNode>) -> Self {
+        self.state_mut().nodes = nodes;
+        self
+    }
+}
+
+impl Template for TreeView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items_panel = Stack::new()
+            .v_align("start")
+            .id(ITEMS_PANEL)
+            .orientation("vertical")
+            .build(ctx);
+
+        let scroll_viewer = ScrollViewer::new()
+            .id(SCROLL_VIEWER)
+            .mode(("disabled", "auto"))
+            .child(items_panel)
+            .build(ctx);
+
+        self.name("TreeView")
+            .style("tree_view")
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .padding(2.0)
+            .indent_width(16.0)
+            .focused(false)
+            .on_key_down(move |states, event| match event.logical.0 {
+                Key::Up | Key::Down | Key::Left | Key::Right | Key::Space | Key::Enter => {
+                    states
+                        .get_mut::<TreeViewState>(id)
+                        .action(TreeViewAction::Key(event.logical.0));
+                    true
+                }
+                _ => false,
+            })
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .opacity(id)
+                    .child(scroll_viewer)
+                    .child(
+                        ScrollIndicator::new()
+                            .padding(2.0)
+                            .content_bounds(("bounds", items_panel))
+                            .view_port_bounds(("bounds", scroll_viewer))
+                            .scroll_padding(("padding", scroll_viewer))
+                            .mode(scroll_viewer)
+                            .opacity(id)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> Vec<TreeNode> {
+        vec![
+            TreeNode::new("a", "A")
+                .expanded(true)
+                .child(TreeNode::new("a1", "A1").child(TreeNode::new("a1x", "A1x")))
+                .child(TreeNode::new("a2", "A2")),
+            TreeNode::new("b", "B").child(TreeNode::new("b1", "B1")),
+        ]
+    }
+
+    fn visible_ids(nodes: &[TreeNode]) -> Vec<(usize, &str)> {
+        visible_nodes(nodes)
+            .iter()
+            .map(|(level, node)| (*level, node.id.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_visible_nodes() {
+        let nodes = tree();
+
+        assert_eq!(
+            visible_ids(&nodes),
+            vec![(0, "a"), (1, "a1"), (1, "a2"), (0, "b")]
+        );
+        assert!(visible_nodes(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_find_node() {
+        let nodes = tree();
+
+        assert_eq!(
+            find_node(&nodes, "a1x").map(|node| node.label.as_str()),
+            Some("A1x")
+        );
+        assert_eq!(find_node(&nodes, "unknown"), None);
+    }
+
+    #[test]
+    fn test_parent_id() {
+        let nodes = tree();
+
+        assert_eq!(parent_id(&nodes, "a1x"), Some(String::from("a1")));
+        assert_eq!(parent_id(&nodes, "a2"), Some(String::from("a")));
+        assert_eq!(parent_id(&nodes, "a"), None);
+    }
+
+    #[test]
+    fn test_visible_neighbor() {
+        let nodes = tree();
+
+        assert_eq!(
+            visible_neighbor(&nodes, "a", true),
+            Some(String::from("a1"))
+        );
+        assert_eq!(
+            visible_neighbor(&nodes, "a2", true),
+            Some(String::from("b"))
+        );
+        assert_eq!(visible_neighbor(&nodes, "b", true), None);
+        assert_eq!(
+            visible_neighbor(&nodes, "b", false),
+            Some(String::from("a2"))
+        );
+        assert_eq!(visible_neighbor(&nodes, "a", false), None);
+
+        // hidden nodes have no visible neighbor
+        assert_eq!(visible_neighbor(&nodes, "b1", true), None);
+    }
+
+    #[test]
+    fn test_scroll_offset_of_item() {
+        // visible item keeps the offset
+        assert_eq!(scroll_offset_of_item(24.0, 24.0, 100.0, 0.0), 0.0);
+        // item below the view port
+        assert_eq!(scroll_offset_of_item(120.0, 24.0, 100.0, 0.0), -44.0);
+        // item above the view port
+        assert_eq!(scroll_offset_of_item(24.0, 24.0, 100.0, -48.0), -24.0);
+    }
+
+    #[test]
+    fn test_toggle_node() {
+        let mut nodes = tree();
+
+        assert!(toggle_node(&mut nodes, "a1"));
+        assert_eq!(
+            visible_ids(&nodes),
+            vec![(0, "a"), (1, "a1"), (2, "a1x"), (1, "a2"), (0, "b")]
+        );
+
+        assert!(toggle_node(&mut nodes, "a"));
+        assert_eq!(visible_ids(&nodes), vec![(0, "a"), (0, "b")]);
+
+        assert!(!toggle_node(&mut nodes, "unknown"));
+    }
+}
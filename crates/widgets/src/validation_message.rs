@@ -0,0 +1,79 @@
+use crate::{api::prelude::*, proc_macros::*, shell::prelude::WindowRequest, theme::prelude::*};
+
+/// The `ValidationMessageState` shows the validation error of the target widget.
+#[derive(Default, AsAny)]
+pub struct ValidationMessageState {
+    error: ErrorMessage,
+}
+
+impl State for ValidationMessageState {
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let target = match ctx.widget().try_clone::<u32>("target") {
+            Some(target) => Entity::from(target),
+            None => return,
+        };
+
+        let error = ctx
+            .get_widget(target)
+            .try_clone::<ErrorMessage>("validation_error")
+            .unwrap_or_default();
+
+        if error == self.error {
+            return;
+        }
+
+        if let Some(message) = &error {
+            ctx.widget().set("text", String16::from(message.as_str()));
+            ctx.widget().set("visibility", Visibility::Visible);
+        } else {
+            ctx.widget().set("text", String16::default());
+            ctx.widget().set("visibility", Visibility::Collapsed);
+        }
+
+        self.error = error;
+        ctx.send_window_request(WindowRequest::Redraw);
+    }
+}
+
+widget!(
+    /// The `ValidationMessage` widget shows the first validation error of its target widget,
+    /// e.g. of a `TextBox` with validators. It is collapsed if the target is valid.
+    ///
+    /// **style:** `error`
+    ValidationMessage<ValidationMessageState> {
+        /// Sets or shares the id of the validated widget.
+        target: u32,
+
+        /// Sets or shares the text property.
+        text: String16,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String
+    }
+);
+
+impl Template for ValidationMessage {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("ValidationMessage")
+            .style("error")
+            .text("")
+            .foreground(colors::CARNATION_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .visibility("collapsed")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(TextRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(FixedSizeLayout::new())
+    }
+}
@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, rc::Rc};
 
-use crate::{api::prelude::*, proc_macros::*, shell::prelude::WindowRequest, theme::prelude::*};
+use crate::{api::prelude::*, proc_macros::*, theme::prelude::*};
 
 // --- KEYS --
 pub static STYLE_WINDOW: &'static str = "window";
@@ -29,10 +29,22 @@ impl WindowState {
     }
 
     fn resize(&self, width: f64, height: f64, ctx: &mut Context) {
+        let old_bounds = ctx.widget().clone::<Rectangle>("bounds");
+
         window(ctx.widget()).bounds_mut().set_size(width, height);
         window(ctx.widget())
             .constraint_mut()
             .set_size(width, height);
+
+        // update all widgets if other media queries of the theme match the new size
+        if ctx
+            .widget()
+            .get::<Global>("global")
+            .theme
+            .media_changed((old_bounds.width(), old_bounds.height()), (width, height))
+        {
+            ctx.widget().update_dirty(true);
+        }
     }
 
     fn active_changed(&self, active: bool, ctx: &mut Context) {
@@ -65,7 +77,7 @@ impl WindowState {
             old_focused_element.set("focused", false);
             old_focused_element
                 .get_mut::<Selector>("selector")
-                .clear_state();
+                .remove_state("focused");
             old_focused_element.update(false);
         }
 
@@ -91,7 +103,7 @@ impl WindowState {
             old_focused_element.set("focused", false);
             old_focused_element
                 .get_mut::<Selector>("selector")
-                .clear_state();
+                .remove_state("focused");
             old_focused_element.update(false);
         }
 
@@ -123,7 +135,7 @@ impl State for WindowState {
 
         if !self.title.eq(window(ctx.widget()).title()) {
             self.title = window(ctx.widget()).clone_title();
-            ctx.send_window_request(WindowRequest::ChangeTitle(self.title.clone()));
+            ctx.window_service().set_title(&self.title);
         }
 
         if let Some(action) = self.actions.pop_front() {
@@ -155,7 +167,7 @@ widget!(
     /// It also contains global properties like keyboard modifier and focused widget.
     ///
     /// **style:** `window`
-    Window<WindowState> {
+    Window<WindowState>: DropHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -0,0 +1,44 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `Wrap` orders its children from left to right and continues with a new row if a child
+    /// does not fit into the current row. It's used for items like tags or chips.
+    ///
+    /// **style:** `wrap`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Wrap::new()
+    ///     .h_gap(8)
+    ///     .v_gap(4)
+    ///     .align_items("center")
+    ///     .child(Button::new().text("rust").build(ctx))
+    ///     .child(Button::new().text("gui").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    Wrap {
+        /// Sets or shares the horizontal space between the children of a row.
+        h_gap: f64,
+
+        /// Sets or shares the vertical space between the rows.
+        v_gap: f64,
+
+        /// Sets or shares the vertical alignment of the children inside of their row.
+        align_items: Alignment
+    }
+);
+
+impl Template for Wrap {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("Wrap")
+            .style("wrap")
+            .h_gap(0.0)
+            .v_gap(0.0)
+            .align_items("start")
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(WrapLayout::new())
+    }
+}
@@ -0,0 +1,164 @@
+use std::{path::PathBuf, sync::mpsc, thread};
+
+use futures::executor::block_on;
+
+use orbtk::{prelude::*, shell::WindowRequest};
+
+#[derive(Copy, Clone, PartialEq)]
+enum Action {
+    Open,
+    OpenMultiple,
+    Save,
+}
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    action: Option<Action>,
+    receiver: Option<mpsc::Receiver<Vec<PathBuf>>>,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+
+    // Runs the dialog on a separate thread so the ui is not blocked while the dialog is open.
+    fn run_dialog(&mut self, action: Action, registry: &mut Registry, ctx: &mut Context) {
+        let service = registry.get_required::<FileDialogService>().clone();
+        let window_sender = ctx.window_sender();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let filters = vec![FileFilter::new("Rust", &["rs"])];
+
+            let paths = match action {
+                Action::Open => block_on(service.open_file(OpenFileConfig {
+                    title: "Open file".to_string(),
+                    filters,
+                    ..Default::default()
+                }))
+                .into_iter()
+                .collect(),
+                Action::OpenMultiple => block_on(service.open_files(OpenFileConfig {
+                    title: "Open files".to_string(),
+                    filters,
+                    multi_select: true,
+                    ..Default::default()
+                })),
+                Action::Save => block_on(service.save_file(SaveFileConfig {
+                    title: "Save file".to_string(),
+                    filters,
+                    file_name: Some("main.rs".to_string()),
+                    ..Default::default()
+                }))
+                .into_iter()
+                .collect(),
+            };
+
+            sender.send(paths).unwrap();
+            window_sender.send(WindowRequest::Redraw).unwrap();
+        });
+
+        self.receiver = Some(receiver);
+        main_view(ctx.widget()).set_info_text(String16::from("Dialog is open..."));
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        if let Some(action) = self.action {
+            self.run_dialog(action, registry, ctx);
+            self.action = None;
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let paths = match self.receiver.as_ref().map(|r| r.try_recv()) {
+            Some(Ok(paths)) => paths,
+            _ => return,
+        };
+
+        self.receiver = None;
+
+        let info_text = if paths.is_empty() {
+            "Dialog canceled.".to_string()
+        } else {
+            paths
+                .iter()
+                .map(|p| p.to_string_lossy().to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        main_view(ctx.widget()).set_info_text(String16::from(info_text));
+    }
+}
+
+widget!(MainView<MainViewState> {
+    info_text: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(4.0)
+                        .child(
+                            Button::new()
+                                .style("button_single_content")
+                                .on_click(move |states, _| {
+                                    state(id, states).action(Action::Open);
+                                    true
+                                })
+                                .text("Open")
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::new()
+                                .style("button_single_content")
+                                .on_click(move |states, _| {
+                                    state(id, states).action(Action::OpenMultiple);
+                                    true
+                                })
+                                .text("Open multiple")
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::new()
+                                .style("button_single_content")
+                                .on_click(move |states, _| {
+                                    state(id, states).action(Action::Save);
+                                    true
+                                })
+                                .text("Save")
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .child(TextBlock::new().text(("info_text", id)).build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - file dialog example")
+                .position((100.0, 100.0))
+                .size(420.0, 200.0)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
+
+// helper to request MainViewState
+fn state<'a>(id: Entity, states: &'a mut StatesContext) -> &'a mut MainViewState {
+    states.get_mut(id)
+}
@@ -0,0 +1,109 @@
+use orbtk::{prelude::*, shell::Key};
+
+// Rotation in degrees per pixel of mouse movement.
+const SENSITIVITY: f64 = 0.2;
+
+#[derive(Copy, Clone)]
+enum Action {
+    Capture(bool),
+    Move(Point),
+}
+
+#[derive(Default, AsAny)]
+pub struct MainViewState {
+    actions: Vec<Action>,
+    yaw: f64,
+    pitch: f64,
+}
+
+impl MainViewState {
+    fn action(&mut self, action: Action) {
+        self.actions.push(action);
+    }
+}
+
+impl State for MainViewState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        for action in self.actions.drain(..) {
+            match action {
+                Action::Capture(capture) => ctx.set_raw_input(capture),
+                Action::Move(delta) => {
+                    self.yaw = (self.yaw + delta.x() * SENSITIVITY) % 360.0;
+                    self.pitch = (self.pitch - delta.y() * SENSITIVITY).max(-90.0).min(90.0);
+                }
+            }
+        }
+
+        main_view(ctx.widget()).set_info_text(String16::from(format!(
+            "yaw: {:.1}°  pitch: {:.1}°",
+            self.yaw, self.pitch
+        )));
+    }
+
+    // Releases the mouse if the view is removed while the mouse is captured.
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        ctx.set_raw_input(false);
+    }
+}
+
+widget!(MainView<MainViewState>: MouseHandler, KeyDownHandler {
+    info_text: String16
+});
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MainView")
+            .info_text("yaw: 0.0°  pitch: 0.0°")
+            .child(
+                Stack::new()
+                    .spacing(8.0)
+                    .child(
+                        Button::new()
+                            .text("Capture mouse")
+                            .on_click(move |states, _| {
+                                states
+                                    .get_mut::<MainViewState>(id)
+                                    .action(Action::Capture(true));
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        TextBlock::new()
+                            .text("Press Escape to release the mouse.")
+                            .build(ctx),
+                    )
+                    .child(TextBlock::new().text(("info_text", id)).build(ctx))
+                    .build(ctx),
+            )
+            .on_mouse_raw_delta(move |states, delta| {
+                states
+                    .get_mut::<MainViewState>(id)
+                    .action(Action::Move(delta));
+                true
+            })
+            .on_key_down(move |states, event| {
+                if event.logical == Key::Escape {
+                    states
+                        .get_mut::<MainViewState>(id)
+                        .action(Action::Capture(false));
+                    return true;
+                }
+
+                false
+            })
+    }
+}
+
+fn main() {
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - raw input example")
+                .position((100.0, 100.0))
+                .size(420.0, 200.0)
+                .child(MainView::new().margin(4.0).build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
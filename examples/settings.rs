@@ -31,9 +31,7 @@ impl State for MainViewState {
             match action {
                 Action::Load => {
                     // load label from settings file.
-                    if let Ok(global) = registry
-                        .get::<Settings>("settings")
-                        .load::<Global>("global")
+                    if let Ok(global) = registry.get_required::<Settings>().load::<Global>("global")
                     {
                         main_view(ctx.widget()).set_text(String16::from(global.label));
                     }
@@ -44,7 +42,7 @@ impl State for MainViewState {
                 Action::Save => {
                     // save label to settings file.
                     registry
-                        .get_mut::<Settings>("settings")
+                        .get_or_insert_default::<Settings>()
                         .save(
                             "global",
                             &Global {
@@ -0,0 +1,33 @@
+// Runs in the browser with: cargo node run --target browser --example web
+use orbtk::prelude::*;
+
+widget!(MainView);
+
+impl Template for MainView {
+    fn template(self, _id: Entity, ctx: &mut BuildContext) -> Self {
+        let slider = Slider::new().min(0.0).max(100.0).build(ctx);
+
+        self.name("MainView").child(
+            Stack::new()
+                .spacing(8.0)
+                .margin(16.0)
+                .child(TextBlock::new().text("Volume").font_size(20.0).build(ctx))
+                .child(slider)
+                .child(NumericBox::new().min(0.0).max(100.0).val(slider).build(ctx))
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - web example")
+                .position((100.0, 100.0))
+                .size(420.0, 200.0)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
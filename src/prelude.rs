@@ -12,7 +12,10 @@ pub use crate::{
     api::prelude::*,
     proc_macros::*,
     render::prelude::*,
-    theme::{colors, dark_theme, fonts, light_theme, vector_graphics::material_icons_font},
+    theme::{
+        colors, dark_theme, fonts, light_theme, material_icon_theme,
+        vector_graphics::material_icons_font, IconSource, IconTheme,
+    },
     theming::prelude::*,
     tree::prelude::*,
     utils::prelude::*,